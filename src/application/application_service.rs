@@ -1,127 +1,613 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
-use futures::future::try_join_all;
+use chrono::{DateTime, Duration, Utc};
+use futures::stream::{self, StreamExt, TryStreamExt};
+use futures::Future;
 
-use crate::application::{repository::RepositoryUrlDto, ApplicationError};
-use crate::domain::authentication::{AuthenticationService, GitHubAuthenticationToken};
+use crate::application::repository::{
+    BranchDeletionResults, BranchDto, LocalCleanupReasonDto, MergeStatusDto, PruneCriteriaDto,
+    PrunePlanDto, RepositoryOwnerDto, RepositoryResults, RepositoryUrlDto,
+};
+use crate::application::ApplicationError;
+use crate::domain::authentication::{AuthenticationService, AuthenticationToken};
 use crate::domain::count_branches::BranchCounterService;
 use crate::domain::error::DomainError;
-use crate::domain::repository::{Repository, RepositoryProvider, RepositoryUrl};
+use crate::domain::merged_branches::{MergeStatus, MergedBranchService};
+use crate::domain::repository::{
+    Branch, BranchComparison, LocalRepositoryProvider, Repository, RepositoryCatalog,
+    RepositoryOwner, RepositoryProviderRegistry, RepositoryUrl, UpstreamStatus,
+};
+use crate::domain::stale_branches::StaleBranchService;
 
-pub struct ApplicationService<BranchCounter, GAS, GRP>
+/// Number of requests to repository hosts that are in flight at once unless configured otherwise.
+pub const DEFAULT_CONCURRENCY: usize = 8;
+
+pub struct ApplicationService<BranchCounter, StaleBranchFinder, MergedBranchFinder, Auth, GRC>
 where
     BranchCounter: BranchCounterService,
-    GAS: AuthenticationService<AuthenticationCredentials = GitHubAuthenticationToken>,
-    GRP: RepositoryProvider,
+    StaleBranchFinder: StaleBranchService,
+    MergedBranchFinder: MergedBranchService,
+    Auth: AuthenticationService<AuthenticationCredentials = AuthenticationToken>,
+    GRC: RepositoryCatalog,
 {
     branch_counter_service: BranchCounter,
-    github_authentication_service: GAS,
-    github_repository_provider: GRP,
+    stale_branch_service: StaleBranchFinder,
+    merged_branch_service: MergedBranchFinder,
+    authentication_service: Auth,
+    github_repository_catalog: GRC,
+    repository_providers: RepositoryProviderRegistry,
+    local_repository_provider: Box<dyn LocalRepositoryProvider + Send + Sync>,
+    concurrency: usize,
 }
 
-impl<BranchCounter, GAS, GRP> ApplicationService<BranchCounter, GAS, GRP>
+impl<BranchCounter, StaleBranchFinder, MergedBranchFinder, Auth, GRC>
+    ApplicationService<BranchCounter, StaleBranchFinder, MergedBranchFinder, Auth, GRC>
 where
     BranchCounter: BranchCounterService,
-    GAS: AuthenticationService<AuthenticationCredentials = GitHubAuthenticationToken>,
-    GRP: RepositoryProvider,
+    StaleBranchFinder: StaleBranchService,
+    MergedBranchFinder: MergedBranchService,
+    Auth: AuthenticationService<AuthenticationCredentials = AuthenticationToken>,
+    GRC: RepositoryCatalog,
 {
     pub fn new(
         branch_counter_service: BranchCounter,
-        github_authentication_service: GAS,
-        github_repository_provider: GRP,
+        stale_branch_service: StaleBranchFinder,
+        merged_branch_service: MergedBranchFinder,
+        authentication_service: Auth,
+        github_repository_catalog: GRC,
+        repository_providers: RepositoryProviderRegistry,
+        local_repository_provider: Box<dyn LocalRepositoryProvider + Send + Sync>,
     ) -> Self {
         ApplicationService {
             branch_counter_service,
-            github_authentication_service,
-            github_repository_provider,
+            stale_branch_service,
+            merged_branch_service,
+            authentication_service,
+            github_repository_catalog,
+            repository_providers,
+            local_repository_provider,
+            concurrency: DEFAULT_CONCURRENCY,
         }
     }
 
-    async fn get_repositories(
+    /// Limits how many repositories, owners or branches are processed at the same time.
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    fn unique_domain_urls(repository_urls: Vec<RepositoryUrlDto>) -> Vec<RepositoryUrl> {
+        let mut seen = HashSet::new();
+        repository_urls
+            .into_iter()
+            .map(RepositoryUrl::from)
+            .filter(|url| seen.insert(url.clone()))
+            .collect()
+    }
+
+    async fn get_repository(&self, url: &RepositoryUrl) -> Result<Repository, DomainError> {
+        Ok(self
+            .repository_providers
+            .provider_for(url)?
+            .get_repository(url)
+            .await?)
+    }
+
+    async fn get_repository_with_head_commits(
+        &self,
+        url: &RepositoryUrl,
+    ) -> Result<Repository, DomainError> {
+        Ok(self
+            .repository_providers
+            .provider_for(url)?
+            .get_repository_with_head_commits(url)
+            .await?)
+    }
+
+    /// Runs `operation` for every unique repository, at most `concurrency` at a time. Unless
+    /// `fail_fast` is set, a failing repository is recorded alongside the others instead of
+    /// aborting the whole run.
+    async fn for_each_repository<T, F, Fut>(
         &self,
         repository_urls: Vec<RepositoryUrlDto>,
-    ) -> Result<Vec<Repository>, DomainError> {
-        let domain_urls: Vec<RepositoryUrl> = repository_urls
+        fail_fast: bool,
+        operation: F,
+    ) -> Result<Vec<(RepositoryUrl, Result<T, DomainError>)>, DomainError>
+    where
+        F: Fn(RepositoryUrl) -> Fut,
+        Fut: Future<Output = Result<T, DomainError>>,
+    {
+        let domain_urls = Self::unique_domain_urls(repository_urls);
+        let operations: Vec<Fut> = domain_urls.iter().cloned().map(&operation).collect();
+        let operations = stream::iter(operations).buffered(self.concurrency);
+        let results: Vec<Result<T, DomainError>> = if fail_fast {
+            operations
+                .try_collect::<Vec<T>>()
+                .await?
+                .into_iter()
+                .map(Ok)
+                .collect()
+        } else {
+            operations.collect().await
+        };
+
+        Ok(domain_urls.into_iter().zip(results).collect())
+    }
+
+    /// Keys the results by the canonical URL of each repository, so the key does not depend on
+    /// which spelling of a repository was passed first.
+    fn into_repository_results<T>(
+        results: Vec<(RepositoryUrl, Result<T, DomainError>)>,
+    ) -> RepositoryResults<T> {
+        results
+            .into_iter()
+            .map(|(url, result)| {
+                (
+                    url.canonical().into(),
+                    result.map_err(ApplicationError::from),
+                )
+            })
+            .collect()
+    }
+
+    /// Branches with a head commit older than the cutoff are stale. An age reaching back before
+    /// the earliest representable date makes no branch stale.
+    fn stale_cutoff(older_than: Duration) -> DateTime<Utc> {
+        Utc::now()
+            .checked_sub_signed(older_than)
+            .unwrap_or(DateTime::<Utc>::MIN_UTC)
+    }
+
+    fn select_reportable_branches(repository: Repository, include_protected: bool) -> Repository {
+        if include_protected {
+            repository
+        } else {
+            repository.without_protected_branches()
+        }
+    }
+
+    async fn compare_branches_with_default_branch(
+        &self,
+        repository: &Repository,
+        candidates: &[Branch],
+    ) -> Result<Vec<BranchComparison>, DomainError> {
+        let url = repository.url();
+        let default_branch = match repository.default_branch() {
+            Some(default_branch) => default_branch,
+            None => return Ok(vec![]),
+        };
+        let provider = self.repository_providers.provider_for(url)?;
+        let comparisons: Vec<_> = candidates
             .iter()
-            .cloned()
-            .map(RepositoryUrlDto::into)
+            .filter(|branch| !branch.is_default())
+            .map(|branch| provider.compare_branches(url, default_branch, branch))
             .collect();
-        try_join_all(
-            domain_urls
-                .iter()
-                .map(|url| self.github_repository_provider.get_repository(url)),
-        )
-        .await
-        .map_err(DomainError::from)
+        stream::iter(comparisons)
+            .buffered(self.concurrency)
+            .try_collect()
+            .await
+            .map_err(DomainError::from)
+    }
+
+    pub async fn list_repositories_of_owners(
+        &self,
+        owners: Vec<RepositoryOwnerDto>,
+    ) -> Result<Vec<RepositoryUrlDto>, ApplicationError> {
+        let owners: Vec<RepositoryOwner> = owners.into_iter().map(RepositoryOwner::from).collect();
+        let listings: Vec<_> = owners
+            .iter()
+            .map(|owner| self.github_repository_catalog.list_repositories(owner))
+            .collect();
+        let repository_urls: Vec<Vec<RepositoryUrl>> = stream::iter(listings)
+            .buffered(self.concurrency)
+            .try_collect()
+            .await
+            .map_err(DomainError::from)?;
+
+        Ok(repository_urls
+            .into_iter()
+            .flatten()
+            .map(RepositoryUrlDto::from)
+            .collect())
     }
 
     pub async fn count_branches_in_repositories(
         &self,
         repository_urls: Vec<RepositoryUrlDto>,
-    ) -> Result<HashMap<RepositoryUrlDto, u32>, ApplicationError> {
-        let repositories = self.get_repositories(repository_urls).await?;
+        fail_fast: bool,
+    ) -> Result<RepositoryResults<u32>, ApplicationError> {
+        let results = self
+            .for_each_repository(repository_urls, fail_fast, |url| async move {
+                let repository = self.get_repository(&url).await?;
+                Ok(self
+                    .branch_counter_service
+                    .count_branches_in_repositories(vec![repository])
+                    .into_iter()
+                    .map(|(_, count)| count)
+                    .sum())
+            })
+            .await?;
 
-        Ok(self
-            .branch_counter_service
-            .count_branches_in_repositories(repositories)
-            .iter()
-            .map(|(repository, count)| (repository.url().clone().into(), *count))
-            .collect())
+        Ok(Self::into_repository_results(results))
     }
 
-    pub async fn authenticate_app_with_github(
+    pub async fn find_stale_branches_in_repositories(
         &self,
-        github_token: String,
-    ) -> Result<(), ApplicationError> {
-        self.github_authentication_service
-            .authenticate(GitHubAuthenticationToken::new(github_token))
+        repository_urls: Vec<RepositoryUrlDto>,
+        older_than: Duration,
+        include_protected: bool,
+        fail_fast: bool,
+    ) -> Result<RepositoryResults<Vec<BranchDto>>, ApplicationError> {
+        let cutoff = Self::stale_cutoff(older_than);
+        let results = self
+            .for_each_repository(repository_urls, fail_fast, |url| async move {
+                let repository = Self::select_reportable_branches(
+                    self.get_repository_with_head_commits(&url).await?,
+                    include_protected,
+                );
+                Ok(self
+                    .stale_branch_service
+                    .find_stale_branches_in_repositories(vec![repository], cutoff)
+                    .into_iter()
+                    .flat_map(|(_, branches)| branches)
+                    .map(BranchDto::from)
+                    .collect())
+            })
+            .await?;
+
+        Ok(Self::into_repository_results(results))
+    }
+
+    pub async fn find_merged_branches_in_repositories(
+        &self,
+        repository_urls: Vec<RepositoryUrlDto>,
+        include_protected: bool,
+        fail_fast: bool,
+    ) -> Result<RepositoryResults<Vec<(BranchDto, MergeStatusDto)>>, ApplicationError> {
+        let results = self
+            .for_each_repository(repository_urls, fail_fast, |url| async move {
+                let repository = self.get_repository(&url).await?;
+                let candidates =
+                    Self::select_reportable_branches(repository.clone(), include_protected);
+                let comparisons = self
+                    .compare_branches_with_default_branch(&repository, candidates.branches())
+                    .await?;
+                Ok(self
+                    .merged_branch_service
+                    .merge_statuses(comparisons)
+                    .into_iter()
+                    .map(|(branch, status)| (branch.into(), status.into()))
+                    .collect())
+            })
+            .await?;
+
+        Ok(Self::into_repository_results(results))
+    }
+
+    pub async fn authenticate_app(&self, token: String) -> Result<(), ApplicationError> {
+        self.authentication_service
+            .authenticate(AuthenticationToken::new(token))
             .await
             .map_err(DomainError::from)
             .map_err(ApplicationError::from)
     }
+
+    async fn find_prunable_branches(
+        &self,
+        url: &RepositoryUrl,
+        criteria: PruneCriteriaDto,
+    ) -> Result<Vec<Branch>, DomainError> {
+        let provider = self.repository_providers.provider_for(url)?;
+        let repository = match criteria.older_than() {
+            Some(_) => provider.get_repository_with_head_commits(url).await?,
+            None => provider.get_repository(url).await?,
+        };
+        let mut candidates: Vec<Branch> =
+            Self::select_reportable_branches(repository.clone(), criteria.include_protected())
+                .branches()
+                .clone();
+
+        if let Some(older_than) = criteria.older_than() {
+            candidates = self
+                .stale_branch_service
+                .find_stale_branches_in_repositories(
+                    vec![Repository::new(url.clone(), candidates)],
+                    Self::stale_cutoff(older_than),
+                )
+                .into_iter()
+                .flat_map(|(_, branches)| branches)
+                .collect();
+        }
+
+        if criteria.merged() {
+            let comparisons = self
+                .compare_branches_with_default_branch(&repository, &candidates)
+                .await?;
+            candidates = self
+                .merged_branch_service
+                .merge_statuses(comparisons)
+                .into_iter()
+                .filter(|(_, status)| *status == MergeStatus::Merged)
+                .map(|(branch, _)| branch)
+                .collect();
+        }
+
+        Ok(candidates)
+    }
+
+    pub async fn plan_branch_pruning(
+        &self,
+        repository_urls: Vec<RepositoryUrlDto>,
+        criteria: PruneCriteriaDto,
+        fail_fast: bool,
+    ) -> Result<RepositoryResults<PrunePlanDto>, ApplicationError> {
+        let results = self
+            .for_each_repository(repository_urls, fail_fast, |url| async move {
+                let branches = self.find_prunable_branches(&url, criteria).await?;
+                Ok(PrunePlanDto::new(
+                    url.into(),
+                    branches.into_iter().map(BranchDto::from).collect(),
+                ))
+            })
+            .await?;
+
+        Ok(Self::into_repository_results(results))
+    }
+
+    /// Deletes every branch of a pruning plan, carrying on past branches that fail to delete.
+    /// Branches are deleted through the URL each repository was given as and reported under the
+    /// plan's key.
+    pub async fn prune_branches(
+        &self,
+        plan: &HashMap<RepositoryUrlDto, PrunePlanDto>,
+    ) -> BranchDeletionResults {
+        let deletions: Vec<(&RepositoryUrlDto, &PrunePlanDto, &BranchDto)> = plan
+            .iter()
+            .flat_map(|(url, repository_plan)| {
+                repository_plan
+                    .branches()
+                    .iter()
+                    .map(move |branch| (url, repository_plan, branch))
+            })
+            .collect();
+        let requests: Vec<_> = deletions
+            .iter()
+            .map(|(url, repository_plan, branch)| async move {
+                let domain_url = RepositoryUrl::from(repository_plan.repository_url().clone());
+                let result = match self.repository_providers.provider_for(&domain_url) {
+                    Ok(provider) => {
+                        provider
+                            .delete_branch(&domain_url, &Branch::new(branch.name().clone()))
+                            .await
+                    }
+                    Err(err) => Err(err),
+                };
+                (
+                    (*url).clone(),
+                    (*branch).clone(),
+                    result.map_err(|err| ApplicationError::from(DomainError::from(err))),
+                )
+            })
+            .collect();
+        stream::iter(requests)
+            .buffer_unordered(self.concurrency)
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect()
+    }
+
+    /// Turns the URL of a remote of the clone in `directory` into the repository to process.
+    pub async fn detect_repository_url(
+        &self,
+        directory: RepositoryUrlDto,
+        remote: &str,
+    ) -> Result<RepositoryUrlDto, ApplicationError> {
+        Ok(self
+            .local_repository_provider
+            .remote_url(&RepositoryUrl::from(directory), remote)
+            .await
+            .map_err(DomainError::from)?
+            .into())
+    }
+
+    /// Finds the branches of a clone on this machine whose upstream is gone or that are merged
+    /// into its default branch. The default branch and the checked out branch are never listed.
+    pub async fn plan_local_branch_cleanup(
+        &self,
+        url: RepositoryUrlDto,
+    ) -> Result<Vec<(BranchDto, LocalCleanupReasonDto)>, ApplicationError> {
+        let url = RepositoryUrl::from(url);
+        let repository = self
+            .local_repository_provider
+            .get_local_repository(&url)
+            .await
+            .map_err(DomainError::from)?;
+        let (gone, tracked): (Vec<_>, Vec<_>) = repository
+            .deletable_branches()
+            .partition(|branch| branch.upstream() == UpstreamStatus::Gone);
+
+        let mut cleanup: Vec<(BranchDto, LocalCleanupReasonDto)> = gone
+            .into_iter()
+            .map(|branch| {
+                (
+                    branch.branch().clone().into(),
+                    LocalCleanupReasonDto::UpstreamGone,
+                )
+            })
+            .collect();
+        if let Some(default_branch) = repository.default_branch() {
+            let comparisons: Vec<_> = tracked
+                .iter()
+                .map(|branch| {
+                    self.local_repository_provider.compare_branches(
+                        &url,
+                        default_branch,
+                        branch.branch(),
+                    )
+                })
+                .collect();
+            let comparisons: Vec<BranchComparison> = stream::iter(comparisons)
+                .buffered(self.concurrency)
+                .try_collect()
+                .await
+                .map_err(DomainError::from)?;
+            cleanup.extend(
+                self.merged_branch_service
+                    .merge_statuses(comparisons)
+                    .into_iter()
+                    .filter(|(_, status)| *status == MergeStatus::Merged)
+                    .map(|(branch, _)| (branch.into(), LocalCleanupReasonDto::Merged)),
+            );
+        }
+
+        Ok(cleanup)
+    }
+
+    /// Deletes the given branches of a local clone, carrying on past branches that fail to
+    /// delete.
+    pub async fn delete_local_branches(
+        &self,
+        url: RepositoryUrlDto,
+        branches: &[BranchDto],
+    ) -> BranchDeletionResults {
+        let domain_url = RepositoryUrl::from(url.clone());
+        let mut results = Vec::new();
+        for branch in branches {
+            let result = self
+                .local_repository_provider
+                .delete_branch(&domain_url, &Branch::new(branch.name().clone()))
+                .await
+                .map_err(|err| ApplicationError::from(DomainError::from(err)));
+            results.push((url.clone(), branch.clone(), result));
+        }
+
+        results.into_iter().collect()
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use chrono::TimeZone;
     use mockall::predicate::eq;
     use spectral::prelude::*;
 
+    use crate::application::repository::CommitDto;
     use crate::domain::authentication::credential_repository::CredentialRepositoryError;
     use crate::domain::authentication::{AuthenticationError, MockAuthenticationService};
     use crate::domain::count_branches::BranchCounterServiceImpl;
-    use crate::domain::repository::Branch;
-    use crate::domain::repository::MockRepositoryProvider;
+    use crate::domain::merged_branches::MergedBranchServiceImpl;
+    use crate::domain::repository::{
+        Commit, LocalBranch, LocalRepository, MockLocalRepositoryProvider, MockRepositoryCatalog,
+        RepositoryMaintainer, RepositoryProvider, RepositoryProviderError,
+    };
+    use crate::domain::stale_branches::StaleBranchServiceImpl;
+    use crate::domain::value_object::ValueObject;
     use crate::utils::test_helpers::async_this;
 
     use super::*;
 
-    type MockGitHubAuthenticationService = MockAuthenticationService<GitHubAuthenticationToken>;
+    type MockTokenAuthenticationService = MockAuthenticationService<AuthenticationToken>;
+
+    mockall::mock! {
+        RepositoryHost {}
+
+        #[async_trait::async_trait]
+        impl RepositoryProvider for RepositoryHost {
+            async fn get_repository(
+                &self,
+                url: &RepositoryUrl,
+            ) -> Result<Repository, RepositoryProviderError>;
+
+            async fn get_repository_with_head_commits(
+                &self,
+                url: &RepositoryUrl,
+            ) -> Result<Repository, RepositoryProviderError>;
+
+            async fn compare_branches(
+                &self,
+                url: &RepositoryUrl,
+                base: &Branch,
+                head: &Branch,
+            ) -> Result<BranchComparison, RepositoryProviderError>;
+        }
+
+        #[async_trait::async_trait]
+        impl RepositoryMaintainer for RepositoryHost {
+            async fn delete_branch(
+                &self,
+                url: &RepositoryUrl,
+                branch: &Branch,
+            ) -> Result<(), RepositoryProviderError>;
+        }
+    }
+
+    const URL: &str = "https://github.com/owner/repo";
+
+    fn numbered_url(n: u32) -> String {
+        format!("https://github.com/owner/{}", n)
+    }
+
+    fn github_only(github_repository_provider: MockRepositoryHost) -> RepositoryProviderRegistry {
+        RepositoryProviderRegistry::new()
+            .with_provider(vec!["github.com".to_string()], github_repository_provider)
+    }
 
     fn under_test<BCS: BranchCounterService>(
         branch_counter_service: BCS,
-        github_authentication_service: MockGitHubAuthenticationService,
-        github_repository_provider: MockRepositoryProvider,
-    ) -> ApplicationService<BCS, MockGitHubAuthenticationService, MockRepositoryProvider> {
+        authentication_service: MockTokenAuthenticationService,
+        github_repository_provider: MockRepositoryHost,
+    ) -> ApplicationService<
+        BCS,
+        StaleBranchServiceImpl,
+        MergedBranchServiceImpl,
+        MockTokenAuthenticationService,
+        MockRepositoryCatalog,
+    > {
         ApplicationService::new(
             branch_counter_service,
-            github_authentication_service,
-            github_repository_provider,
+            StaleBranchServiceImpl::new(),
+            MergedBranchServiceImpl::new(),
+            authentication_service,
+            MockRepositoryCatalog::default(),
+            github_only(github_repository_provider),
+            Box::new(MockLocalRepositoryProvider::default()),
         )
     }
 
     fn repository_with_branches(n: u32) -> Repository {
         Repository::new(
-            RepositoryUrl::new(n.to_string()),
+            RepositoryUrl::new(numbered_url(n)),
             (0..n).map(|index| Branch::new(index.to_string())).collect(),
         )
     }
 
+    fn date(year: i32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, 1, 1, 0, 0, 0).unwrap()
+    }
+
+    fn commit(year: i32) -> Commit {
+        Commit::new("sha".to_string(), date(year), "author".to_string())
+    }
+
+    fn repository_with_branches_committed_in(url: &str, years: Vec<i32>) -> Repository {
+        Repository::new(
+            RepositoryUrl::new(url.to_string()),
+            years
+                .iter()
+                .map(|&year| Branch::with_head_commit(year.to_string(), commit(year)))
+                .collect(),
+        )
+    }
+
     fn repository_error() -> CredentialRepositoryError {
         CredentialRepositoryError::FailedToGetCredential
     }
 
     fn prepare_mock_repository_provider(
-        mock: &mut MockRepositoryProvider,
+        mock: &mut MockRepositoryHost,
         urls_and_branch_counts: Vec<(RepositoryUrl, u32)>,
     ) {
         urls_and_branch_counts
@@ -130,7 +616,7 @@ mod tests {
             .for_each(|(url, count)| {
                 mock.expect_get_repository()
                     .with(eq(url))
-                    .returning(move |_| Box::pin(async_this(Ok(repository_with_branches(count)))));
+                    .returning(move |_| Ok(repository_with_branches(count)));
             });
     }
 
@@ -152,51 +638,685 @@ mod tests {
     #[async_std::test]
     async fn counts_branches_in_list_of_repositories() {
         let branch_counter_service = BranchCounterServiceImpl::new();
-        let mock_github_authentication_service = MockGitHubAuthenticationService::default();
-        let mut mock_github_repository_provider = MockRepositoryProvider::default();
+        let mock_authentication_service = MockTokenAuthenticationService::default();
+        let mut mock_github_repository_provider = MockRepositoryHost::default();
         prepare_mock_repository_provider(
             &mut mock_github_repository_provider,
             vec![
-                (RepositoryUrl::new("1".to_string()), 1),
-                (RepositoryUrl::new("2".to_string()), 2),
+                (RepositoryUrl::new(numbered_url(1)), 1),
+                (RepositoryUrl::new(numbered_url(2)), 2),
             ],
         );
 
         assert_that(
             &under_test(
                 branch_counter_service,
-                mock_github_authentication_service,
+                mock_authentication_service,
+                mock_github_repository_provider,
+            )
+            .count_branches_in_repositories(
+                vec![
+                    RepositoryUrlDto::new(numbered_url(1)),
+                    RepositoryUrlDto::new(numbered_url(2)),
+                ],
+                false,
+            )
+            .await
+            .unwrap()
+            .successes(),
+        )
+        .is_equal_to(&to_hash_map(vec![
+            (RepositoryUrlDto::new(numbered_url(1)), 1u32),
+            (RepositoryUrlDto::new(numbered_url(2)), 2u32),
+        ]));
+    }
+
+    #[async_std::test]
+    async fn counts_branches_once_per_repository_identity() {
+        let mut mock_github_repository_provider = MockRepositoryHost::default();
+        mock_github_repository_provider
+            .expect_get_repository()
+            .with(eq(RepositoryUrl::new(URL.to_string())))
+            .times(1)
+            .returning(|url| {
+                Ok(Repository::new(
+                    url.clone(),
+                    vec![Branch::new("main".to_string())],
+                ))
+            });
+
+        assert_that(
+            &under_test(
+                BranchCounterServiceImpl::new(),
+                MockTokenAuthenticationService::default(),
                 mock_github_repository_provider,
             )
-            .count_branches_in_repositories(to_urls(vec!["1", "2"]))
+            .count_branches_in_repositories(
+                to_urls(vec![
+                    "github.com/owner/repo",
+                    URL,
+                    "https://github.com/Owner/repo.git",
+                ]),
+                false,
+            )
             .await
-            .unwrap(),
+            .unwrap()
+            .successes(),
+        )
+        .is_equal_to(&to_hash_map(vec![(
+            RepositoryUrlDto::new(URL.to_string()),
+            1u32,
+        )]));
+    }
+
+    #[async_std::test]
+    async fn finds_stale_branches_in_list_of_repositories() {
+        let branch_counter_service = BranchCounterServiceImpl::new();
+        let mock_authentication_service = MockTokenAuthenticationService::default();
+        let mut mock_github_repository_provider = MockRepositoryHost::default();
+        mock_github_repository_provider
+            .expect_get_repository_with_head_commits()
+            .with(eq(RepositoryUrl::new(URL.to_string())))
+            .returning(|_| Ok(repository_with_branches_committed_in(URL, vec![2000, 2999])));
+
+        let result = under_test(
+            branch_counter_service,
+            mock_authentication_service,
+            mock_github_repository_provider,
+        )
+        .find_stale_branches_in_repositories(to_urls(vec![URL]), Duration::days(90), false, false)
+        .await
+        .unwrap();
+
+        assert_that(
+            &result
+                .successes()
+                .get(&RepositoryUrlDto::new(URL.to_string())),
+        )
+        .is_some()
+        .is_equal_to(&vec![BranchDto::new(
+            "2000".to_string(),
+            Some(CommitDto::new(
+                "sha".to_string(),
+                date(2000),
+                "author".to_string(),
+            )),
+        )]);
+    }
+
+    #[async_std::test]
+    async fn finds_no_stale_branches_older_than_earliest_representable_date() {
+        let branch_counter_service = BranchCounterServiceImpl::new();
+        let mock_authentication_service = MockTokenAuthenticationService::default();
+        let mut mock_github_repository_provider = MockRepositoryHost::default();
+        mock_github_repository_provider
+            .expect_get_repository_with_head_commits()
+            .returning(|_| Ok(repository_with_branches_committed_in(URL, vec![2000])));
+
+        let result = under_test(
+            branch_counter_service,
+            mock_authentication_service,
+            mock_github_repository_provider,
+        )
+        .find_stale_branches_in_repositories(to_urls(vec![URL]), Duration::MAX, false, false)
+        .await
+        .unwrap();
+
+        assert_that(
+            &result
+                .successes()
+                .get(&RepositoryUrlDto::new(URL.to_string())),
+        )
+        .is_some()
+        .is_equal_to(&vec![]);
+    }
+
+    fn repository_with_protected_and_default_branches(url: &RepositoryUrl) -> Repository {
+        Repository::new(
+            url.clone(),
+            vec![
+                Branch::new("main".to_string()).marked_default(true),
+                Branch::new("release".to_string()).marked_protected(true),
+                Branch::new("merged".to_string()),
+                Branch::new("unmerged".to_string()),
+            ],
+        )
+    }
+
+    fn comparison_with_main(head: &Branch) -> BranchComparison {
+        let ahead_by = if head.value() == "unmerged" { 1 } else { 0 };
+        BranchComparison::new(head.clone(), ahead_by, 0)
+    }
+
+    fn prepare_mock_repository_provider_for_merge_comparison(mock: &mut MockRepositoryHost) {
+        mock.expect_get_repository()
+            .with(eq(RepositoryUrl::new(URL.to_string())))
+            .returning(|url| Ok(repository_with_protected_and_default_branches(url)));
+        mock.expect_compare_branches()
+            .withf(|_, base, head| base.value() == "main" && head.value() != "main")
+            .returning(|_, _, head| Ok(comparison_with_main(head)));
+    }
+
+    #[async_std::test]
+    async fn finds_merged_branches_including_protected_branches_when_requested() {
+        let branch_counter_service = BranchCounterServiceImpl::new();
+        let mock_authentication_service = MockTokenAuthenticationService::default();
+        let mut mock_github_repository_provider = MockRepositoryHost::default();
+        prepare_mock_repository_provider_for_merge_comparison(&mut mock_github_repository_provider);
+
+        let result = under_test(
+            branch_counter_service,
+            mock_authentication_service,
+            mock_github_repository_provider,
+        )
+        .find_merged_branches_in_repositories(to_urls(vec![URL]), true, false)
+        .await
+        .unwrap();
+
+        assert_that(
+            &result
+                .successes()
+                .get(&RepositoryUrlDto::new(URL.to_string())),
+        )
+        .is_some()
+        .is_equal_to(&vec![
+            (
+                BranchDto::new("release".to_string(), None),
+                MergeStatusDto::Merged,
+            ),
+            (
+                BranchDto::new("merged".to_string(), None),
+                MergeStatusDto::Merged,
+            ),
+            (
+                BranchDto::new("unmerged".to_string(), None),
+                MergeStatusDto::Unmerged,
+            ),
+        ]);
+    }
+
+    #[async_std::test]
+    async fn finds_merged_branches_in_list_of_repositories() {
+        let branch_counter_service = BranchCounterServiceImpl::new();
+        let mock_authentication_service = MockTokenAuthenticationService::default();
+        let mut mock_github_repository_provider = MockRepositoryHost::default();
+        prepare_mock_repository_provider_for_merge_comparison(&mut mock_github_repository_provider);
+
+        let result = under_test(
+            branch_counter_service,
+            mock_authentication_service,
+            mock_github_repository_provider,
+        )
+        .find_merged_branches_in_repositories(to_urls(vec![URL]), false, false)
+        .await
+        .unwrap();
+
+        assert_that(
+            &result
+                .successes()
+                .get(&RepositoryUrlDto::new(URL.to_string())),
+        )
+        .is_some()
+        .is_equal_to(&vec![
+            (
+                BranchDto::new("merged".to_string(), None),
+                MergeStatusDto::Merged,
+            ),
+            (
+                BranchDto::new("unmerged".to_string(), None),
+                MergeStatusDto::Unmerged,
+            ),
+        ]);
+    }
+
+    fn with_repository_providers(
+        repository_providers: RepositoryProviderRegistry,
+    ) -> ApplicationService<
+        BranchCounterServiceImpl,
+        StaleBranchServiceImpl,
+        MergedBranchServiceImpl,
+        MockTokenAuthenticationService,
+        MockRepositoryCatalog,
+    > {
+        ApplicationService::new(
+            BranchCounterServiceImpl::new(),
+            StaleBranchServiceImpl::new(),
+            MergedBranchServiceImpl::new(),
+            MockTokenAuthenticationService::default(),
+            MockRepositoryCatalog::default(),
+            repository_providers,
+            Box::new(MockLocalRepositoryProvider::default()),
+        )
+    }
+
+    fn prune_under_test(
+        repository_host: MockRepositoryHost,
+    ) -> ApplicationService<
+        BranchCounterServiceImpl,
+        StaleBranchServiceImpl,
+        MergedBranchServiceImpl,
+        MockTokenAuthenticationService,
+        MockRepositoryCatalog,
+    > {
+        with_repository_providers(github_only(repository_host))
+    }
+
+    #[async_std::test]
+    async fn counts_branches_in_repositories_hosted_by_different_providers() {
+        let gitlab_url = "https://gitlab.example.com/group/project";
+        let mut mock_github_repository_provider = MockRepositoryHost::default();
+        prepare_mock_repository_provider(
+            &mut mock_github_repository_provider,
+            vec![(RepositoryUrl::new(numbered_url(1)), 1)],
+        );
+        let mut mock_gitlab_repository_provider = MockRepositoryHost::default();
+        mock_gitlab_repository_provider
+            .expect_get_repository()
+            .with(eq(RepositoryUrl::new(gitlab_url.to_string())))
+            .returning(|url| Ok(Repository::new(url.clone(), vec![])));
+        let repository_providers = github_only(mock_github_repository_provider).with_provider(
+            vec!["gitlab.example.com".to_string()],
+            mock_gitlab_repository_provider,
+        );
+
+        assert_that(
+            &with_repository_providers(repository_providers)
+                .count_branches_in_repositories(
+                    vec![
+                        RepositoryUrlDto::new(numbered_url(1)),
+                        RepositoryUrlDto::new(gitlab_url.to_string()),
+                    ],
+                    false,
+                )
+                .await
+                .unwrap()
+                .successes(),
         )
         .is_equal_to(&to_hash_map(vec![
-            (RepositoryUrlDto::new("1".to_string()), 1u32),
-            (RepositoryUrlDto::new("2".to_string()), 2u32),
+            (RepositoryUrlDto::new(numbered_url(1)), 1u32),
+            (RepositoryUrlDto::new(gitlab_url.to_string()), 0u32),
+        ]));
+    }
+
+    #[async_std::test]
+    async fn fails_to_count_branches_in_repository_on_unknown_host() {
+        let unknown_url = RepositoryUrlDto::new("https://example.com/owner/repo".to_string());
+        let result = with_repository_providers(github_only(MockRepositoryHost::default()))
+            .count_branches_in_repositories(vec![unknown_url.clone()], false)
+            .await
+            .unwrap();
+
+        assert_that(&result.failures().get(&unknown_url).unwrap().to_string())
+            .is_equal_to("no provider for host 'example.com'".to_string());
+    }
+
+    fn prepare_mock_repository_provider_with_missing_repository(mock: &mut MockRepositoryHost) {
+        prepare_mock_repository_provider(mock, vec![(RepositoryUrl::new(numbered_url(1)), 1)]);
+        mock.expect_get_repository()
+            .with(eq(RepositoryUrl::new(URL.to_string())))
+            .returning(|url| {
+                Err(RepositoryProviderError::NotFound {
+                    repository: Some(url.identity().clone()),
+                    message: "not found".to_string(),
+                })
+            });
+    }
+
+    #[async_std::test]
+    async fn counts_branches_in_remaining_repositories_when_one_fails() {
+        let mut mock_github_repository_provider = MockRepositoryHost::default();
+        prepare_mock_repository_provider_with_missing_repository(
+            &mut mock_github_repository_provider,
+        );
+
+        let result = with_repository_providers(github_only(mock_github_repository_provider))
+            .count_branches_in_repositories(to_urls(vec![&numbered_url(1), URL]), false)
+            .await
+            .unwrap();
+
+        assert_that(result.successes()).is_equal_to(to_hash_map(vec![(
+            RepositoryUrlDto::new(numbered_url(1)),
+            1u32,
+        )]));
+        assert_that(
+            &result
+                .failures()
+                .get(&RepositoryUrlDto::new(URL.to_string()))
+                .unwrap()
+                .to_string(),
+        )
+        .is_equal_to("not found".to_string());
+    }
+
+    #[async_std::test]
+    async fn stops_counting_branches_at_first_failure_when_failing_fast() {
+        let mut mock_github_repository_provider = MockRepositoryHost::default();
+        prepare_mock_repository_provider_with_missing_repository(
+            &mut mock_github_repository_provider,
+        );
+
+        let result = with_repository_providers(github_only(mock_github_repository_provider))
+            .count_branches_in_repositories(to_urls(vec![&numbered_url(1), URL]), true)
+            .await;
+
+        assert_that(&result.err().unwrap().to_string()).is_equal_to("not found".to_string());
+    }
+
+    #[async_std::test]
+    async fn processes_at_most_configured_number_of_repositories_at_once() {
+        let in_flight = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+        let under_test =
+            with_repository_providers(RepositoryProviderRegistry::new()).with_concurrency(2);
+
+        let results = under_test
+            .for_each_repository(
+                (1..=5)
+                    .map(|n| RepositoryUrlDto::new(numbered_url(n)))
+                    .collect(),
+                false,
+                |url| {
+                    let (in_flight, peak) = (&in_flight, &peak);
+                    async move {
+                        peak.fetch_max(
+                            in_flight.fetch_add(1, Ordering::SeqCst) + 1,
+                            Ordering::SeqCst,
+                        );
+                        async_std::task::yield_now().await;
+                        in_flight.fetch_sub(1, Ordering::SeqCst);
+                        Ok(url)
+                    }
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_that(&results).has_length(5);
+        assert_that(&peak.load(Ordering::SeqCst)).is_equal_to(2);
+    }
+
+    #[async_std::test]
+    async fn plans_pruning_of_merged_branches_excluding_protected_and_default_branches() {
+        let mut mock_repository_host = MockRepositoryHost::default();
+        mock_repository_host
+            .expect_get_repository()
+            .with(eq(RepositoryUrl::new(URL.to_string())))
+            .returning(|url| Ok(repository_with_protected_and_default_branches(url)));
+        mock_repository_host
+            .expect_compare_branches()
+            .withf(|_, base, head| base.value() == "main" && head.value() != "main")
+            .returning(|_, _, head| Ok(comparison_with_main(head)));
+
+        let result = prune_under_test(mock_repository_host)
+            .plan_branch_pruning(
+                to_urls(vec![URL]),
+                PruneCriteriaDto::new(true, None, false),
+                false,
+            )
+            .await
+            .unwrap();
+
+        assert_that(
+            &result
+                .successes()
+                .get(&RepositoryUrlDto::new(URL.to_string()))
+                .map(PrunePlanDto::branches),
+        )
+        .is_some()
+        .is_equal_to(&vec![BranchDto::new("merged".to_string(), None)]);
+    }
+
+    #[async_std::test]
+    async fn plans_pruning_through_url_repository_was_given_as() {
+        let mut mock_repository_host = MockRepositoryHost::default();
+        mock_repository_host
+            .expect_get_repository()
+            .returning(|url| Ok(Repository::new(url.clone(), vec![])));
+
+        let result = prune_under_test(mock_repository_host)
+            .plan_branch_pruning(
+                to_urls(vec!["git@github.com:Owner/repo.git"]),
+                PruneCriteriaDto::new(false, None, false),
+                false,
+            )
+            .await
+            .unwrap();
+
+        assert_that(
+            &result
+                .successes()
+                .get(&RepositoryUrlDto::new(URL.to_string()))
+                .map(PrunePlanDto::repository_url),
+        )
+        .is_some()
+        .is_equal_to(&RepositoryUrlDto::new(
+            "git@github.com:Owner/repo.git".to_string(),
+        ));
+    }
+
+    #[async_std::test]
+    async fn plans_pruning_of_stale_branches_excluding_protected_and_default_branches() {
+        let mut mock_repository_host = MockRepositoryHost::default();
+        mock_repository_host
+            .expect_get_repository_with_head_commits()
+            .with(eq(RepositoryUrl::new(URL.to_string())))
+            .returning(|url| {
+                Ok(Repository::new(
+                    url.clone(),
+                    vec![
+                        Branch::with_head_commit("1999".to_string(), commit(1999))
+                            .marked_protected(true),
+                        Branch::with_head_commit("2000".to_string(), commit(2000)),
+                        Branch::with_head_commit("2001".to_string(), commit(2001))
+                            .marked_default(true),
+                        Branch::with_head_commit("2999".to_string(), commit(2999)),
+                    ],
+                ))
+            });
+
+        let result = prune_under_test(mock_repository_host)
+            .plan_branch_pruning(
+                to_urls(vec![URL]),
+                PruneCriteriaDto::new(false, Some(Duration::days(90)), false),
+                false,
+            )
+            .await
+            .unwrap();
+
+        assert_that(
+            &result
+                .successes()
+                .get(&RepositoryUrlDto::new(URL.to_string()))
+                .map(PrunePlanDto::branches),
+        )
+        .is_some()
+        .is_equal_to(&vec![BranchDto::new(
+            "2000".to_string(),
+            Some(CommitDto::new(
+                "sha".to_string(),
+                date(2000),
+                "author".to_string(),
+            )),
+        )]);
+    }
+
+    fn prune_plan(url: &str, branches: Vec<&str>) -> PrunePlanDto {
+        PrunePlanDto::new(
+            RepositoryUrlDto::new(url.to_string()),
+            branches
+                .into_iter()
+                .map(|branch| BranchDto::new(branch.to_string(), None))
+                .collect(),
+        )
+    }
+
+    #[async_std::test]
+    async fn prunes_every_planned_branch() {
+        let mut mock_repository_host = MockRepositoryHost::default();
+        mock_repository_host
+            .expect_delete_branch()
+            .with(
+                eq(RepositoryUrl::new(URL.to_string())),
+                eq(Branch::new("merged".to_string())),
+            )
+            .once()
+            .returning(|_, _| Ok(()));
+        let mut plan = HashMap::new();
+        plan.insert(
+            RepositoryUrlDto::new(URL.to_string()),
+            prune_plan(URL, vec!["merged"]),
+        );
+
+        let result = prune_under_test(mock_repository_host)
+            .prune_branches(&plan)
+            .await;
+
+        assert_that(&result.failures().is_empty()).is_true();
+        assert_that(
+            &result
+                .deleted()
+                .get(&RepositoryUrlDto::new(URL.to_string())),
+        )
+        .is_some()
+        .is_equal_to(&vec![BranchDto::new("merged".to_string(), None)]);
+    }
+
+    #[async_std::test]
+    async fn prunes_branches_through_url_repository_was_given_as() {
+        let mut mock_repository_host = MockRepositoryHost::default();
+        mock_repository_host
+            .expect_delete_branch()
+            .withf(|url, _| url.value() == "https://GitHub.com/owner/repo.git")
+            .once()
+            .returning(|_, _| Ok(()));
+        let mut plan = HashMap::new();
+        plan.insert(
+            RepositoryUrlDto::new(URL.to_string()),
+            prune_plan("https://GitHub.com/owner/repo.git", vec!["merged"]),
+        );
+
+        let result = prune_under_test(mock_repository_host)
+            .prune_branches(&plan)
+            .await;
+
+        assert_that(&result.failures().is_empty()).is_true();
+        assert_that(
+            &result
+                .deleted()
+                .contains_key(&RepositoryUrlDto::new(URL.to_string())),
+        )
+        .is_true();
+    }
+
+    #[async_std::test]
+    async fn prunes_remaining_branches_when_one_fails_to_delete() {
+        let mut mock_repository_host = MockRepositoryHost::default();
+        mock_repository_host
+            .expect_delete_branch()
+            .with(
+                eq(RepositoryUrl::new(URL.to_string())),
+                eq(Branch::new("locked".to_string())),
+            )
+            .once()
+            .returning(|_, _| {
+                Err(RepositoryProviderError::Other {
+                    repository: None,
+                    message: "locked".to_string(),
+                })
+            });
+        mock_repository_host
+            .expect_delete_branch()
+            .with(
+                eq(RepositoryUrl::new(URL.to_string())),
+                eq(Branch::new("merged".to_string())),
+            )
+            .once()
+            .returning(|_, _| Ok(()));
+        let mut plan = HashMap::new();
+        plan.insert(
+            RepositoryUrlDto::new(URL.to_string()),
+            prune_plan(URL, vec!["locked", "merged"]),
+        );
+
+        let result = prune_under_test(mock_repository_host)
+            .prune_branches(&plan)
+            .await;
+
+        assert_that(
+            &result
+                .deleted()
+                .get(&RepositoryUrlDto::new(URL.to_string())),
+        )
+        .is_some()
+        .is_equal_to(&vec![BranchDto::new("merged".to_string(), None)]);
+        assert_that(&result.failures().len()).is_equal_to(1);
+        assert_that(&result.failures()[0].1)
+            .is_equal_to(BranchDto::new("locked".to_string(), None));
+    }
+
+    #[async_std::test]
+    async fn lists_repositories_of_organizations_and_users() {
+        let mut mock_repository_catalog = MockRepositoryCatalog::default();
+        mock_repository_catalog
+            .expect_list_repositories()
+            .with(eq(RepositoryOwner::Organization("org".to_string())))
+            .returning(|_| {
+                Box::pin(async_this(Ok(vec![
+                    RepositoryUrl::new("https://github.com/org/repo1".to_string()),
+                    RepositoryUrl::new("https://github.com/org/repo2".to_string()),
+                ])))
+            });
+        mock_repository_catalog
+            .expect_list_repositories()
+            .with(eq(RepositoryOwner::User("someone".to_string())))
+            .returning(|_| {
+                Box::pin(async_this(Ok(vec![RepositoryUrl::new(
+                    "https://github.com/someone/repo".to_string(),
+                )])))
+            });
+        let under_test = ApplicationService::new(
+            BranchCounterServiceImpl::new(),
+            StaleBranchServiceImpl::new(),
+            MergedBranchServiceImpl::new(),
+            MockTokenAuthenticationService::default(),
+            mock_repository_catalog,
+            RepositoryProviderRegistry::new(),
+            Box::new(MockLocalRepositoryProvider::default()),
+        );
+
+        assert_that(
+            &under_test
+                .list_repositories_of_owners(vec![
+                    RepositoryOwnerDto::Organization("org".to_string()),
+                    RepositoryOwnerDto::User("someone".to_string()),
+                ])
+                .await
+                .unwrap(),
+        )
+        .is_equal_to(to_urls(vec![
+            "https://github.com/org/repo1",
+            "https://github.com/org/repo2",
+            "https://github.com/someone/repo",
         ]));
     }
 
     #[async_std::test]
     async fn authenticates_with_github() {
         let branch_counter_service = BranchCounterServiceImpl::new();
-        let mock_github_repository_provider = MockRepositoryProvider::default();
-        let mut mock_github_authentication_service = MockGitHubAuthenticationService::default();
-        mock_github_authentication_service
+        let mock_github_repository_provider = MockRepositoryHost::default();
+        let mut mock_authentication_service = MockTokenAuthenticationService::default();
+        mock_authentication_service
             .expect_authenticate()
-            .with(eq(GitHubAuthenticationToken::new(
-                "credentials".to_string(),
-            )))
+            .with(eq(AuthenticationToken::new("credentials".to_string())))
             .returning(|_| Ok(()));
 
         assert_that(
             &under_test(
                 branch_counter_service,
-                mock_github_authentication_service,
+                mock_authentication_service,
                 mock_github_repository_provider,
             )
-            .authenticate_app_with_github("credentials".to_string())
+            .authenticate_app("credentials".to_string())
             .await,
         )
         .is_ok();
@@ -205,21 +1325,19 @@ mod tests {
     #[async_std::test]
     async fn fails_to_authenticate_with_github_when_persistence_fails() {
         let branch_counter_service = BranchCounterServiceImpl::new();
-        let mock_github_repository_provider = MockRepositoryProvider::default();
-        let mut mock_github_authentication_service = MockGitHubAuthenticationService::default();
-        mock_github_authentication_service
+        let mock_github_repository_provider = MockRepositoryHost::default();
+        let mut mock_authentication_service = MockTokenAuthenticationService::default();
+        mock_authentication_service
             .expect_authenticate()
-            .with(eq(GitHubAuthenticationToken::new(
-                "credentials".to_string(),
-            )))
+            .with(eq(AuthenticationToken::new("credentials".to_string())))
             .returning(|_| Err(AuthenticationError::Persistence(repository_error())));
 
         let result = under_test(
             branch_counter_service,
-            mock_github_authentication_service,
+            mock_authentication_service,
             mock_github_repository_provider,
         )
-        .authenticate_app_with_github("credentials".to_string())
+        .authenticate_app("credentials".to_string())
         .await;
 
         assert_that(&matches!(
@@ -230,4 +1348,196 @@ mod tests {
         ))
         .is_true();
     }
+
+    const LOCAL_URL: &str = "/home/user/project";
+
+    fn local_under_test(
+        local_repository_provider: MockLocalRepositoryProvider,
+    ) -> ApplicationService<
+        BranchCounterServiceImpl,
+        StaleBranchServiceImpl,
+        MergedBranchServiceImpl,
+        MockTokenAuthenticationService,
+        MockRepositoryCatalog,
+    > {
+        ApplicationService::new(
+            BranchCounterServiceImpl::new(),
+            StaleBranchServiceImpl::new(),
+            MergedBranchServiceImpl::new(),
+            MockTokenAuthenticationService::default(),
+            MockRepositoryCatalog::default(),
+            RepositoryProviderRegistry::new(),
+            Box::new(local_repository_provider),
+        )
+    }
+
+    fn local_repository() -> LocalRepository {
+        LocalRepository::new(
+            RepositoryUrl::new(LOCAL_URL.to_string()),
+            vec![
+                LocalBranch::new(Branch::new("main".to_string()).marked_default(true)),
+                LocalBranch::new(Branch::new("current".to_string()))
+                    .with_upstream(UpstreamStatus::Gone)
+                    .marked_checked_out(true),
+                LocalBranch::new(Branch::new("gone".to_string()))
+                    .with_upstream(UpstreamStatus::Gone),
+                LocalBranch::new(Branch::new("merged".to_string()))
+                    .with_upstream(UpstreamStatus::Present),
+                LocalBranch::new(Branch::new("unmerged".to_string())),
+            ],
+        )
+    }
+
+    #[async_std::test]
+    async fn plans_cleanup_of_local_branches_whose_upstream_is_gone_or_that_are_merged() {
+        let mut mock_local_repository_provider = MockLocalRepositoryProvider::default();
+        mock_local_repository_provider
+            .expect_get_local_repository()
+            .with(eq(RepositoryUrl::new(LOCAL_URL.to_string())))
+            .returning(|_| Box::pin(async_this(Ok(local_repository()))));
+        mock_local_repository_provider
+            .expect_compare_branches()
+            .withf(|_, base, head| {
+                base.value() == "main" && ["merged", "unmerged"].contains(&head.value().as_str())
+            })
+            .times(2)
+            .returning(|_, _, head| Box::pin(async_this(Ok(comparison_with_main(head)))));
+
+        let result = local_under_test(mock_local_repository_provider)
+            .plan_local_branch_cleanup(RepositoryUrlDto::new(LOCAL_URL.to_string()))
+            .await
+            .unwrap();
+
+        assert_that(&result).is_equal_to(vec![
+            (
+                BranchDto::new("gone".to_string(), None),
+                LocalCleanupReasonDto::UpstreamGone,
+            ),
+            (
+                BranchDto::new("merged".to_string(), None),
+                LocalCleanupReasonDto::Merged,
+            ),
+        ]);
+    }
+
+    #[async_std::test]
+    async fn only_plans_cleanup_of_branches_whose_upstream_is_gone_without_default_branch() {
+        let mut mock_local_repository_provider = MockLocalRepositoryProvider::default();
+        mock_local_repository_provider
+            .expect_get_local_repository()
+            .returning(|url| {
+                Box::pin(async_this(Ok(LocalRepository::new(
+                    url.clone(),
+                    vec![
+                        LocalBranch::new(Branch::new("gone".to_string()))
+                            .with_upstream(UpstreamStatus::Gone),
+                        LocalBranch::new(Branch::new("other".to_string())),
+                    ],
+                ))))
+            });
+        mock_local_repository_provider
+            .expect_compare_branches()
+            .never();
+
+        let result = local_under_test(mock_local_repository_provider)
+            .plan_local_branch_cleanup(RepositoryUrlDto::new(LOCAL_URL.to_string()))
+            .await
+            .unwrap();
+
+        assert_that(&result).is_equal_to(vec![(
+            BranchDto::new("gone".to_string(), None),
+            LocalCleanupReasonDto::UpstreamGone,
+        )]);
+    }
+
+    #[async_std::test]
+    async fn deletes_local_branches() {
+        let mut mock_local_repository_provider = MockLocalRepositoryProvider::default();
+        mock_local_repository_provider
+            .expect_delete_branch()
+            .withf(|url, branch| {
+                url.value() == LOCAL_URL && ["gone", "merged"].contains(&branch.value().as_str())
+            })
+            .times(2)
+            .returning(|_, _| Box::pin(async_this(Ok(()))));
+
+        let result = local_under_test(mock_local_repository_provider)
+            .delete_local_branches(
+                RepositoryUrlDto::new(LOCAL_URL.to_string()),
+                &[
+                    BranchDto::new("gone".to_string(), None),
+                    BranchDto::new("merged".to_string(), None),
+                ],
+            )
+            .await;
+
+        assert_that(&result.failures().is_empty()).is_true();
+        assert_that(
+            &result
+                .deleted()
+                .get(&RepositoryUrlDto::new(LOCAL_URL.to_string())),
+        )
+        .is_some()
+        .is_equal_to(&vec![
+            BranchDto::new("gone".to_string(), None),
+            BranchDto::new("merged".to_string(), None),
+        ]);
+    }
+
+    #[async_std::test]
+    async fn deletes_remaining_local_branches_when_one_fails_to_delete() {
+        let mut mock_local_repository_provider = MockLocalRepositoryProvider::default();
+        mock_local_repository_provider
+            .expect_delete_branch()
+            .withf(|_, branch| branch.value() == "checked-out")
+            .once()
+            .returning(|_, _| {
+                Box::pin(async_this(Err(RepositoryProviderError::Other {
+                    repository: None,
+                    message: "branch is checked out".to_string(),
+                })))
+            });
+        mock_local_repository_provider
+            .expect_delete_branch()
+            .withf(|_, branch| branch.value() == "merged")
+            .once()
+            .returning(|_, _| Box::pin(async_this(Ok(()))));
+
+        let result = local_under_test(mock_local_repository_provider)
+            .delete_local_branches(
+                RepositoryUrlDto::new(LOCAL_URL.to_string()),
+                &[
+                    BranchDto::new("checked-out".to_string(), None),
+                    BranchDto::new("merged".to_string(), None),
+                ],
+            )
+            .await;
+
+        assert_that(
+            &result
+                .deleted()
+                .get(&RepositoryUrlDto::new(LOCAL_URL.to_string())),
+        )
+        .is_some()
+        .is_equal_to(&vec![BranchDto::new("merged".to_string(), None)]);
+        assert_that(&result.failures().len()).is_equal_to(1);
+        assert_that(&result.failures()[0].1)
+            .is_equal_to(BranchDto::new("checked-out".to_string(), None));
+    }
+
+    #[async_std::test]
+    async fn detects_repository_url_from_remote_of_local_clone() {
+        let mut mock_local_repository_provider = MockLocalRepositoryProvider::default();
+        mock_local_repository_provider
+            .expect_remote_url()
+            .withf(|url, remote| url.value() == LOCAL_URL && remote == "upstream")
+            .returning(|_, _| Box::pin(async_this(Ok(RepositoryUrl::new(URL.to_string())))));
+
+        let result = local_under_test(mock_local_repository_provider)
+            .detect_repository_url(RepositoryUrlDto::new(LOCAL_URL.to_string()), "upstream")
+            .await
+            .unwrap();
+
+        assert_that(&result).is_equal_to(RepositoryUrlDto::new(URL.to_string()));
+    }
 }
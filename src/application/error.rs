@@ -1,7 +1,74 @@
 use crate::domain::error::DomainError;
+use crate::domain::repository::{RepositoryIdentity, RepositoryProviderError};
 
 #[derive(Debug, thiserror::Error)]
 pub enum ApplicationError {
     #[error(transparent)]
     Domain(#[from] DomainError),
 }
+
+impl ApplicationError {
+    /// The repository hosting failure behind this error, if there is one.
+    pub fn repository_provider_error(&self) -> Option<&RepositoryProviderError> {
+        match self {
+            ApplicationError::Domain(DomainError::RepositoryProvider(provider_error)) => {
+                Some(provider_error)
+            }
+            ApplicationError::Domain(..) => None,
+        }
+    }
+
+    pub fn repository(&self) -> Option<&RepositoryIdentity> {
+        match self {
+            ApplicationError::Domain(domain_error) => domain_error.repository(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use crate::domain::authentication::AuthenticationError;
+    use crate::domain::repository::RepositoryUrl;
+
+    use super::*;
+
+    fn rate_limited() -> RepositoryProviderError {
+        RepositoryProviderError::RateLimited {
+            repository: None,
+            message: "rate limit exceeded".to_string(),
+        }
+        .for_repository(&RepositoryUrl::new(
+            "https://github.com/owner/repo".to_string(),
+        ))
+    }
+
+    #[test]
+    fn exposes_repository_provider_error() {
+        let under_test = ApplicationError::from(DomainError::from(rate_limited()));
+
+        assert_that(&matches!(
+            under_test.repository_provider_error(),
+            Some(RepositoryProviderError::RateLimited { .. })
+        ))
+        .is_true();
+    }
+
+    #[test]
+    fn exposes_identity_of_failed_repository() {
+        let under_test = ApplicationError::from(DomainError::from(rate_limited()));
+
+        assert_that(&under_test.repository().unwrap().to_string())
+            .is_equal_to("github.com/owner/repo".to_string());
+    }
+
+    #[test]
+    fn has_no_repository_for_authentication_errors() {
+        let under_test =
+            ApplicationError::from(DomainError::from(AuthenticationError::InvalidCredentials));
+
+        assert_that(&under_test.repository()).is_none();
+        assert_that(&under_test.repository_provider_error().is_none()).is_true();
+    }
+}
@@ -0,0 +1,103 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::repository::{Branch, Commit};
+use crate::domain::value_object::ValueObject;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct BranchDto {
+    name: String,
+    head_commit: Option<CommitDto>,
+}
+
+impl BranchDto {
+    pub fn new(name: String, head_commit: Option<CommitDto>) -> Self {
+        BranchDto { name, head_commit }
+    }
+
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+
+    pub fn head_commit(&self) -> Option<&CommitDto> {
+        self.head_commit.as_ref()
+    }
+}
+
+impl From<Branch> for BranchDto {
+    fn from(branch: Branch) -> Self {
+        BranchDto::new(
+            branch.value().clone(),
+            branch.head_commit().cloned().map(CommitDto::from),
+        )
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct CommitDto {
+    sha: String,
+    date: DateTime<Utc>,
+    author: String,
+}
+
+impl CommitDto {
+    pub fn new(sha: String, date: DateTime<Utc>, author: String) -> Self {
+        CommitDto { sha, date, author }
+    }
+
+    pub fn sha(&self) -> &String {
+        &self.sha
+    }
+
+    pub fn date(&self) -> &DateTime<Utc> {
+        &self.date
+    }
+
+    pub fn author(&self) -> &String {
+        &self.author
+    }
+}
+
+impl From<Commit> for CommitDto {
+    fn from(commit: Commit) -> Self {
+        CommitDto::new(
+            commit.sha().clone(),
+            *commit.date(),
+            commit.author().clone(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use spectral::prelude::*;
+
+    use super::*;
+
+    fn date() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn implements_from_domain_branch_without_head_commit() {
+        let result: BranchDto = Branch::new("branch".to_string()).into();
+        assert_that(&result).is_equal_to(BranchDto::new("branch".to_string(), None));
+    }
+
+    #[test]
+    fn implements_from_domain_branch_with_head_commit() {
+        let result: BranchDto = Branch::with_head_commit(
+            "branch".to_string(),
+            Commit::new("sha".to_string(), date(), "author".to_string()),
+        )
+        .into();
+        assert_that(&result).is_equal_to(BranchDto::new(
+            "branch".to_string(),
+            Some(CommitDto::new(
+                "sha".to_string(),
+                date(),
+                "author".to_string(),
+            )),
+        ));
+    }
+}
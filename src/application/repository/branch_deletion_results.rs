@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+use std::iter::FromIterator;
+
+use crate::application::repository::{BranchDto, RepositoryUrlDto};
+use crate::application::ApplicationError;
+
+/// A branch that could not be deleted, with the repository it belongs to.
+pub type BranchDeletionFailure = (RepositoryUrlDto, BranchDto, ApplicationError);
+
+/// The outcome of deleting several branches, where each branch either was deleted or failed on
+/// its own.
+#[derive(Debug)]
+pub struct BranchDeletionResults {
+    deleted: HashMap<RepositoryUrlDto, Vec<BranchDto>>,
+    failures: Vec<BranchDeletionFailure>,
+}
+
+impl BranchDeletionResults {
+    pub fn deleted(&self) -> &HashMap<RepositoryUrlDto, Vec<BranchDto>> {
+        &self.deleted
+    }
+
+    pub fn failures(&self) -> &Vec<BranchDeletionFailure> {
+        &self.failures
+    }
+
+    pub fn into_parts(
+        self,
+    ) -> (
+        HashMap<RepositoryUrlDto, Vec<BranchDto>>,
+        Vec<BranchDeletionFailure>,
+    ) {
+        (self.deleted, self.failures)
+    }
+}
+
+impl FromIterator<(RepositoryUrlDto, BranchDto, Result<(), ApplicationError>)>
+    for BranchDeletionResults
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (RepositoryUrlDto, BranchDto, Result<(), ApplicationError>)>,
+    {
+        let mut deleted: HashMap<RepositoryUrlDto, Vec<BranchDto>> = HashMap::new();
+        let mut failures = Vec::new();
+        iter.into_iter()
+            .for_each(|(url, branch, result)| match result {
+                Ok(()) => deleted.entry(url).or_default().push(branch),
+                Err(err) => failures.push((url, branch, err)),
+            });
+        BranchDeletionResults { deleted, failures }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use crate::domain::error::DomainError;
+    use crate::domain::repository::RepositoryProviderError;
+
+    use super::*;
+
+    fn url(value: &str) -> RepositoryUrlDto {
+        RepositoryUrlDto::new(value.to_string())
+    }
+
+    fn branch(name: &str) -> BranchDto {
+        BranchDto::new(name.to_string(), None)
+    }
+
+    fn failure() -> ApplicationError {
+        DomainError::from(RepositoryProviderError::Other {
+            repository: None,
+            message: "failed".to_string(),
+        })
+        .into()
+    }
+
+    #[test]
+    fn separates_deleted_branches_from_failures() {
+        let under_test: BranchDeletionResults = vec![
+            (url("a"), branch("deleted"), Ok(())),
+            (url("a"), branch("kept"), Err(failure())),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_that(&under_test.deleted().get(&url("a")))
+            .is_some()
+            .is_equal_to(&vec![branch("deleted")]);
+        assert_that(&under_test.failures().len()).is_equal_to(1);
+        assert_that(&under_test.failures()[0].1).is_equal_to(branch("kept"));
+    }
+}
@@ -0,0 +1,31 @@
+use std::fmt::{Display, Formatter};
+
+/// Why a local branch can be cleaned up.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum LocalCleanupReasonDto {
+    UpstreamGone,
+    Merged,
+}
+
+impl Display for LocalCleanupReasonDto {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LocalCleanupReasonDto::UpstreamGone => write!(f, "upstream gone"),
+            LocalCleanupReasonDto::Merged => write!(f, "merged"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn implements_display() {
+        assert_that(&LocalCleanupReasonDto::UpstreamGone.to_string())
+            .is_equal_to("upstream gone".to_string());
+        assert_that(&LocalCleanupReasonDto::Merged.to_string()).is_equal_to("merged".to_string());
+    }
+}
@@ -0,0 +1,47 @@
+use std::fmt::{Display, Formatter};
+
+use crate::domain::merged_branches::MergeStatus;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum MergeStatusDto {
+    Merged,
+    Unmerged,
+}
+
+impl From<MergeStatus> for MergeStatusDto {
+    fn from(status: MergeStatus) -> Self {
+        match status {
+            MergeStatus::Merged => MergeStatusDto::Merged,
+            MergeStatus::Unmerged => MergeStatusDto::Unmerged,
+        }
+    }
+}
+
+impl Display for MergeStatusDto {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MergeStatusDto::Merged => write!(f, "merged"),
+            MergeStatusDto::Unmerged => write!(f, "unmerged"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn implements_from_domain_merge_status() {
+        assert_that(&MergeStatusDto::from(MergeStatus::Merged)).is_equal_to(MergeStatusDto::Merged);
+        assert_that(&MergeStatusDto::from(MergeStatus::Unmerged))
+            .is_equal_to(MergeStatusDto::Unmerged);
+    }
+
+    #[test]
+    fn implements_display() {
+        assert_that(&MergeStatusDto::Merged.to_string()).is_equal_to("merged".to_string());
+        assert_that(&MergeStatusDto::Unmerged.to_string()).is_equal_to("unmerged".to_string());
+    }
+}
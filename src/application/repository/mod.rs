@@ -1,3 +1,19 @@
+pub use branch::*;
+pub use branch_deletion_results::*;
+pub use local_cleanup_reason::*;
+pub use merge_status::*;
+pub use prune_criteria::*;
+pub use prune_plan::*;
+pub use repository_owner::*;
+pub use repository_results::*;
 pub use repository_url::*;
 
+mod branch;
+mod branch_deletion_results;
+mod local_cleanup_reason;
+mod merge_status;
+mod prune_criteria;
+mod prune_plan;
+mod repository_owner;
+mod repository_results;
 mod repository_url;
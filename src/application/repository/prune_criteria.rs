@@ -0,0 +1,30 @@
+use chrono::Duration;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct PruneCriteriaDto {
+    merged: bool,
+    older_than: Option<Duration>,
+    include_protected: bool,
+}
+
+impl PruneCriteriaDto {
+    pub fn new(merged: bool, older_than: Option<Duration>, include_protected: bool) -> Self {
+        PruneCriteriaDto {
+            merged,
+            older_than,
+            include_protected,
+        }
+    }
+
+    pub fn merged(&self) -> bool {
+        self.merged
+    }
+
+    pub fn older_than(&self) -> Option<Duration> {
+        self.older_than
+    }
+
+    pub fn include_protected(&self) -> bool {
+        self.include_protected
+    }
+}
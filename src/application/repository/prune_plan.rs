@@ -0,0 +1,57 @@
+use crate::application::repository::{BranchDto, RepositoryUrlDto};
+
+/// The branches to prune in one repository, together with the URL the repository was given as.
+/// Results are keyed by the canonical URL, which only names the repository, so the branches are
+/// deleted through this URL instead.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct PrunePlanDto {
+    repository_url: RepositoryUrlDto,
+    branches: Vec<BranchDto>,
+}
+
+impl PrunePlanDto {
+    pub fn new(repository_url: RepositoryUrlDto, branches: Vec<BranchDto>) -> Self {
+        PrunePlanDto {
+            repository_url,
+            branches,
+        }
+    }
+
+    pub fn repository_url(&self) -> &RepositoryUrlDto {
+        &self.repository_url
+    }
+
+    pub fn branches(&self) -> &Vec<BranchDto> {
+        &self.branches
+    }
+
+    pub fn into_branches(self) -> Vec<BranchDto> {
+        self.branches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    fn under_test() -> PrunePlanDto {
+        PrunePlanDto::new(
+            RepositoryUrlDto::new("https://gitlab.example.com:8443/group/project".to_string()),
+            vec![BranchDto::new("feature".to_string(), None)],
+        )
+    }
+
+    #[test]
+    fn returns_repository_url() {
+        assert_that(&under_test().repository_url().value())
+            .is_equal_to(&"https://gitlab.example.com:8443/group/project".to_string());
+    }
+
+    #[test]
+    fn returns_branches() {
+        assert_that(&under_test().into_branches())
+            .is_equal_to(vec![BranchDto::new("feature".to_string(), None)]);
+    }
+}
@@ -0,0 +1,35 @@
+use crate::domain::repository::RepositoryOwner;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum RepositoryOwnerDto {
+    Organization(String),
+    User(String),
+}
+
+impl From<RepositoryOwnerDto> for RepositoryOwner {
+    fn from(owner: RepositoryOwnerDto) -> Self {
+        match owner {
+            RepositoryOwnerDto::Organization(name) => RepositoryOwner::Organization(name),
+            RepositoryOwnerDto::User(name) => RepositoryOwner::User(name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn implements_to_domain_repository_owner() {
+        assert_that(&RepositoryOwner::from(RepositoryOwnerDto::Organization(
+            "org".to_string(),
+        )))
+        .is_equal_to(RepositoryOwner::Organization("org".to_string()));
+        assert_that(&RepositoryOwner::from(RepositoryOwnerDto::User(
+            "user".to_string(),
+        )))
+        .is_equal_to(RepositoryOwner::User("user".to_string()));
+    }
+}
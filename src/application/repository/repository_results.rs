@@ -0,0 +1,101 @@
+use std::collections::HashMap;
+use std::iter::FromIterator;
+
+use crate::application::repository::RepositoryUrlDto;
+use crate::application::ApplicationError;
+
+/// The outcome of processing several repositories, where each repository either produced a value
+/// or failed on its own.
+#[derive(Debug)]
+pub struct RepositoryResults<T> {
+    successes: HashMap<RepositoryUrlDto, T>,
+    failures: HashMap<RepositoryUrlDto, ApplicationError>,
+}
+
+impl<T> RepositoryResults<T> {
+    pub fn successes(&self) -> &HashMap<RepositoryUrlDto, T> {
+        &self.successes
+    }
+
+    pub fn failures(&self) -> &HashMap<RepositoryUrlDto, ApplicationError> {
+        &self.failures
+    }
+
+    pub fn has_failures(&self) -> bool {
+        !self.failures.is_empty()
+    }
+
+    pub fn into_parts(
+        self,
+    ) -> (
+        HashMap<RepositoryUrlDto, T>,
+        HashMap<RepositoryUrlDto, ApplicationError>,
+    ) {
+        (self.successes, self.failures)
+    }
+}
+
+impl<T> FromIterator<(RepositoryUrlDto, Result<T, ApplicationError>)> for RepositoryResults<T> {
+    fn from_iter<I: IntoIterator<Item = (RepositoryUrlDto, Result<T, ApplicationError>)>>(
+        iter: I,
+    ) -> Self {
+        let mut successes = HashMap::new();
+        let mut failures = HashMap::new();
+        iter.into_iter().for_each(|(url, result)| match result {
+            Ok(value) => {
+                successes.insert(url, value);
+            }
+            Err(err) => {
+                failures.insert(url, err);
+            }
+        });
+        RepositoryResults {
+            successes,
+            failures,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use crate::domain::error::DomainError;
+    use crate::domain::repository::RepositoryProviderError;
+
+    use super::*;
+
+    fn url(value: &str) -> RepositoryUrlDto {
+        RepositoryUrlDto::new(value.to_string())
+    }
+
+    fn failure() -> ApplicationError {
+        DomainError::from(RepositoryProviderError::Other {
+            repository: None,
+            message: "failed".to_string(),
+        })
+        .into()
+    }
+
+    #[test]
+    fn separates_successes_from_failures() {
+        let under_test: RepositoryResults<u32> =
+            vec![(url("a"), Ok(1)), (url("b"), Err(failure()))]
+                .into_iter()
+                .collect();
+
+        assert_that(&under_test.has_failures()).is_true();
+        assert_that(&under_test.successes().get(&url("a")))
+            .is_some()
+            .is_equal_to(&1);
+        assert_that(&under_test.failures().get(&url("b")).unwrap().to_string())
+            .is_equal_to("failed".to_string());
+    }
+
+    #[test]
+    fn has_no_failures_when_every_repository_succeeds() {
+        let under_test: RepositoryResults<u32> = vec![(url("a"), Ok(1))].into_iter().collect();
+
+        assert_that(&under_test.has_failures()).is_false();
+    }
+}
@@ -26,9 +26,9 @@ impl FromStr for RepositoryUrlDto {
     }
 }
 
-impl Into<RepositoryUrl> for RepositoryUrlDto {
-    fn into(self) -> RepositoryUrl {
-        RepositoryUrl::new(self.0)
+impl From<RepositoryUrlDto> for RepositoryUrl {
+    fn from(url: RepositoryUrlDto) -> Self {
+        RepositoryUrl::new(url.0)
     }
 }
 
@@ -61,20 +61,20 @@ mod tests {
 
     #[test]
     fn implements_from_string_infallibly() {
-        assert_that(&RepositoryUrlDto::from_str(&"url".to_string()).unwrap())
-            .is_equal_to(&RepositoryUrlDto::new("url".to_string()));
+        assert_that(&RepositoryUrlDto::from_str("url").unwrap())
+            .is_equal_to(RepositoryUrlDto::new("url".to_string()));
     }
 
     #[test]
     fn implements_to_domain_repository_url() {
         let result: RepositoryUrl = under_test().into();
-        assert_that(&result).is_equal_to(&RepositoryUrl::new("url".to_string()));
+        assert_that(&result).is_equal_to(RepositoryUrl::new("url".to_string()));
     }
 
     #[test]
     fn to_string_equals_contained_string_url() {
         let under_test = RepositoryUrlDto::new("https://github.com/owner/repo".to_string());
         assert_that(&under_test.to_string())
-            .is_equal_to(&"https://github.com/owner/repo".to_string())
+            .is_equal_to("https://github.com/owner/repo".to_string())
     }
 }
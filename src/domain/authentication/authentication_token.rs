@@ -2,11 +2,11 @@ use std::convert::Infallible;
 use std::str::FromStr;
 
 #[derive(Debug, Clone, Eq, PartialEq)]
-pub struct GitHubAuthenticationToken(String);
+pub struct AuthenticationToken(String);
 
-impl GitHubAuthenticationToken {
+impl AuthenticationToken {
     pub fn new(value: String) -> Self {
-        GitHubAuthenticationToken(value)
+        AuthenticationToken(value)
     }
 
     pub fn value(&self) -> String {
@@ -14,10 +14,10 @@ impl GitHubAuthenticationToken {
     }
 }
 
-impl FromStr for GitHubAuthenticationToken {
+impl FromStr for AuthenticationToken {
     type Err = Infallible;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(GitHubAuthenticationToken(s.to_string()))
+        Ok(AuthenticationToken(s.to_string()))
     }
 }
@@ -1,16 +1,70 @@
 use async_trait::async_trait;
 
-use crate::domain::authentication::GitHubAuthenticationToken;
+use crate::domain::authentication::AuthenticationToken;
 
 #[async_trait]
 #[cfg_attr(test, mockall::automock)]
 pub trait CredentialRepository {
     async fn store(
         &self,
-        credentials: GitHubAuthenticationToken,
+        key: &CredentialKey,
+        credentials: AuthenticationToken,
     ) -> Result<(), CredentialRepositoryError>;
 
-    async fn get(&self) -> Result<GitHubAuthenticationToken, CredentialRepositoryError>;
+    async fn get(
+        &self,
+        key: &CredentialKey,
+    ) -> Result<AuthenticationToken, CredentialRepositoryError>;
+}
+
+/// The kind of hosting service a credential is used with.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum HostingProvider {
+    GitHub,
+    GitLab,
+    Bitbucket,
+    Gitea,
+}
+
+/// Identifies the credential of one host, so that credentials of several hosts can be stored
+/// side by side.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct CredentialKey {
+    provider: HostingProvider,
+    host: String,
+}
+
+impl CredentialKey {
+    pub fn new(provider: HostingProvider, host: &str) -> Self {
+        CredentialKey {
+            provider,
+            host: host.to_lowercase(),
+        }
+    }
+
+    pub fn github(host: &str) -> Self {
+        Self::new(HostingProvider::GitHub, host)
+    }
+
+    pub fn gitlab(host: &str) -> Self {
+        Self::new(HostingProvider::GitLab, host)
+    }
+
+    pub fn bitbucket(host: &str) -> Self {
+        Self::new(HostingProvider::Bitbucket, host)
+    }
+
+    pub fn gitea(host: &str) -> Self {
+        Self::new(HostingProvider::Gitea, host)
+    }
+
+    pub fn provider(&self) -> HostingProvider {
+        self.provider
+    }
+
+    pub fn host(&self) -> &str {
+        self.host.as_str()
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -25,3 +79,22 @@ pub enum CredentialRepositoryError {
     #[error("Failed to store credential")]
     FailedToStoreCredential,
 }
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn identifies_host_ignoring_case() {
+        assert_that(&CredentialKey::github("GitHub.Corp.Example"))
+            .is_equal_to(CredentialKey::github("github.corp.example"));
+    }
+
+    #[test]
+    fn distinguishes_providers_on_same_host() {
+        assert_that(&CredentialKey::github("git.example.com"))
+            .is_not_equal_to(CredentialKey::gitlab("git.example.com"));
+    }
+}
@@ -1,14 +1,14 @@
 use async_trait::async_trait;
 
-pub use github_authentication_service::*;
-pub use github_token::*;
+pub use authentication_token::*;
+pub use token_authentication_service::*;
 pub use validator::*;
 
 use crate::domain::authentication::credential_repository::CredentialRepositoryError;
 
+mod authentication_token;
 pub mod credential_repository;
-mod github_authentication_service;
-mod github_token;
+mod token_authentication_service;
 mod validator;
 
 #[async_trait]
@@ -1,42 +1,48 @@
 use async_trait::async_trait;
 
-use crate::domain::authentication::credential_repository::CredentialRepository;
+use crate::domain::authentication::credential_repository::{CredentialKey, CredentialRepository};
 use crate::domain::authentication::AuthenticationValidity;
 use crate::domain::authentication::{
-    AuthenticationError, AuthenticationService, GitHubAuthenticationToken,
-    RepositoryCredentialsValidator,
+    AuthenticationError, AuthenticationService, AuthenticationToken, RepositoryCredentialsValidator,
 };
 
-#[derive(Debug, Default)]
-pub struct GitHubAuthenticationService<AV, CR>
+/// Validates tokens with a hosting service and stores them under the key of its host.
+#[derive(Debug)]
+pub struct TokenAuthenticationService<AV, CR>
 where
     AV: RepositoryCredentialsValidator,
     CR: CredentialRepository,
 {
     authentication_validator: AV,
     credential_repository: CR,
+    credential_key: CredentialKey,
 }
 
-impl<AV, CR> GitHubAuthenticationService<AV, CR>
+impl<AV, CR> TokenAuthenticationService<AV, CR>
 where
     AV: RepositoryCredentialsValidator,
     CR: CredentialRepository,
 {
-    pub fn new(authentication_validator: AV, credential_repository: CR) -> Self {
-        GitHubAuthenticationService {
+    pub fn new(
+        authentication_validator: AV,
+        credential_repository: CR,
+        credential_key: CredentialKey,
+    ) -> Self {
+        TokenAuthenticationService {
             authentication_validator,
             credential_repository,
+            credential_key,
         }
     }
 }
 
 #[async_trait]
-impl<AV, CR> AuthenticationService for GitHubAuthenticationService<AV, CR>
+impl<AV, CR> AuthenticationService for TokenAuthenticationService<AV, CR>
 where
     AV: RepositoryCredentialsValidator + Send + Sync,
     CR: CredentialRepository + Send + Sync,
 {
-    type AuthenticationCredentials = GitHubAuthenticationToken;
+    type AuthenticationCredentials = AuthenticationToken;
 
     async fn authenticate(
         &self,
@@ -51,7 +57,7 @@ where
         match validity {
             AuthenticationValidity::Valid => self
                 .credential_repository
-                .store(credentials)
+                .store(&self.credential_key, credentials)
                 .await
                 .map_err(AuthenticationError::from),
             AuthenticationValidity::Invalid => Err(AuthenticationError::InvalidCredentials),
@@ -62,7 +68,7 @@ where
         &self,
     ) -> Result<Self::AuthenticationCredentials, AuthenticationError> {
         self.credential_repository
-            .get()
+            .get(&self.credential_key)
             .await
             .map_err(AuthenticationError::from)
     }
@@ -85,9 +91,13 @@ mod tests {
     fn under_test(
         authentication_validator: MockRepositoryCredentialsValidator,
         credential_repository: MockCredentialRepository,
-    ) -> GitHubAuthenticationService<MockRepositoryCredentialsValidator, MockCredentialRepository>
+    ) -> TokenAuthenticationService<MockRepositoryCredentialsValidator, MockCredentialRepository>
     {
-        GitHubAuthenticationService::new(authentication_validator, credential_repository)
+        TokenAuthenticationService::new(
+            authentication_validator,
+            credential_repository,
+            CredentialKey::github("github.com"),
+        )
     }
 
     fn mock_credential_repository() -> MockCredentialRepository {
@@ -100,12 +110,12 @@ mod tests {
 
     #[async_std::test]
     async fn authenticates_valid_credentials() {
-        let token = GitHubAuthenticationToken::new("credentials".into());
+        let token = AuthenticationToken::new("credentials".into());
         let mut mock_credential_repository = mock_credential_repository();
         mock_credential_repository
             .expect_store()
-            .with(eq(token.clone()))
-            .returning(|_| Box::pin(async_this(Ok(()))));
+            .with(eq(CredentialKey::github("github.com")), eq(token.clone()))
+            .returning(|_, _| Box::pin(async_this(Ok(()))));
         let mut mock_credentials_validator = mock_credentials_validator();
         mock_credentials_validator
             .expect_validate()
@@ -122,12 +132,12 @@ mod tests {
 
     #[async_std::test]
     async fn fails_to_authenticate_invalid_credentials() {
-        let token = GitHubAuthenticationToken::new("credentials".into());
+        let token = AuthenticationToken::new("credentials".into());
         let mut mock_credential_repository = mock_credential_repository();
         mock_credential_repository
             .expect_store()
-            .with(eq(token.clone()))
-            .returning(|_| Box::pin(async_this(Ok(()))));
+            .with(eq(CredentialKey::github("github.com")), eq(token.clone()))
+            .returning(|_, _| Box::pin(async_this(Ok(()))));
         let mut mock_credentials_validator = mock_credentials_validator();
         mock_credentials_validator
             .expect_validate()
@@ -138,18 +148,21 @@ mod tests {
             .authenticate(token)
             .await;
 
-        assert_that(&matches!(result.err().unwrap(), AuthenticationError::InvalidCredentials {..}))
-            .is_true();
+        assert_that(&matches!(
+            result.err().unwrap(),
+            AuthenticationError::InvalidCredentials
+        ))
+        .is_true();
     }
 
     #[async_std::test]
     async fn fails_to_authenticate_valid_credentials_when_fails_to_persist() {
-        let token = GitHubAuthenticationToken::new("credentials".into());
+        let token = AuthenticationToken::new("credentials".into());
         let mut mock_credential_repository = mock_credential_repository();
         mock_credential_repository
             .expect_store()
-            .with(eq(token.clone()))
-            .returning(|_| {
+            .with(eq(CredentialKey::github("github.com")), eq(token.clone()))
+            .returning(|_, _| {
                 Box::pin(async_this(Err(
                     CredentialRepositoryError::FailedToStoreCredential,
                 )))
@@ -174,8 +187,8 @@ mod tests {
     #[async_std::test]
     async fn returns_persisted_authentication_credentials() {
         let mut mock_credential_repository = mock_credential_repository();
-        mock_credential_repository.expect_get().returning(|| {
-            Box::pin(async_this(Ok(GitHubAuthenticationToken::new(
+        mock_credential_repository.expect_get().returning(|_| {
+            Box::pin(async_this(Ok(AuthenticationToken::new(
                 "credentials".into(),
             ))))
         });
@@ -192,7 +205,7 @@ mod tests {
     #[async_std::test]
     async fn fails_to_return_persisted_authentication_credentials_when_persistence_service_fails() {
         let mut mock_credential_repository = mock_credential_repository();
-        mock_credential_repository.expect_get().returning(|| {
+        mock_credential_repository.expect_get().returning(|_| {
             Box::pin(async_this(Err(
                 CredentialRepositoryError::FailedToGetCredential,
             )))
@@ -1,14 +1,28 @@
-use crate::domain::authentication::GitHubAuthenticationToken;
+use crate::domain::authentication::AuthenticationToken;
 
 #[async_trait::async_trait]
 #[cfg_attr(test, mockall::automock)]
 pub trait RepositoryCredentialsValidator {
     async fn validate(
         &self,
-        credentials: GitHubAuthenticationToken,
+        credentials: AuthenticationToken,
     ) -> Result<AuthenticationValidity, RepositoryCredentialsValidationError>;
 }
 
+/// Lets the validator of a hosting service be chosen at runtime.
+#[async_trait::async_trait]
+impl<T> RepositoryCredentialsValidator for Box<T>
+where
+    T: RepositoryCredentialsValidator + Send + Sync + ?Sized,
+{
+    async fn validate(
+        &self,
+        credentials: AuthenticationToken,
+    ) -> Result<AuthenticationValidity, RepositoryCredentialsValidationError> {
+        self.as_ref().validate(credentials).await
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum RepositoryCredentialsValidationError {
     #[error("Failed to validate credentials")]
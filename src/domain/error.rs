@@ -1,5 +1,5 @@
 use crate::domain::authentication::AuthenticationError;
-use crate::domain::repository::RepositoryProviderError;
+use crate::domain::repository::{RepositoryIdentity, RepositoryProviderError};
 
 #[derive(Debug, thiserror::Error)]
 pub enum DomainError {
@@ -8,3 +8,12 @@ pub enum DomainError {
     #[error(transparent)]
     RepositoryProvider(#[from] RepositoryProviderError),
 }
+
+impl DomainError {
+    pub fn repository(&self) -> Option<&RepositoryIdentity> {
+        match self {
+            DomainError::RepositoryProvider(provider_error) => provider_error.repository(),
+            DomainError::Authentication(..) => None,
+        }
+    }
+}
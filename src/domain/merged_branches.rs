@@ -0,0 +1,72 @@
+use crate::domain::repository::{Branch, BranchComparison};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum MergeStatus {
+    Merged,
+    Unmerged,
+}
+
+#[cfg_attr(test, mockall::automock)]
+pub trait MergedBranchService {
+    fn merge_statuses(&self, comparisons: Vec<BranchComparison>) -> Vec<(Branch, MergeStatus)>;
+}
+
+#[derive(Default)]
+pub struct MergedBranchServiceImpl;
+
+impl MergedBranchServiceImpl {
+    pub fn new() -> Self {
+        MergedBranchServiceImpl
+    }
+}
+
+impl MergedBranchService for MergedBranchServiceImpl {
+    fn merge_statuses(&self, comparisons: Vec<BranchComparison>) -> Vec<(Branch, MergeStatus)> {
+        comparisons
+            .iter()
+            .map(|comparison| {
+                let status = if comparison.ahead_by() == 0 {
+                    MergeStatus::Merged
+                } else {
+                    MergeStatus::Unmerged
+                };
+                (comparison.branch().clone(), status)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    fn branch() -> Branch {
+        Branch::new("branch".to_string())
+    }
+
+    #[test]
+    fn marks_branch_that_is_not_ahead_of_base_as_merged() {
+        let under_test = MergedBranchServiceImpl::new();
+
+        assert_that(&under_test.merge_statuses(vec![BranchComparison::new(branch(), 0, 3)]))
+            .is_equal_to(vec![(branch(), MergeStatus::Merged)]);
+    }
+
+    #[test]
+    fn marks_branch_that_is_ahead_of_base_as_unmerged() {
+        let under_test = MergedBranchServiceImpl::new();
+
+        assert_that(&under_test.merge_statuses(vec![BranchComparison::new(branch(), 1, 0)]))
+            .is_equal_to(vec![(branch(), MergeStatus::Unmerged)]);
+    }
+
+    #[test]
+    fn marks_identical_branch_as_merged() {
+        let under_test = MergedBranchServiceImpl::new();
+
+        assert_that(&under_test.merge_statuses(vec![BranchComparison::new(branch(), 0, 0)]))
+            .is_equal_to(vec![(branch(), MergeStatus::Merged)]);
+    }
+}
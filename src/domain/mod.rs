@@ -1,5 +1,7 @@
 pub mod authentication;
 pub mod count_branches;
 pub mod error;
+pub mod merged_branches;
 pub mod repository;
+pub mod stale_branches;
 pub mod value_object;
@@ -1,13 +1,53 @@
+use crate::domain::repository::Commit;
 use crate::domain::value_object::ValueObject;
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Branch {
     name: String,
+    head_commit: Option<Commit>,
+    protected: bool,
+    is_default: bool,
 }
 
 impl Branch {
     pub fn new(name: String) -> Self {
-        Branch { name }
+        Branch {
+            name,
+            head_commit: None,
+            protected: false,
+            is_default: false,
+        }
+    }
+
+    pub fn with_head_commit(name: String, head_commit: Commit) -> Self {
+        Branch {
+            name,
+            head_commit: Some(head_commit),
+            protected: false,
+            is_default: false,
+        }
+    }
+
+    pub fn marked_protected(mut self, protected: bool) -> Self {
+        self.protected = protected;
+        self
+    }
+
+    pub fn marked_default(mut self, is_default: bool) -> Self {
+        self.is_default = is_default;
+        self
+    }
+
+    pub fn head_commit(&self) -> Option<&Commit> {
+        self.head_commit.as_ref()
+    }
+
+    pub fn is_protected(&self) -> bool {
+        self.protected
+    }
+
+    pub fn is_default(&self) -> bool {
+        self.is_default
     }
 }
 
@@ -19,6 +59,7 @@ impl ValueObject<String> for Branch {
 
 #[cfg(test)]
 mod tests {
+    use chrono::{TimeZone, Utc};
     use spectral::prelude::*;
 
     use super::*;
@@ -27,8 +68,45 @@ mod tests {
         Branch::new("branch".to_string())
     }
 
+    fn commit() -> Commit {
+        Commit::new(
+            "sha".to_string(),
+            Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap(),
+            "author".to_string(),
+        )
+    }
+
     #[test]
     fn returns_string_value() {
         assert_that(&under_test().value()).is_equal_to(&"branch".to_string());
     }
+
+    #[test]
+    fn head_commit_is_absent_by_default() {
+        assert_that(&under_test().head_commit()).is_none();
+    }
+
+    #[test]
+    fn returns_head_commit() {
+        let under_test = Branch::with_head_commit("branch".to_string(), commit());
+        assert_that(&under_test.head_commit())
+            .is_some()
+            .is_equal_to(&commit());
+    }
+
+    #[test]
+    fn is_neither_protected_nor_default_by_default() {
+        assert_that(&under_test().is_protected()).is_false();
+        assert_that(&under_test().is_default()).is_false();
+    }
+
+    #[test]
+    fn marks_branch_as_protected() {
+        assert_that(&under_test().marked_protected(true).is_protected()).is_true();
+    }
+
+    #[test]
+    fn marks_branch_as_default() {
+        assert_that(&under_test().marked_default(true).is_default()).is_true();
+    }
 }
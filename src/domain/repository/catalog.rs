@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use crate::domain::repository::{RepositoryProviderError, RepositoryUrl};
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum RepositoryOwner {
+    Organization(String),
+    User(String),
+}
+
+#[async_trait::async_trait]
+#[cfg_attr(test, mockall::automock)]
+pub trait RepositoryCatalog {
+    async fn list_repositories(
+        &self,
+        owner: &RepositoryOwner,
+    ) -> Result<Vec<RepositoryUrl>, RepositoryProviderError>;
+}
+
+#[async_trait::async_trait]
+impl<T> RepositoryCatalog for Arc<T>
+where
+    T: RepositoryCatalog + Send + Sync + ?Sized,
+{
+    async fn list_repositories(
+        &self,
+        owner: &RepositoryOwner,
+    ) -> Result<Vec<RepositoryUrl>, RepositoryProviderError> {
+        self.as_ref().list_repositories(owner).await
+    }
+}
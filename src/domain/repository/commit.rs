@@ -0,0 +1,26 @@
+use chrono::{DateTime, Utc};
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Commit {
+    sha: String,
+    date: DateTime<Utc>,
+    author: String,
+}
+
+impl Commit {
+    pub fn new(sha: String, date: DateTime<Utc>, author: String) -> Self {
+        Commit { sha, date, author }
+    }
+
+    pub fn sha(&self) -> &String {
+        &self.sha
+    }
+
+    pub fn date(&self) -> &DateTime<Utc> {
+        &self.date
+    }
+
+    pub fn author(&self) -> &String {
+        &self.author
+    }
+}
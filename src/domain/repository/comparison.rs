@@ -0,0 +1,30 @@
+use crate::domain::repository::Branch;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct BranchComparison {
+    branch: Branch,
+    ahead_by: u32,
+    behind_by: u32,
+}
+
+impl BranchComparison {
+    pub fn new(branch: Branch, ahead_by: u32, behind_by: u32) -> Self {
+        BranchComparison {
+            branch,
+            ahead_by,
+            behind_by,
+        }
+    }
+
+    pub fn branch(&self) -> &Branch {
+        &self.branch
+    }
+
+    pub fn ahead_by(&self) -> u32 {
+        self.ahead_by
+    }
+
+    pub fn behind_by(&self) -> u32 {
+        self.behind_by
+    }
+}
@@ -0,0 +1,124 @@
+use crate::domain::repository::{Branch, RepositoryUrl};
+
+/// Whether the remote-tracking branch a local branch was set up to follow still exists.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum UpstreamStatus {
+    Untracked,
+    Present,
+    Gone,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct LocalBranch {
+    branch: Branch,
+    upstream: UpstreamStatus,
+    checked_out: bool,
+}
+
+impl LocalBranch {
+    pub fn new(branch: Branch) -> Self {
+        LocalBranch {
+            branch,
+            upstream: UpstreamStatus::Untracked,
+            checked_out: false,
+        }
+    }
+
+    pub fn with_upstream(mut self, upstream: UpstreamStatus) -> Self {
+        self.upstream = upstream;
+        self
+    }
+
+    pub fn marked_checked_out(mut self, checked_out: bool) -> Self {
+        self.checked_out = checked_out;
+        self
+    }
+
+    pub fn branch(&self) -> &Branch {
+        &self.branch
+    }
+
+    pub fn upstream(&self) -> UpstreamStatus {
+        self.upstream
+    }
+
+    pub fn is_checked_out(&self) -> bool {
+        self.checked_out
+    }
+}
+
+/// A clone on this machine, with the branches of its working copy.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct LocalRepository {
+    url: RepositoryUrl,
+    branches: Vec<LocalBranch>,
+}
+
+impl LocalRepository {
+    pub fn new(url: RepositoryUrl, branches: Vec<LocalBranch>) -> Self {
+        LocalRepository { url, branches }
+    }
+
+    pub fn url(&self) -> &RepositoryUrl {
+        &self.url
+    }
+
+    pub fn branches(&self) -> &Vec<LocalBranch> {
+        &self.branches
+    }
+
+    pub fn default_branch(&self) -> Option<&Branch> {
+        self.branches
+            .iter()
+            .map(LocalBranch::branch)
+            .find(|branch| branch.is_default())
+    }
+
+    /// Branches that may be deleted at all: neither the default branch nor the one checked out.
+    pub fn deletable_branches(&self) -> impl Iterator<Item = &LocalBranch> {
+        self.branches
+            .iter()
+            .filter(|branch| !branch.is_checked_out() && !branch.branch().is_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    fn under_test() -> LocalRepository {
+        LocalRepository::new(
+            RepositoryUrl::new("/home/user/project".to_string()),
+            vec![
+                LocalBranch::new(Branch::new("main".to_string()).marked_default(true)),
+                LocalBranch::new(Branch::new("current".to_string())).marked_checked_out(true),
+                LocalBranch::new(Branch::new("gone".to_string()))
+                    .with_upstream(UpstreamStatus::Gone),
+            ],
+        )
+    }
+
+    #[test]
+    fn returns_default_branch() {
+        assert_that(&under_test().default_branch())
+            .is_equal_to(Some(&Branch::new("main".to_string()).marked_default(true)));
+    }
+
+    #[test]
+    fn excludes_default_and_checked_out_branches_from_deletable_branches() {
+        let repository = under_test();
+        let deletable: Vec<&LocalBranch> = repository.deletable_branches().collect();
+
+        assert_that(&deletable)
+            .is_equal_to(vec![&LocalBranch::new(Branch::new("gone".to_string()))
+                .with_upstream(UpstreamStatus::Gone)]);
+    }
+
+    #[test]
+    fn has_untracked_upstream_by_default() {
+        assert_that(&LocalBranch::new(Branch::new("branch".to_string())).upstream())
+            .is_equal_to(UpstreamStatus::Untracked);
+    }
+}
@@ -0,0 +1,34 @@
+use crate::domain::repository::{
+    Branch, BranchComparison, LocalRepository, RepositoryProviderError, RepositoryUrl,
+};
+
+/// The counterpart of `RepositoryProvider` and `RepositoryMaintainer` for clones on this
+/// machine, which also know about upstream branches and the branch that is checked out.
+#[async_trait::async_trait]
+#[cfg_attr(test, mockall::automock)]
+pub trait LocalRepositoryProvider {
+    async fn get_local_repository(
+        &self,
+        url: &RepositoryUrl,
+    ) -> Result<LocalRepository, RepositoryProviderError>;
+
+    async fn compare_branches(
+        &self,
+        url: &RepositoryUrl,
+        base: &Branch,
+        head: &Branch,
+    ) -> Result<BranchComparison, RepositoryProviderError>;
+
+    async fn delete_branch(
+        &self,
+        url: &RepositoryUrl,
+        branch: &Branch,
+    ) -> Result<(), RepositoryProviderError>;
+
+    /// The URL the named remote of the clone fetches from.
+    async fn remote_url(
+        &self,
+        url: &RepositoryUrl,
+        remote: &str,
+    ) -> Result<RepositoryUrl, RepositoryProviderError>;
+}
@@ -0,0 +1,27 @@
+use std::sync::Arc;
+
+use crate::domain::repository::{Branch, RepositoryProviderError, RepositoryUrl};
+
+#[async_trait::async_trait]
+#[cfg_attr(test, mockall::automock)]
+pub trait RepositoryMaintainer {
+    async fn delete_branch(
+        &self,
+        url: &RepositoryUrl,
+        branch: &Branch,
+    ) -> Result<(), RepositoryProviderError>;
+}
+
+#[async_trait::async_trait]
+impl<T> RepositoryMaintainer for Arc<T>
+where
+    T: RepositoryMaintainer + Send + Sync + ?Sized,
+{
+    async fn delete_branch(
+        &self,
+        url: &RepositoryUrl,
+        branch: &Branch,
+    ) -> Result<(), RepositoryProviderError> {
+        self.as_ref().delete_branch(url, branch).await
+    }
+}
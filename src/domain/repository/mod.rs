@@ -1,9 +1,25 @@
 pub use branch::*;
+pub use catalog::*;
+pub use commit::*;
+pub use comparison::*;
+pub use local::*;
+pub use local_provider::*;
+pub use maintainer::*;
 pub use provider::*;
+pub use provider_error::*;
+pub use registry::*;
 pub use url::*;
 
 mod branch;
+mod catalog;
+mod commit;
+mod comparison;
+mod local;
+mod local_provider;
+mod maintainer;
 mod provider;
+mod provider_error;
+mod registry;
 mod url;
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -24,4 +40,59 @@ impl Repository {
     pub fn url(&self) -> &RepositoryUrl {
         &self.url
     }
+
+    pub fn default_branch(&self) -> Option<&Branch> {
+        self.branches.iter().find(|branch| branch.is_default())
+    }
+
+    pub fn without_protected_branches(self) -> Self {
+        Repository {
+            url: self.url,
+            branches: self
+                .branches
+                .into_iter()
+                .filter(|branch| !branch.is_protected() && !branch.is_default())
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    fn under_test() -> Repository {
+        Repository::new(
+            RepositoryUrl::new("url".to_string()),
+            vec![
+                Branch::new("main".to_string()).marked_default(true),
+                Branch::new("release".to_string()).marked_protected(true),
+                Branch::new("feature".to_string()),
+            ],
+        )
+    }
+
+    #[test]
+    fn returns_default_branch() {
+        assert_that(&under_test().default_branch())
+            .is_some()
+            .is_equal_to(&Branch::new("main".to_string()).marked_default(true));
+    }
+
+    #[test]
+    fn has_no_default_branch_when_none_is_marked() {
+        let under_test = Repository::new(
+            RepositoryUrl::new("url".to_string()),
+            vec![Branch::new("feature".to_string())],
+        );
+        assert_that(&under_test.default_branch()).is_none();
+    }
+
+    #[test]
+    fn removes_protected_and_default_branches() {
+        assert_that(under_test().without_protected_branches().branches())
+            .is_equal_to(vec![Branch::new("feature".to_string())]);
+    }
 }
@@ -1,4 +1,8 @@
-use crate::domain::repository::{Repository, RepositoryUrl};
+use std::sync::Arc;
+
+use crate::domain::repository::{
+    Branch, BranchComparison, Repository, RepositoryProviderError, RepositoryUrl,
+};
 
 #[async_trait::async_trait]
 #[cfg_attr(test, mockall::automock)]
@@ -7,16 +11,46 @@ pub trait RepositoryProvider {
         &self,
         url: &RepositoryUrl,
     ) -> Result<Repository, RepositoryProviderError>;
-}
 
-#[derive(Debug, thiserror::Error)]
-#[error("{msg}")]
-pub struct RepositoryProviderError {
-    msg: String,
+    async fn get_repository_with_head_commits(
+        &self,
+        url: &RepositoryUrl,
+    ) -> Result<Repository, RepositoryProviderError>;
+
+    async fn compare_branches(
+        &self,
+        url: &RepositoryUrl,
+        base: &Branch,
+        head: &Branch,
+    ) -> Result<BranchComparison, RepositoryProviderError>;
 }
 
-impl RepositoryProviderError {
-    pub fn new(msg: String) -> Self {
-        RepositoryProviderError { msg }
+/// Lets one provider be shared, e.g. by the repository catalog and the provider registry.
+#[async_trait::async_trait]
+impl<T> RepositoryProvider for Arc<T>
+where
+    T: RepositoryProvider + Send + Sync + ?Sized,
+{
+    async fn get_repository(
+        &self,
+        url: &RepositoryUrl,
+    ) -> Result<Repository, RepositoryProviderError> {
+        self.as_ref().get_repository(url).await
+    }
+
+    async fn get_repository_with_head_commits(
+        &self,
+        url: &RepositoryUrl,
+    ) -> Result<Repository, RepositoryProviderError> {
+        self.as_ref().get_repository_with_head_commits(url).await
+    }
+
+    async fn compare_branches(
+        &self,
+        url: &RepositoryUrl,
+        base: &Branch,
+        head: &Branch,
+    ) -> Result<BranchComparison, RepositoryProviderError> {
+        self.as_ref().compare_branches(url, base, head).await
     }
 }
@@ -0,0 +1,124 @@
+use crate::domain::repository::{RepositoryIdentity, RepositoryUrl};
+
+/// Why a repository hosting provider failed.
+///
+/// Each variant keeps the provider's own description as `message` and, when the failure concerns
+/// a single repository, the identity of that repository.
+#[derive(Debug, thiserror::Error)]
+pub enum RepositoryProviderError {
+    #[error("{message}")]
+    NotFound {
+        repository: Option<RepositoryIdentity>,
+        message: String,
+    },
+    #[error("{message}")]
+    Unauthorized {
+        repository: Option<RepositoryIdentity>,
+        message: String,
+    },
+    #[error("{message}")]
+    Forbidden {
+        repository: Option<RepositoryIdentity>,
+        message: String,
+    },
+    #[error("{message}")]
+    RateLimited {
+        repository: Option<RepositoryIdentity>,
+        message: String,
+    },
+    #[error("{message}")]
+    InvalidUrl {
+        repository: Option<RepositoryIdentity>,
+        message: String,
+    },
+    #[error("no provider for host '{host}'")]
+    UnsupportedHost {
+        repository: Option<RepositoryIdentity>,
+        host: String,
+    },
+    #[error("{message}")]
+    Transport {
+        repository: Option<RepositoryIdentity>,
+        message: String,
+    },
+    #[error("{message}")]
+    Other {
+        repository: Option<RepositoryIdentity>,
+        message: String,
+    },
+}
+
+impl RepositoryProviderError {
+    pub fn repository(&self) -> Option<&RepositoryIdentity> {
+        match self {
+            RepositoryProviderError::NotFound { repository, .. }
+            | RepositoryProviderError::Unauthorized { repository, .. }
+            | RepositoryProviderError::Forbidden { repository, .. }
+            | RepositoryProviderError::RateLimited { repository, .. }
+            | RepositoryProviderError::InvalidUrl { repository, .. }
+            | RepositoryProviderError::UnsupportedHost { repository, .. }
+            | RepositoryProviderError::Transport { repository, .. }
+            | RepositoryProviderError::Other { repository, .. } => repository.as_ref(),
+        }
+    }
+
+    pub fn for_repository(mut self, url: &RepositoryUrl) -> Self {
+        match &mut self {
+            RepositoryProviderError::NotFound { repository, .. }
+            | RepositoryProviderError::Unauthorized { repository, .. }
+            | RepositoryProviderError::Forbidden { repository, .. }
+            | RepositoryProviderError::RateLimited { repository, .. }
+            | RepositoryProviderError::InvalidUrl { repository, .. }
+            | RepositoryProviderError::UnsupportedHost { repository, .. }
+            | RepositoryProviderError::Transport { repository, .. }
+            | RepositoryProviderError::Other { repository, .. } => {
+                *repository = Some(url.identity().clone())
+            }
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    fn not_found() -> RepositoryProviderError {
+        RepositoryProviderError::NotFound {
+            repository: None,
+            message: "repository 'url' not found".to_string(),
+        }
+    }
+
+    #[test]
+    fn displays_provider_message() {
+        assert_that(&not_found().to_string()).is_equal_to("repository 'url' not found".to_string());
+    }
+
+    #[test]
+    fn has_no_repository_by_default() {
+        assert_that(&not_found().repository()).is_none();
+    }
+
+    #[test]
+    fn carries_identity_of_failed_repository() {
+        let url = RepositoryUrl::new("https://github.com/Owner/repo.git".to_string());
+
+        assert_that(&not_found().for_repository(&url).repository())
+            .is_some()
+            .is_equal_to(url.identity());
+    }
+
+    #[test]
+    fn keeps_failure_kind_when_repository_is_added() {
+        let url = RepositoryUrl::new("https://github.com/owner/repo".to_string());
+
+        assert_that(&matches!(
+            not_found().for_repository(&url),
+            RepositoryProviderError::NotFound { .. }
+        ))
+        .is_true();
+    }
+}
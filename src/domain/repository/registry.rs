@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::domain::repository::{
+    RepositoryMaintainer, RepositoryProvider, RepositoryProviderError, RepositoryUrl,
+};
+
+pub trait RepositoryHost: RepositoryProvider + RepositoryMaintainer + Send + Sync {}
+
+impl<T> RepositoryHost for T where T: RepositoryProvider + RepositoryMaintainer + Send + Sync {}
+
+#[derive(Default)]
+pub struct RepositoryProviderRegistry {
+    providers: HashMap<String, Arc<dyn RepositoryHost>>,
+    local_provider: Option<Arc<dyn RepositoryHost>>,
+}
+
+impl RepositoryProviderRegistry {
+    pub fn new() -> Self {
+        RepositoryProviderRegistry {
+            providers: HashMap::new(),
+            local_provider: None,
+        }
+    }
+
+    /// Registers the provider for repositories given as local paths or `file://` URLs.
+    pub fn with_local_provider<P>(mut self, provider: P) -> Self
+    where
+        P: RepositoryHost + 'static,
+    {
+        self.local_provider = Some(Arc::new(provider));
+        self
+    }
+
+    pub fn with_provider<P>(mut self, hosts: Vec<String>, provider: P) -> Self
+    where
+        P: RepositoryHost + 'static,
+    {
+        let provider: Arc<dyn RepositoryHost> = Arc::new(provider);
+        hosts.into_iter().for_each(|host| {
+            self.providers
+                .insert(host.to_lowercase(), Arc::clone(&provider));
+        });
+        self
+    }
+
+    pub fn provider_for(
+        &self,
+        url: &RepositoryUrl,
+    ) -> Result<&dyn RepositoryHost, RepositoryProviderError> {
+        let host = url.host();
+        let provider = if url.is_local() {
+            self.local_provider.as_ref()
+        } else {
+            self.providers.get(host)
+        };
+        provider.map(|provider| provider.as_ref()).ok_or_else(|| {
+            RepositoryProviderError::UnsupportedHost {
+                repository: Some(url.identity().clone()),
+                host: host.to_string(),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use crate::domain::repository::{Branch, BranchComparison, Repository};
+
+    use super::*;
+
+    mockall::mock! {
+        RepositoryHost {}
+
+        #[async_trait::async_trait]
+        impl RepositoryProvider for RepositoryHost {
+            async fn get_repository(
+                &self,
+                url: &RepositoryUrl,
+            ) -> Result<Repository, RepositoryProviderError>;
+
+            async fn get_repository_with_head_commits(
+                &self,
+                url: &RepositoryUrl,
+            ) -> Result<Repository, RepositoryProviderError>;
+
+            async fn compare_branches(
+                &self,
+                url: &RepositoryUrl,
+                base: &Branch,
+                head: &Branch,
+            ) -> Result<BranchComparison, RepositoryProviderError>;
+        }
+
+        #[async_trait::async_trait]
+        impl RepositoryMaintainer for RepositoryHost {
+            async fn delete_branch(
+                &self,
+                url: &RepositoryUrl,
+                branch: &Branch,
+            ) -> Result<(), RepositoryProviderError>;
+        }
+    }
+
+    fn mock_repository_host_with_branch(name: &'static str) -> MockRepositoryHost {
+        let mut mock_repository_host = MockRepositoryHost::default();
+        mock_repository_host
+            .expect_get_repository()
+            .returning(move |url| {
+                Ok(Repository::new(
+                    url.clone(),
+                    vec![Branch::new(name.to_string())],
+                ))
+            });
+        mock_repository_host
+    }
+
+    fn under_test() -> RepositoryProviderRegistry {
+        RepositoryProviderRegistry::new()
+            .with_provider(
+                vec!["github.com".to_string()],
+                mock_repository_host_with_branch("github"),
+            )
+            .with_provider(
+                vec!["gitlab.com".to_string(), "GitLab.example.com".to_string()],
+                mock_repository_host_with_branch("gitlab"),
+            )
+    }
+
+    async fn branches_from_provider_for(url: &str) -> Vec<Branch> {
+        let url = RepositoryUrl::new(url.to_string());
+        under_test()
+            .provider_for(&url)
+            .unwrap()
+            .get_repository(&url)
+            .await
+            .unwrap()
+            .branches()
+            .clone()
+    }
+
+    #[async_std::test]
+    async fn routes_url_to_provider_registered_for_its_host() {
+        assert_that(&branches_from_provider_for("https://github.com/owner/repo").await)
+            .is_equal_to(vec![Branch::new("github".to_string())]);
+        assert_that(&branches_from_provider_for("https://gitlab.com/group/project").await)
+            .is_equal_to(vec![Branch::new("gitlab".to_string())]);
+    }
+
+    #[async_std::test]
+    async fn shares_provider_between_hosts_ignoring_case() {
+        assert_that(&branches_from_provider_for("https://gitlab.example.com/group/project").await)
+            .is_equal_to(vec![Branch::new("gitlab".to_string())]);
+    }
+
+    #[async_std::test]
+    async fn routes_local_path_to_local_provider() {
+        let under_test =
+            under_test().with_local_provider(mock_repository_host_with_branch("local"));
+        let url = RepositoryUrl::new("/srv/mirrors/repo.git".to_string());
+
+        assert_that(
+            under_test
+                .provider_for(&url)
+                .unwrap()
+                .get_repository(&url)
+                .await
+                .unwrap()
+                .branches(),
+        )
+        .is_equal_to(vec![Branch::new("local".to_string())]);
+    }
+
+    #[test]
+    fn fails_when_no_local_provider_is_registered() {
+        assert_that(
+            &under_test()
+                .provider_for(&RepositoryUrl::new("file:///srv/repo".to_string()))
+                .is_err(),
+        )
+        .is_true();
+    }
+
+    #[test]
+    fn fails_when_no_provider_is_registered_for_host() {
+        let under_test = under_test();
+        let result = under_test.provider_for(&RepositoryUrl::new(
+            "https://bitbucket.org/owner/repo".to_string(),
+        ));
+
+        assert_that(&result.err().unwrap().to_string())
+            .is_equal_to("no provider for host 'bitbucket.org'".to_string());
+    }
+
+    #[test]
+    fn reports_unsupported_host_with_repository_identity() {
+        let under_test = under_test();
+        let result = under_test.provider_for(&RepositoryUrl::new(
+            "https://bitbucket.org/owner/repo".to_string(),
+        ));
+
+        match result.err().unwrap() {
+            error @ RepositoryProviderError::UnsupportedHost { .. } => {
+                assert_that(&error.repository().unwrap().to_string())
+                    .is_equal_to("bitbucket.org/owner/repo".to_string())
+            }
+            error => panic!("unexpected error {:?}", error),
+        }
+    }
+}
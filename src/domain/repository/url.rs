@@ -1,20 +1,164 @@
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+
 use crate::domain::value_object::ValueObject;
 
-#[derive(Debug, Eq, PartialEq, Clone, Hash)]
-pub struct RepositoryUrl(String);
+#[derive(Debug, Clone)]
+pub struct RepositoryUrl {
+    value: String,
+    identity: RepositoryIdentity,
+}
 
 impl ValueObject<String> for RepositoryUrl {
     fn value(&self) -> &String {
-        &self.0
+        &self.value
     }
 }
 
 impl RepositoryUrl {
     pub fn new(value: String) -> Self {
-        RepositoryUrl(value)
+        let identity = RepositoryIdentity::from_url(value.as_str());
+        RepositoryUrl { value, identity }
+    }
+
+    pub fn identity(&self) -> &RepositoryIdentity {
+        &self.identity
+    }
+
+    pub fn host(&self) -> &str {
+        self.identity.host()
+    }
+
+    /// The URL shared by every spelling of the repository, e.g. `https://github.com/owner/repo`
+    /// for `git@github.com:Owner/repo.git`. Local repositories are named by their path. It names
+    /// the repository in results and is not meant to be connected to.
+    pub fn canonical(&self) -> RepositoryUrl {
+        if self.identity.host().is_empty() {
+            RepositoryUrl::new(self.identity.path().to_string())
+        } else {
+            RepositoryUrl::new(format!("https://{}", self.identity))
+        }
+    }
+
+    /// Whether the URL names a repository on this machine rather than on a hosting provider.
+    pub fn is_local(&self) -> bool {
+        is_local_url(self.value.trim())
     }
 }
 
+impl PartialEq for RepositoryUrl {
+    fn eq(&self, other: &Self) -> bool {
+        self.identity == other.identity
+    }
+}
+
+impl Eq for RepositoryUrl {}
+
+impl Hash for RepositoryUrl {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.identity.hash(state)
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Hash)]
+pub struct RepositoryIdentity {
+    host: String,
+    port: Option<u16>,
+    path: String,
+}
+
+impl RepositoryIdentity {
+    fn from_url(url: &str) -> Self {
+        if is_local_url(url.trim()) {
+            return RepositoryIdentity::from_local_url(url.trim());
+        }
+        let trimmed = url.trim().trim_end_matches('/');
+        let trimmed = trimmed.strip_suffix(".git").unwrap_or(trimmed);
+        let (scheme, without_scheme) = match trimmed.split_once("://") {
+            Some((scheme, rest)) => (scheme.to_lowercase(), rest),
+            None => ("https".to_string(), trimmed),
+        };
+        let (authority, path) = without_scheme
+            .split_once('/')
+            .unwrap_or((without_scheme, ""));
+        let host_and_port = match authority.rsplit_once('@') {
+            Some((_, host_and_port)) => host_and_port,
+            None => authority,
+        };
+        let (host, port, path) = match host_and_port.split_once(':') {
+            Some((host, port)) if port.chars().all(|c| c.is_ascii_digit()) => {
+                (host, Self::web_port(&scheme, port), path.to_string())
+            }
+            Some((host, scp_path)) => (host, None, format!("{}/{}", scp_path, path)),
+            None => (host_and_port, None, path.to_string()),
+        };
+
+        RepositoryIdentity {
+            host: host.to_lowercase(),
+            port,
+            path: path.trim_end_matches('/').to_lowercase(),
+        }
+    }
+
+    /// A port only tells repositories apart when it is a non-default HTTP(S) port; an SSH port
+    /// reaches the same repository as the web URL without it.
+    fn web_port(scheme: &str, port: &str) -> Option<u16> {
+        let default_port = match scheme {
+            "https" => 443,
+            "http" => 80,
+            _ => return None,
+        };
+        port.parse().ok().filter(|port| *port != default_port)
+    }
+
+    /// Local paths keep their case and `.git` suffix, as both matter on most filesystems.
+    fn from_local_url(url: &str) -> Self {
+        let path = url.strip_prefix(FILE_URL_PREFIX).unwrap_or(url);
+        RepositoryIdentity {
+            host: String::new(),
+            port: None,
+            path: match path.trim_end_matches('/') {
+                "" => "/".to_string(),
+                path => path.to_string(),
+            },
+        }
+    }
+
+    pub fn host(&self) -> &str {
+        &self.host
+    }
+
+    pub fn port(&self) -> Option<u16> {
+        self.port
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+}
+
+impl Display for RepositoryIdentity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match (self.host.is_empty(), self.port) {
+            (true, _) => write!(f, "{}", self.path),
+            (false, Some(port)) => write!(f, "{}:{}/{}", self.host, port, self.path),
+            (false, None) => write!(f, "{}/{}", self.host, self.path),
+        }
+    }
+}
+
+const FILE_URL_PREFIX: &str = "file://";
+
+fn is_local_url(url: &str) -> bool {
+    url.starts_with(FILE_URL_PREFIX)
+        || url.starts_with('/')
+        || url.starts_with('~')
+        || url == "."
+        || url == ".."
+        || url.starts_with("./")
+        || url.starts_with("../")
+}
+
 #[cfg(test)]
 mod tests {
     use spectral::prelude::*;
@@ -25,8 +169,168 @@ mod tests {
         RepositoryUrl::new("url".to_string())
     }
 
+    fn identity_of(url: &str) -> String {
+        RepositoryUrl::new(url.to_string()).identity().to_string()
+    }
+
     #[test]
     fn returns_string_value() {
         assert_that(&under_test().value()).is_equal_to(&"url".to_string());
     }
+
+    #[test]
+    fn canonicalizes_every_spelling_of_remote_repository_to_https_url() {
+        [
+            "github.com/owner/repo",
+            "https://github.com/Owner/repo.git",
+            "git@github.com:owner/repo.git",
+        ]
+        .iter()
+        .for_each(|url| {
+            assert_that(&RepositoryUrl::new(url.to_string()).canonical().value())
+                .is_equal_to(&"https://github.com/owner/repo".to_string())
+        });
+    }
+
+    #[test]
+    fn keeps_non_default_web_port_in_canonical_url() {
+        assert_that(
+            &RepositoryUrl::new("https://gitlab.example.com:8443/group/project.git".to_string())
+                .canonical()
+                .value(),
+        )
+        .is_equal_to(&"https://gitlab.example.com:8443/group/project".to_string());
+    }
+
+    #[test]
+    fn canonicalizes_local_repository_to_its_path() {
+        assert_that(
+            &RepositoryUrl::new("file:///srv/mirrors/repo.git".to_string())
+                .canonical()
+                .value(),
+        )
+        .is_equal_to(&"/srv/mirrors/repo.git".to_string());
+    }
+
+    #[test]
+    fn returns_host_of_https_url() {
+        assert_that(&RepositoryUrl::new("https://GitHub.com/owner/repo".to_string()).host())
+            .is_equal_to("github.com");
+    }
+
+    #[test]
+    fn returns_host_of_url_without_scheme() {
+        assert_that(&RepositoryUrl::new("gitlab.com/group/project".to_string()).host())
+            .is_equal_to("gitlab.com");
+    }
+
+    #[test]
+    fn returns_host_without_port_or_user() {
+        assert_that(
+            &RepositoryUrl::new("ssh://git@gitlab.example.com:2222/group/project".to_string())
+                .host(),
+        )
+        .is_equal_to("gitlab.example.com");
+    }
+
+    #[test]
+    fn normalizes_identity_of_https_url() {
+        assert_that(&identity_of("https://github.com/A/b.git/"))
+            .is_equal_to("github.com/a/b".to_string());
+    }
+
+    #[test]
+    fn keeps_missing_owner_in_identity() {
+        assert_that(&identity_of("https://github.com//repo"))
+            .is_equal_to("github.com//repo".to_string());
+    }
+
+    #[test]
+    fn normalizes_identity_of_scp_like_ssh_url() {
+        assert_that(&identity_of("git@github.com:owner/repo.git"))
+            .is_equal_to("github.com/owner/repo".to_string());
+    }
+
+    #[test]
+    fn keeps_nested_groups_in_identity() {
+        assert_that(&identity_of("https://gitlab.example.com/group/sub/project"))
+            .is_equal_to("gitlab.example.com/group/sub/project".to_string());
+    }
+
+    #[test]
+    fn keeps_non_default_web_port_in_identity() {
+        assert_that(&identity_of(
+            "https://gitlab.example.com:8443/group/sub/project",
+        ))
+        .is_equal_to("gitlab.example.com:8443/group/sub/project".to_string());
+    }
+
+    #[test]
+    fn drops_default_web_port_and_ssh_port_from_identity() {
+        [
+            "https://gitlab.example.com:443/group/project",
+            "http://gitlab.example.com:80/group/project",
+            "ssh://git@gitlab.example.com:2222/group/project",
+        ]
+        .iter()
+        .for_each(|url| {
+            assert_that(&identity_of(url))
+                .is_equal_to("gitlab.example.com/group/project".to_string())
+        });
+    }
+
+    #[test]
+    fn does_not_equal_url_on_different_port_of_same_host() {
+        assert_that(&RepositoryUrl::new(
+            "https://gitlab.example.com:8443/group/project".to_string(),
+        ))
+        .is_not_equal_to(RepositoryUrl::new(
+            "https://gitlab.example.com/group/project".to_string(),
+        ));
+    }
+
+    #[test]
+    fn recognizes_local_paths_and_file_urls() {
+        [
+            "/srv/mirrors/repo.git",
+            "./repo",
+            "../repo",
+            ".",
+            "~/repo",
+            "file:///srv/repo",
+        ]
+        .iter()
+        .for_each(|url| {
+            assert_that(&RepositoryUrl::new(url.to_string()).is_local()).is_true();
+        });
+        assert_that(&RepositoryUrl::new("github.com/owner/repo".to_string()).is_local()).is_false();
+        assert_that(&RepositoryUrl::new("git@github.com:owner/repo".to_string()).is_local())
+            .is_false();
+    }
+
+    #[test]
+    fn keeps_case_and_suffix_in_identity_of_local_path() {
+        let url = RepositoryUrl::new("file:///srv/Mirrors/repo.git/".to_string());
+
+        assert_that(&url.host()).is_equal_to("");
+        assert_that(&url.identity().to_string()).is_equal_to("/srv/Mirrors/repo.git".to_string());
+    }
+
+    #[test]
+    fn equals_file_url_for_same_path() {
+        assert_that(&RepositoryUrl::new("/srv/repo".to_string()))
+            .is_equal_to(RepositoryUrl::new("file:///srv/repo".to_string()));
+    }
+
+    #[test]
+    fn equals_url_with_same_identity() {
+        assert_that(&RepositoryUrl::new("github.com/a/b".to_string()))
+            .is_equal_to(RepositoryUrl::new("https://github.com/A/b.git".to_string()));
+    }
+
+    #[test]
+    fn does_not_equal_url_with_different_identity() {
+        assert_that(&RepositoryUrl::new("https://github.com/a/b".to_string()))
+            .is_not_equal_to(RepositoryUrl::new("https://gitlab.com/a/b".to_string()));
+    }
 }
@@ -0,0 +1,110 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::repository::{Branch, Repository};
+
+#[cfg_attr(test, mockall::automock)]
+pub trait StaleBranchService {
+    fn find_stale_branches_in_repositories(
+        &self,
+        repositories: Vec<Repository>,
+        cutoff: DateTime<Utc>,
+    ) -> Vec<(Repository, Vec<Branch>)>;
+}
+
+#[derive(Default)]
+pub struct StaleBranchServiceImpl;
+
+impl StaleBranchServiceImpl {
+    pub fn new() -> Self {
+        StaleBranchServiceImpl
+    }
+
+    fn is_stale(branch: &Branch, cutoff: &DateTime<Utc>) -> bool {
+        match branch.head_commit() {
+            Some(commit) => commit.date() < cutoff,
+            None => false,
+        }
+    }
+}
+
+impl StaleBranchService for StaleBranchServiceImpl {
+    fn find_stale_branches_in_repositories(
+        &self,
+        repositories: Vec<Repository>,
+        cutoff: DateTime<Utc>,
+    ) -> Vec<(Repository, Vec<Branch>)> {
+        repositories
+            .iter()
+            .map(|repo| {
+                let stale_branches = repo
+                    .branches()
+                    .iter()
+                    .filter(|branch| Self::is_stale(branch, &cutoff))
+                    .cloned()
+                    .collect();
+                (repo.clone(), stale_branches)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use spectral::prelude::*;
+
+    use crate::domain::repository::{Commit, RepositoryUrl};
+
+    use super::*;
+
+    fn date(year: i32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, 1, 1, 0, 0, 0).unwrap()
+    }
+
+    fn branch_committed_in(name: &str, year: i32) -> Branch {
+        Branch::with_head_commit(
+            name.to_string(),
+            Commit::new("sha".to_string(), date(year), "author".to_string()),
+        )
+    }
+
+    fn repository(branches: Vec<Branch>) -> Repository {
+        Repository::new(RepositoryUrl::new("url".to_string()), branches)
+    }
+
+    #[test]
+    fn finds_branches_with_head_commit_older_than_cutoff() {
+        let under_test = StaleBranchServiceImpl::new();
+        let repository = repository(vec![
+            branch_committed_in("old", 2000),
+            branch_committed_in("new", 2020),
+        ]);
+
+        assert_that(
+            &under_test.find_stale_branches_in_repositories(vec![repository.clone()], date(2010)),
+        )
+        .is_equal_to(vec![(repository, vec![branch_committed_in("old", 2000)])]);
+    }
+
+    #[test]
+    fn does_not_consider_branch_committed_at_cutoff_to_be_stale() {
+        let under_test = StaleBranchServiceImpl::new();
+        let repository = repository(vec![branch_committed_in("branch", 2010)]);
+
+        assert_that(
+            &under_test.find_stale_branches_in_repositories(vec![repository.clone()], date(2010)),
+        )
+        .is_equal_to(vec![(repository, vec![])]);
+    }
+
+    #[test]
+    fn does_not_consider_branch_without_head_commit_to_be_stale() {
+        let under_test = StaleBranchServiceImpl::new();
+        let repository = repository(vec![Branch::new("branch".to_string())]);
+
+        assert_that(
+            &under_test.find_stale_branches_in_repositories(vec![repository.clone()], date(2010)),
+        )
+        .is_equal_to(vec![(repository, vec![])]);
+    }
+}
@@ -1,46 +1,146 @@
+use std::io::ErrorKind;
 use std::path::PathBuf;
+use std::sync::{Arc, OnceLock};
 
 use structopt::StructOpt;
 
 use std::process::exit;
-use tidy_repo::application::ApplicationService;
-use tidy_repo::domain::authentication::GitHubAuthenticationService;
+use tidy_repo::application::{ApplicationService, DEFAULT_CONCURRENCY};
+use tidy_repo::domain::authentication::credential_repository::CredentialKey;
+use tidy_repo::domain::authentication::{
+    RepositoryCredentialsValidator, TokenAuthenticationService,
+};
 use tidy_repo::domain::count_branches::BranchCounterServiceImpl;
+use tidy_repo::domain::merged_branches::MergedBranchServiceImpl;
+use tidy_repo::domain::repository::{
+    RepositoryCatalog, RepositoryHost, RepositoryProviderRegistry,
+};
+use tidy_repo::domain::stale_branches::StaleBranchServiceImpl;
 use tidy_repo::ports::cli::terminal_client::{
-    StructOptClientOptions, TerminalClientTidyRepoAppAdapter,
+    AuthenticateCommand, ClientOptions, GitHubApi, StructOptClientOptions,
+    TerminalClientTidyRepoAppAdapter,
 };
 use tidy_repo::ports::persistence::filesystem::{
-    FilesystemCredentialRepositoryAdapter, SerializableContentFilesystemStore,
+    ContentStore, FileSystemPersistenceError, FilesystemCredentialRepositoryAdapter,
+    SerializableContentFilesystemStore,
 };
-use tidy_repo::ports::persistence::Credentials;
+use tidy_repo::ports::persistence::{Configuration, Credentials, GitHubEnterpriseHost, GiteaHost};
+use tidy_repo::ports::repository_hosting::bitbucket::{
+    BitbucketClient, BitbucketCredentialsValidatorAdapter, BitbucketRepositoryProviderAdapter,
+    BitbucketRepositoryUrlParserImpl,
+};
+use tidy_repo::ports::repository_hosting::gitea::GiteaClient;
 use tidy_repo::ports::repository_hosting::github::{
-    GitHubClient, GitHubCredentialsValidatorAdapter, GitHubRepositoryProviderAdapter,
-    GitHubRepositoryUrlParserImpl,
+    GitHubClient, GitHubCredentialsValidatorAdapter, GitHubGraphQlClient,
+    GitHubRepositoryProviderAdapter, GitHubRepositoryUrlParserImpl,
+};
+use tidy_repo::ports::repository_hosting::gitlab::{
+    GitLabClient, GitLabCredentialsValidatorAdapter, GitLabRepositoryProviderAdapter,
+    GitLabRepositoryUrlParserImpl,
+};
+use tidy_repo::ports::repository_hosting::local::{
+    LocalRepositoryProviderAdapter, LocalWorkingCopyProviderAdapter,
 };
 use tidy_repo::utils::environment::{EnvironmentReader, EnvironmentReaderStd};
-use tidy_repo::utils::http::HttpClientFacadeImpl;
+use tidy_repo::utils::http::{
+    AsyncStdSleeper, CachingHttpClientFacade, ConcurrencyLimitingHttpClientFacade,
+    FilesystemHttpCache, HttpClientFacadeImpl, RequestLimiter, RetryPolicy,
+    RetryingHttpClientFacade,
+};
 use tidy_repo::TidyRepoApp;
 
+type HttpClientAlias = RetryingHttpClientFacade<
+    CachingHttpClientFacade<
+        ConcurrencyLimitingHttpClientFacade<HttpClientFacadeImpl>,
+        FilesystemHttpCache,
+    >,
+    AsyncStdSleeper,
+>;
 type GitHubClientAlias =
-    GitHubClient<HttpClientFacadeImpl, GitHubRepositoryUrlParserImpl, EnvironmentReaderStd>;
-type GitHubAuthenticationServiceAlias = GitHubAuthenticationService<
-    GitHubCredentialsValidatorAdapter<GitHubClientAlias>,
+    GitHubClient<HttpClientAlias, GitHubRepositoryUrlParserImpl, EnvironmentReaderStd>;
+type GitHubGraphQlClientAlias =
+    GitHubGraphQlClient<HttpClientAlias, GitHubRepositoryUrlParserImpl, EnvironmentReaderStd>;
+type GiteaClientAlias = GiteaClient<HttpClientAlias, GitHubRepositoryUrlParserImpl>;
+type GitLabClientAlias =
+    GitLabClient<HttpClientAlias, GitLabRepositoryUrlParserImpl, EnvironmentReaderStd>;
+type BitbucketClientAlias =
+    BitbucketClient<HttpClientAlias, BitbucketRepositoryUrlParserImpl, EnvironmentReaderStd>;
+type AuthenticationServiceAlias = TokenAuthenticationService<
+    Box<dyn RepositoryCredentialsValidator + Send + Sync>,
     FilesystemCredentialRepositoryAdapterAlias,
 >;
+type GitHubRepositoryProviderAdapterAlias<GC> =
+    GitHubRepositoryProviderAdapter<GC, FilesystemCredentialRepositoryAdapterAlias>;
 type FilesystemCredentialRepositoryAdapterAlias =
     FilesystemCredentialRepositoryAdapter<SerializableContentFilesystemStore<Credentials>>;
 
+const BITBUCKET_HOST: &str = "bitbucket.org";
+
+/// Set from `--concurrency` before any client is built.
+static CONCURRENCY: OnceLock<usize> = OnceLock::new();
+/// Shared by every HTTP client, so the limit holds across repositories, branches and hosts.
+static REQUEST_LIMITER: OnceLock<RequestLimiter> = OnceLock::new();
+
 #[async_std::main]
 async fn main() {
-    tidy_repo_app().run().await;
+    let client_options = StructOptClientOptions::from_args();
+    CONCURRENCY
+        .set(client_options.concurrency().value())
+        .expect("concurrency is only set once");
+    let configuration = configuration().await;
+    match client_options.github_api() {
+        GitHubApi::Rest => {
+            tidy_repo_app(
+                client_options,
+                configuration,
+                github_repository_provider(None),
+                |host| github_repository_provider(Some(host)),
+            )
+            .run()
+            .await
+        }
+        GitHubApi::GraphQl => {
+            tidy_repo_app(
+                client_options,
+                configuration,
+                github_graphql_repository_provider(None),
+                |host| github_graphql_repository_provider(Some(host)),
+            )
+            .run()
+            .await
+        }
+    }
 }
 
-fn tidy_repo_app() -> impl TidyRepoApp {
-    let client_options = StructOptClientOptions::from_args();
-    TerminalClientTidyRepoAppAdapter::new(client_options, application_service())
+fn tidy_repo_app<GRP, ERP>(
+    client_options: StructOptClientOptions,
+    configuration: Configuration,
+    github_repository_provider: GRP,
+    github_enterprise_repository_provider: impl Fn(&GitHubEnterpriseHost) -> ERP,
+) -> impl TidyRepoApp
+where
+    GRP: RepositoryHost + RepositoryCatalog + 'static,
+    ERP: RepositoryHost + 'static,
+{
+    let authentication_service = authentication_service(&client_options, &configuration);
+    let repository_providers = |github_repository_provider| {
+        repository_providers(
+            github_repository_provider,
+            &configuration,
+            github_enterprise_repository_provider,
+        )
+    };
+    TerminalClientTidyRepoAppAdapter::new(
+        client_options,
+        application_service(
+            authentication_service,
+            github_repository_provider,
+            repository_providers,
+        ),
+    )
 }
 
-fn app_credentials_filepath() -> PathBuf {
+fn app_home() -> PathBuf {
     let env_reader = EnvironmentReaderStd::new();
 
     match &env_reader.read("TIDY_REPO_HOME") {
@@ -50,13 +150,289 @@ fn app_credentials_filepath() -> PathBuf {
             exit(1);
         }
     }
-    .join("credentials.yml")
 }
 
-fn github_client() -> GitHubClientAlias {
-    let http_client = HttpClientFacadeImpl::new(surf::client());
-    let url_parser = GitHubRepositoryUrlParserImpl::new();
-    GitHubClient::new(http_client, url_parser, EnvironmentReaderStd::new())
+fn app_credentials_filepath() -> PathBuf {
+    app_home().join("credentials.yml")
+}
+
+fn app_configuration_filepath() -> PathBuf {
+    app_home().join("config.yml")
+}
+
+async fn configuration() -> Configuration {
+    let filepath = app_configuration_filepath();
+    let store = SerializableContentFilesystemStore::<Configuration>::new(filepath.clone());
+    match store.get().await {
+        Ok(configuration) => configuration,
+        Err(FileSystemPersistenceError::IO(e)) if e.kind() == ErrorKind::NotFound => {
+            Configuration::default()
+        }
+        Err(e) => {
+            eprintln!("Error: could not read {}: {}", filepath.display(), e);
+            exit(1);
+        }
+    }
+}
+
+fn github_enterprise_host<'a>(
+    configuration: &'a Configuration,
+    host: &str,
+) -> &'a GitHubEnterpriseHost {
+    match configuration.github_enterprise_host(host) {
+        Some(enterprise_host) => enterprise_host,
+        None => {
+            eprintln!(
+                "Error: GitHub Enterprise Server host '{}' is not configured in {}",
+                host,
+                app_configuration_filepath().display()
+            );
+            exit(1);
+        }
+    }
+}
+
+/// The user's git config files in the order git reads them: `$XDG_CONFIG_HOME/git/config`,
+/// falling back to `~/.config/git/config`, then `~/.gitconfig`.
+fn global_git_config_files() -> Vec<PathBuf> {
+    let env_reader = EnvironmentReaderStd::new();
+    let home = env_reader.read("HOME").ok().map(PathBuf::from);
+    let xdg_config_home = env_reader
+        .read("XDG_CONFIG_HOME")
+        .ok()
+        .filter(|path| !path.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| home.as_ref().map(|home| home.join(".config")));
+    xdg_config_home
+        .map(|directory| directory.join("git").join("config"))
+        .into_iter()
+        .chain(home.map(|home| home.join(".gitconfig")))
+        .collect()
+}
+
+fn http_cache_directory() -> PathBuf {
+    app_home().join("http-cache")
+}
+
+fn concurrency() -> usize {
+    *CONCURRENCY.get_or_init(|| DEFAULT_CONCURRENCY)
+}
+
+fn request_limiter() -> RequestLimiter {
+    REQUEST_LIMITER
+        .get_or_init(|| RequestLimiter::new(concurrency()))
+        .clone()
+}
+
+fn http_client() -> HttpClientAlias {
+    RetryingHttpClientFacade::new(
+        CachingHttpClientFacade::new(
+            ConcurrencyLimitingHttpClientFacade::new(
+                HttpClientFacadeImpl::new(surf::client()),
+                request_limiter(),
+            ),
+            FilesystemHttpCache::new(http_cache_directory()),
+        ),
+        RetryPolicy::default(),
+        AsyncStdSleeper::new(),
+    )
+}
+
+fn github_client(enterprise_host: Option<&GitHubEnterpriseHost>) -> GitHubClientAlias {
+    let http_client = http_client();
+    match enterprise_host {
+        Some(enterprise_host) => GitHubClient::new(
+            http_client,
+            GitHubRepositoryUrlParserImpl::for_host(enterprise_host.host()),
+            EnvironmentReaderStd::new(),
+        )
+        .with_api_base_url(enterprise_host.api_url()),
+        None => GitHubClient::new(
+            http_client,
+            GitHubRepositoryUrlParserImpl::new(),
+            EnvironmentReaderStd::new(),
+        ),
+    }
+}
+
+fn github_graphql_client(
+    enterprise_host: Option<&GitHubEnterpriseHost>,
+) -> GitHubGraphQlClientAlias {
+    let http_client = http_client();
+    match enterprise_host {
+        Some(enterprise_host) => GitHubGraphQlClient::new(
+            http_client,
+            GitHubRepositoryUrlParserImpl::for_host(enterprise_host.host()),
+            EnvironmentReaderStd::new(),
+        )
+        .with_api_base_url(enterprise_host.api_url()),
+        None => GitHubGraphQlClient::new(
+            http_client,
+            GitHubRepositoryUrlParserImpl::new(),
+            EnvironmentReaderStd::new(),
+        ),
+    }
+}
+
+fn gitlab_client() -> GitLabClientAlias {
+    let http_client = http_client();
+    let url_parser = GitLabRepositoryUrlParserImpl::new();
+    GitLabClient::new(http_client, url_parser, EnvironmentReaderStd::new())
+}
+
+fn bitbucket_client() -> BitbucketClientAlias {
+    BitbucketClient::new(
+        http_client(),
+        BitbucketRepositoryUrlParserImpl::new(),
+        EnvironmentReaderStd::new(),
+    )
+}
+
+fn gitea_host<'a>(configuration: &'a Configuration, host: &str) -> &'a GiteaHost {
+    match configuration.gitea_host(host) {
+        Some(gitea_host) => gitea_host,
+        None => {
+            eprintln!(
+                "Error: Gitea host '{}' is not configured in {}",
+                host,
+                app_configuration_filepath().display()
+            );
+            exit(1);
+        }
+    }
+}
+
+fn gitea_client(gitea_host: &GiteaHost) -> GiteaClientAlias {
+    GiteaClient::new(
+        http_client(),
+        GitHubRepositoryUrlParserImpl::for_host(gitea_host.host()),
+        gitea_host.api_url(),
+    )
+}
+
+fn gitlab_host(host: &str) -> String {
+    match gitlab_hosts()
+        .into_iter()
+        .find(|gitlab_host| gitlab_host.eq_ignore_ascii_case(host))
+    {
+        Some(gitlab_host) => gitlab_host,
+        None => {
+            eprintln!(
+                "Error: GitLab host '{}' is not listed in TIDY_REPO_GITLAB_HOSTS",
+                host
+            );
+            exit(1);
+        }
+    }
+}
+
+fn gitlab_hosts() -> Vec<String> {
+    let mut hosts = vec!["gitlab.com".to_string()];
+    if let Ok(self_hosted) = EnvironmentReaderStd::new().read("TIDY_REPO_GITLAB_HOSTS") {
+        hosts.extend(
+            self_hosted
+                .split(',')
+                .map(str::trim)
+                .filter(|host| !host.is_empty())
+                .map(str::to_string),
+        );
+    }
+    hosts
+}
+
+fn github_credential_key(enterprise_host: Option<&GitHubEnterpriseHost>) -> CredentialKey {
+    match enterprise_host {
+        Some(enterprise_host) => CredentialKey::github(enterprise_host.host()),
+        None => CredentialKey::github("github.com"),
+    }
+}
+
+fn github_repository_provider(
+    enterprise_host: Option<&GitHubEnterpriseHost>,
+) -> GitHubRepositoryProviderAdapterAlias<GitHubClientAlias> {
+    GitHubRepositoryProviderAdapter::new(
+        github_client(enterprise_host),
+        credential_repository(),
+        &github_credential_key(enterprise_host),
+    )
+    .with_concurrency(concurrency())
+}
+
+fn github_graphql_repository_provider(
+    enterprise_host: Option<&GitHubEnterpriseHost>,
+) -> GitHubRepositoryProviderAdapterAlias<GitHubGraphQlClientAlias> {
+    GitHubRepositoryProviderAdapter::new(
+        github_graphql_client(enterprise_host),
+        credential_repository(),
+        &github_credential_key(enterprise_host),
+    )
+    .with_concurrency(concurrency())
+}
+
+fn gitlab_repository_provider(host: &str) -> GitLabRepositoryProviderAdapter<GitLabClientAlias> {
+    GitLabRepositoryProviderAdapter::new(gitlab_client().with_host(host.to_string()))
+        .with_stored_credentials(&credential_repository(), &CredentialKey::gitlab(host))
+        .with_concurrency(concurrency())
+}
+
+fn bitbucket_repository_provider() -> BitbucketRepositoryProviderAdapter<BitbucketClientAlias> {
+    BitbucketRepositoryProviderAdapter::new(bitbucket_client())
+        .with_stored_credentials(
+            &credential_repository(),
+            &CredentialKey::bitbucket(BITBUCKET_HOST),
+        )
+        .with_concurrency(concurrency())
+}
+
+fn gitea_repository_provider(
+    gitea_host: &GiteaHost,
+) -> GitHubRepositoryProviderAdapterAlias<GiteaClientAlias> {
+    GitHubRepositoryProviderAdapter::new(
+        gitea_client(gitea_host),
+        credential_repository(),
+        &CredentialKey::gitea(gitea_host.host()),
+    )
+    .with_concurrency(concurrency())
+}
+
+fn repository_providers<GRP, ERP>(
+    github_repository_provider: Arc<GRP>,
+    configuration: &Configuration,
+    github_enterprise_repository_provider: impl Fn(&GitHubEnterpriseHost) -> ERP,
+) -> RepositoryProviderRegistry
+where
+    GRP: RepositoryHost + 'static,
+    ERP: RepositoryHost + 'static,
+{
+    let registry = RepositoryProviderRegistry::new()
+        .with_provider(vec!["github.com".to_string()], github_repository_provider)
+        .with_provider(
+            vec![BITBUCKET_HOST.to_string()],
+            bitbucket_repository_provider(),
+        )
+        .with_local_provider(LocalRepositoryProviderAdapter::new());
+    let registry = gitlab_hosts().into_iter().fold(registry, |registry, host| {
+        let provider = gitlab_repository_provider(host.as_str());
+        registry.with_provider(vec![host], provider)
+    });
+    let registry = configuration
+        .gitea_hosts()
+        .iter()
+        .fold(registry, |registry, gitea_host| {
+            registry.with_provider(
+                vec![gitea_host.host().to_string()],
+                gitea_repository_provider(gitea_host),
+            )
+        });
+    configuration
+        .github_enterprise_hosts()
+        .iter()
+        .fold(registry, |registry, enterprise_host| {
+            registry.with_provider(
+                vec![enterprise_host.host().to_string()],
+                github_enterprise_repository_provider(enterprise_host),
+            )
+        })
 }
 
 fn credential_repository() -> FilesystemCredentialRepositoryAdapterAlias {
@@ -65,24 +441,81 @@ fn credential_repository() -> FilesystemCredentialRepositoryAdapterAlias {
     ))
 }
 
-fn github_authentication_service() -> GitHubAuthenticationServiceAlias {
-    GitHubAuthenticationService::new(
-        GitHubCredentialsValidatorAdapter::new(github_client()),
-        credential_repository(),
-    )
+fn authentication_service(
+    client_options: &StructOptClientOptions,
+    configuration: &Configuration,
+) -> AuthenticationServiceAlias {
+    match client_options {
+        StructOptClientOptions::Authenticate(AuthenticateCommand::GitLab { host, .. }) => {
+            let host = gitlab_host(host.as_deref().unwrap_or("gitlab.com"));
+            TokenAuthenticationService::new(
+                Box::new(GitLabCredentialsValidatorAdapter::new(
+                    gitlab_client().with_host(host.clone()),
+                )),
+                credential_repository(),
+                CredentialKey::gitlab(host.as_str()),
+            )
+        }
+        StructOptClientOptions::Authenticate(AuthenticateCommand::Gitea { host, .. }) => {
+            let gitea_host = gitea_host(configuration, host.as_str());
+            TokenAuthenticationService::new(
+                Box::new(GitHubCredentialsValidatorAdapter::new(gitea_client(
+                    gitea_host,
+                ))),
+                credential_repository(),
+                CredentialKey::gitea(gitea_host.host()),
+            )
+        }
+        StructOptClientOptions::Authenticate(AuthenticateCommand::Bitbucket { .. }) => {
+            TokenAuthenticationService::new(
+                Box::new(BitbucketCredentialsValidatorAdapter::new(bitbucket_client())),
+                credential_repository(),
+                CredentialKey::bitbucket(BITBUCKET_HOST),
+            )
+        }
+        _ => {
+            let enterprise_host = client_options
+                .auth_host()
+                .map(|host| github_enterprise_host(configuration, host.as_str()));
+            TokenAuthenticationService::new(
+                Box::new(GitHubCredentialsValidatorAdapter::new(github_client(
+                    enterprise_host,
+                ))),
+                credential_repository(),
+                github_credential_key(enterprise_host),
+            )
+        }
+    }
 }
 
-fn application_service() -> ApplicationService<
+fn application_service<GRP>(
+    authentication_service: AuthenticationServiceAlias,
+    github_repository_provider: GRP,
+    repository_providers: impl FnOnce(Arc<GRP>) -> RepositoryProviderRegistry,
+) -> ApplicationService<
     BranchCounterServiceImpl,
-    GitHubAuthenticationServiceAlias,
-    GitHubRepositoryProviderAdapter<GitHubClientAlias, FilesystemCredentialRepositoryAdapterAlias>,
-> {
-    let github_repository_provider =
-        GitHubRepositoryProviderAdapter::new(github_client(), credential_repository());
+    StaleBranchServiceImpl,
+    MergedBranchServiceImpl,
+    AuthenticationServiceAlias,
+    Arc<GRP>,
+>
+where
+    GRP: RepositoryHost + RepositoryCatalog + 'static,
+{
+    let github_repository_provider = Arc::new(github_repository_provider);
     let branch_counter_service = BranchCounterServiceImpl::new();
+    let stale_branch_service = StaleBranchServiceImpl::new();
+    let merged_branch_service = MergedBranchServiceImpl::new();
     ApplicationService::new(
         branch_counter_service,
-        github_authentication_service(),
-        github_repository_provider,
+        stale_branch_service,
+        merged_branch_service,
+        authentication_service,
+        Arc::clone(&github_repository_provider),
+        repository_providers(github_repository_provider),
+        Box::new(
+            LocalWorkingCopyProviderAdapter::new()
+                .with_global_config_files(global_git_config_files()),
+        ),
     )
 }
@@ -0,0 +1,67 @@
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct AuthenticationToken(String);
+
+impl AuthenticationToken {
+    pub fn new(token: String) -> Self {
+        AuthenticationToken(token)
+    }
+
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+
+    pub fn parse_github(s: &str) -> Result<Self, AuthenticationTokenParseError> {
+        Self::parse(s, "GitHub")
+    }
+
+    pub fn parse_gitlab(s: &str) -> Result<Self, AuthenticationTokenParseError> {
+        Self::parse(s, "GitLab")
+    }
+
+    pub fn parse_gitea(s: &str) -> Result<Self, AuthenticationTokenParseError> {
+        Self::parse(s, "Gitea")
+    }
+
+    pub fn parse_bitbucket(s: &str) -> Result<Self, AuthenticationTokenParseError> {
+        Self::parse(s, "Bitbucket")
+    }
+
+    fn parse(s: &str, service_name: &'static str) -> Result<Self, AuthenticationTokenParseError> {
+        if s.is_empty() {
+            Err(AuthenticationTokenParseError::Empty(service_name))
+        } else {
+            Ok(AuthenticationToken(s.to_string()))
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum AuthenticationTokenParseError {
+    #[error("{0} authentication token must not be empty")]
+    Empty(&'static str),
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn returns_string_value() {
+        assert_that(&AuthenticationToken::new("token".to_string()).value()).is_equal_to("token");
+    }
+
+    #[test]
+    fn parses_from_a_non_empty_string() {
+        assert_that(&AuthenticationToken::parse_github("token").unwrap())
+            .is_equal_to(AuthenticationToken::new("token".to_string()));
+    }
+
+    #[test]
+    fn fails_to_parse_from_an_empty_string() {
+        let result = AuthenticationToken::parse_gitlab("");
+        assert_that(&result.err().unwrap().to_string())
+            .is_equal_to("GitLab authentication token must not be empty".to_string());
+    }
+}
@@ -0,0 +1,121 @@
+use std::str::FromStr;
+
+use chrono::Duration;
+
+/// Ages are capped at about a hundred years, well short of what a date can reach back to.
+const MAX_AGE_IN_DAYS: i64 = 36_500;
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub struct BranchAge(Duration);
+
+impl BranchAge {
+    pub fn new(duration: Duration) -> Self {
+        BranchAge(duration)
+    }
+
+    pub fn value(&self) -> Duration {
+        self.0
+    }
+}
+
+impl FromStr for BranchAge {
+    type Err = BranchAgeParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || BranchAgeParseError::Invalid(s.to_string());
+        let unit = s.chars().last().ok_or_else(invalid)?;
+        let amount: u32 = s[..s.len() - unit.len_utf8()]
+            .parse()
+            .map_err(|_| invalid())?;
+
+        let duration = match unit {
+            'h' => Duration::try_hours(amount.into()),
+            'd' => Duration::try_days(amount.into()),
+            'w' => Duration::try_weeks(amount.into()),
+            _ => return Err(invalid()),
+        };
+        match duration {
+            Some(duration) if duration.num_days() <= MAX_AGE_IN_DAYS => Ok(BranchAge(duration)),
+            _ => Err(BranchAgeParseError::OutOfRange(s.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum BranchAgeParseError {
+    #[error("invalid age '{0}', expected a whole number of hours, days or weeks (e.g. 90d)")]
+    Invalid(String),
+    #[error("age '{0}' is too large, expected at most {MAX_AGE_IN_DAYS} days")]
+    OutOfRange(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn parses_hours() {
+        assert_that(&BranchAge::from_str("12h").unwrap())
+            .is_equal_to(BranchAge::new(Duration::hours(12)));
+    }
+
+    #[test]
+    fn parses_days() {
+        assert_that(&BranchAge::from_str("90d").unwrap())
+            .is_equal_to(BranchAge::new(Duration::days(90)));
+    }
+
+    #[test]
+    fn parses_weeks() {
+        assert_that(&BranchAge::from_str("2w").unwrap())
+            .is_equal_to(BranchAge::new(Duration::weeks(2)));
+    }
+
+    #[test]
+    fn parses_largest_age() {
+        assert_that(&BranchAge::from_str("36500d").unwrap())
+            .is_equal_to(BranchAge::new(Duration::days(36_500)));
+    }
+
+    #[test]
+    fn fails_to_parse_age_beyond_largest_age() {
+        let result = BranchAge::from_str("4000000000d");
+        assert_that(&matches!(
+            result.err().unwrap(),
+            BranchAgeParseError::OutOfRange(..)
+        ))
+        .is_true();
+    }
+
+    #[test]
+    fn fails_to_parse_unknown_unit() {
+        let result = BranchAge::from_str("3y");
+        assert_that(&matches!(
+            result.err().unwrap(),
+            BranchAgeParseError::Invalid(..)
+        ))
+        .is_true();
+    }
+
+    #[test]
+    fn fails_to_parse_missing_amount() {
+        let result = BranchAge::from_str("d");
+        assert_that(&matches!(
+            result.err().unwrap(),
+            BranchAgeParseError::Invalid(..)
+        ))
+        .is_true();
+    }
+
+    #[test]
+    fn fails_to_parse_an_empty_string() {
+        let result = BranchAge::from_str("");
+        assert_that(&matches!(
+            result.err().unwrap(),
+            BranchAgeParseError::Invalid(..)
+        ))
+        .is_true();
+    }
+}
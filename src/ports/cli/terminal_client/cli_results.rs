@@ -1,7 +1,13 @@
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
 
-use crate::application::repository::RepositoryUrlDto;
+use chrono::SecondsFormat;
+use serde::Serialize;
+
+use crate::application::repository::{
+    BranchDto, LocalCleanupReasonDto, MergeStatusDto, RepositoryUrlDto,
+};
+use crate::ports::cli::terminal_client::output_format::TabularResult;
 
 #[derive(Debug)]
 pub struct CountBranchesResult {
@@ -21,16 +27,276 @@ impl Display for CountBranchesResult {
     }
 }
 
+#[derive(Debug, Serialize)]
+pub struct BranchCountRecord {
+    repository: String,
+    branch_count: u32,
+}
+
+impl TabularResult for CountBranchesResult {
+    type Record = BranchCountRecord;
+
+    const HEADERS: &'static [&'static str] = &["repository", "branch_count"];
+
+    fn records(&self) -> Vec<Self::Record> {
+        let mut records: Vec<BranchCountRecord> = self
+            .hash_map
+            .iter()
+            .map(|(url, &branch_count)| BranchCountRecord {
+                repository: url.to_string(),
+                branch_count,
+            })
+            .collect();
+        records.sort_by(|a, b| a.repository.cmp(&b.repository));
+        records
+    }
+
+    fn fields(record: &Self::Record) -> Vec<String> {
+        vec![record.repository.clone(), record.branch_count.to_string()]
+    }
+}
+
 impl From<HashMap<RepositoryUrlDto, u32>> for CountBranchesResult {
     fn from(hash_map: HashMap<RepositoryUrlDto, u32>) -> Self {
         CountBranchesResult { hash_map }
     }
 }
 
+#[derive(Debug)]
+pub struct StaleBranchesResult {
+    hash_map: HashMap<RepositoryUrlDto, Vec<BranchDto>>,
+}
+
+impl StaleBranchesResult {
+    fn branch_line(url: &RepositoryUrlDto, branch: &BranchDto) -> String {
+        match branch.head_commit() {
+            Some(commit) => format!(
+                "{}: {} (last commit on {} by {})",
+                url,
+                branch.name(),
+                commit.date().format("%Y-%m-%d"),
+                commit.author()
+            ),
+            None => format!("{}: {}", url, branch.name()),
+        }
+    }
+}
+
+impl Display for StaleBranchesResult {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut lines: Vec<String> = self
+            .hash_map
+            .iter()
+            .flat_map(|(url, branches)| {
+                branches
+                    .iter()
+                    .map(move |branch| Self::branch_line(url, branch))
+            })
+            .collect::<Vec<String>>();
+        lines.sort();
+
+        write!(f, "{}", lines.join("\n"))
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct StaleBranchRecord {
+    repository: String,
+    branch: String,
+    last_commit_sha: Option<String>,
+    last_commit_date: Option<String>,
+    last_commit_author: Option<String>,
+}
+
+impl TabularResult for StaleBranchesResult {
+    type Record = StaleBranchRecord;
+
+    const HEADERS: &'static [&'static str] = &[
+        "repository",
+        "branch",
+        "last_commit_sha",
+        "last_commit_date",
+        "last_commit_author",
+    ];
+
+    fn records(&self) -> Vec<Self::Record> {
+        let mut records: Vec<StaleBranchRecord> = self
+            .hash_map
+            .iter()
+            .flat_map(|(url, branches)| {
+                branches.iter().map(move |branch| {
+                    let commit = branch.head_commit();
+                    StaleBranchRecord {
+                        repository: url.to_string(),
+                        branch: branch.name().to_string(),
+                        last_commit_sha: commit.map(|commit| commit.sha().to_string()),
+                        last_commit_date: commit
+                            .map(|commit| commit.date().to_rfc3339_opts(SecondsFormat::Secs, true)),
+                        last_commit_author: commit.map(|commit| commit.author().to_string()),
+                    }
+                })
+            })
+            .collect();
+        records.sort_by(|a, b| (&a.repository, &a.branch).cmp(&(&b.repository, &b.branch)));
+        records
+    }
+
+    fn fields(record: &Self::Record) -> Vec<String> {
+        vec![
+            record.repository.clone(),
+            record.branch.clone(),
+            record.last_commit_sha.clone().unwrap_or_default(),
+            record.last_commit_date.clone().unwrap_or_default(),
+            record.last_commit_author.clone().unwrap_or_default(),
+        ]
+    }
+}
+
+impl From<HashMap<RepositoryUrlDto, Vec<BranchDto>>> for StaleBranchesResult {
+    fn from(hash_map: HashMap<RepositoryUrlDto, Vec<BranchDto>>) -> Self {
+        StaleBranchesResult { hash_map }
+    }
+}
+
+#[derive(Debug)]
+pub struct MergedBranchesResult {
+    hash_map: HashMap<RepositoryUrlDto, Vec<(BranchDto, MergeStatusDto)>>,
+}
+
+impl Display for MergedBranchesResult {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut lines: Vec<String> = self
+            .hash_map
+            .iter()
+            .flat_map(|(url, branches)| {
+                branches
+                    .iter()
+                    .map(move |(branch, status)| format!("{}: {} ({})", url, branch.name(), status))
+            })
+            .collect::<Vec<String>>();
+        lines.sort();
+
+        write!(f, "{}", lines.join("\n"))
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct MergedBranchRecord {
+    repository: String,
+    branch: String,
+    status: String,
+}
+
+impl TabularResult for MergedBranchesResult {
+    type Record = MergedBranchRecord;
+
+    const HEADERS: &'static [&'static str] = &["repository", "branch", "status"];
+
+    fn records(&self) -> Vec<Self::Record> {
+        let mut records: Vec<MergedBranchRecord> = self
+            .hash_map
+            .iter()
+            .flat_map(|(url, branches)| {
+                branches
+                    .iter()
+                    .map(move |(branch, status)| MergedBranchRecord {
+                        repository: url.to_string(),
+                        branch: branch.name().to_string(),
+                        status: status.to_string(),
+                    })
+            })
+            .collect();
+        records.sort_by(|a, b| (&a.repository, &a.branch).cmp(&(&b.repository, &b.branch)));
+        records
+    }
+
+    fn fields(record: &Self::Record) -> Vec<String> {
+        vec![
+            record.repository.clone(),
+            record.branch.clone(),
+            record.status.clone(),
+        ]
+    }
+}
+
+impl From<HashMap<RepositoryUrlDto, Vec<(BranchDto, MergeStatusDto)>>> for MergedBranchesResult {
+    fn from(hash_map: HashMap<RepositoryUrlDto, Vec<(BranchDto, MergeStatusDto)>>) -> Self {
+        MergedBranchesResult { hash_map }
+    }
+}
+
+#[derive(Debug)]
+pub struct PruneResult {
+    hash_map: HashMap<RepositoryUrlDto, Vec<BranchDto>>,
+    applied: bool,
+}
+
+impl PruneResult {
+    pub fn new(hash_map: HashMap<RepositoryUrlDto, Vec<BranchDto>>, applied: bool) -> Self {
+        PruneResult { hash_map, applied }
+    }
+}
+
+impl Display for PruneResult {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let action = if self.applied {
+            "deleted"
+        } else {
+            "would delete"
+        };
+        let mut lines: Vec<String> = self
+            .hash_map
+            .iter()
+            .flat_map(|(url, branches)| {
+                branches
+                    .iter()
+                    .map(move |branch| format!("{} {}: {}", action, url, branch.name()))
+            })
+            .collect::<Vec<String>>();
+        lines.sort();
+
+        write!(f, "{}", lines.join("\n"))
+    }
+}
+
+#[derive(Debug)]
+pub struct LocalCleanupResult {
+    branches: Vec<(BranchDto, LocalCleanupReasonDto)>,
+    applied: bool,
+}
+
+impl LocalCleanupResult {
+    pub fn new(branches: Vec<(BranchDto, LocalCleanupReasonDto)>, applied: bool) -> Self {
+        LocalCleanupResult { branches, applied }
+    }
+}
+
+impl Display for LocalCleanupResult {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let action = if self.applied {
+            "deleted"
+        } else {
+            "would delete"
+        };
+        let mut lines: Vec<String> = self
+            .branches
+            .iter()
+            .map(|(branch, reason)| format!("{} {} ({})", action, branch.name(), reason))
+            .collect();
+        lines.sort();
+
+        write!(f, "{}", lines.join("\n"))
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use chrono::{TimeZone, Utc};
     use spectral::prelude::*;
 
+    use crate::application::repository::CommitDto;
+    use crate::ports::cli::terminal_client::output_format::{render, OutputFormat};
+
     use super::*;
 
     fn count_branches_result<S: AsRef<str>>(hash_map: HashMap<S, u32>) -> CountBranchesResult {
@@ -47,7 +313,7 @@ mod tests {
         let mut hash_map = HashMap::new();
         hash_map.insert("url", 1);
         let under_test = count_branches_result(hash_map);
-        assert_that(&under_test.to_string()).is_equal_to(&"url: 1".to_string());
+        assert_that(&under_test.to_string()).is_equal_to("url: 1".to_string());
     }
 
     #[test]
@@ -56,6 +322,173 @@ mod tests {
         hash_map.insert("url", 1);
         hash_map.insert("other_url", 0);
         let under_test = count_branches_result(hash_map);
-        assert_that(&under_test.to_string()).is_equal_to(&"other_url: 0\nurl: 1".to_string());
+        assert_that(&under_test.to_string()).is_equal_to("other_url: 0\nurl: 1".to_string());
+    }
+
+    fn stale_branch(name: &str) -> BranchDto {
+        BranchDto::new(
+            name.to_string(),
+            Some(CommitDto::new(
+                "sha".to_string(),
+                Utc.with_ymd_and_hms(2020, 1, 2, 3, 4, 5).unwrap(),
+                "author".to_string(),
+            )),
+        )
+    }
+
+    #[test]
+    fn implements_display_for_stale_branches() {
+        let mut hash_map = HashMap::new();
+        hash_map.insert(
+            RepositoryUrlDto::new("url".to_string()),
+            vec![stale_branch("b"), stale_branch("a")],
+        );
+        hash_map.insert(RepositoryUrlDto::new("other_url".to_string()), vec![]);
+        let under_test: StaleBranchesResult = hash_map.into();
+        assert_that(&under_test.to_string()).is_equal_to(
+            "url: a (last commit on 2020-01-02 by author)\n\
+              url: b (last commit on 2020-01-02 by author)"
+                .to_string(),
+        );
+    }
+
+    #[test]
+    fn implements_display_for_merged_branches() {
+        let mut hash_map = HashMap::new();
+        hash_map.insert(
+            RepositoryUrlDto::new("url".to_string()),
+            vec![
+                (
+                    BranchDto::new("unmerged".to_string(), None),
+                    MergeStatusDto::Unmerged,
+                ),
+                (
+                    BranchDto::new("merged".to_string(), None),
+                    MergeStatusDto::Merged,
+                ),
+            ],
+        );
+        let under_test: MergedBranchesResult = hash_map.into();
+        assert_that(&under_test.to_string())
+            .is_equal_to("url: merged (merged)\nurl: unmerged (unmerged)".to_string());
+    }
+
+    #[test]
+    fn renders_branch_counts_as_csv() {
+        let mut hash_map = HashMap::new();
+        hash_map.insert("url", 1);
+        hash_map.insert("other_url", 0);
+        let under_test = count_branches_result(hash_map);
+        assert_that(&render(&under_test, OutputFormat::Csv))
+            .is_equal_to("repository,branch_count\nother_url,0\nurl,1".to_string());
+    }
+
+    #[test]
+    fn renders_stale_branches_as_json() {
+        let mut hash_map = HashMap::new();
+        hash_map.insert(
+            RepositoryUrlDto::new("url".to_string()),
+            vec![stale_branch("a"), BranchDto::new("b".to_string(), None)],
+        );
+        let under_test: StaleBranchesResult = hash_map.into();
+        assert_that(&render(&under_test, OutputFormat::Json)).is_equal_to(
+            r#"[
+  {
+    "repository": "url",
+    "branch": "a",
+    "last_commit_sha": "sha",
+    "last_commit_date": "2020-01-02T03:04:05Z",
+    "last_commit_author": "author"
+  },
+  {
+    "repository": "url",
+    "branch": "b",
+    "last_commit_sha": null,
+    "last_commit_date": null,
+    "last_commit_author": null
+  }
+]"#
+            .to_string(),
+        );
+    }
+
+    #[test]
+    fn renders_merged_branches_as_markdown() {
+        let mut hash_map = HashMap::new();
+        hash_map.insert(
+            RepositoryUrlDto::new("url".to_string()),
+            vec![
+                (
+                    BranchDto::new("unmerged".to_string(), None),
+                    MergeStatusDto::Unmerged,
+                ),
+                (
+                    BranchDto::new("merged".to_string(), None),
+                    MergeStatusDto::Merged,
+                ),
+            ],
+        );
+        let under_test: MergedBranchesResult = hash_map.into();
+        assert_that(&render(&under_test, OutputFormat::Markdown)).is_equal_to(
+            "| repository | branch | status |\n\
+             | --- | --- | --- |\n\
+             | url | merged | merged |\n\
+             | url | unmerged | unmerged |"
+                .to_string(),
+        );
+    }
+
+    fn prune_plan() -> HashMap<RepositoryUrlDto, Vec<BranchDto>> {
+        let mut hash_map = HashMap::new();
+        hash_map.insert(
+            RepositoryUrlDto::new("url".to_string()),
+            vec![
+                BranchDto::new("b".to_string(), None),
+                BranchDto::new("a".to_string(), None),
+            ],
+        );
+        hash_map
+    }
+
+    #[test]
+    fn implements_display_for_prune_plan() {
+        let under_test = PruneResult::new(prune_plan(), false);
+        assert_that(&under_test.to_string())
+            .is_equal_to("would delete url: a\nwould delete url: b".to_string());
+    }
+
+    #[test]
+    fn implements_display_for_applied_prune() {
+        let under_test = PruneResult::new(prune_plan(), true);
+        assert_that(&under_test.to_string())
+            .is_equal_to("deleted url: a\ndeleted url: b".to_string());
+    }
+
+    fn local_cleanup_plan() -> Vec<(BranchDto, LocalCleanupReasonDto)> {
+        vec![
+            (
+                BranchDto::new("gone".to_string(), None),
+                LocalCleanupReasonDto::UpstreamGone,
+            ),
+            (
+                BranchDto::new("done".to_string(), None),
+                LocalCleanupReasonDto::Merged,
+            ),
+        ]
+    }
+
+    #[test]
+    fn implements_display_for_local_cleanup_plan() {
+        let under_test = LocalCleanupResult::new(local_cleanup_plan(), false);
+        assert_that(&under_test.to_string()).is_equal_to(
+            "would delete done (merged)\nwould delete gone (upstream gone)".to_string(),
+        );
+    }
+
+    #[test]
+    fn implements_display_for_applied_local_cleanup() {
+        let under_test = LocalCleanupResult::new(local_cleanup_plan(), true);
+        assert_that(&under_test.to_string())
+            .is_equal_to("deleted done (merged)\ndeleted gone (upstream gone)".to_string());
     }
 }
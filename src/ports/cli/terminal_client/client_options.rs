@@ -1,17 +1,54 @@
+use std::path::PathBuf;
+
+use structopt::clap::ArgGroup;
 use structopt::StructOpt;
 
-use crate::application::repository::RepositoryUrlDto;
+use crate::application::repository::{PruneCriteriaDto, RepositoryOwnerDto, RepositoryUrlDto};
+use crate::ports::cli::terminal_client::authentication_token::AuthenticationToken;
+use crate::ports::cli::terminal_client::branch_age::BranchAge;
 use crate::ports::cli::terminal_client::commands::CliCommand;
-use crate::ports::cli::terminal_client::github_token::GitHubAuthenticationToken;
+use crate::ports::cli::terminal_client::concurrency::Concurrency;
+use crate::ports::cli::terminal_client::github_api::GitHubApi;
+use crate::ports::cli::terminal_client::output_format::OutputFormat;
+
+const OUTPUT_FORMATS: &[&str] = &["text", "json", "yaml", "csv", "table", "markdown"];
+const GITHUB_APIS: &[&str] = &["rest", "graphql"];
+const DEFAULT_REMOTE: &str = "origin";
 
 pub trait ClientOptions {
     fn command(&self) -> CliCommand;
 
-    fn repository_urls(&self) -> Option<&Vec<RepositoryUrlDto>>;
+    fn repository_urls(&self) -> Option<Vec<RepositoryUrlDto>>;
+
+    fn repository_owners(&self) -> Vec<RepositoryOwnerDto>;
+
+    fn repository_directory(&self) -> Option<PathBuf>;
+
+    fn remote(&self) -> String;
+
+    fn stale_branch_age(&self) -> Option<BranchAge>;
+
+    fn output_format(&self) -> OutputFormat;
+
+    fn prune_criteria(&self) -> Option<PruneCriteriaDto>;
 
-    fn github_auth_token(&self) -> Option<GitHubAuthenticationToken>;
+    fn apply_changes(&self) -> bool;
+
+    fn fail_fast(&self) -> bool;
+
+    fn concurrency(&self) -> Concurrency;
+
+    fn github_api(&self) -> GitHubApi;
+
+    fn include_protected(&self) -> bool;
+
+    fn auth_token(&self) -> Option<AuthenticationToken>;
+
+    fn auth_host(&self) -> Option<String>;
 }
 
+// Parsed once per run, so the size of the largest command does not matter.
+#[allow(clippy::large_enum_variant)]
 #[derive(StructOpt, Debug)]
 #[structopt(name = "Tidy Repo")]
 pub enum StructOptClientOptions {
@@ -19,10 +56,126 @@ pub enum StructOptClientOptions {
     Authenticate(AuthenticateCommand),
     /// Get info relating to branches in a repository
     Branches {
+        #[structopt(flatten)]
+        options: BranchesOptions,
+        #[structopt(subcommand)]
+        report: Option<BranchesReport>,
+    },
+    /// Delete branches matching the given criteria (prints a plan unless --apply is passed)
+    #[structopt(group = ArgGroup::with_name("criteria").required(true).multiple(true))]
+    Prune {
+        /// Select branches that have been merged into the default branch
+        #[structopt(long, group = "criteria")]
+        merged: bool,
+        /// Select branches whose head commit is older than this age e.g. 12h, 90d or 2w
+        #[structopt(name = "older-than", long, group = "criteria")]
+        older_than: Option<BranchAge>,
+        /// Also consider protected branches and the default branch
+        #[structopt(name = "include-protected", long)]
+        include_protected: bool,
+        /// Delete the selected branches instead of only printing the plan
+        #[structopt(long)]
+        apply: bool,
+        /// Stop at the first repository that fails instead of reporting every failure
+        #[structopt(name = "fail-fast", long)]
+        fail_fast: bool,
+        /// Maximum number of repositories processed at the same time
+        #[structopt(long, default_value = "8")]
+        concurrency: Concurrency,
+        /// GitHub API to query repositories on github.com through
+        #[structopt(long, default_value = "rest", possible_values = GITHUB_APIS)]
+        api: GitHubApi,
         /// Repository URLs to process
         #[structopt(name = "REPOSITORY_URL")]
         repository_urls: Vec<RepositoryUrlDto>,
     },
+    /// Delete local branches of the clone in the current directory whose upstream is gone or
+    /// that are merged into the default branch (prints a plan unless --apply is passed)
+    Local {
+        /// Delete the selected branches instead of only printing the plan
+        #[structopt(long)]
+        apply: bool,
+    },
+}
+
+#[derive(StructOpt, Debug)]
+pub enum BranchesReport {
+    /// List branches whose head commit is older than a threshold
+    Stale {
+        /// Minimum age of a branch's head commit e.g. 12h, 90d or 2w
+        #[structopt(name = "older-than", long)]
+        older_than: BranchAge,
+        /// Also consider protected branches and the default branch
+        #[structopt(name = "include-protected", long)]
+        include_protected: bool,
+        #[structopt(flatten)]
+        options: BranchesOptions,
+    },
+    /// List branches that have been merged into the default branch
+    Merged {
+        /// Also consider protected branches
+        #[structopt(name = "include-protected", long)]
+        include_protected: bool,
+        #[structopt(flatten)]
+        options: BranchesOptions,
+    },
+}
+
+/// Options of the `branches` command, accepted both before and after its report subcommand.
+/// Values passed after the report win over those passed before it.
+#[derive(StructOpt, Debug, Default)]
+pub struct BranchesOptions {
+    /// Repository URLs to process
+    #[structopt(name = "REPOSITORY_URL")]
+    repository_urls: Vec<RepositoryUrlDto>,
+    #[structopt(flatten)]
+    owners: RepositoryOwnerOptions,
+    #[structopt(flatten)]
+    detection: RepositoryDetectionOptions,
+    /// Format of the printed results [default: text]
+    #[structopt(long, possible_values = OUTPUT_FORMATS)]
+    output: Option<OutputFormat>,
+    /// Stop at the first repository that fails instead of reporting every failure
+    #[structopt(name = "fail-fast", long)]
+    fail_fast: bool,
+    /// Maximum number of repositories processed at the same time [default: 8]
+    #[structopt(long)]
+    concurrency: Option<Concurrency>,
+    /// GitHub API to query repositories on github.com through [default: rest]
+    #[structopt(long, possible_values = GITHUB_APIS)]
+    api: Option<GitHubApi>,
+}
+
+#[derive(StructOpt, Debug, Default)]
+pub struct RepositoryOwnerOptions {
+    /// Also process every repository of this GitHub organization
+    #[structopt(name = "org", long, number_of_values = 1)]
+    orgs: Vec<String>,
+    /// Also process every repository of this GitHub user
+    #[structopt(name = "user", long, number_of_values = 1)]
+    users: Vec<String>,
+}
+
+impl RepositoryOwnerOptions {
+    fn repository_owners(&self) -> Vec<RepositoryOwnerDto> {
+        self.orgs
+            .iter()
+            .cloned()
+            .map(RepositoryOwnerDto::Organization)
+            .chain(self.users.iter().cloned().map(RepositoryOwnerDto::User))
+            .collect()
+    }
+}
+
+/// Where to look for the repository when no repository URL or owner is given.
+#[derive(StructOpt, Debug, Default)]
+pub struct RepositoryDetectionOptions {
+    /// Clone to read the remote from instead of the one in the current directory
+    #[structopt(name = "repo-dir", long)]
+    repo_dir: Option<PathBuf>,
+    /// Remote whose URL is processed when no repository URL is passed [default: origin]
+    #[structopt(long)]
+    remote: Option<String>,
 }
 
 #[derive(StructOpt, Debug)]
@@ -31,33 +184,259 @@ pub enum AuthenticateCommand {
     #[structopt(name = "github")]
     GitHub {
         /// Personal access token
-        #[structopt(name = "token", long, short)]
-        token: GitHubAuthenticationToken,
+        #[structopt(name = "token", long, short, parse(try_from_str = AuthenticationToken::parse_github))]
+        token: AuthenticationToken,
+        /// GitHub Enterprise Server host from the configuration to authenticate with instead of
+        /// github.com
+        #[structopt(long)]
+        host: Option<String>,
+    },
+    /// Authenticate with GitLab
+    #[structopt(name = "gitlab")]
+    GitLab {
+        /// Personal access token
+        #[structopt(name = "token", long, short, parse(try_from_str = AuthenticationToken::parse_gitlab))]
+        token: AuthenticationToken,
+        /// Self-hosted GitLab host to authenticate with instead of gitlab.com
+        #[structopt(long)]
+        host: Option<String>,
+    },
+    /// Authenticate with a self-hosted Gitea or Forgejo instance
+    #[structopt(name = "gitea")]
+    Gitea {
+        /// Access token
+        #[structopt(name = "token", long, short, parse(try_from_str = AuthenticationToken::parse_gitea))]
+        token: AuthenticationToken,
+        /// Gitea or Forgejo host from the configuration to authenticate with
+        #[structopt(long)]
+        host: String,
+    },
+    /// Authenticate with Bitbucket Cloud
+    #[structopt(name = "bitbucket")]
+    Bitbucket {
+        /// Bitbucket username the app password belongs to
+        #[structopt(long, short)]
+        username: String,
+        /// App password with read and write access to repositories
+        #[structopt(name = "app-password", long, short, parse(try_from_str = AuthenticationToken::parse_bitbucket))]
+        app_password: AuthenticationToken,
     },
 }
 
+impl StructOptClientOptions {
+    /// The options of the `branches` command, those passed before its report first.
+    fn branches_options(&self) -> Vec<&BranchesOptions> {
+        match self {
+            StructOptClientOptions::Branches {
+                options,
+                report:
+                    Some(BranchesReport::Stale {
+                        options: report_options,
+                        ..
+                    }),
+            }
+            | StructOptClientOptions::Branches {
+                options,
+                report:
+                    Some(BranchesReport::Merged {
+                        options: report_options,
+                        ..
+                    }),
+            } => vec![options, report_options],
+            StructOptClientOptions::Branches {
+                options,
+                report: None,
+            } => vec![options],
+            _ => vec![],
+        }
+    }
+
+    fn last_branches_option<T, F>(&self, option: F) -> Option<T>
+    where
+        F: Fn(&BranchesOptions) -> Option<T>,
+    {
+        self.branches_options().into_iter().rev().find_map(option)
+    }
+}
+
 impl ClientOptions for StructOptClientOptions {
     fn command(&self) -> CliCommand {
         match self {
             StructOptClientOptions::Authenticate(AuthenticateCommand::GitHub { .. }) => {
                 CliCommand::AuthenticateGitHub
             }
-            StructOptClientOptions::Branches { .. } => CliCommand::Branches,
+            StructOptClientOptions::Authenticate(AuthenticateCommand::GitLab { .. }) => {
+                CliCommand::AuthenticateGitLab
+            }
+            StructOptClientOptions::Authenticate(AuthenticateCommand::Gitea { .. }) => {
+                CliCommand::AuthenticateGitea
+            }
+            StructOptClientOptions::Authenticate(AuthenticateCommand::Bitbucket { .. }) => {
+                CliCommand::AuthenticateBitbucket
+            }
+            StructOptClientOptions::Branches { report: None, .. } => CliCommand::Branches,
+            StructOptClientOptions::Branches {
+                report: Some(BranchesReport::Stale { .. }),
+                ..
+            } => CliCommand::StaleBranches,
+            StructOptClientOptions::Branches {
+                report: Some(BranchesReport::Merged { .. }),
+                ..
+            } => CliCommand::MergedBranches,
+            StructOptClientOptions::Prune { .. } => CliCommand::Prune,
+            StructOptClientOptions::Local { .. } => CliCommand::Local,
         }
     }
 
-    fn repository_urls(&self) -> Option<&Vec<RepositoryUrlDto>> {
+    fn repository_urls(&self) -> Option<Vec<RepositoryUrlDto>> {
         match self {
-            StructOptClientOptions::Branches { repository_urls } => Some(&repository_urls),
+            StructOptClientOptions::Branches { .. } => Some(
+                self.branches_options()
+                    .into_iter()
+                    .flat_map(|options| options.repository_urls.iter().cloned())
+                    .collect(),
+            ),
+            StructOptClientOptions::Prune {
+                repository_urls, ..
+            } => Some(repository_urls.clone()),
             _ => None,
         }
     }
 
-    fn github_auth_token(&self) -> Option<GitHubAuthenticationToken> {
+    fn repository_owners(&self) -> Vec<RepositoryOwnerDto> {
+        self.branches_options()
+            .into_iter()
+            .flat_map(|options| options.owners.repository_owners())
+            .collect()
+    }
+
+    fn repository_directory(&self) -> Option<PathBuf> {
+        self.last_branches_option(|options| options.detection.repo_dir.clone())
+    }
+
+    fn remote(&self) -> String {
+        self.last_branches_option(|options| options.detection.remote.clone())
+            .unwrap_or_else(|| DEFAULT_REMOTE.to_string())
+    }
+
+    fn output_format(&self) -> OutputFormat {
+        self.last_branches_option(|options| options.output)
+            .unwrap_or_default()
+    }
+
+    fn stale_branch_age(&self) -> Option<BranchAge> {
         match self {
-            StructOptClientOptions::Authenticate(AuthenticateCommand::GitHub { token }) => {
+            StructOptClientOptions::Branches {
+                report: Some(BranchesReport::Stale { older_than, .. }),
+                ..
+            } => Some(*older_than),
+            _ => None,
+        }
+    }
+
+    fn prune_criteria(&self) -> Option<PruneCriteriaDto> {
+        match self {
+            StructOptClientOptions::Prune {
+                merged,
+                older_than,
+                include_protected,
+                ..
+            } => Some(PruneCriteriaDto::new(
+                *merged,
+                older_than.map(|age| age.value()),
+                *include_protected,
+            )),
+            _ => None,
+        }
+    }
+
+    fn apply_changes(&self) -> bool {
+        matches!(
+            self,
+            StructOptClientOptions::Prune { apply: true, .. }
+                | StructOptClientOptions::Local { apply: true }
+        )
+    }
+
+    fn fail_fast(&self) -> bool {
+        match self {
+            StructOptClientOptions::Prune { fail_fast, .. } => *fail_fast,
+            _ => self
+                .branches_options()
+                .into_iter()
+                .any(|options| options.fail_fast),
+        }
+    }
+
+    fn concurrency(&self) -> Concurrency {
+        match self {
+            StructOptClientOptions::Prune { concurrency, .. } => *concurrency,
+            _ => self
+                .last_branches_option(|options| options.concurrency)
+                .unwrap_or_default(),
+        }
+    }
+
+    fn github_api(&self) -> GitHubApi {
+        match self {
+            StructOptClientOptions::Prune { api, .. } => *api,
+            _ => self
+                .last_branches_option(|options| options.api)
+                .unwrap_or_default(),
+        }
+    }
+
+    fn include_protected(&self) -> bool {
+        match self {
+            StructOptClientOptions::Branches {
+                report:
+                    Some(BranchesReport::Stale {
+                        include_protected, ..
+                    }),
+                ..
+            }
+            | StructOptClientOptions::Branches {
+                report:
+                    Some(BranchesReport::Merged {
+                        include_protected, ..
+                    }),
+                ..
+            }
+            | StructOptClientOptions::Prune {
+                include_protected, ..
+            } => *include_protected,
+            _ => false,
+        }
+    }
+
+    fn auth_token(&self) -> Option<AuthenticationToken> {
+        match self {
+            StructOptClientOptions::Authenticate(AuthenticateCommand::GitHub { token, .. })
+            | StructOptClientOptions::Authenticate(AuthenticateCommand::GitLab { token, .. })
+            | StructOptClientOptions::Authenticate(AuthenticateCommand::Gitea { token, .. }) => {
                 Some(token.clone())
             }
+            StructOptClientOptions::Authenticate(AuthenticateCommand::Bitbucket {
+                username,
+                app_password,
+            }) => Some(AuthenticationToken::new(format!(
+                "{}:{}",
+                username,
+                app_password.value()
+            ))),
+            _ => None,
+        }
+    }
+
+    fn auth_host(&self) -> Option<String> {
+        match self {
+            StructOptClientOptions::Authenticate(AuthenticateCommand::GitHub { host, .. })
+            | StructOptClientOptions::Authenticate(AuthenticateCommand::GitLab { host, .. }) => {
+                host.clone()
+            }
+            StructOptClientOptions::Authenticate(AuthenticateCommand::Gitea { host, .. }) => {
+                Some(host.clone())
+            }
             _ => None,
         }
     }
@@ -65,26 +444,419 @@ impl ClientOptions for StructOptClientOptions {
 
 #[cfg(test)]
 mod tests {
+    use chrono::Duration;
     use spectral::prelude::*;
 
     use super::*;
 
     fn branches_options() -> StructOptClientOptions {
         StructOptClientOptions::Branches {
-            repository_urls: vec![RepositoryUrlDto::new("url".to_string())],
+            options: BranchesOptions {
+                repository_urls: vec![RepositoryUrlDto::new("url".to_string())],
+                ..BranchesOptions::default()
+            },
+            report: None,
+        }
+    }
+
+    fn stale_branches_options() -> StructOptClientOptions {
+        StructOptClientOptions::Branches {
+            options: BranchesOptions::default(),
+            report: Some(BranchesReport::Stale {
+                older_than: BranchAge::new(Duration::days(90)),
+                include_protected: false,
+                options: BranchesOptions {
+                    repository_urls: vec![RepositoryUrlDto::new("url".to_string())],
+                    ..BranchesOptions::default()
+                },
+            }),
         }
     }
 
     fn authenticate_github_options() -> StructOptClientOptions {
         StructOptClientOptions::Authenticate(AuthenticateCommand::GitHub {
-            token: GitHubAuthenticationToken::new("token".to_string()),
+            token: AuthenticationToken::new("token".to_string()),
+            host: None,
         })
     }
 
     #[test]
     fn returns_list_of_repository_urls_when_counting_branches() {
         assert_that(&branches_options().repository_urls().unwrap())
-            .is_equal_to(&vec![RepositoryUrlDto::new("url".to_string())])
+            .is_equal_to(vec![RepositoryUrlDto::new("url".to_string())])
+    }
+
+    #[test]
+    fn returns_list_of_repository_urls_when_finding_stale_branches() {
+        assert_that(&stale_branches_options().repository_urls().unwrap())
+            .is_equal_to(vec![RepositoryUrlDto::new("url".to_string())])
+    }
+
+    #[test]
+    fn returns_stale_branch_age_when_finding_stale_branches() {
+        assert_that(&stale_branches_options().stale_branch_age().unwrap())
+            .is_equal_to(BranchAge::new(Duration::days(90)))
+    }
+
+    #[test]
+    fn returns_no_stale_branch_age_when_counting_branches() {
+        assert_that(&branches_options().stale_branch_age()).is_none();
+    }
+
+    #[test]
+    fn parses_stale_branches_subcommand() {
+        let options = StructOptClientOptions::from_iter(vec![
+            "tidy-repo",
+            "branches",
+            "stale",
+            "--older-than",
+            "90d",
+            "url",
+        ]);
+
+        assert_that(&matches!(options.command(), CliCommand::StaleBranches)).is_true();
+        assert_that(&options.repository_urls().unwrap())
+            .is_equal_to(vec![RepositoryUrlDto::new("url".to_string())]);
+    }
+
+    #[test]
+    fn parses_merged_branches_subcommand() {
+        let options =
+            StructOptClientOptions::from_iter(vec!["tidy-repo", "branches", "merged", "url"]);
+
+        assert_that(&matches!(options.command(), CliCommand::MergedBranches)).is_true();
+        assert_that(&options.repository_urls().unwrap())
+            .is_equal_to(vec![RepositoryUrlDto::new("url".to_string())]);
+    }
+
+    #[test]
+    fn combines_repository_urls_passed_before_and_after_report_subcommand() {
+        let options = StructOptClientOptions::from_iter(vec![
+            "tidy-repo",
+            "branches",
+            "first-url",
+            "merged",
+            "second-url",
+        ]);
+
+        assert_that(&options.repository_urls().unwrap()).is_equal_to(vec![
+            RepositoryUrlDto::new("first-url".to_string()),
+            RepositoryUrlDto::new("second-url".to_string()),
+        ]);
+    }
+
+    #[test]
+    fn parses_branches_command_with_repository_urls() {
+        let options = StructOptClientOptions::from_iter(vec!["tidy-repo", "branches", "url"]);
+
+        assert_that(&matches!(options.command(), CliCommand::Branches)).is_true();
+        assert_that(&options.repository_urls().unwrap())
+            .is_equal_to(vec![RepositoryUrlDto::new("url".to_string())]);
+    }
+
+    #[test]
+    fn parses_repository_owners_of_branches_command() {
+        let options = StructOptClientOptions::from_iter(vec![
+            "tidy-repo",
+            "branches",
+            "--org",
+            "my-org",
+            "--user",
+            "someone",
+            "--org",
+            "other-org",
+        ]);
+
+        assert_that(&options.repository_owners()).is_equal_to(vec![
+            RepositoryOwnerDto::Organization("my-org".to_string()),
+            RepositoryOwnerDto::Organization("other-org".to_string()),
+            RepositoryOwnerDto::User("someone".to_string()),
+        ]);
+        assert_that(&options.repository_urls().unwrap()).is_equal_to(vec![]);
+    }
+
+    #[test]
+    fn parses_repository_owners_of_stale_branches_subcommand() {
+        let options = StructOptClientOptions::from_iter(vec![
+            "tidy-repo",
+            "branches",
+            "stale",
+            "--older-than",
+            "90d",
+            "--org",
+            "my-org",
+        ]);
+
+        assert_that(&options.repository_owners())
+            .is_equal_to(vec![RepositoryOwnerDto::Organization("my-org".to_string())]);
+    }
+
+    #[test]
+    fn detects_repository_from_origin_remote_of_current_directory_by_default() {
+        let options = StructOptClientOptions::from_iter(vec!["tidy-repo", "branches"]);
+
+        assert_that(&options.repository_directory()).is_none();
+        assert_that(&options.remote()).is_equal_to("origin".to_string());
+    }
+
+    #[test]
+    fn parses_repository_directory_and_remote_of_merged_branches_subcommand() {
+        let options = StructOptClientOptions::from_iter(vec![
+            "tidy-repo",
+            "branches",
+            "merged",
+            "--repo-dir",
+            "../project",
+            "--remote",
+            "upstream",
+        ]);
+
+        assert_that(&options.repository_directory()).is_equal_to(Some(PathBuf::from("../project")));
+        assert_that(&options.remote()).is_equal_to("upstream".to_string());
+    }
+
+    #[test]
+    fn has_no_repository_owners_by_default() {
+        assert_that(&branches_options().repository_owners()).is_equal_to(vec![]);
+    }
+
+    #[test]
+    fn prints_text_output_by_default() {
+        assert_that(&branches_options().output_format()).is_equal_to(OutputFormat::Text);
+    }
+
+    #[test]
+    fn parses_output_format_of_branches_command() {
+        let options = StructOptClientOptions::from_iter(vec![
+            "tidy-repo",
+            "branches",
+            "--output",
+            "json",
+            "url",
+        ]);
+
+        assert_that(&options.output_format()).is_equal_to(OutputFormat::Json);
+    }
+
+    #[test]
+    fn parses_output_format_of_merged_branches_subcommand() {
+        let options = StructOptClientOptions::from_iter(vec![
+            "tidy-repo",
+            "branches",
+            "merged",
+            "--output",
+            "csv",
+            "url",
+        ]);
+
+        assert_that(&options.output_format()).is_equal_to(OutputFormat::Csv);
+    }
+
+    #[test]
+    fn parses_options_passed_before_stale_branches_subcommand() {
+        let options = StructOptClientOptions::from_iter(vec![
+            "tidy-repo",
+            "branches",
+            "--output",
+            "json",
+            "--fail-fast",
+            "--concurrency",
+            "2",
+            "--api",
+            "graphql",
+            "--org",
+            "my-org",
+            "--remote",
+            "upstream",
+            "stale",
+            "--older-than",
+            "90d",
+        ]);
+
+        assert_that(&matches!(options.command(), CliCommand::StaleBranches)).is_true();
+        assert_that(&options.output_format()).is_equal_to(OutputFormat::Json);
+        assert_that(&options.fail_fast()).is_true();
+        assert_that(&options.concurrency().value()).is_equal_to(2);
+        assert_that(&options.github_api()).is_equal_to(GitHubApi::GraphQl);
+        assert_that(&options.repository_owners())
+            .is_equal_to(vec![RepositoryOwnerDto::Organization("my-org".to_string())]);
+        assert_that(&options.remote()).is_equal_to("upstream".to_string());
+    }
+
+    #[test]
+    fn prefers_output_format_passed_after_report_subcommand() {
+        let options = StructOptClientOptions::from_iter(vec![
+            "tidy-repo",
+            "branches",
+            "--output",
+            "json",
+            "merged",
+            "--output",
+            "csv",
+        ]);
+
+        assert_that(&options.output_format()).is_equal_to(OutputFormat::Csv);
+    }
+
+    #[test]
+    fn reports_every_failure_by_default() {
+        assert_that(&branches_options().fail_fast()).is_false();
+    }
+
+    #[test]
+    fn parses_fail_fast_flag_of_stale_branches_subcommand() {
+        let options = StructOptClientOptions::from_iter(vec![
+            "tidy-repo",
+            "branches",
+            "stale",
+            "--older-than",
+            "90d",
+            "--fail-fast",
+            "url",
+        ]);
+
+        assert_that(&options.fail_fast()).is_true();
+    }
+
+    #[test]
+    fn parses_fail_fast_flag_of_prune_command() {
+        let options = StructOptClientOptions::from_iter(vec![
+            "tidy-repo",
+            "prune",
+            "--merged",
+            "--fail-fast",
+            "url",
+        ]);
+
+        assert_that(&options.fail_fast()).is_true();
+    }
+
+    #[test]
+    fn processes_default_number_of_repositories_at_once_by_default() {
+        let options = StructOptClientOptions::from_iter(vec!["tidy-repo", "branches", "url"]);
+
+        assert_that(&options.concurrency()).is_equal_to(Concurrency::default());
+    }
+
+    #[test]
+    fn parses_concurrency_of_merged_branches_subcommand() {
+        let options = StructOptClientOptions::from_iter(vec![
+            "tidy-repo",
+            "branches",
+            "merged",
+            "--concurrency",
+            "2",
+            "url",
+        ]);
+
+        assert_that(&options.concurrency().value()).is_equal_to(2);
+    }
+
+    #[test]
+    fn rejects_zero_concurrency() {
+        let result = StructOptClientOptions::from_iter_safe(vec![
+            "tidy-repo",
+            "prune",
+            "--merged",
+            "--concurrency",
+            "0",
+            "url",
+        ]);
+
+        assert_that(&result).is_err();
+    }
+
+    #[test]
+    fn uses_rest_api_by_default() {
+        assert_that(&branches_options().github_api()).is_equal_to(GitHubApi::Rest);
+    }
+
+    #[test]
+    fn parses_github_api_of_stale_branches_subcommand() {
+        let options = StructOptClientOptions::from_iter(vec![
+            "tidy-repo",
+            "branches",
+            "stale",
+            "--older-than",
+            "90d",
+            "--api",
+            "graphql",
+            "url",
+        ]);
+
+        assert_that(&options.github_api()).is_equal_to(GitHubApi::GraphQl);
+    }
+
+    #[test]
+    fn parses_prune_command_as_dry_run_by_default() {
+        let options =
+            StructOptClientOptions::from_iter(vec!["tidy-repo", "prune", "--merged", "url"]);
+
+        assert_that(&matches!(options.command(), CliCommand::Prune)).is_true();
+        assert_that(&options.prune_criteria().unwrap())
+            .is_equal_to(PruneCriteriaDto::new(true, None, false));
+        assert_that(&options.apply_changes()).is_false();
+        assert_that(&options.repository_urls().unwrap())
+            .is_equal_to(vec![RepositoryUrlDto::new("url".to_string())]);
+    }
+
+    #[test]
+    fn parses_prune_command_with_apply_and_age() {
+        let options = StructOptClientOptions::from_iter(vec![
+            "tidy-repo",
+            "prune",
+            "--older-than",
+            "2w",
+            "--apply",
+            "url",
+        ]);
+
+        assert_that(&options.prune_criteria().unwrap()).is_equal_to(PruneCriteriaDto::new(
+            false,
+            Some(Duration::weeks(2)),
+            false,
+        ));
+        assert_that(&options.apply_changes()).is_true();
+    }
+
+    #[test]
+    fn parses_include_protected_flag() {
+        let options = StructOptClientOptions::from_iter(vec![
+            "tidy-repo",
+            "branches",
+            "merged",
+            "--include-protected",
+            "url",
+        ]);
+
+        assert_that(&options.include_protected()).is_true();
+    }
+
+    #[test]
+    fn excludes_protected_branches_by_default() {
+        assert_that(&stale_branches_options().include_protected()).is_false();
+    }
+
+    #[test]
+    fn parses_local_command_as_dry_run_by_default() {
+        let options = StructOptClientOptions::from_iter(vec!["tidy-repo", "local"]);
+
+        assert_that(&matches!(options.command(), CliCommand::Local)).is_true();
+        assert_that(&options.apply_changes()).is_false();
+    }
+
+    #[test]
+    fn parses_apply_flag_of_local_command() {
+        let options = StructOptClientOptions::from_iter(vec!["tidy-repo", "local", "--apply"]);
+
+        assert_that(&options.apply_changes()).is_true();
+    }
+
+    #[test]
+    fn rejects_prune_command_without_criteria() {
+        let result = StructOptClientOptions::from_iter_safe(vec!["tidy-repo", "prune", "url"]);
+
+        assert_that(&result.is_err()).is_true();
     }
 
     #[test]
@@ -94,12 +866,103 @@ mod tests {
 
     #[test]
     fn returns_github_token_when_authenticating_with_github() {
-        assert_that(&authenticate_github_options().github_auth_token().unwrap())
-            .is_equal_to(&GitHubAuthenticationToken::new("token".to_string()))
+        assert_that(&authenticate_github_options().auth_token().unwrap())
+            .is_equal_to(AuthenticationToken::new("token".to_string()))
     }
 
     #[test]
     fn returns_none_when_not_authenticating_with_github() {
-        assert_that(&branches_options().github_auth_token()).is_none();
+        assert_that(&branches_options().auth_token()).is_none();
+    }
+
+    #[test]
+    fn authenticates_with_github_com_by_default() {
+        assert_that(&authenticate_github_options().auth_host()).is_none();
+    }
+
+    #[test]
+    fn parses_github_enterprise_host_of_authenticate_command() {
+        let options = StructOptClientOptions::from_iter(vec![
+            "tidy-repo",
+            "authenticate",
+            "github",
+            "--token",
+            "token",
+            "--host",
+            "github.corp.example",
+        ]);
+
+        assert_that(&options.auth_host()).is_equal_to(Some("github.corp.example".to_string()));
+    }
+
+    #[test]
+    fn parses_gitlab_authenticate_command() {
+        let options = StructOptClientOptions::from_iter(vec![
+            "tidy-repo",
+            "authenticate",
+            "gitlab",
+            "--token",
+            "token",
+            "--host",
+            "gitlab.example.com",
+        ]);
+
+        assert_that(&matches!(options.command(), CliCommand::AuthenticateGitLab)).is_true();
+        assert_that(&options.auth_token().unwrap())
+            .is_equal_to(AuthenticationToken::new("token".to_string()));
+        assert_that(&options.auth_host()).is_equal_to(Some("gitlab.example.com".to_string()));
+    }
+
+    #[test]
+    fn parses_bitbucket_authenticate_command_into_username_and_app_password() {
+        let options = StructOptClientOptions::from_iter(vec![
+            "tidy-repo",
+            "authenticate",
+            "bitbucket",
+            "--username",
+            "user",
+            "--app-password",
+            "app-password",
+        ]);
+
+        assert_that(&matches!(
+            options.command(),
+            CliCommand::AuthenticateBitbucket
+        ))
+        .is_true();
+        assert_that(&options.auth_token().unwrap())
+            .is_equal_to(AuthenticationToken::new("user:app-password".to_string()));
+        assert_that(&options.auth_host()).is_none();
+    }
+
+    #[test]
+    fn parses_gitea_authenticate_command() {
+        let options = StructOptClientOptions::from_iter(vec![
+            "tidy-repo",
+            "authenticate",
+            "gitea",
+            "--token",
+            "token",
+            "--host",
+            "gitea.example.com",
+        ]);
+
+        assert_that(&matches!(options.command(), CliCommand::AuthenticateGitea)).is_true();
+        assert_that(&options.auth_token().unwrap())
+            .is_equal_to(AuthenticationToken::new("token".to_string()));
+        assert_that(&options.auth_host()).is_equal_to(Some("gitea.example.com".to_string()));
+    }
+
+    #[test]
+    fn requires_host_to_authenticate_with_gitea() {
+        let result = StructOptClientOptions::from_iter_safe(vec![
+            "tidy-repo",
+            "authenticate",
+            "gitea",
+            "--token",
+            "token",
+        ]);
+
+        assert_that(&result.is_err()).is_true();
     }
 }
@@ -1,4 +1,11 @@
 pub enum CliCommand {
     AuthenticateGitHub,
+    AuthenticateGitLab,
+    AuthenticateGitea,
+    AuthenticateBitbucket,
     Branches,
+    StaleBranches,
+    MergedBranches,
+    Prune,
+    Local,
 }
@@ -0,0 +1,67 @@
+use std::str::FromStr;
+
+use crate::application::DEFAULT_CONCURRENCY;
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub struct Concurrency(usize);
+
+impl Concurrency {
+    pub fn value(&self) -> usize {
+        self.0
+    }
+}
+
+impl Default for Concurrency {
+    fn default() -> Self {
+        Concurrency(DEFAULT_CONCURRENCY)
+    }
+}
+
+impl FromStr for Concurrency {
+    type Err = ConcurrencyParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.parse::<usize>() {
+            Ok(value) if value > 0 => Ok(Concurrency(value)),
+            _ => Err(ConcurrencyParseError::Invalid(s.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ConcurrencyParseError {
+    #[error("invalid concurrency '{0}', expected a whole number greater than zero")]
+    Invalid(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn parses_positive_number() {
+        assert_that(&Concurrency::from_str("16").unwrap().value()).is_equal_to(16);
+    }
+
+    #[test]
+    fn fails_to_parse_zero() {
+        let result = Concurrency::from_str("0");
+        assert_that(&matches!(
+            result.err().unwrap(),
+            ConcurrencyParseError::Invalid(..)
+        ))
+        .is_true();
+    }
+
+    #[test]
+    fn fails_to_parse_non_numeric_value() {
+        let result = Concurrency::from_str("many");
+        assert_that(&matches!(
+            result.err().unwrap(),
+            ConcurrencyParseError::Invalid(..)
+        ))
+        .is_true();
+    }
+}
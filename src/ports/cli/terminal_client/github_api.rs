@@ -0,0 +1,53 @@
+use std::str::FromStr;
+
+/// Which of GitHub's APIs to query repositories through.
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default)]
+pub enum GitHubApi {
+    #[default]
+    Rest,
+    GraphQl,
+}
+
+impl FromStr for GitHubApi {
+    type Err = GitHubApiParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "rest" => Ok(GitHubApi::Rest),
+            "graphql" => Ok(GitHubApi::GraphQl),
+            _ => Err(GitHubApiParseError::Unknown(s.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GitHubApiParseError {
+    #[error("unknown GitHub API '{0}', expected rest or graphql")]
+    Unknown(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn parses_rest() {
+        assert_that(&GitHubApi::from_str("rest").unwrap()).is_equal_to(GitHubApi::Rest);
+    }
+
+    #[test]
+    fn parses_graphql() {
+        assert_that(&GitHubApi::from_str("graphql").unwrap()).is_equal_to(GitHubApi::GraphQl);
+    }
+
+    #[test]
+    fn fails_to_parse_unknown_api() {
+        assert_that(&matches!(
+            GitHubApi::from_str("soap").err().unwrap(),
+            GitHubApiParseError::Unknown(..)
+        ))
+        .is_true();
+    }
+}
@@ -1,8 +1,13 @@
 pub use client_options::*;
+pub use github_api::GitHubApi;
 pub use tidy_repo_app_adapter::*;
 
+mod authentication_token;
+mod branch_age;
 mod cli_results;
 mod client_options;
 mod commands;
-mod github_token;
+mod concurrency;
+mod github_api;
+mod output_format;
 mod tidy_repo_app_adapter;
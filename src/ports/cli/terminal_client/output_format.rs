@@ -0,0 +1,243 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use serde::Serialize;
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+    Yaml,
+    Csv,
+    Table,
+    Markdown,
+}
+
+impl FromStr for OutputFormat {
+    type Err = OutputFormatParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            "yaml" => Ok(OutputFormat::Yaml),
+            "csv" => Ok(OutputFormat::Csv),
+            "table" => Ok(OutputFormat::Table),
+            "markdown" => Ok(OutputFormat::Markdown),
+            _ => Err(OutputFormatParseError::Unknown(s.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum OutputFormatParseError {
+    #[error(
+        "unknown output format '{0}', expected one of text, json, yaml, csv, table or markdown"
+    )]
+    Unknown(String),
+}
+
+/// A result that can be rendered as rows of named columns.
+///
+/// Records are serialized as-is for JSON and YAML, so their field names must match `HEADERS`.
+pub trait TabularResult: Display {
+    type Record: Serialize;
+
+    const HEADERS: &'static [&'static str];
+
+    fn records(&self) -> Vec<Self::Record>;
+
+    fn fields(record: &Self::Record) -> Vec<String>;
+}
+
+pub fn render<T: TabularResult>(result: &T, format: OutputFormat) -> String {
+    let rows = || -> Vec<Vec<String>> { result.records().iter().map(T::fields).collect() };
+    match format {
+        OutputFormat::Text => result.to_string(),
+        OutputFormat::Json => serde_json::to_string_pretty(&result.records())
+            .expect("result records are always serializable"),
+        OutputFormat::Yaml => serde_yaml::to_string(&result.records())
+            .expect("result records are always serializable"),
+        OutputFormat::Csv => render_csv(T::HEADERS, rows()),
+        OutputFormat::Table => render_table(T::HEADERS, rows()),
+        OutputFormat::Markdown => render_markdown(T::HEADERS, rows()),
+    }
+}
+
+fn header_row(headers: &[&str]) -> Vec<String> {
+    headers.iter().map(|header| header.to_string()).collect()
+}
+
+fn render_csv(headers: &[&str], rows: Vec<Vec<String>>) -> String {
+    let escape = |field: &String| {
+        if field.contains([',', '"', '\n']) {
+            format!("\"{}\"", field.replace('"', "\"\""))
+        } else {
+            field.clone()
+        }
+    };
+    std::iter::once(header_row(headers))
+        .chain(rows)
+        .map(|row| row.iter().map(escape).collect::<Vec<String>>().join(","))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn render_table(headers: &[&str], rows: Vec<Vec<String>>) -> String {
+    let widths: Vec<usize> = (0..headers.len())
+        .map(|column| {
+            rows.iter()
+                .map(|row| row[column].chars().count())
+                .chain(std::iter::once(headers[column].len()))
+                .max()
+                .unwrap_or_default()
+        })
+        .collect();
+    let format_row = |row: &Vec<String>| {
+        row.iter()
+            .zip(&widths)
+            .map(|(field, &width)| format!("{:width$}", field, width = width))
+            .collect::<Vec<String>>()
+            .join("  ")
+            .trim_end()
+            .to_string()
+    };
+    let separator: Vec<String> = widths.iter().map(|&width| "-".repeat(width)).collect();
+
+    std::iter::once(header_row(headers))
+        .chain(std::iter::once(separator))
+        .chain(rows)
+        .map(|row| format_row(&row))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn render_markdown(headers: &[&str], rows: Vec<Vec<String>>) -> String {
+    let format_row = |row: &Vec<String>| {
+        format!(
+            "| {} |",
+            row.iter()
+                .map(|field| field.replace('|', "\\|"))
+                .collect::<Vec<String>>()
+                .join(" | ")
+        )
+    };
+    let separator: Vec<String> = headers.iter().map(|_| "---".to_string()).collect();
+
+    std::iter::once(header_row(headers))
+        .chain(std::iter::once(separator))
+        .chain(rows)
+        .map(|row| format_row(&row))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Formatter;
+
+    use spectral::prelude::*;
+
+    use super::*;
+
+    #[derive(Serialize)]
+    struct FakeRecord {
+        name: String,
+        value: String,
+    }
+
+    struct FakeResult;
+
+    impl Display for FakeResult {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            write!(f, "text")
+        }
+    }
+
+    impl TabularResult for FakeResult {
+        type Record = FakeRecord;
+
+        const HEADERS: &'static [&'static str] = &["name", "value"];
+
+        fn records(&self) -> Vec<Self::Record> {
+            vec![
+                FakeRecord {
+                    name: "a".to_string(),
+                    value: "x, \"y\"".to_string(),
+                },
+                FakeRecord {
+                    name: "long name".to_string(),
+                    value: "a|b".to_string(),
+                },
+            ]
+        }
+
+        fn fields(record: &Self::Record) -> Vec<String> {
+            vec![record.name.clone(), record.value.clone()]
+        }
+    }
+
+    #[test]
+    fn parses_every_output_format() {
+        vec![
+            ("text", OutputFormat::Text),
+            ("json", OutputFormat::Json),
+            ("yaml", OutputFormat::Yaml),
+            ("csv", OutputFormat::Csv),
+            ("table", OutputFormat::Table),
+            ("markdown", OutputFormat::Markdown),
+        ]
+        .into_iter()
+        .for_each(|(name, format)| {
+            assert_that(&OutputFormat::from_str(name).unwrap()).is_equal_to(format)
+        });
+    }
+
+    #[test]
+    fn fails_to_parse_unknown_output_format() {
+        assert_that(&OutputFormat::from_str("xml").is_err()).is_true();
+    }
+
+    #[test]
+    fn renders_text_using_display() {
+        assert_that(&render(&FakeResult, OutputFormat::Text)).is_equal_to("text".to_string());
+    }
+
+    #[test]
+    fn renders_json_array_of_records() {
+        assert_that(&render(&FakeResult, OutputFormat::Json)).is_equal_to(
+            "[\n  {\n    \"name\": \"a\",\n    \"value\": \"x, \\\"y\\\"\"\n  },\n  \
+             {\n    \"name\": \"long name\",\n    \"value\": \"a|b\"\n  }\n]"
+                .to_string(),
+        );
+    }
+
+    #[test]
+    fn renders_yaml_list_of_records() {
+        assert_that(&render(&FakeResult, OutputFormat::Yaml)).is_equal_to(
+            "---\n- name: a\n  value: \"x, \\\"y\\\"\"\n- name: long name\n  value: a|b"
+                .to_string(),
+        );
+    }
+
+    #[test]
+    fn renders_csv_with_header_and_quoted_fields() {
+        assert_that(&render(&FakeResult, OutputFormat::Csv))
+            .is_equal_to("name,value\na,\"x, \"\"y\"\"\"\nlong name,a|b".to_string());
+    }
+
+    #[test]
+    fn renders_aligned_table() {
+        assert_that(&render(&FakeResult, OutputFormat::Table)).is_equal_to(
+            "name       value\n---------  ------\na          x, \"y\"\nlong name  a|b".to_string(),
+        );
+    }
+
+    #[test]
+    fn renders_markdown_table_with_escaped_pipes() {
+        assert_that(&render(&FakeResult, OutputFormat::Markdown)).is_equal_to(
+            "| name | value |\n| --- | --- |\n| a | x, \"y\" |\n| long name | a\\|b |".to_string(),
+        );
+    }
+}
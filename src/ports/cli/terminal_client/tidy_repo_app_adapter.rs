@@ -1,67 +1,308 @@
-use crate::application::ApplicationService;
+use std::collections::HashMap;
+
+use crate::application::repository::{BranchDto, RepositoryResults, RepositoryUrlDto};
+use crate::application::{ApplicationError, ApplicationService};
 use crate::domain::authentication::{
-    AuthenticationService, GitHubAuthenticationToken as DomainCliGitHubAuthenticationToken,
+    AuthenticationService, AuthenticationToken as DomainAuthenticationToken,
 };
 use crate::domain::count_branches::BranchCounterService;
-use crate::domain::repository::RepositoryProvider;
-use crate::ports::cli::terminal_client::cli_results::CountBranchesResult;
+use crate::domain::merged_branches::MergedBranchService;
+use crate::domain::repository::RepositoryCatalog;
+use crate::domain::stale_branches::StaleBranchService;
+use crate::ports::cli::terminal_client::authentication_token::AuthenticationToken;
+use crate::ports::cli::terminal_client::branch_age::BranchAge;
+use crate::ports::cli::terminal_client::cli_results::{
+    CountBranchesResult, LocalCleanupResult, MergedBranchesResult, PruneResult, StaleBranchesResult,
+};
 use crate::ports::cli::terminal_client::commands::CliCommand;
-use crate::ports::cli::terminal_client::github_token::GitHubAuthenticationToken;
+use crate::ports::cli::terminal_client::output_format::{render, TabularResult};
 use crate::ports::cli::terminal_client::ClientOptions;
 use crate::TidyRepoApp;
 
-pub struct TerminalClientTidyRepoAppAdapter<CO, BranchCounter, GAS, GRP>
-where
+/// Exit code used when the command completed but at least one repository failed.
+pub const REPOSITORY_FAILURE_EXIT_CODE: i32 = 2;
+
+pub struct TerminalClientTidyRepoAppAdapter<
+    CO,
+    BranchCounter,
+    StaleBranchFinder,
+    MergedBranchFinder,
+    Auth,
+    GRC,
+> where
     CO: ClientOptions,
     BranchCounter: BranchCounterService,
-    GAS: AuthenticationService<AuthenticationCredentials = DomainCliGitHubAuthenticationToken>,
-    GRP: RepositoryProvider,
+    StaleBranchFinder: StaleBranchService,
+    MergedBranchFinder: MergedBranchService,
+    Auth: AuthenticationService<AuthenticationCredentials = DomainAuthenticationToken>,
+    GRC: RepositoryCatalog,
 {
     client_options: CO,
-    application_service: ApplicationService<BranchCounter, GAS, GRP>,
+    application_service:
+        ApplicationService<BranchCounter, StaleBranchFinder, MergedBranchFinder, Auth, GRC>,
 }
 
-impl<CO, BranchCounter, GAS, GRP> TerminalClientTidyRepoAppAdapter<CO, BranchCounter, GAS, GRP>
+impl<CO, BranchCounter, StaleBranchFinder, MergedBranchFinder, Auth, GRC>
+    TerminalClientTidyRepoAppAdapter<
+        CO,
+        BranchCounter,
+        StaleBranchFinder,
+        MergedBranchFinder,
+        Auth,
+        GRC,
+    >
 where
     CO: ClientOptions,
     BranchCounter: BranchCounterService,
-    GAS: AuthenticationService<AuthenticationCredentials = DomainCliGitHubAuthenticationToken>,
-    GRP: RepositoryProvider,
+    StaleBranchFinder: StaleBranchService,
+    MergedBranchFinder: MergedBranchService,
+    Auth: AuthenticationService<AuthenticationCredentials = DomainAuthenticationToken>,
+    GRC: RepositoryCatalog,
 {
     pub fn new(
         client_options: CO,
-        application_service: ApplicationService<BranchCounter, GAS, GRP>,
+        application_service: ApplicationService<
+            BranchCounter,
+            StaleBranchFinder,
+            MergedBranchFinder,
+            Auth,
+            GRC,
+        >,
     ) -> Self {
+        let application_service =
+            application_service.with_concurrency(client_options.concurrency().value());
         TerminalClientTidyRepoAppAdapter {
             client_options,
             application_service,
         }
     }
 
+    async fn repository_urls(&self) -> Vec<RepositoryUrlDto> {
+        let mut repository_urls = self.client_options.repository_urls().unwrap();
+        let owners = self.client_options.repository_owners();
+        if owners.is_empty() {
+            if repository_urls.is_empty() {
+                repository_urls.push(self.detect_repository_url().await);
+            }
+            return repository_urls;
+        }
+        match self
+            .application_service
+            .list_repositories_of_owners(owners)
+            .await
+        {
+            Ok(owned_repository_urls) => repository_urls.extend(owned_repository_urls),
+            Err(err) => {
+                eprintln!("Error: {}", err);
+                std::process::exit(1)
+            }
+        }
+        repository_urls
+    }
+
+    /// Reads the URL of the chosen remote from the clone in `--repo-dir` or the current directory.
+    async fn detect_repository_url(&self) -> RepositoryUrlDto {
+        let directory = match self.client_options.repository_directory() {
+            Some(directory) => directory,
+            None => match std::env::current_dir() {
+                Ok(directory) => directory,
+                Err(err) => {
+                    eprintln!("Error: {}", err);
+                    std::process::exit(1)
+                }
+            },
+        };
+        match self
+            .application_service
+            .detect_repository_url(
+                RepositoryUrlDto::new(directory.display().to_string()),
+                &self.client_options.remote(),
+            )
+            .await
+        {
+            Ok(repository_url) => repository_url,
+            Err(err) => {
+                eprintln!("Error: {}", err);
+                std::process::exit(1)
+            }
+        }
+    }
+
+    /// Prints the error of every failed repository, ordered by URL, and exits with
+    /// `REPOSITORY_FAILURE_EXIT_CODE` if there were any.
+    fn exit_if_any_repository_failed(failures: HashMap<RepositoryUrlDto, ApplicationError>) {
+        Self::exit_if_any_failed(
+            failures
+                .into_iter()
+                .map(|(url, err)| (url.to_string(), err))
+                .collect(),
+        )
+    }
+
+    /// Prints every failure, ordered by what failed, and exits with
+    /// `REPOSITORY_FAILURE_EXIT_CODE` if there were any.
+    fn exit_if_any_failed(mut failures: Vec<(String, ApplicationError)>) {
+        if failures.is_empty() {
+            return;
+        }
+        failures.sort_by(|(subject, _), (other_subject, _)| subject.cmp(other_subject));
+        failures
+            .iter()
+            .for_each(|(subject, err)| eprintln!("Error: {}: {}", subject, err));
+        std::process::exit(REPOSITORY_FAILURE_EXIT_CODE)
+    }
+
+    fn report_results<T, R>(&self, result: Result<RepositoryResults<T>, ApplicationError>)
+    where
+        R: TabularResult + From<HashMap<RepositoryUrlDto, T>>,
+    {
+        match result {
+            Ok(results) => {
+                let (successes, failures) = results.into_parts();
+                let report: R = successes.into();
+                println!("{}", render(&report, self.client_options.output_format()));
+                Self::exit_if_any_repository_failed(failures);
+            }
+            Err(err) => {
+                eprintln!("Error: {}", err);
+                std::process::exit(1)
+            }
+        };
+    }
+
     async fn count_branches_in_repositories(&mut self) {
+        let repository_urls = self.repository_urls().await;
         let result = self
             .application_service
-            .count_branches_in_repositories(self.client_options.repository_urls().unwrap().clone())
+            .count_branches_in_repositories(repository_urls, self.client_options.fail_fast())
             .await;
-        match result {
-            Ok(counts_map) => {
-                let counts: CountBranchesResult = counts_map.into();
-                println!("{}", counts);
+        self.report_results::<_, CountBranchesResult>(result);
+    }
+
+    async fn find_stale_branches_in_repositories(&mut self, older_than: BranchAge) {
+        let repository_urls = self.repository_urls().await;
+        let result = self
+            .application_service
+            .find_stale_branches_in_repositories(
+                repository_urls,
+                older_than.value(),
+                self.client_options.include_protected(),
+                self.client_options.fail_fast(),
+            )
+            .await;
+        self.report_results::<_, StaleBranchesResult>(result);
+    }
+
+    async fn find_merged_branches_in_repositories(&mut self) {
+        let repository_urls = self.repository_urls().await;
+        let result = self
+            .application_service
+            .find_merged_branches_in_repositories(
+                repository_urls,
+                self.client_options.include_protected(),
+                self.client_options.fail_fast(),
+            )
+            .await;
+        self.report_results::<_, MergedBranchesResult>(result);
+    }
+
+    async fn prune_branches(&mut self) {
+        let repository_urls = self.client_options.repository_urls().unwrap();
+        let criteria = self.client_options.prune_criteria().unwrap();
+        let apply = self.client_options.apply_changes();
+        let result = self
+            .application_service
+            .plan_branch_pruning(repository_urls, criteria, self.client_options.fail_fast())
+            .await;
+        let (plan, failures) = match result {
+            Ok(results) => results.into_parts(),
+            Err(err) => {
+                eprintln!("Error: {}", err);
+                std::process::exit(1)
             }
+        };
+        let mut failures: Vec<(String, ApplicationError)> = failures
+            .into_iter()
+            .map(|(url, err)| (url.to_string(), err))
+            .collect();
+        let pruned = if apply {
+            let (deleted, deletion_failures) = self
+                .application_service
+                .prune_branches(&plan)
+                .await
+                .into_parts();
+            failures.extend(
+                deletion_failures
+                    .into_iter()
+                    .map(|(url, branch, err)| (format!("{}: {}", url, branch.name()), err)),
+            );
+            deleted
+        } else {
+            plan.into_iter()
+                .map(|(url, repository_plan)| (url, repository_plan.into_branches()))
+                .collect()
+        };
+        println!("{}", PruneResult::new(pruned, apply));
+        Self::exit_if_any_failed(failures);
+    }
+
+    async fn clean_up_local_branches(&mut self) {
+        let url = match std::env::current_dir() {
+            Ok(directory) => RepositoryUrlDto::new(directory.display().to_string()),
+            Err(err) => {
+                eprintln!("Error: {}", err);
+                std::process::exit(1)
+            }
+        };
+        let apply = self.client_options.apply_changes();
+        let plan = match self
+            .application_service
+            .plan_local_branch_cleanup(url.clone())
+            .await
+        {
+            Ok(plan) => plan,
             Err(err) => {
                 eprintln!("Error: {}", err);
                 std::process::exit(1)
             }
         };
+        if !apply {
+            println!("{}", LocalCleanupResult::new(plan, apply));
+            return;
+        }
+        let branches: Vec<_> = plan.iter().map(|(branch, _)| branch.clone()).collect();
+        let (deleted, failures) = self
+            .application_service
+            .delete_local_branches(url, &branches)
+            .await
+            .into_parts();
+        let deleted: Vec<BranchDto> = deleted.into_values().flatten().collect();
+        let cleaned_up = plan
+            .into_iter()
+            .filter(|(branch, _)| deleted.contains(branch))
+            .collect();
+        println!("{}", LocalCleanupResult::new(cleaned_up, apply));
+        Self::exit_if_any_failed(
+            failures
+                .into_iter()
+                .map(|(_, branch, err)| (branch.name().clone(), err))
+                .collect(),
+        );
     }
 
-    async fn authenticate_github(&self, github_token: GitHubAuthenticationToken) {
+    async fn authenticate(&self, token: AuthenticationToken, service_name: &str) {
         let result = self
             .application_service
-            .authenticate_app_with_github(github_token.value().to_string())
+            .authenticate_app(token.value().to_string())
             .await;
         match result {
-            Ok(_) => println!("Successfully authenticated with GitHub"),
+            Ok(_) => match self.client_options.auth_host() {
+                Some(host) => println!(
+                    "Successfully authenticated with {} at {}",
+                    service_name, host
+                ),
+                None => println!("Successfully authenticated with {}", service_name),
+            },
             Err(err) => {
                 eprintln!("Error: {}", err);
                 std::process::exit(1)
@@ -71,23 +312,52 @@ where
 }
 
 #[async_trait::async_trait]
-impl<CO, BranchCounter, GAS, GRP> TidyRepoApp
-    for TerminalClientTidyRepoAppAdapter<CO, BranchCounter, GAS, GRP>
+impl<CO, BranchCounter, StaleBranchFinder, MergedBranchFinder, Auth, GRC> TidyRepoApp
+    for TerminalClientTidyRepoAppAdapter<
+        CO,
+        BranchCounter,
+        StaleBranchFinder,
+        MergedBranchFinder,
+        Auth,
+        GRC,
+    >
 where
     CO: ClientOptions + Send + Sync,
     BranchCounter: BranchCounterService + Send + Sync,
-    GAS: AuthenticationService<AuthenticationCredentials = DomainCliGitHubAuthenticationToken>
-        + Send
-        + Sync,
-    GRP: RepositoryProvider + Send + Sync,
+    StaleBranchFinder: StaleBranchService + Send + Sync,
+    MergedBranchFinder: MergedBranchService + Send + Sync,
+    Auth:
+        AuthenticationService<AuthenticationCredentials = DomainAuthenticationToken> + Send + Sync,
+    GRC: RepositoryCatalog + Send + Sync,
 {
     async fn run(&mut self) {
         match self.client_options.command() {
             CliCommand::AuthenticateGitHub => {
-                self.authenticate_github(self.client_options.github_auth_token().unwrap())
+                self.authenticate(self.client_options.auth_token().unwrap(), "GitHub")
+                    .await
+            }
+            CliCommand::AuthenticateGitLab => {
+                self.authenticate(self.client_options.auth_token().unwrap(), "GitLab")
+                    .await
+            }
+            CliCommand::AuthenticateGitea => {
+                self.authenticate(self.client_options.auth_token().unwrap(), "Gitea")
+                    .await
+            }
+            CliCommand::AuthenticateBitbucket => {
+                self.authenticate(self.client_options.auth_token().unwrap(), "Bitbucket")
                     .await
             }
             CliCommand::Branches => self.count_branches_in_repositories().await,
+            CliCommand::StaleBranches => {
+                self.find_stale_branches_in_repositories(
+                    self.client_options.stale_branch_age().unwrap(),
+                )
+                .await
+            }
+            CliCommand::MergedBranches => self.find_merged_branches_in_repositories().await,
+            CliCommand::Prune => self.prune_branches().await,
+            CliCommand::Local => self.clean_up_local_branches().await,
         }
     }
 }
@@ -0,0 +1,134 @@
+use chrono::{DateTime, TimeZone, Utc};
+
+use crate::ports::git::GitError;
+
+/// The parts of a commit object that branch reports need: its parents, author and commit date.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CommitObject {
+    sha: String,
+    parents: Vec<String>,
+    author: String,
+    date: DateTime<Utc>,
+}
+
+impl CommitObject {
+    pub fn new(sha: String, parents: Vec<String>, author: String, date: DateTime<Utc>) -> Self {
+        CommitObject {
+            sha,
+            parents,
+            author,
+            date,
+        }
+    }
+
+    pub(super) fn parse(sha: &str, data: &[u8]) -> Result<Self, GitError> {
+        let text = String::from_utf8_lossy(data);
+        let mut parents = vec![];
+        let mut author = None;
+        let mut date = None;
+        for line in text.lines().take_while(|line| !line.is_empty()) {
+            match line.split_once(' ') {
+                Some(("parent", parent)) => parents.push(parent.to_string()),
+                Some(("author", signature)) => author = Some(signature_name(signature)),
+                Some(("committer", signature)) => date = signature_date(signature),
+                _ => {}
+            }
+        }
+
+        Ok(CommitObject {
+            sha: sha.to_string(),
+            parents,
+            author: author.ok_or_else(|| GitError::corrupt_object(sha, "missing author"))?,
+            date: date.ok_or_else(|| GitError::corrupt_object(sha, "missing committer date"))?,
+        })
+    }
+
+    pub fn sha(&self) -> &String {
+        &self.sha
+    }
+
+    pub fn parents(&self) -> &Vec<String> {
+        &self.parents
+    }
+
+    pub fn author(&self) -> &String {
+        &self.author
+    }
+
+    pub fn date(&self) -> &DateTime<Utc> {
+        &self.date
+    }
+}
+
+/// Takes the name from a signature of the form `Name <email> 1600000000 +0100`.
+fn signature_name(signature: &str) -> String {
+    match signature.split_once(" <") {
+        Some((name, _)) => name.trim().to_string(),
+        None => signature.trim().to_string(),
+    }
+}
+
+fn signature_date(signature: &str) -> Option<DateTime<Utc>> {
+    let (_, timestamp_and_zone) = signature.rsplit_once("> ")?;
+    let timestamp = timestamp_and_zone.split_whitespace().next()?;
+    Utc.timestamp_opt(timestamp.parse().ok()?, 0).single()
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    const COMMIT: &str = "tree 4b825dc642cb6eb9a060e54bf8d69288fbee4904\n\
+                          parent 1111111111111111111111111111111111111111\n\
+                          parent 2222222222222222222222222222222222222222\n\
+                          author Jane Doe <jane@example.com> 946684800 +0100\n\
+                          committer John Roe <john@example.com> 978307200 -0500\n\
+                          \n\
+                          Merge branch 'feature'\n\
+                          \n\
+                          author Not A Header <nobody@example.com> 0 +0000\n";
+
+    fn under_test() -> CommitObject {
+        CommitObject::parse("sha", COMMIT.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn parses_parents() {
+        assert_that(under_test().parents()).is_equal_to(vec![
+            "1111111111111111111111111111111111111111".to_string(),
+            "2222222222222222222222222222222222222222".to_string(),
+        ]);
+    }
+
+    #[test]
+    fn parses_author_name_from_headers_only() {
+        assert_that(under_test().author()).is_equal_to("Jane Doe".to_string());
+    }
+
+    #[test]
+    fn parses_committer_date() {
+        assert_that(under_test().date())
+            .is_equal_to(Utc.with_ymd_and_hms(2001, 1, 1, 0, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn parses_root_commit_without_parents() {
+        let commit = CommitObject::parse(
+            "sha",
+            b"tree t\nauthor A <a@b> 0 +0000\ncommitter A <a@b> 0 +0000\n\nroot\n",
+        )
+        .unwrap();
+
+        assert_that(commit.parents()).is_empty();
+    }
+
+    #[test]
+    fn fails_to_parse_commit_without_committer() {
+        let result = CommitObject::parse("sha", b"tree t\nauthor A <a@b> 0 +0000\n\nmessage\n");
+
+        assert_that(&result.err().unwrap().to_string())
+            .is_equal_to("object sha is corrupt (missing committer date)".to_string());
+    }
+}
@@ -0,0 +1,341 @@
+/// The entries of a git `config` file.
+///
+/// Section and key names are case-insensitive and stored lowercased, while subsections such as
+/// branch and remote names keep their case, as in git itself.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct GitConfig {
+    entries: Vec<ConfigEntry>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct ConfigEntry {
+    section: String,
+    subsection: Option<String>,
+    key: String,
+    value: String,
+}
+
+impl GitConfig {
+    pub fn parse(content: &str) -> Self {
+        let mut entries = vec![];
+        let mut section: Option<(String, Option<String>)> = None;
+        for line in logical_lines(content) {
+            let line = line.trim();
+            if line.starts_with('[') {
+                section = parse_section_header(line);
+                continue;
+            }
+            let (section, subsection) = match &section {
+                Some(section) => section,
+                None => continue,
+            };
+            let line = strip_comment(line);
+            if line.is_empty() {
+                continue;
+            }
+            let (key, value) = match line.split_once('=') {
+                Some((key, value)) => (key.trim(), unquote(value.trim())),
+                None => (line, "true".to_string()),
+            };
+            entries.push(ConfigEntry {
+                section: section.clone(),
+                subsection: subsection.clone(),
+                key: key.to_lowercase(),
+                value,
+            });
+        }
+        GitConfig { entries }
+    }
+
+    /// Combines config files from the most general to the most specific, such as the global
+    /// files before a repository's own, so later files win as they do in git.
+    pub fn layered(layers: Vec<GitConfig>) -> Self {
+        GitConfig {
+            entries: layers.into_iter().flat_map(|layer| layer.entries).collect(),
+        }
+    }
+
+    /// The last value set for the key, which is the one git uses.
+    pub fn get(&self, section: &str, subsection: Option<&str>, key: &str) -> Option<&str> {
+        self.get_all(section, subsection, key).into_iter().last()
+    }
+
+    pub fn get_all(&self, section: &str, subsection: Option<&str>, key: &str) -> Vec<&str> {
+        self.entries
+            .iter()
+            .filter(|entry| {
+                entry.section == section.to_lowercase()
+                    && entry.subsection.as_deref() == subsection
+                    && entry.key == key.to_lowercase()
+            })
+            .map(|entry| entry.value.as_str())
+            .collect()
+    }
+
+    /// The remote-tracking reference a branch follows, from its `remote` and `merge` settings
+    /// mapped through the remote's fetch refspecs.
+    pub fn upstream_reference(&self, branch: &str) -> Option<String> {
+        let remote = self.get("branch", Some(branch), "remote")?;
+        let merge = self.get("branch", Some(branch), "merge")?;
+        if remote == "." {
+            return Some(merge.to_string());
+        }
+        self.get_all("remote", Some(remote), "fetch")
+            .into_iter()
+            .find_map(|refspec| map_through_refspec(refspec, merge))
+    }
+
+    /// The URL a remote fetches from, rewritten by the longest matching `url.<base>.insteadOf`.
+    pub fn remote_url(&self, remote: &str) -> Option<String> {
+        let url = self.get("remote", Some(remote), "url")?;
+        let rewrite = self
+            .entries
+            .iter()
+            .filter(|entry| {
+                entry.section == "url" && entry.key == "insteadof" && url.starts_with(&entry.value)
+            })
+            .max_by_key(|entry| entry.value.len());
+        Some(match rewrite {
+            Some(entry) => format!(
+                "{}{}",
+                entry.subsection.as_deref().unwrap_or_default(),
+                &url[entry.value.len()..]
+            ),
+            None => url.to_string(),
+        })
+    }
+}
+
+/// Removes every `[section "subsection"]` block, keeping the rest of the file as it was.
+pub(super) fn remove_section(content: &str, section: &str, subsection: Option<&str>) -> String {
+    let target = Some((section.to_lowercase(), subsection.map(str::to_string)));
+    let mut removing = false;
+    content
+        .split_inclusive('\n')
+        .filter(|line| {
+            if line.trim_start().starts_with('[') {
+                removing = parse_section_header(line.trim()) == target;
+            }
+            !removing
+        })
+        .collect()
+}
+
+fn map_through_refspec(refspec: &str, reference: &str) -> Option<String> {
+    let refspec = refspec.strip_prefix('+').unwrap_or(refspec);
+    let (source, destination) = refspec.split_once(':')?;
+    match (source.strip_suffix('*'), destination.strip_suffix('*')) {
+        (Some(source_prefix), Some(destination_prefix)) => reference
+            .strip_prefix(source_prefix)
+            .map(|name| format!("{}{}", destination_prefix, name)),
+        _ if source == reference => Some(destination.to_string()),
+        _ => None,
+    }
+}
+
+/// Joins lines that end in a backslash with the line after them.
+fn logical_lines(content: &str) -> Vec<String> {
+    let mut lines = vec![];
+    let mut current = String::new();
+    for line in content.lines() {
+        match line.strip_suffix('\\') {
+            Some(continued) => current.push_str(continued),
+            None => {
+                current.push_str(line);
+                lines.push(std::mem::take(&mut current));
+            }
+        }
+    }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+fn parse_section_header(line: &str) -> Option<(String, Option<String>)> {
+    let header = &line[1..line.find(']')?];
+    match header.split_once('"') {
+        Some((section, subsection)) => Some((
+            section.trim().to_lowercase(),
+            Some(unquote(&format!("\"{}", subsection.trim_end()))),
+        )),
+        None => match header.split_once('.') {
+            Some((section, subsection)) => Some((
+                section.trim().to_lowercase(),
+                Some(subsection.trim().to_lowercase()),
+            )),
+            None => Some((header.trim().to_lowercase(), None)),
+        },
+    }
+}
+
+fn strip_comment(line: &str) -> &str {
+    let mut in_quotes = false;
+    let mut escaped = false;
+    for (index, character) in line.char_indices() {
+        match character {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '"' => in_quotes = !in_quotes,
+            '#' | ';' if !in_quotes => return line[..index].trim_end(),
+            _ => {}
+        }
+    }
+    line
+}
+
+fn unquote(value: &str) -> String {
+    let mut unquoted = String::new();
+    let mut characters = value.chars();
+    while let Some(character) = characters.next() {
+        match character {
+            '"' => {}
+            '\\' => match characters.next() {
+                Some('n') => unquoted.push('\n'),
+                Some('t') => unquoted.push('\t'),
+                Some(escaped) => unquoted.push(escaped),
+                None => {}
+            },
+            _ => unquoted.push(character),
+        }
+    }
+    unquoted
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    const CONFIG: &str = "[core]\n\
+                          \tbare = false\n\
+                          \tlogallrefupdates\n\
+                          [remote \"origin\"]\n\
+                          \turl = git@github.com:owner/repo.git ; where we push\n\
+                          \tfetch = +refs/heads/*:refs/remotes/origin/*\n\
+                          [branch \"Feature/One\"]\n\
+                          \tremote = origin\n\
+                          \tmerge = refs/heads/feature/one\n\
+                          [branch \"local\"]\n\
+                          \tremote = .\n\
+                          \tmerge = refs/heads/main\n\
+                          [Alias]\n\
+                          \tlg = \"log --oneline # not a comment\"\n";
+
+    fn under_test() -> GitConfig {
+        GitConfig::parse(CONFIG)
+    }
+
+    #[test]
+    fn reads_values_of_subsections_ignoring_trailing_comments() {
+        assert_that(&under_test().get("remote", Some("origin"), "url"))
+            .is_equal_to(Some("git@github.com:owner/repo.git"));
+    }
+
+    #[test]
+    fn reads_keys_without_value_as_true() {
+        assert_that(&under_test().get("core", None, "logAllRefUpdates")).is_equal_to(Some("true"));
+    }
+
+    #[test]
+    fn matches_section_names_ignoring_case_and_keeps_quoted_values() {
+        assert_that(&under_test().get("alias", None, "lg"))
+            .is_equal_to(Some("log --oneline # not a comment"));
+    }
+
+    #[test]
+    fn uses_last_value_of_repeated_key() {
+        let config = GitConfig::parse("[user]\n\tname = First\n[user]\n\tname = Second\n");
+
+        assert_that(&config.get("user", None, "name")).is_equal_to(Some("Second"));
+    }
+
+    #[test]
+    fn joins_continued_lines() {
+        let config = GitConfig::parse("[alias]\n\tst = status \\\n--short\n");
+
+        assert_that(&config.get("alias", None, "st")).is_equal_to(Some("status --short"));
+    }
+
+    #[test]
+    fn reads_legacy_subsection_syntax() {
+        let config = GitConfig::parse("[branch.main]\n\tremote = origin\n");
+
+        assert_that(&config.get("branch", Some("main"), "remote")).is_equal_to(Some("origin"));
+    }
+
+    #[test]
+    fn maps_upstream_through_fetch_refspec() {
+        assert_that(&under_test().upstream_reference("Feature/One"))
+            .is_equal_to(Some("refs/remotes/origin/feature/one".to_string()));
+    }
+
+    #[test]
+    fn uses_merge_reference_as_upstream_of_branch_tracking_local_branch() {
+        assert_that(&under_test().upstream_reference("local"))
+            .is_equal_to(Some("refs/heads/main".to_string()));
+    }
+
+    #[test]
+    fn has_no_upstream_for_branch_without_tracking_configuration() {
+        assert_that(&under_test().upstream_reference("feature/one")).is_none();
+    }
+
+    #[test]
+    fn reads_remote_url() {
+        assert_that(&under_test().remote_url("origin"))
+            .is_equal_to(Some("git@github.com:owner/repo.git".to_string()));
+    }
+
+    #[test]
+    fn has_no_url_for_remote_that_is_not_configured() {
+        assert_that(&under_test().remote_url("upstream")).is_none();
+    }
+
+    #[test]
+    fn rewrites_remote_url_with_longest_matching_instead_of_prefix() {
+        let config = GitConfig::parse(
+            "[remote \"origin\"]\n\
+             \turl = gh:owner/repo\n\
+             [url \"https://example.com/\"]\n\
+             \tinsteadOf = g\n\
+             [url \"https://github.com/\"]\n\
+             \tinsteadOf = gh:\n",
+        );
+
+        assert_that(&config.remote_url("origin"))
+            .is_equal_to(Some("https://github.com/owner/repo".to_string()));
+    }
+
+    #[test]
+    fn rewrites_remote_url_with_instead_of_from_earlier_layer() {
+        let config = GitConfig::layered(vec![
+            GitConfig::parse("[url \"https://github.com/\"]\n\tinsteadOf = gh:\n"),
+            GitConfig::parse("[remote \"origin\"]\n\turl = gh:owner/repo\n"),
+        ]);
+
+        assert_that(&config.remote_url("origin"))
+            .is_equal_to(Some("https://github.com/owner/repo".to_string()));
+    }
+
+    #[test]
+    fn uses_value_of_later_layer() {
+        let config = GitConfig::layered(vec![
+            GitConfig::parse("[user]\n\tname = Global\n"),
+            GitConfig::parse("[user]\n\tname = Repository\n"),
+        ]);
+
+        assert_that(&config.get("user", None, "name")).is_equal_to(Some("Repository"));
+    }
+
+    #[test]
+    fn removes_section_and_its_entries() {
+        assert_that(&remove_section(CONFIG, "branch", Some("Feature/One"))).is_equal_to(
+            CONFIG.replace(
+                "[branch \"Feature/One\"]\n\tremote = origin\n\tmerge = refs/heads/feature/one\n",
+                "",
+            ),
+        );
+    }
+}
@@ -0,0 +1,26 @@
+use std::path::PathBuf;
+
+#[derive(Debug, thiserror::Error)]
+pub enum GitError {
+    #[error("'{0}' is not a git repository")]
+    NotARepository(PathBuf),
+    #[error("branch '{0}' not found")]
+    BranchNotFound(String),
+    #[error("remote '{0}' not found")]
+    RemoteNotFound(String),
+    #[error("object {0} not found")]
+    ObjectNotFound(String),
+    #[error("object {sha} is corrupt ({message})")]
+    CorruptObject { sha: String, message: String },
+    #[error(transparent)]
+    IO(#[from] std::io::Error),
+}
+
+impl GitError {
+    pub(super) fn corrupt_object(sha: &str, message: impl Into<String>) -> Self {
+        GitError::CorruptObject {
+            sha: sha.to_string(),
+            message: message.into(),
+        }
+    }
+}
@@ -0,0 +1,777 @@
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::fs::OpenOptions;
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+use crate::ports::git::config;
+use crate::ports::git::object_database::{ObjectDatabase, ObjectKind};
+use crate::ports::git::{CommitObject, GitConfig, GitError};
+
+const BRANCH_PREFIX: &str = "refs/heads/";
+const SYMBOLIC_REFERENCE_PREFIX: &str = "ref: ";
+
+const REACHABLE_FROM_BASE: u8 = 1;
+const REACHABLE_FROM_HEAD: u8 = 2;
+const REACHABLE_FROM_BOTH: u8 = REACHABLE_FROM_BASE | REACHABLE_FROM_HEAD;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct BranchReference {
+    name: String,
+    sha: String,
+}
+
+impl BranchReference {
+    pub fn new(name: String, sha: String) -> Self {
+        BranchReference { name, sha }
+    }
+
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+
+    pub fn sha(&self) -> &String {
+        &self.sha
+    }
+}
+
+/// How many commits each side of a comparison has that the other does not.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Divergence {
+    ahead_by: u32,
+    behind_by: u32,
+}
+
+impl Divergence {
+    pub fn ahead_by(&self) -> u32 {
+        self.ahead_by
+    }
+
+    pub fn behind_by(&self) -> u32 {
+        self.behind_by
+    }
+}
+
+/// A repository read straight from its git directory, so no git installation is needed.
+///
+/// Works for working copies, linked worktrees and bare repositories such as mirrors.
+pub struct GitDirectory {
+    git_directory: PathBuf,
+    common_directory: PathBuf,
+    objects: ObjectDatabase,
+}
+
+impl GitDirectory {
+    pub fn open(path: &Path) -> Result<Self, GitError> {
+        let git_directory = locate_git_directory(path)?;
+        let common_directory = match std::fs::read_to_string(git_directory.join("commondir")) {
+            Ok(common_directory) => git_directory.join(common_directory.trim()),
+            Err(e) if e.kind() == ErrorKind::NotFound => git_directory.clone(),
+            Err(e) => return Err(e.into()),
+        };
+        let objects = ObjectDatabase::open(common_directory.join("objects"))?;
+
+        Ok(GitDirectory {
+            git_directory,
+            common_directory,
+            objects,
+        })
+    }
+
+    /// Lists local branches sorted by name, preferring loose references over packed ones.
+    pub fn branches(&self) -> Result<Vec<BranchReference>, GitError> {
+        let mut references = self.packed_references()?;
+        self.read_loose_references(&self.common_directory.join("refs/heads"), &mut references)?;
+
+        let mut branches: Vec<BranchReference> = references
+            .into_iter()
+            .filter_map(|(reference, sha)| {
+                reference
+                    .strip_prefix(BRANCH_PREFIX)
+                    .map(|name| BranchReference::new(name.to_string(), sha))
+            })
+            .collect();
+        branches.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(branches)
+    }
+
+    /// The branch HEAD points at, which for a bare repository is its default branch.
+    pub fn head_branch(&self) -> Result<Option<String>, GitError> {
+        let head = std::fs::read_to_string(self.git_directory.join("HEAD"))?;
+        Ok(head
+            .trim()
+            .strip_prefix(SYMBOLIC_REFERENCE_PREFIX)
+            .and_then(|reference| reference.strip_prefix(BRANCH_PREFIX))
+            .map(str::to_string))
+    }
+
+    pub fn commit(&self, sha: &str) -> Result<CommitObject, GitError> {
+        let object = self.objects.read(sha)?;
+        match object.kind() {
+            ObjectKind::Commit => CommitObject::parse(sha, object.data()),
+            _ => Err(GitError::corrupt_object(sha, "not a commit")),
+        }
+    }
+
+    /// Counts the commits reachable from only one of `base` and `head`.
+    ///
+    /// Walks both histories newest first and stops once every commit still queued is reachable
+    /// from both sides, so shared history is not read further than the merge base.
+    pub fn compare(&self, base_sha: &str, head_sha: &str) -> Result<Divergence, GitError> {
+        let shallow_commits = self.shallow_commits()?;
+        let mut walk = CommitWalk::default();
+        walk.mark(self, base_sha, REACHABLE_FROM_BASE)?;
+        walk.mark(self, head_sha, REACHABLE_FROM_HEAD)?;
+
+        while walk.has_commits_reachable_from_one_side() {
+            let sha = match walk.queue.pop() {
+                Some((_, sha)) => sha,
+                None => break,
+            };
+            if shallow_commits.contains(&sha) {
+                continue;
+            }
+            let reachable_from = walk.reachable_from[&sha];
+            for parent in walk.commits[&sha].parents().clone() {
+                walk.mark(self, &parent, reachable_from)?;
+            }
+        }
+
+        let count = |side| {
+            walk.reachable_from
+                .values()
+                .filter(|reachable_from| **reachable_from == side)
+                .count() as u32
+        };
+        Ok(Divergence {
+            ahead_by: count(REACHABLE_FROM_HEAD),
+            behind_by: count(REACHABLE_FROM_BASE),
+        })
+    }
+
+    /// Deletes a branch's loose reference, its reflog, its entry in `packed-refs` and its
+    /// configuration, as `git branch --delete` does.
+    pub fn delete_branch(&self, name: &str) -> Result<(), GitError> {
+        if name.is_empty() || name.starts_with('/') || name.split('/').any(|part| part == "..") {
+            return Err(GitError::BranchNotFound(name.to_string()));
+        }
+        let reference = format!("{}{}", BRANCH_PREFIX, name);
+        let deleted_loose = remove_file_if_exists(&self.common_directory.join(&reference))?;
+        let deleted_packed = self.remove_packed_reference(&reference)?;
+        if !deleted_loose && !deleted_packed {
+            return Err(GitError::BranchNotFound(name.to_string()));
+        }
+        remove_file_if_exists(&self.common_directory.join("logs").join(&reference))?;
+        self.remove_branch_configuration(name)
+    }
+
+    pub fn config(&self) -> Result<GitConfig, GitError> {
+        Ok(GitConfig::parse(&self.read_config()?))
+    }
+
+    pub fn reference_exists(&self, reference: &str) -> Result<bool, GitError> {
+        Ok(self.resolve_reference(reference)?.is_some())
+    }
+
+    /// The branch a remote's `HEAD` points at, which is recorded when the repository is cloned.
+    pub fn remote_head_branch(&self, remote: &str) -> Result<Option<String>, GitError> {
+        let remote_prefix = format!("refs/remotes/{}/", remote);
+        let path = self.common_directory.join(&remote_prefix).join("HEAD");
+        match std::fs::read_to_string(path) {
+            Ok(head) => Ok(head
+                .trim()
+                .strip_prefix(SYMBOLIC_REFERENCE_PREFIX)
+                .and_then(|reference| reference.strip_prefix(&remote_prefix))
+                .map(str::to_string)),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn packed_references(&self) -> Result<HashMap<String, String>, GitError> {
+        Ok(self
+            .read_packed_references()?
+            .lines()
+            .filter(|line| !line.starts_with('#') && !line.starts_with('^'))
+            .filter_map(|line| line.split_once(' '))
+            .map(|(sha, reference)| (reference.trim().to_string(), sha.to_string()))
+            .collect())
+    }
+
+    fn read_packed_references(&self) -> Result<String, GitError> {
+        match std::fs::read_to_string(self.common_directory.join("packed-refs")) {
+            Ok(packed_references) => Ok(packed_references),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(String::new()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn read_loose_references(
+        &self,
+        directory: &Path,
+        references: &mut HashMap<String, String>,
+    ) -> Result<(), GitError> {
+        let entries = match std::fs::read_dir(directory) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(()),
+            Err(e) => return Err(e.into()),
+        };
+        for entry in entries {
+            let path = entry?.path();
+            if path.is_dir() {
+                self.read_loose_references(&path, references)?;
+                continue;
+            }
+            let content = std::fs::read_to_string(&path)?;
+            let sha = match content.trim().strip_prefix(SYMBOLIC_REFERENCE_PREFIX) {
+                Some(target) => match self.resolve_reference(target)? {
+                    Some(sha) => sha,
+                    None => continue,
+                },
+                None => content.trim().to_string(),
+            };
+            let reference = path
+                .strip_prefix(&self.common_directory)
+                .expect("loose references live in the common directory")
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            references.insert(reference, sha);
+        }
+        Ok(())
+    }
+
+    fn resolve_reference(&self, reference: &str) -> Result<Option<String>, GitError> {
+        match std::fs::read_to_string(self.common_directory.join(reference)) {
+            Ok(content) if !content.starts_with(SYMBOLIC_REFERENCE_PREFIX) => {
+                Ok(Some(content.trim().to_string()))
+            }
+            Ok(_) => Ok(None),
+            Err(e) if e.kind() == ErrorKind::NotFound => {
+                Ok(self.packed_references()?.remove(reference))
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Rewrites `packed-refs` without the reference.
+    fn remove_packed_reference(&self, reference: &str) -> Result<bool, GitError> {
+        let packed_references = self.read_packed_references()?;
+        let mut removed = false;
+        let mut removing_peeled_line = false;
+        let remaining: String = packed_references
+            .lines()
+            .filter(|line| {
+                if removing_peeled_line && line.starts_with('^') {
+                    return false;
+                }
+                removing_peeled_line =
+                    line.split_once(' ').map(|(_, name)| name.trim()) == Some(reference);
+                removed |= removing_peeled_line;
+                !removing_peeled_line
+            })
+            .map(|line| format!("{}\n", line))
+            .collect();
+        if !removed {
+            return Ok(false);
+        }
+
+        write_through_lock_file(&self.common_directory.join("packed-refs"), &remaining)?;
+        Ok(true)
+    }
+
+    fn remove_branch_configuration(&self, name: &str) -> Result<(), GitError> {
+        let config = self.read_config()?;
+        let remaining = config::remove_section(&config, "branch", Some(name));
+        if remaining != config {
+            write_through_lock_file(&self.common_directory.join("config"), &remaining)?;
+        }
+        Ok(())
+    }
+
+    fn read_config(&self) -> Result<String, GitError> {
+        match std::fs::read_to_string(self.common_directory.join("config")) {
+            Ok(config) => Ok(config),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(String::new()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn shallow_commits(&self) -> Result<HashSet<String>, GitError> {
+        match std::fs::read_to_string(self.common_directory.join("shallow")) {
+            Ok(shallow) => Ok(shallow.lines().map(str::to_string).collect()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(HashSet::new()),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+/// The state of a comparison: which side reaches each commit seen so far, newest first.
+#[derive(Default)]
+struct CommitWalk {
+    commits: HashMap<String, CommitObject>,
+    reachable_from: HashMap<String, u8>,
+    queue: BinaryHeap<(chrono::DateTime<chrono::Utc>, String)>,
+}
+
+impl CommitWalk {
+    fn mark(&mut self, git: &GitDirectory, sha: &str, side: u8) -> Result<(), GitError> {
+        let reachable_from = self.reachable_from.entry(sha.to_string()).or_insert(0);
+        if *reachable_from | side == *reachable_from {
+            return Ok(());
+        }
+        *reachable_from |= side;
+        if !self.commits.contains_key(sha) {
+            self.commits.insert(sha.to_string(), git.commit(sha)?);
+        }
+        self.queue
+            .push((*self.commits[sha].date(), sha.to_string()));
+        Ok(())
+    }
+
+    fn has_commits_reachable_from_one_side(&self) -> bool {
+        self.queue
+            .iter()
+            .any(|(_, sha)| self.reachable_from[sha] != REACHABLE_FROM_BOTH)
+    }
+}
+
+fn locate_git_directory(path: &Path) -> Result<PathBuf, GitError> {
+    let dot_git = path.join(".git");
+    if dot_git.is_dir() {
+        return Ok(dot_git);
+    }
+    if dot_git.is_file() {
+        let content = std::fs::read_to_string(&dot_git)?;
+        if let Some(git_directory) = content.trim().strip_prefix("gitdir:") {
+            return Ok(path.join(git_directory.trim()));
+        }
+    }
+    if path.join("HEAD").is_file() && path.join("objects").is_dir() && path.join("refs").is_dir() {
+        return Ok(path.to_path_buf());
+    }
+    Err(GitError::NotARepository(path.to_path_buf()))
+}
+
+/// Replaces a file by renaming a lock file over it, which also keeps git from writing it at the
+/// same time.
+fn write_through_lock_file(path: &Path, content: &str) -> Result<(), GitError> {
+    let mut lock_path = path.as_os_str().to_owned();
+    lock_path.push(".lock");
+    let lock_path = PathBuf::from(lock_path);
+    let mut lock_file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&lock_path)?;
+    if let Err(e) = lock_file
+        .write_all(content.as_bytes())
+        .and_then(|_| std::fs::rename(&lock_path, path))
+    {
+        let _ = std::fs::remove_file(&lock_path);
+        return Err(e.into());
+    }
+    Ok(())
+}
+
+fn remove_file_if_exists(path: &Path) -> Result<bool, GitError> {
+    match std::fs::remove_file(path) {
+        Ok(()) => Ok(true),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(false),
+        Err(e) => Err(e.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use crate::ports::git::TestRepository;
+
+    use super::*;
+
+    fn sha(number: u32) -> String {
+        TestRepository::sha(number)
+    }
+
+    fn branch(name: &str, number: u32) -> BranchReference {
+        BranchReference::new(name.to_string(), sha(number))
+    }
+
+    /// main: 1 - 2 - 4 (merge of 3)
+    ///            \ 3 /
+    /// done points at 3, which main has merged; wip: 2 - 5 - 6 is not merged.
+    fn repository_with_history(path: &Path) -> TestRepository {
+        let repository = TestRepository::bare(path, "main");
+        repository.commit(&sha(1), &[], "Ann", 100);
+        repository.commit(&sha(2), &[&sha(1)], "Ann", 200);
+        repository.commit(&sha(3), &[&sha(2)], "Bob", 300);
+        repository.commit(&sha(4), &[&sha(2), &sha(3)], "Ann", 400);
+        repository.commit(&sha(5), &[&sha(2)], "Cid", 250);
+        repository.commit(&sha(6), &[&sha(5)], "Cid", 350);
+        repository.branch("main", &sha(4));
+        repository.branch("done", &sha(3));
+        repository.branch("feature/wip", &sha(6));
+        repository
+    }
+
+    #[test]
+    fn opens_bare_repository() {
+        let directory = assert_fs::TempDir::new().unwrap();
+        repository_with_history(directory.path());
+
+        assert_that(&GitDirectory::open(directory.path()).is_ok()).is_true();
+    }
+
+    #[test]
+    fn opens_working_copy_through_its_dot_git_directory() {
+        let directory = assert_fs::TempDir::new().unwrap();
+        repository_with_history(&directory.path().join(".git"));
+
+        assert_that(
+            &GitDirectory::open(directory.path())
+                .unwrap()
+                .head_branch()
+                .unwrap(),
+        )
+        .is_equal_to(Some("main".to_string()));
+    }
+
+    #[test]
+    fn opens_linked_worktree_through_its_dot_git_file() {
+        let directory = assert_fs::TempDir::new().unwrap();
+        let repository = repository_with_history(&directory.path().join("main/.git"));
+        let worktree_git_directory = repository.path().join("worktrees/wip");
+        std::fs::create_dir_all(&worktree_git_directory).unwrap();
+        std::fs::write(worktree_git_directory.join("commondir"), "../..\n").unwrap();
+        std::fs::write(
+            worktree_git_directory.join("HEAD"),
+            "ref: refs/heads/feature/wip\n",
+        )
+        .unwrap();
+        std::fs::create_dir_all(directory.path().join("wip")).unwrap();
+        std::fs::write(
+            directory.path().join("wip/.git"),
+            format!("gitdir: {}\n", worktree_git_directory.display()),
+        )
+        .unwrap();
+
+        let under_test = GitDirectory::open(&directory.path().join("wip")).unwrap();
+
+        assert_that(&under_test.head_branch().unwrap())
+            .is_equal_to(Some("feature/wip".to_string()));
+        assert_that(&under_test.branches().unwrap()).has_length(3);
+    }
+
+    #[test]
+    fn fails_to_open_directory_that_is_not_a_repository() {
+        let directory = assert_fs::TempDir::new().unwrap();
+
+        assert_that(
+            &GitDirectory::open(directory.path())
+                .err()
+                .unwrap()
+                .to_string(),
+        )
+        .is_equal_to(format!(
+            "'{}' is not a git repository",
+            directory.path().display()
+        ));
+    }
+
+    #[test]
+    fn lists_loose_branches_sorted_by_name() {
+        let directory = assert_fs::TempDir::new().unwrap();
+        repository_with_history(directory.path());
+
+        assert_that(
+            &GitDirectory::open(directory.path())
+                .unwrap()
+                .branches()
+                .unwrap(),
+        )
+        .is_equal_to(vec![
+            branch("done", 3),
+            branch("feature/wip", 6),
+            branch("main", 4),
+        ]);
+    }
+
+    #[test]
+    fn lists_packed_branches_overridden_by_loose_ones() {
+        let directory = assert_fs::TempDir::new().unwrap();
+        let repository = repository_with_history(directory.path());
+        repository.packed_branches(&[("archived", &sha(1)), ("main", &sha(2))]);
+        std::fs::write(
+            directory.path().join("packed-refs"),
+            std::fs::read_to_string(directory.path().join("packed-refs")).unwrap()
+                + &format!("{} refs/tags/v1\n^{}\n", sha(7), sha(1)),
+        )
+        .unwrap();
+
+        assert_that(
+            &GitDirectory::open(directory.path())
+                .unwrap()
+                .branches()
+                .unwrap(),
+        )
+        .is_equal_to(vec![
+            branch("archived", 1),
+            branch("done", 3),
+            branch("feature/wip", 6),
+            branch("main", 4),
+        ]);
+    }
+
+    #[test]
+    fn resolves_symbolic_branch_references() {
+        let directory = assert_fs::TempDir::new().unwrap();
+        let repository = repository_with_history(directory.path());
+        std::fs::write(
+            repository.path().join("refs/heads/trunk"),
+            "ref: refs/heads/main\n",
+        )
+        .unwrap();
+
+        assert_that(
+            &GitDirectory::open(directory.path())
+                .unwrap()
+                .branches()
+                .unwrap(),
+        )
+        .contains(branch("trunk", 4));
+    }
+
+    #[test]
+    fn has_no_head_branch_when_head_is_detached() {
+        let directory = assert_fs::TempDir::new().unwrap();
+        repository_with_history(directory.path());
+        std::fs::write(directory.path().join("HEAD"), format!("{}\n", sha(4))).unwrap();
+
+        assert_that(
+            &GitDirectory::open(directory.path())
+                .unwrap()
+                .head_branch()
+                .unwrap(),
+        )
+        .is_none();
+    }
+
+    #[test]
+    fn reads_commit() {
+        let directory = assert_fs::TempDir::new().unwrap();
+        repository_with_history(directory.path());
+
+        let commit = GitDirectory::open(directory.path())
+            .unwrap()
+            .commit(&sha(3))
+            .unwrap();
+
+        assert_that(commit.author()).is_equal_to("Bob".to_string());
+        assert_that(&commit.date().timestamp()).is_equal_to(300);
+    }
+
+    #[test]
+    fn compares_merged_branch() {
+        let directory = assert_fs::TempDir::new().unwrap();
+        repository_with_history(directory.path());
+
+        let divergence = GitDirectory::open(directory.path())
+            .unwrap()
+            .compare(&sha(4), &sha(3))
+            .unwrap();
+
+        assert_that(&divergence.ahead_by()).is_equal_to(0);
+        assert_that(&divergence.behind_by()).is_equal_to(1);
+    }
+
+    #[test]
+    fn compares_diverged_branch() {
+        let directory = assert_fs::TempDir::new().unwrap();
+        repository_with_history(directory.path());
+
+        let divergence = GitDirectory::open(directory.path())
+            .unwrap()
+            .compare(&sha(4), &sha(6))
+            .unwrap();
+
+        assert_that(&divergence.ahead_by()).is_equal_to(2);
+        assert_that(&divergence.behind_by()).is_equal_to(2);
+    }
+
+    #[test]
+    fn compares_branch_with_itself() {
+        let directory = assert_fs::TempDir::new().unwrap();
+        repository_with_history(directory.path());
+
+        let divergence = GitDirectory::open(directory.path())
+            .unwrap()
+            .compare(&sha(4), &sha(4))
+            .unwrap();
+
+        assert_that(&divergence.ahead_by()).is_equal_to(0);
+        assert_that(&divergence.behind_by()).is_equal_to(0);
+    }
+
+    #[test]
+    fn compares_without_reading_past_shallow_boundary() {
+        let directory = assert_fs::TempDir::new().unwrap();
+        let repository = TestRepository::bare(directory.path(), "main");
+        repository.commit(&sha(2), &[&sha(1)], "Ann", 200);
+        repository.commit(&sha(3), &[&sha(1)], "Ann", 300);
+        std::fs::write(
+            directory.path().join("shallow"),
+            format!("{}\n{}\n", sha(2), sha(3)),
+        )
+        .unwrap();
+
+        let divergence = GitDirectory::open(directory.path())
+            .unwrap()
+            .compare(&sha(2), &sha(3))
+            .unwrap();
+
+        assert_that(&divergence.ahead_by()).is_equal_to(1);
+        assert_that(&divergence.behind_by()).is_equal_to(1);
+    }
+
+    #[test]
+    fn deletes_loose_branch_and_its_reflog() {
+        let directory = assert_fs::TempDir::new().unwrap();
+        let repository = repository_with_history(directory.path());
+        let reflog = repository.path().join("logs/refs/heads/feature/wip");
+        std::fs::create_dir_all(reflog.parent().unwrap()).unwrap();
+        std::fs::write(&reflog, "").unwrap();
+        let under_test = GitDirectory::open(directory.path()).unwrap();
+
+        under_test.delete_branch("feature/wip").unwrap();
+
+        assert_that(&under_test.branches().unwrap())
+            .is_equal_to(vec![branch("done", 3), branch("main", 4)]);
+        assert_that(&reflog.exists()).is_false();
+    }
+
+    #[test]
+    fn deletes_packed_branch_with_its_peeled_line() {
+        let directory = assert_fs::TempDir::new().unwrap();
+        let repository = repository_with_history(directory.path());
+        std::fs::write(
+            repository.path().join("packed-refs"),
+            format!(
+                "# pack-refs with: peeled\n{0} refs/heads/archived\n^{1}\n{0} refs/tags/v1\n",
+                sha(1),
+                sha(2)
+            ),
+        )
+        .unwrap();
+        let under_test = GitDirectory::open(directory.path()).unwrap();
+
+        under_test.delete_branch("archived").unwrap();
+
+        assert_that(&std::fs::read_to_string(repository.path().join("packed-refs")).unwrap())
+            .is_equal_to(format!(
+                "# pack-refs with: peeled\n{} refs/tags/v1\n",
+                sha(1)
+            ));
+        assert_that(&repository.path().join("packed-refs.lock").exists()).is_false();
+    }
+
+    #[test]
+    fn deletes_branch_that_is_both_loose_and_packed() {
+        let directory = assert_fs::TempDir::new().unwrap();
+        let repository = repository_with_history(directory.path());
+        repository.packed_branches(&[("done", &sha(2))]);
+        let under_test = GitDirectory::open(directory.path()).unwrap();
+
+        under_test.delete_branch("done").unwrap();
+
+        assert_that(&under_test.branches().unwrap())
+            .is_equal_to(vec![branch("feature/wip", 6), branch("main", 4)]);
+    }
+
+    #[test]
+    fn deletes_branch_configuration_with_branch() {
+        let directory = assert_fs::TempDir::new().unwrap();
+        let repository = repository_with_history(directory.path());
+        std::fs::write(
+            repository.path().join("config"),
+            "[core]\n\tbare = true\n[branch \"done\"]\n\tremote = origin\n[branch \"main\"]\n\tremote = origin\n",
+        )
+        .unwrap();
+        let under_test = GitDirectory::open(directory.path()).unwrap();
+
+        under_test.delete_branch("done").unwrap();
+
+        assert_that(
+            &under_test
+                .config()
+                .unwrap()
+                .get("branch", Some("done"), "remote"),
+        )
+        .is_none();
+        assert_that(
+            &under_test
+                .config()
+                .unwrap()
+                .get("branch", Some("main"), "remote"),
+        )
+        .is_equal_to(Some("origin"));
+    }
+
+    #[test]
+    fn finds_remote_head_branch() {
+        let directory = assert_fs::TempDir::new().unwrap();
+        let repository = repository_with_history(&directory.path().join(".git"));
+        std::fs::create_dir_all(repository.path().join("refs/remotes/origin")).unwrap();
+        std::fs::write(
+            repository.path().join("refs/remotes/origin/HEAD"),
+            "ref: refs/remotes/origin/trunk\n",
+        )
+        .unwrap();
+        let under_test = GitDirectory::open(directory.path()).unwrap();
+
+        assert_that(&under_test.remote_head_branch("origin").unwrap())
+            .is_equal_to(Some("trunk".to_string()));
+        assert_that(&under_test.remote_head_branch("upstream").unwrap()).is_none();
+    }
+
+    #[test]
+    fn checks_whether_loose_or_packed_reference_exists() {
+        let directory = assert_fs::TempDir::new().unwrap();
+        let repository = repository_with_history(directory.path());
+        std::fs::write(
+            repository.path().join("packed-refs"),
+            format!("{} refs/remotes/origin/done\n", sha(3)),
+        )
+        .unwrap();
+        let under_test = GitDirectory::open(directory.path()).unwrap();
+
+        assert_that(&under_test.reference_exists("refs/heads/main").unwrap()).is_true();
+        assert_that(
+            &under_test
+                .reference_exists("refs/remotes/origin/done")
+                .unwrap(),
+        )
+        .is_true();
+        assert_that(
+            &under_test
+                .reference_exists("refs/remotes/origin/gone")
+                .unwrap(),
+        )
+        .is_false();
+    }
+
+    #[test]
+    fn fails_to_delete_branch_that_does_not_exist() {
+        let directory = assert_fs::TempDir::new().unwrap();
+        repository_with_history(directory.path());
+
+        assert_that(
+            &GitDirectory::open(directory.path())
+                .unwrap()
+                .delete_branch("../../HEAD")
+                .err()
+                .unwrap()
+                .to_string(),
+        )
+        .is_equal_to("branch '../../HEAD' not found".to_string());
+    }
+}
@@ -0,0 +1,15 @@
+pub use commit_object::*;
+pub use config::GitConfig;
+pub use error::*;
+pub use git_directory::*;
+#[cfg(test)]
+pub(crate) use test_repository::TestRepository;
+
+mod commit_object;
+mod config;
+mod error;
+mod git_directory;
+mod object_database;
+mod pack;
+#[cfg(test)]
+mod test_repository;
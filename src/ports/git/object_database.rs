@@ -0,0 +1,280 @@
+use std::io::{ErrorKind, Read};
+use std::path::{Path, PathBuf};
+
+use flate2::read::ZlibDecoder;
+
+use crate::ports::git::pack::{apply_delta, Pack, PackedObject};
+use crate::ports::git::GitError;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(super) enum ObjectKind {
+    Commit,
+    Tree,
+    Blob,
+    Tag,
+}
+
+impl ObjectKind {
+    pub(super) fn from_pack_type(type_id: u8) -> Option<Self> {
+        match type_id {
+            1 => Some(ObjectKind::Commit),
+            2 => Some(ObjectKind::Tree),
+            3 => Some(ObjectKind::Blob),
+            4 => Some(ObjectKind::Tag),
+            _ => None,
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "commit" => Some(ObjectKind::Commit),
+            "tree" => Some(ObjectKind::Tree),
+            "blob" => Some(ObjectKind::Blob),
+            "tag" => Some(ObjectKind::Tag),
+            _ => None,
+        }
+    }
+}
+
+pub(super) struct Object {
+    kind: ObjectKind,
+    data: Vec<u8>,
+}
+
+impl Object {
+    pub(super) fn new(kind: ObjectKind, data: Vec<u8>) -> Self {
+        Object { kind, data }
+    }
+
+    pub(super) fn kind(&self) -> ObjectKind {
+        self.kind
+    }
+
+    pub(super) fn data(&self) -> &Vec<u8> {
+        &self.data
+    }
+}
+
+/// Reads objects from the `objects` directory, looking at loose objects before pack files.
+pub(super) struct ObjectDatabase {
+    objects_directory: PathBuf,
+    packs: Vec<Pack>,
+}
+
+impl ObjectDatabase {
+    pub(super) fn open(objects_directory: PathBuf) -> Result<Self, GitError> {
+        let packs = match std::fs::read_dir(objects_directory.join("pack")) {
+            Ok(entries) => {
+                let mut index_paths = entries
+                    .map(|entry| entry.map(|entry| entry.path()))
+                    .collect::<Result<Vec<_>, _>>()?;
+                index_paths.retain(|path| path.extension() == Some("idx".as_ref()));
+                index_paths.sort();
+                index_paths
+                    .iter()
+                    .map(|index_path| Pack::open(index_path))
+                    .collect::<Result<Vec<_>, _>>()?
+            }
+            Err(e) if e.kind() == ErrorKind::NotFound => vec![],
+            Err(e) => return Err(e.into()),
+        };
+
+        Ok(ObjectDatabase {
+            objects_directory,
+            packs,
+        })
+    }
+
+    pub(super) fn read(&self, sha: &str) -> Result<Object, GitError> {
+        if sha.len() != 40 || !sha.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(GitError::ObjectNotFound(sha.to_string()));
+        }
+        let sha = sha.to_lowercase();
+        if let Some(object) = self.read_loose(&sha)? {
+            return Ok(object);
+        }
+        match self
+            .packs
+            .iter()
+            .find_map(|pack| {
+                pack.offset_of(&sha)
+                    .map(|offset| offset.map(|offset| (pack, offset)))
+                    .transpose()
+            })
+            .transpose()?
+        {
+            Some((pack, offset)) => self.read_packed(&sha, pack, offset),
+            None => Err(GitError::ObjectNotFound(sha)),
+        }
+    }
+
+    fn read_loose(&self, sha: &str) -> Result<Option<Object>, GitError> {
+        let compressed = match std::fs::read(loose_object_path(&self.objects_directory, sha)) {
+            Ok(compressed) => compressed,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        let mut content = vec![];
+        ZlibDecoder::new(compressed.as_slice()).read_to_end(&mut content)?;
+
+        let header_end = content
+            .iter()
+            .position(|byte| *byte == 0)
+            .ok_or_else(|| GitError::corrupt_object(sha, "missing object header"))?;
+        let header = String::from_utf8_lossy(&content[..header_end]);
+        let kind = header
+            .split(' ')
+            .next()
+            .and_then(ObjectKind::from_name)
+            .ok_or_else(|| GitError::corrupt_object(sha, "unknown object type"))?;
+        Ok(Some(Object::new(kind, content.split_off(header_end + 1))))
+    }
+
+    /// Follows a chain of deltas down to a whole object, then applies them from the base up.
+    fn read_packed(&self, sha: &str, pack: &Pack, offset: u64) -> Result<Object, GitError> {
+        let mut deltas = vec![];
+        let mut offset = offset;
+        let base = loop {
+            match pack.read_at(offset)? {
+                PackedObject::Whole(object) => break object,
+                PackedObject::OffsetDelta { base_offset, delta } => {
+                    deltas.push(delta);
+                    offset = base_offset;
+                }
+                PackedObject::ReferenceDelta { base_sha, delta } => {
+                    deltas.push(delta);
+                    break self.read(&base_sha)?;
+                }
+            }
+        };
+
+        deltas.iter().rev().try_fold(base, |object, delta| {
+            apply_delta(object.data(), delta)
+                .map(|data| Object::new(object.kind(), data))
+                .ok_or_else(|| GitError::corrupt_object(sha, "delta does not apply to its base"))
+        })
+    }
+}
+
+fn loose_object_path(objects_directory: &Path, sha: &str) -> PathBuf {
+    objects_directory.join(&sha[..2]).join(&sha[2..])
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use std::io::Write;
+
+    use flate2::write::ZlibEncoder;
+    use flate2::Compression;
+    use spectral::prelude::*;
+
+    use crate::ports::git::pack::tests::{pack_entry, truncating_delta, write_pack};
+
+    use super::*;
+
+    pub(in crate::ports::git) fn write_loose_object(
+        objects_directory: &Path,
+        sha: &str,
+        kind: &str,
+        data: &[u8],
+    ) {
+        let path = loose_object_path(objects_directory, sha);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        let mut encoder = ZlibEncoder::new(vec![], Compression::default());
+        write!(encoder, "{} {}\0", kind, data.len()).unwrap();
+        encoder.write_all(data).unwrap();
+        std::fs::write(path, encoder.finish().unwrap()).unwrap();
+    }
+
+    fn sha(digit: char) -> String {
+        digit.to_string().repeat(40)
+    }
+
+    fn hex_name(sha: &str) -> Vec<u8> {
+        (0..40)
+            .step_by(2)
+            .map(|start| u8::from_str_radix(&sha[start..start + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn reads_loose_object() {
+        let directory = assert_fs::TempDir::new().unwrap();
+        write_loose_object(directory.path(), &sha('a'), "commit", b"content");
+        let under_test = ObjectDatabase::open(directory.path().to_path_buf()).unwrap();
+
+        let object = under_test.read(&sha('a')).unwrap();
+
+        assert_that(&object.kind()).is_equal_to(ObjectKind::Commit);
+        assert_that(object.data()).is_equal_to(b"content".to_vec());
+    }
+
+    #[test]
+    fn reads_object_rebuilt_from_offset_delta_chain() {
+        let directory = assert_fs::TempDir::new().unwrap();
+        let base = pack_entry(1, b"base content", &[]);
+        let first_delta = pack_entry(
+            6,
+            &truncating_delta(b"base content", 4, b"!"),
+            &[base.len() as u8],
+        );
+        let second_delta = pack_entry(
+            6,
+            &truncating_delta(b"base!", 4, b"?"),
+            &[first_delta.len() as u8],
+        );
+        write_pack(
+            &directory.path().join("pack"),
+            vec![
+                (sha('a').as_str(), base),
+                (sha('b').as_str(), first_delta),
+                (sha('c').as_str(), second_delta),
+            ],
+        );
+        let under_test = ObjectDatabase::open(directory.path().to_path_buf()).unwrap();
+
+        assert_that(under_test.read(&sha('b')).unwrap().data()).is_equal_to(b"base!".to_vec());
+        assert_that(under_test.read(&sha('c')).unwrap().data()).is_equal_to(b"base?".to_vec());
+    }
+
+    #[test]
+    fn reads_object_rebuilt_from_reference_delta_on_loose_base() {
+        let directory = assert_fs::TempDir::new().unwrap();
+        write_loose_object(directory.path(), &sha('a'), "commit", b"base content");
+        write_pack(
+            &directory.path().join("pack"),
+            vec![(
+                sha('b').as_str(),
+                pack_entry(
+                    7,
+                    &truncating_delta(b"base content", 4, b"!"),
+                    &hex_name(&sha('a')),
+                ),
+            )],
+        );
+        let under_test = ObjectDatabase::open(directory.path().to_path_buf()).unwrap();
+
+        let object = under_test.read(&sha('b')).unwrap();
+
+        assert_that(&object.kind()).is_equal_to(ObjectKind::Commit);
+        assert_that(object.data()).is_equal_to(b"base!".to_vec());
+    }
+
+    #[test]
+    fn fails_to_read_missing_object() {
+        let directory = assert_fs::TempDir::new().unwrap();
+        let under_test = ObjectDatabase::open(directory.path().to_path_buf()).unwrap();
+
+        assert_that(&under_test.read(&sha('a')).err().unwrap().to_string())
+            .is_equal_to(format!("object {} not found", sha('a')));
+    }
+
+    #[test]
+    fn does_not_look_up_objects_by_malformed_name() {
+        let directory = assert_fs::TempDir::new().unwrap();
+        let under_test = ObjectDatabase::open(directory.path().to_path_buf()).unwrap();
+
+        assert_that(&under_test.read("../../HEAD").err().unwrap().to_string())
+            .is_equal_to("object ../../HEAD not found".to_string());
+    }
+}
@@ -0,0 +1,570 @@
+use std::convert::{TryFrom, TryInto};
+use std::fs::File;
+use std::io::{BufReader, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+
+use flate2::read::ZlibDecoder;
+
+use crate::ports::git::object_database::{Object, ObjectKind};
+use crate::ports::git::GitError;
+
+const INDEX_MAGIC: [u8; 4] = [0xff, b't', b'O', b'c'];
+const FANOUT_OFFSET: usize = 8;
+const FANOUT_LENGTH: usize = 256 * 4;
+const LARGE_OFFSET_FLAG: u32 = 0x8000_0000;
+
+const OFS_DELTA: u8 = 6;
+const REF_DELTA: u8 = 7;
+
+/// A pack file together with its version 2 index, which is kept in memory for lookups.
+pub(super) struct Pack {
+    pack_path: PathBuf,
+    index: Vec<u8>,
+    object_count: usize,
+}
+
+/// A packed object as stored, before any delta has been applied to its base.
+pub(super) enum PackedObject {
+    Whole(Object),
+    OffsetDelta { base_offset: u64, delta: Vec<u8> },
+    ReferenceDelta { base_sha: String, delta: Vec<u8> },
+}
+
+impl Pack {
+    pub(super) fn open(index_path: &Path) -> Result<Self, GitError> {
+        let index = std::fs::read(index_path)?;
+        let corrupt =
+            |message| GitError::corrupt_object(&index_path.display().to_string(), message);
+        if index.len() < FANOUT_OFFSET + FANOUT_LENGTH || index[..4] != INDEX_MAGIC {
+            return Err(corrupt("unsupported pack index format"));
+        }
+        if read_u32(&index, 4) != Some(2) {
+            return Err(corrupt("unsupported pack index version"));
+        }
+        let object_count = read_u32(&index, FANOUT_OFFSET + FANOUT_LENGTH - 4)
+            .ok_or_else(|| corrupt("truncated pack index"))? as usize;
+        let minimum_length = object_count
+            .checked_mul(28)
+            .and_then(|tables_length| tables_length.checked_add(FANOUT_OFFSET + FANOUT_LENGTH));
+        if minimum_length.is_none_or(|minimum_length| index.len() < minimum_length) {
+            return Err(corrupt("truncated pack index"));
+        }
+
+        Ok(Pack {
+            pack_path: index_path.with_extension("pack"),
+            index,
+            object_count,
+        })
+    }
+
+    /// Finds the offset of an object in the pack file by binary search over the sorted names.
+    pub(super) fn offset_of(&self, sha: &str) -> Result<Option<u64>, GitError> {
+        let corrupt = || {
+            GitError::corrupt_object(
+                &self.pack_path.with_extension("idx").display().to_string(),
+                "pack index points outside of its tables",
+            )
+        };
+        let name = match hex_to_bytes(sha) {
+            Some(name) => name,
+            None => return Ok(None),
+        };
+        let first_byte = name[0] as usize;
+        let mut low = match first_byte {
+            0 => 0,
+            _ => read_u32(&self.index, FANOUT_OFFSET + (first_byte - 1) * 4).ok_or_else(corrupt)?
+                as usize,
+        };
+        let mut high =
+            read_u32(&self.index, FANOUT_OFFSET + first_byte * 4).ok_or_else(corrupt)? as usize;
+        if low > high || high > self.object_count {
+            return Err(corrupt());
+        }
+        while low < high {
+            let middle = (low + high) / 2;
+            match self.name_at(middle).cmp(name.as_slice()) {
+                std::cmp::Ordering::Less => low = middle + 1,
+                std::cmp::Ordering::Greater => high = middle,
+                std::cmp::Ordering::Equal => {
+                    return self.offset_at(middle).map(Some).ok_or_else(corrupt)
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Only called for positions below the object count, which `open` checked the index covers.
+    fn name_at(&self, position: usize) -> &[u8] {
+        let start = FANOUT_OFFSET + FANOUT_LENGTH + position * 20;
+        &self.index[start..start + 20]
+    }
+
+    fn offset_at(&self, position: usize) -> Option<u64> {
+        let offsets_start = FANOUT_OFFSET + FANOUT_LENGTH + self.object_count * 24;
+        let offset = read_u32(&self.index, offsets_start + position * 4)?;
+        if offset & LARGE_OFFSET_FLAG == 0 {
+            return Some(offset as u64);
+        }
+        let large_offsets_start = offsets_start + self.object_count * 4;
+        let large_offset_position = (offset & !LARGE_OFFSET_FLAG) as usize;
+        read_u64(
+            &self.index,
+            large_offset_position
+                .checked_mul(8)?
+                .checked_add(large_offsets_start)?,
+        )
+    }
+
+    pub(super) fn read_at(&self, offset: u64) -> Result<PackedObject, GitError> {
+        let corrupt = |message| {
+            GitError::corrupt_object(&format!("{}@{}", self.pack_path.display(), offset), message)
+        };
+        let file = File::open(&self.pack_path)?;
+        let pack_length = file.metadata()?.len();
+        let mut reader = BufReader::new(file);
+        reader.seek(SeekFrom::Start(offset))?;
+
+        let mut byte = read_byte(&mut reader)?;
+        let type_id = (byte >> 4) & 0x07;
+        let mut size = (byte & 0x0f) as u64;
+        let mut shift = 4;
+        while byte & 0x80 != 0 {
+            byte = read_byte(&mut reader)?;
+            size |= shifted_left((byte & 0x7f) as u64, shift)
+                .ok_or_else(|| corrupt("object size does not fit in 64 bits"))?;
+            shift += 7;
+        }
+        let inflate_entry = |reader: &mut BufReader<File>| {
+            let data = inflate(reader, size, pack_length)?;
+            if data.len() as u64 != size {
+                return Err(corrupt("object is shorter than its recorded size"));
+            }
+            Ok(data)
+        };
+
+        match type_id {
+            OFS_DELTA => {
+                let relative_offset = read_base_offset(&mut reader)?
+                    .ok_or_else(|| corrupt("delta base offset does not fit in 64 bits"))?;
+                let base_offset = offset
+                    .checked_sub(relative_offset)
+                    .filter(|_| relative_offset > 0)
+                    .ok_or_else(|| corrupt("delta base lies outside of the pack"))?;
+                Ok(PackedObject::OffsetDelta {
+                    base_offset,
+                    delta: inflate_entry(&mut reader)?,
+                })
+            }
+            REF_DELTA => {
+                let mut base_name = [0u8; 20];
+                reader.read_exact(&mut base_name)?;
+                Ok(PackedObject::ReferenceDelta {
+                    base_sha: bytes_to_hex(&base_name),
+                    delta: inflate_entry(&mut reader)?,
+                })
+            }
+            _ => {
+                let kind = ObjectKind::from_pack_type(type_id)
+                    .ok_or_else(|| corrupt("unknown packed object type"))?;
+                Ok(PackedObject::Whole(Object::new(
+                    kind,
+                    inflate_entry(&mut reader)?,
+                )))
+            }
+        }
+    }
+}
+
+/// Rebuilds an object from its base and a git delta made of copy and insert instructions.
+pub(super) fn apply_delta(base: &[u8], delta: &[u8]) -> Option<Vec<u8>> {
+    let mut position = 0;
+    let base_size = read_delta_size(delta, &mut position)?;
+    let result_size = read_delta_size(delta, &mut position)?;
+    if base_size != base.len() {
+        return None;
+    }
+
+    let mut result = Vec::with_capacity(result_size.min(base.len().saturating_add(delta.len())));
+    while position < delta.len() {
+        let instruction = delta[position];
+        position += 1;
+        if instruction & 0x80 != 0 {
+            let mut copy_offset = 0usize;
+            for byte_index in 0..4 {
+                if instruction & (1 << byte_index) != 0 {
+                    copy_offset |= (*delta.get(position)? as usize) << (8 * byte_index);
+                    position += 1;
+                }
+            }
+            let mut copy_size = 0usize;
+            for byte_index in 0..3 {
+                if instruction & (0x10 << byte_index) != 0 {
+                    copy_size |= (*delta.get(position)? as usize) << (8 * byte_index);
+                    position += 1;
+                }
+            }
+            if copy_size == 0 {
+                copy_size = 0x10000;
+            }
+            result.extend_from_slice(base.get(copy_offset..copy_offset.checked_add(copy_size)?)?);
+        } else if instruction != 0 {
+            let insert_size = instruction as usize;
+            result.extend_from_slice(delta.get(position..position.checked_add(insert_size)?)?);
+            position += insert_size;
+        } else {
+            return None;
+        }
+    }
+
+    if result.len() == result_size {
+        Some(result)
+    } else {
+        None
+    }
+}
+
+fn read_delta_size(delta: &[u8], position: &mut usize) -> Option<usize> {
+    let mut size = 0usize;
+    let mut shift = 0;
+    loop {
+        let byte = *delta.get(*position)?;
+        *position += 1;
+        size |= usize::try_from(shifted_left((byte & 0x7f) as u64, shift)?).ok()?;
+        shift += 7;
+        if byte & 0x80 == 0 {
+            return Some(size);
+        }
+    }
+}
+
+/// Shifts a part of a variable-length number into place, or `None` when bits would be lost.
+fn shifted_left(part: u64, shift: u32) -> Option<u64> {
+    let shifted = part.checked_shl(shift)?;
+    if shifted >> shift == part {
+        Some(shifted)
+    } else {
+        None
+    }
+}
+
+/// Reads the distance back to an offset delta's base, which uses its own big-endian encoding.
+/// `None` means the distance does not fit in 64 bits.
+fn read_base_offset(reader: &mut impl Read) -> Result<Option<u64>, GitError> {
+    let mut byte = read_byte(reader)?;
+    let mut offset = (byte & 0x7f) as u64;
+    while byte & 0x80 != 0 {
+        byte = read_byte(reader)?;
+        offset = match offset
+            .checked_add(1)
+            .and_then(|offset| offset.checked_mul(0x80))
+        {
+            Some(offset) => offset | (byte & 0x7f) as u64,
+            None => return Ok(None),
+        };
+    }
+    Ok(Some(offset))
+}
+
+/// Inflates up to `size` bytes. The recorded size comes from the pack itself, so no more than
+/// the length of the pack is reserved up front.
+fn inflate(reader: &mut impl Read, size: u64, pack_length: u64) -> Result<Vec<u8>, GitError> {
+    let mut data = Vec::with_capacity(size.min(pack_length) as usize);
+    ZlibDecoder::new(reader).take(size).read_to_end(&mut data)?;
+    Ok(data)
+}
+
+fn read_byte(reader: &mut impl Read) -> Result<u8, GitError> {
+    let mut byte = [0u8; 1];
+    reader.read_exact(&mut byte)?;
+    Ok(byte[0])
+}
+
+fn read_u32(bytes: &[u8], start: usize) -> Option<u32> {
+    let end = start.checked_add(4)?;
+    Some(u32::from_be_bytes(bytes.get(start..end)?.try_into().ok()?))
+}
+
+fn read_u64(bytes: &[u8], start: usize) -> Option<u64> {
+    let end = start.checked_add(8)?;
+    Some(u64::from_be_bytes(bytes.get(start..end)?.try_into().ok()?))
+}
+
+fn hex_to_bytes(sha: &str) -> Option<Vec<u8>> {
+    if sha.len() != 40 {
+        return None;
+    }
+    (0..40)
+        .step_by(2)
+        .map(|start| u8::from_str_radix(sha.get(start..start + 2)?, 16).ok())
+        .collect()
+}
+
+fn bytes_to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use std::io::Write;
+
+    use flate2::write::ZlibEncoder;
+    use flate2::Compression;
+    use spectral::prelude::*;
+
+    use super::*;
+
+    /// Writes a pack and its index holding the given entries, each already encoded as a pack
+    /// entry header followed by its compressed data.
+    pub(in crate::ports::git) fn write_pack(
+        pack_directory: &Path,
+        entries: Vec<(&str, Vec<u8>)>,
+    ) -> PathBuf {
+        let mut pack = b"PACK".to_vec();
+        pack.extend_from_slice(&2u32.to_be_bytes());
+        pack.extend_from_slice(&(entries.len() as u32).to_be_bytes());
+        let mut names_and_offsets = vec![];
+        for (sha, entry) in entries {
+            names_and_offsets.push((hex_to_bytes(sha).unwrap(), pack.len() as u32));
+            pack.extend_from_slice(&entry);
+        }
+        pack.extend_from_slice(&[0u8; 20]);
+        names_and_offsets.sort();
+
+        let mut index = INDEX_MAGIC.to_vec();
+        index.extend_from_slice(&2u32.to_be_bytes());
+        for first_byte in 0..256usize {
+            let count = names_and_offsets
+                .iter()
+                .filter(|(name, _)| (name[0] as usize) <= first_byte)
+                .count() as u32;
+            index.extend_from_slice(&count.to_be_bytes());
+        }
+        names_and_offsets
+            .iter()
+            .for_each(|(name, _)| index.extend_from_slice(name));
+        names_and_offsets
+            .iter()
+            .for_each(|_| index.extend_from_slice(&0u32.to_be_bytes()));
+        names_and_offsets
+            .iter()
+            .for_each(|(_, offset)| index.extend_from_slice(&offset.to_be_bytes()));
+        index.extend_from_slice(&[0u8; 40]);
+
+        std::fs::create_dir_all(pack_directory).unwrap();
+        let index_path = pack_directory.join("pack-test.idx");
+        std::fs::write(pack_directory.join("pack-test.pack"), pack).unwrap();
+        std::fs::write(&index_path, index).unwrap();
+        index_path
+    }
+
+    pub(in crate::ports::git) fn pack_entry(type_id: u8, data: &[u8], base: &[u8]) -> Vec<u8> {
+        let mut size = data.len();
+        let mut byte = (type_id << 4) | (size & 0x0f) as u8;
+        size >>= 4;
+        let mut entry = vec![];
+        while size > 0 {
+            entry.push(byte | 0x80);
+            byte = (size & 0x7f) as u8;
+            size >>= 7;
+        }
+        entry.push(byte);
+        entry.extend_from_slice(base);
+        let mut encoder = ZlibEncoder::new(vec![], Compression::default());
+        encoder.write_all(data).unwrap();
+        entry.extend(encoder.finish().unwrap());
+        entry
+    }
+
+    /// A delta that keeps the first `kept` bytes of the base and appends `appended`.
+    pub(in crate::ports::git) fn truncating_delta(
+        base: &[u8],
+        kept: u8,
+        appended: &[u8],
+    ) -> Vec<u8> {
+        let mut delta = vec![
+            base.len() as u8,
+            kept + appended.len() as u8,
+            0x90,
+            kept,
+            appended.len() as u8,
+        ];
+        delta.extend_from_slice(appended);
+        delta
+    }
+
+    fn sha(digit: char) -> String {
+        digit.to_string().repeat(40)
+    }
+
+    fn under_test(directory: &Path, entries: Vec<(&str, Vec<u8>)>) -> Pack {
+        Pack::open(&write_pack(directory, entries)).unwrap()
+    }
+
+    #[test]
+    fn finds_offsets_of_packed_objects() {
+        let directory = assert_fs::TempDir::new().unwrap();
+        let (first, second) = (sha('a'), sha('1'));
+        let pack = under_test(
+            directory.path(),
+            vec![
+                (first.as_str(), pack_entry(3, b"first", &[])),
+                (second.as_str(), pack_entry(3, b"second", &[])),
+            ],
+        );
+
+        assert_that(&pack.offset_of(&first).unwrap()).is_equal_to(Some(12));
+        assert_that(&pack.offset_of(&second).unwrap()).is_some();
+        assert_that(&pack.offset_of(&sha('b')).unwrap()).is_none();
+    }
+
+    #[test]
+    fn reads_whole_object_with_multi_byte_size() {
+        let directory = assert_fs::TempDir::new().unwrap();
+        let data = "x".repeat(300);
+        let pack = under_test(
+            directory.path(),
+            vec![(sha('a').as_str(), pack_entry(1, data.as_bytes(), &[]))],
+        );
+
+        match pack.read_at(12).unwrap() {
+            PackedObject::Whole(object) => {
+                assert_that(&object.kind()).is_equal_to(ObjectKind::Commit);
+                assert_that(object.data()).is_equal_to(data.as_bytes().to_vec());
+            }
+            _ => panic!("expected a whole object"),
+        }
+    }
+
+    #[test]
+    fn reads_offset_delta_with_its_base_offset() {
+        let directory = assert_fs::TempDir::new().unwrap();
+        let base = pack_entry(3, b"base content", &[]);
+        let delta_offset = 12 + base.len() as u8;
+        let pack = under_test(
+            directory.path(),
+            vec![
+                (sha('a').as_str(), base),
+                (
+                    sha('b').as_str(),
+                    pack_entry(
+                        OFS_DELTA,
+                        &truncating_delta(b"base content", 4, b"!"),
+                        &[delta_offset - 12],
+                    ),
+                ),
+            ],
+        );
+
+        match pack.read_at(delta_offset as u64).unwrap() {
+            PackedObject::OffsetDelta { base_offset, .. } => {
+                assert_that(&base_offset).is_equal_to(12)
+            }
+            _ => panic!("expected an offset delta"),
+        }
+    }
+
+    fn zlib(data: &[u8]) -> Vec<u8> {
+        let mut encoder = ZlibEncoder::new(vec![], Compression::default());
+        encoder.write_all(data).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    /// A blob entry whose header claims a size of 2^(4 + 7 * (`continuations` - 1)), followed by the
+    /// compressed `data`.
+    fn entry_with_oversized_header(continuations: usize, data: &[u8]) -> Vec<u8> {
+        let mut entry = vec![0x80 | (3 << 4)];
+        entry.extend(std::iter::repeat_n(0x80, continuations - 1));
+        entry.push(0x01);
+        entry.extend(zlib(data));
+        entry
+    }
+
+    fn read_error(entry: Vec<u8>) -> String {
+        let directory = assert_fs::TempDir::new().unwrap();
+        let pack = under_test(directory.path(), vec![(sha('a').as_str(), entry)]);
+
+        match pack.read_at(12) {
+            Err(err) => err.to_string(),
+            Ok(_) => panic!("expected a corrupt object"),
+        }
+    }
+
+    #[test]
+    fn rejects_object_larger_than_its_data_without_reserving_its_size() {
+        assert_that(&read_error(entry_with_oversized_header(9, b"abc")))
+            .contains("shorter than its recorded size");
+    }
+
+    #[test]
+    fn rejects_object_size_overflowing_64_bits() {
+        assert_that(&read_error(entry_with_oversized_header(10, b"abc")))
+            .contains("does not fit in 64 bits");
+    }
+
+    #[test]
+    fn rejects_base_offset_overflowing_64_bits() {
+        let mut base_offset = vec![0xffu8; 10];
+        base_offset.push(0x7f);
+
+        assert_that(&read_base_offset(&mut base_offset.as_slice()).unwrap()).is_none();
+    }
+
+    #[test]
+    fn rejects_index_with_fanout_beyond_its_objects() {
+        let directory = assert_fs::TempDir::new().unwrap();
+        let index_path = write_pack(
+            directory.path(),
+            vec![(sha('a').as_str(), pack_entry(3, b"blob", &[]))],
+        );
+        let mut index = std::fs::read(&index_path).unwrap();
+        let fanout_entry = FANOUT_OFFSET + 0xaa * 4;
+        index[fanout_entry..fanout_entry + 4].copy_from_slice(&1000u32.to_be_bytes());
+        std::fs::write(&index_path, index).unwrap();
+
+        assert_that(&Pack::open(&index_path).unwrap().offset_of(&sha('a'))).is_err();
+    }
+
+    #[test]
+    fn reads_nothing_past_end_of_index() {
+        assert_that(&read_u32(&[0, 0, 1], 0)).is_none();
+        assert_that(&read_u64(&[0; 8], usize::MAX)).is_none();
+    }
+
+    #[test]
+    fn rejects_delta_size_overflowing_usize() {
+        assert_that(&apply_delta(b"", &[0xff; 11])).is_none();
+    }
+
+    #[test]
+    fn rejects_index_with_unsupported_version() {
+        let directory = assert_fs::TempDir::new().unwrap();
+        let index_path = directory.path().join("pack-test.idx");
+        std::fs::write(&index_path, [0u8; 1032]).unwrap();
+
+        assert_that(&Pack::open(&index_path).err().unwrap().to_string()).contains("unsupported");
+    }
+
+    #[test]
+    fn applies_copy_and_insert_instructions() {
+        assert_that(&apply_delta(
+            b"hello world",
+            &truncating_delta(b"hello world", 6, b"git"),
+        ))
+        .is_equal_to(Some(b"hello git".to_vec()));
+    }
+
+    #[test]
+    fn rejects_delta_for_base_of_different_size() {
+        assert_that(&apply_delta(
+            b"hello",
+            &truncating_delta(b"hello world", 6, b"git"),
+        ))
+        .is_none();
+    }
+
+    #[test]
+    fn decodes_multi_byte_base_offset() {
+        assert_that(&read_base_offset(&mut [0x81u8, 0x00].as_ref()).unwrap())
+            .is_equal_to(Some(256));
+    }
+}
@@ -0,0 +1,66 @@
+use std::path::{Path, PathBuf};
+
+use crate::ports::git::object_database::tests::write_loose_object;
+
+/// Builds bare repositories on disk, one loose object or reference at a time.
+pub(crate) struct TestRepository {
+    path: PathBuf,
+}
+
+impl TestRepository {
+    pub(crate) fn bare(path: &Path, default_branch: &str) -> Self {
+        std::fs::create_dir_all(path.join("objects")).unwrap();
+        std::fs::create_dir_all(path.join("refs").join("heads")).unwrap();
+        std::fs::write(
+            path.join("HEAD"),
+            format!("ref: refs/heads/{}\n", default_branch),
+        )
+        .unwrap();
+        TestRepository {
+            path: path.to_path_buf(),
+        }
+    }
+
+    pub(crate) fn sha(number: u32) -> String {
+        format!("{:040x}", number)
+    }
+
+    pub(crate) fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Writes a commit whose committer date is `timestamp` seconds after the epoch.
+    pub(crate) fn commit(&self, sha: &str, parents: &[&str], author: &str, timestamp: i64) {
+        let mut content = "tree 4b825dc642cb6eb9a060e54bf8d69288fbee4904\n".to_string();
+        parents
+            .iter()
+            .for_each(|parent| content.push_str(&format!("parent {}\n", parent)));
+        content.push_str(&format!(
+            "author {0} <author@example.com> {1} +0000\n\
+             committer {0} <author@example.com> {1} +0000\n\
+             \n\
+             Commit {2}\n",
+            author, timestamp, sha
+        ));
+        write_loose_object(
+            &self.path.join("objects"),
+            sha,
+            "commit",
+            content.as_bytes(),
+        );
+    }
+
+    pub(crate) fn branch(&self, name: &str, sha: &str) {
+        let path = self.path.join("refs").join("heads").join(name);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, format!("{}\n", sha)).unwrap();
+    }
+
+    pub(crate) fn packed_branches(&self, branches: &[(&str, &str)]) {
+        let mut content = "# pack-refs with: peeled fully-peeled sorted \n".to_string();
+        branches.iter().for_each(|(name, sha)| {
+            content.push_str(&format!("{} refs/heads/{}\n", sha, name));
+        });
+        std::fs::write(self.path.join("packed-refs"), content).unwrap();
+    }
+}
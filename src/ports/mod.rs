@@ -1,3 +1,4 @@
 pub mod cli;
+pub mod git;
 pub mod persistence;
 pub mod repository_hosting;
@@ -0,0 +1,157 @@
+use serde::{Deserialize, Serialize};
+
+/// Settings read from `config.yml` in the app home directory.
+#[derive(Debug, Default, Serialize, Deserialize, Eq, PartialEq)]
+pub struct Configuration {
+    #[serde(default)]
+    github_enterprise_hosts: Vec<GitHubEnterpriseHost>,
+    #[serde(default)]
+    gitea_hosts: Vec<GiteaHost>,
+}
+
+impl Configuration {
+    pub fn new(github_enterprise_hosts: Vec<GitHubEnterpriseHost>) -> Self {
+        Configuration {
+            github_enterprise_hosts,
+            gitea_hosts: Vec::new(),
+        }
+    }
+
+    pub fn with_gitea_hosts(mut self, gitea_hosts: Vec<GiteaHost>) -> Self {
+        self.gitea_hosts = gitea_hosts;
+        self
+    }
+
+    pub fn github_enterprise_hosts(&self) -> &Vec<GitHubEnterpriseHost> {
+        &self.github_enterprise_hosts
+    }
+
+    pub fn github_enterprise_host(&self, host: &str) -> Option<&GitHubEnterpriseHost> {
+        self.github_enterprise_hosts
+            .iter()
+            .find(|enterprise_host| enterprise_host.host().eq_ignore_ascii_case(host))
+    }
+
+    pub fn gitea_hosts(&self) -> &Vec<GiteaHost> {
+        &self.gitea_hosts
+    }
+
+    pub fn gitea_host(&self, host: &str) -> Option<&GiteaHost> {
+        self.gitea_hosts
+            .iter()
+            .find(|gitea_host| gitea_host.host().eq_ignore_ascii_case(host))
+    }
+}
+
+/// A GitHub Enterprise Server instance. Its REST API is assumed to live at `/api/v3` on the
+/// host unless an API URL is given.
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+pub struct GitHubEnterpriseHost {
+    host: String,
+    #[serde(default)]
+    api_url: Option<String>,
+}
+
+impl GitHubEnterpriseHost {
+    pub fn new(host: String, api_url: Option<String>) -> Self {
+        GitHubEnterpriseHost { host, api_url }
+    }
+
+    pub fn host(&self) -> &str {
+        self.host.as_str()
+    }
+
+    pub fn api_url(&self) -> String {
+        match &self.api_url {
+            Some(api_url) => api_url.trim_end_matches('/').to_string(),
+            None => format!("https://{}/api/v3", self.host),
+        }
+    }
+}
+
+/// A self-hosted Gitea or Forgejo instance. Its API is assumed to live at `/api/v1` on the host
+/// unless an API URL is given.
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+pub struct GiteaHost {
+    host: String,
+    #[serde(default)]
+    api_url: Option<String>,
+}
+
+impl GiteaHost {
+    pub fn new(host: String, api_url: Option<String>) -> Self {
+        GiteaHost { host, api_url }
+    }
+
+    pub fn host(&self) -> &str {
+        self.host.as_str()
+    }
+
+    pub fn api_url(&self) -> String {
+        match &self.api_url {
+            Some(api_url) => api_url.trim_end_matches('/').to_string(),
+            None => format!("https://{}/api/v1", self.host),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn derives_api_url_from_host_by_default() {
+        let host = GitHubEnterpriseHost::new("github.corp.example".to_string(), None);
+
+        assert_that(&host.api_url()).is_equal_to("https://github.corp.example/api/v3".to_string());
+    }
+
+    #[test]
+    fn uses_configured_api_url() {
+        let host = GitHubEnterpriseHost::new(
+            "github.corp.example".to_string(),
+            Some("https://api.github.corp.example/".to_string()),
+        );
+
+        assert_that(&host.api_url()).is_equal_to("https://api.github.corp.example".to_string());
+    }
+
+    #[test]
+    fn finds_enterprise_host_ignoring_case() {
+        let configuration: Configuration = serde_yaml::from_str(
+            "github_enterprise_hosts:\n  - host: github.corp.example\n    api_url: https://github.corp.example/api/v3",
+        )
+        .unwrap();
+
+        assert_that(&configuration.github_enterprise_host("GitHub.Corp.Example")).is_some();
+        assert_that(&configuration.github_enterprise_host("github.com")).is_none();
+    }
+
+    #[test]
+    fn has_no_enterprise_hosts_when_empty() {
+        let configuration: Configuration = serde_yaml::from_str("{}").unwrap();
+
+        assert_that(configuration.github_enterprise_hosts()).is_empty();
+    }
+
+    #[test]
+    fn derives_gitea_api_url_from_host_by_default() {
+        let host = GiteaHost::new("gitea.example.com".to_string(), None);
+
+        assert_that(&host.api_url()).is_equal_to("https://gitea.example.com/api/v1".to_string());
+    }
+
+    #[test]
+    fn finds_gitea_host_ignoring_case() {
+        let configuration: Configuration = serde_yaml::from_str(
+            "gitea_hosts:\n  - host: gitea.example.com\n  - host: codeberg.org",
+        )
+        .unwrap();
+
+        assert_that(&configuration.gitea_host("Gitea.Example.com")).is_some();
+        assert_that(&configuration.gitea_host("codeberg.org")).is_some();
+        assert_that(&configuration.gitea_host("github.com")).is_none();
+    }
+}
@@ -1,16 +1,122 @@
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq)]
+/// Tokens keyed by provider and then by host, e.g.
+///
+/// ```yaml
+/// tokens:
+///   github:
+///     github.com: <token>
+///     github.corp.example: <token>
+///   gitlab:
+///     gitlab.com: <token>
+/// ```
+///
+/// Files written by earlier versions, which held a single `github_token` for github.com, are read
+/// into the same shape and written back in it the next time a token is stored.
+#[derive(Debug, Default, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(from = "StoredCredentials")]
 pub struct Credentials {
-    github_token: String,
+    tokens: BTreeMap<String, BTreeMap<String, String>>,
 }
 
 impl Credentials {
-    pub fn new(github_token: String) -> Self {
-        Credentials { github_token }
+    pub fn token(&self, provider: &str, host: &str) -> Option<&str> {
+        self.tokens
+            .get(provider)
+            .and_then(|tokens| tokens.get(&host.to_lowercase()))
+            .map(String::as_str)
+    }
+
+    pub fn with_token(mut self, provider: &str, host: &str, token: String) -> Self {
+        self.tokens
+            .entry(provider.to_string())
+            .or_default()
+            .insert(host.to_lowercase(), token);
+        self
+    }
+}
+
+#[derive(Deserialize)]
+struct StoredCredentials {
+    #[serde(default)]
+    tokens: BTreeMap<String, BTreeMap<String, String>>,
+    #[serde(default)]
+    github_token: Option<String>,
+    #[serde(default)]
+    github_enterprise_tokens: BTreeMap<String, String>,
+}
+
+impl From<StoredCredentials> for Credentials {
+    fn from(stored: StoredCredentials) -> Self {
+        let mut credentials = Credentials {
+            tokens: stored.tokens,
+        };
+        for (host, token) in stored.github_enterprise_tokens {
+            if credentials.token("github", &host).is_none() {
+                credentials = credentials.with_token("github", &host, token);
+            }
+        }
+        match stored.github_token {
+            Some(github_token) if credentials.token("github", "github.com").is_none() => {
+                credentials.with_token("github", "github.com", github_token)
+            }
+            _ => credentials,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn keeps_tokens_of_other_hosts_when_adding_token() {
+        let credentials = Credentials::default()
+            .with_token("github", "github.com", "public".to_string())
+            .with_token("github", "github.corp.example", "enterprise".to_string())
+            .with_token("gitlab", "gitlab.com", "gitlab".to_string());
+
+        assert_that(&credentials.token("github", "github.com")).is_equal_to(Some("public"));
+        assert_that(&credentials.token("github", "GitHub.Corp.Example"))
+            .is_equal_to(Some("enterprise"));
+        assert_that(&credentials.token("gitlab", "gitlab.com")).is_equal_to(Some("gitlab"));
+        assert_that(&credentials.token("gitlab", "github.com")).is_none();
+    }
+
+    #[test]
+    fn migrates_single_github_token() {
+        let credentials: Credentials = serde_yaml::from_str("github_token: public").unwrap();
+
+        assert_that(&credentials).is_equal_to(Credentials::default().with_token(
+            "github",
+            "github.com",
+            "public".to_string(),
+        ));
     }
 
-    pub fn github_token(&self) -> &str {
-        self.github_token.as_str()
+    #[test]
+    fn migrates_github_enterprise_tokens() {
+        let credentials: Credentials =
+            serde_yaml::from_str("github_enterprise_tokens:\n  github.corp.example: enterprise")
+                .unwrap();
+
+        assert_that(&credentials.token("github", "github.corp.example"))
+            .is_equal_to(Some("enterprise"));
+        assert_that(&credentials.token("github", "github.com")).is_none();
+    }
+
+    #[test]
+    fn writes_tokens_keyed_by_provider_and_host() {
+        let credentials: Credentials = serde_yaml::from_str("github_token: public").unwrap();
+
+        let serialized = serde_yaml::to_string(&credentials).unwrap();
+
+        assert_that(&serialized.contains("github_token")).is_false();
+        assert_that(&serde_yaml::from_str::<Credentials>(&serialized).unwrap())
+            .is_equal_to(credentials);
     }
 }
@@ -122,7 +122,8 @@ mod tests {
     async fn storing_credentials_creates_file_when_not_present() {
         let temp_directory = assert_fs::TempDir::new().unwrap();
         let credentials_file_path = temp_directory.child(TEST_STORE_FILE_NAME);
-        let credentials = Credentials::new("token".parse().unwrap());
+        let credentials =
+            Credentials::default().with_token("github", "github.com", "token".to_string());
 
         under_test(credentials_file_path.path().to_path_buf())
             .store(credentials)
@@ -137,7 +138,8 @@ mod tests {
     async fn stores_credentials_as_yaml() {
         let temp_directory = assert_fs::TempDir::new().unwrap();
         let credentials_file_path = temp_directory.child(TEST_STORE_FILE_NAME);
-        let credentials = Credentials::new("token".parse().unwrap());
+        let credentials =
+            Credentials::default().with_token("github", "github.com", "token".to_string());
 
         under_test(credentials_file_path.path().to_path_buf())
             .store(credentials)
@@ -149,7 +151,11 @@ mod tests {
                 .await
                 .unwrap(),
         )
-        .is_equal_to(Credentials::new("token".parse().unwrap()));
+        .is_equal_to(Credentials::default().with_token(
+            "github",
+            "github.com",
+            "token".to_string(),
+        ));
         temp_directory.close().unwrap();
     }
 
@@ -157,7 +163,8 @@ mod tests {
     async fn loads_credentials_from_file() {
         let temp_directory = assert_fs::TempDir::new().unwrap();
         let credentials_file_path = temp_directory.child(TEST_STORE_FILE_NAME);
-        let credentials = Credentials::new("token".parse().unwrap());
+        let credentials =
+            Credentials::default().with_token("github", "github.com", "token".to_string());
         write_credentials_to_file(credentials_file_path.path(), credentials).await;
 
         assert_that(
@@ -166,7 +173,11 @@ mod tests {
                 .await
                 .unwrap(),
         )
-        .is_equal_to(Credentials::new("token".parse().unwrap()));
+        .is_equal_to(Credentials::default().with_token(
+            "github",
+            "github.com",
+            "token".to_string(),
+        ));
         temp_directory.close().unwrap();
     }
 
@@ -179,8 +190,11 @@ mod tests {
             .get()
             .await;
 
-        assert_that(&matches!(result.err().unwrap(), FileSystemPersistenceError::IO {..}))
-            .is_true();
+        assert_that(&matches!(
+            result.err().unwrap(),
+            FileSystemPersistenceError::IO { .. }
+        ))
+        .is_true();
         temp_directory.close().unwrap();
     }
 }
@@ -1,9 +1,9 @@
 use futures::io::ErrorKind;
 
 use crate::domain::authentication::credential_repository::{
-    CredentialRepository, CredentialRepositoryError,
+    CredentialKey, CredentialRepository, CredentialRepositoryError, HostingProvider,
 };
-use crate::domain::authentication::GitHubAuthenticationToken;
+use crate::domain::authentication::AuthenticationToken;
 use crate::ports::persistence::filesystem::{ContentStore, FileSystemPersistenceError};
 use crate::ports::persistence::Credentials;
 
@@ -22,6 +22,19 @@ where
     pub fn new(content_store: S) -> Self {
         FilesystemCredentialRepositoryAdapter { content_store }
     }
+
+    async fn stored_credentials(&self) -> Result<Credentials, CredentialRepositoryError> {
+        match self.content_store.get().await {
+            Ok(credentials) => Ok(credentials),
+            Err(FileSystemPersistenceError::IO(e)) if e.kind() == ErrorKind::NotFound => {
+                Ok(Credentials::default())
+            }
+            Err(FileSystemPersistenceError::Serialization(_)) => {
+                Err(CredentialRepositoryError::CorruptData)
+            }
+            Err(e) => Err(map_filesystem_error_when_storing(e)),
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -31,23 +44,42 @@ where
 {
     async fn store(
         &self,
-        credentials: GitHubAuthenticationToken,
+        key: &CredentialKey,
+        credentials: AuthenticationToken,
     ) -> Result<(), CredentialRepositoryError> {
-        let credentials_at_rest = Credentials::new(credentials.value());
+        let credentials_at_rest = self.stored_credentials().await?.with_token(
+            provider_name(key.provider()),
+            key.host(),
+            credentials.value(),
+        );
         self.content_store
             .store(credentials_at_rest)
             .await
             .map_err(map_filesystem_error_when_storing)
     }
 
-    async fn get(&self) -> Result<GitHubAuthenticationToken, CredentialRepositoryError> {
-        self.content_store
+    async fn get(
+        &self,
+        key: &CredentialKey,
+    ) -> Result<AuthenticationToken, CredentialRepositoryError> {
+        let credentials_at_rest = self
+            .content_store
             .get()
             .await
-            .map_err(map_filesystem_error_when_getting)
-            .map(|credentials_at_rest| {
-                GitHubAuthenticationToken::new(credentials_at_rest.github_token().to_string())
-            })
+            .map_err(map_filesystem_error_when_getting)?;
+        credentials_at_rest
+            .token(provider_name(key.provider()), key.host())
+            .map(|token| AuthenticationToken::new(token.to_string()))
+            .ok_or(CredentialRepositoryError::CredentialDoesNotExist)
+    }
+}
+
+fn provider_name(provider: HostingProvider) -> &'static str {
+    match provider {
+        HostingProvider::GitHub => "github",
+        HostingProvider::GitLab => "gitlab",
+        HostingProvider::Bitbucket => "bitbucket",
+        HostingProvider::Gitea => "gitea",
     }
 }
 
@@ -85,7 +117,7 @@ mod tests {
     use mockall::predicate::eq;
     use spectral::prelude::*;
 
-    use crate::domain::authentication::GitHubAuthenticationToken;
+    use crate::domain::authentication::AuthenticationToken;
     use crate::ports::persistence::filesystem::MockContentStore;
     use crate::ports::persistence::Credentials;
 
@@ -97,37 +129,122 @@ mod tests {
         FilesystemCredentialRepositoryAdapter::new(content_store)
     }
 
-    #[derive(Debug, Eq, PartialEq)]
-    struct TestAuthenticationCredentials {
-        value: String,
+    fn not_found() -> FileSystemPersistenceError {
+        FileSystemPersistenceError::IO(std::io::Error::from(ErrorKind::NotFound))
     }
 
     #[async_std::test]
     async fn persists_credentials() {
         let mut mock_content_store = MockContentStore::default();
+        mock_content_store
+            .expect_get()
+            .returning(|| Err(not_found()));
         mock_content_store
             .expect_store()
             .times(1)
-            .with(eq(Credentials::new("credentials".parse().unwrap())))
+            .with(eq(Credentials::default().with_token(
+                "github",
+                "github.com",
+                "credentials".to_string(),
+            )))
             .returning(|_| Ok(()));
 
         assert_that(
             &under_test(mock_content_store)
-                .store(GitHubAuthenticationToken::new("credentials".to_string()))
+                .store(
+                    &CredentialKey::github("github.com"),
+                    AuthenticationToken::new("credentials".to_string()),
+                )
                 .await,
         )
         .is_ok();
     }
 
     #[async_std::test]
-    async fn returns_persisted_credentials() {
+    async fn keeps_credentials_of_other_hosts_when_persisting_credentials() {
         let mut mock_content_store = MockContentStore::default();
+        mock_content_store.expect_get().returning(|| {
+            Ok(Credentials::default()
+                .with_token("github", "github.com", "public".to_string())
+                .with_token("github", "github.corp.example", "old".to_string()))
+        });
         mock_content_store
-            .expect_get()
+            .expect_store()
             .times(1)
-            .returning(|| Ok(Credentials::new("credentials".to_string())));
+            .with(eq(Credentials::default()
+                .with_token("github", "github.com", "public".to_string())
+                .with_token("github", "github.corp.example", "old".to_string())
+                .with_token("gitlab", "gitlab.com", "new".to_string())))
+            .returning(|_| Ok(()));
+
+        assert_that(
+            &under_test(mock_content_store)
+                .store(
+                    &CredentialKey::gitlab("gitlab.com"),
+                    AuthenticationToken::new("new".to_string()),
+                )
+                .await,
+        )
+        .is_ok();
+    }
 
-        assert_that(&under_test(mock_content_store).get().await.unwrap())
-            .is_equal_to(&GitHubAuthenticationToken::new("credentials".to_string()));
+    #[async_std::test]
+    async fn leaves_corrupt_credentials_in_place_when_persisting_credentials() {
+        let mut mock_content_store = MockContentStore::default();
+        mock_content_store.expect_get().returning(|| {
+            Err(FileSystemPersistenceError::Serialization(
+                serde_yaml::from_str::<Credentials>("tokens: [").unwrap_err(),
+            ))
+        });
+        mock_content_store.expect_store().times(0);
+
+        let result = under_test(mock_content_store)
+            .store(
+                &CredentialKey::gitlab("gitlab.com"),
+                AuthenticationToken::new("new".to_string()),
+            )
+            .await;
+
+        assert_that(&matches!(
+            result.err().unwrap(),
+            CredentialRepositoryError::CorruptData
+        ))
+        .is_true();
+    }
+
+    #[async_std::test]
+    async fn returns_persisted_credentials_of_host() {
+        let mut mock_content_store = MockContentStore::default();
+        mock_content_store.expect_get().returning(|| {
+            Ok(Credentials::default()
+                .with_token("github", "github.com", "public".to_string())
+                .with_token("github", "github.corp.example", "enterprise".to_string()))
+        });
+
+        assert_that(
+            &under_test(mock_content_store)
+                .get(&CredentialKey::github("github.corp.example"))
+                .await
+                .unwrap(),
+        )
+        .is_equal_to(AuthenticationToken::new("enterprise".to_string()));
+    }
+
+    #[async_std::test]
+    async fn fails_when_no_credentials_are_persisted_for_host() {
+        let mut mock_content_store = MockContentStore::default();
+        mock_content_store.expect_get().returning(|| {
+            Ok(Credentials::default().with_token("github", "gitlab.com", "github".to_string()))
+        });
+
+        let result = under_test(mock_content_store)
+            .get(&CredentialKey::gitlab("gitlab.com"))
+            .await;
+
+        assert_that(&matches!(
+            result.err().unwrap(),
+            CredentialRepositoryError::CredentialDoesNotExist
+        ))
+        .is_true();
     }
 }
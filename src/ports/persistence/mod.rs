@@ -1,4 +1,6 @@
+pub use configuration::{Configuration, GitHubEnterpriseHost, GiteaHost};
 pub use credentials::Credentials;
 
+mod configuration;
 mod credentials;
 pub mod filesystem;
@@ -0,0 +1,57 @@
+/// A Bitbucket Cloud username together with one of its app passwords.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct BitbucketAppPassword {
+    username: String,
+    app_password: String,
+}
+
+impl BitbucketAppPassword {
+    pub fn new(username: String, app_password: String) -> Self {
+        BitbucketAppPassword {
+            username,
+            app_password,
+        }
+    }
+
+    /// Reads credentials stored as `<username>:<app password>`.
+    pub fn parse(credentials: &str) -> Option<Self> {
+        match credentials.split_once(':') {
+            Some((username, app_password)) if !username.is_empty() && !app_password.is_empty() => {
+                Some(Self::new(username.to_string(), app_password.to_string()))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn username(&self) -> &str {
+        &self.username
+    }
+
+    pub fn app_password(&self) -> &str {
+        &self.app_password
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn parses_username_and_app_password() {
+        assert_that(&BitbucketAppPassword::parse("user:pass:word").unwrap()).is_equal_to(
+            BitbucketAppPassword::new("user".to_string(), "pass:word".to_string()),
+        );
+    }
+
+    #[test]
+    fn fails_to_parse_credentials_without_username() {
+        assert_that(&BitbucketAppPassword::parse(":password")).is_none();
+    }
+
+    #[test]
+    fn fails_to_parse_credentials_without_separator() {
+        assert_that(&BitbucketAppPassword::parse("password")).is_none();
+    }
+}
@@ -0,0 +1,139 @@
+use crate::domain::authentication::{
+    AuthenticationToken, AuthenticationValidity, RepositoryCredentialsValidationError,
+    RepositoryCredentialsValidator,
+};
+use crate::ports::repository_hosting::bitbucket::app_password::BitbucketAppPassword;
+use crate::ports::repository_hosting::bitbucket::error::BitbucketClientError;
+use crate::ports::repository_hosting::{AuthenticationCredentialValidity, RepositoryHostClient};
+
+pub struct BitbucketCredentialsValidatorAdapter<GC>
+where
+    GC: RepositoryHostClient<
+        Err = BitbucketClientError,
+        AuthenticationCredentials = BitbucketAppPassword,
+    >,
+{
+    bitbucket_client: GC,
+}
+
+impl<GC> BitbucketCredentialsValidatorAdapter<GC>
+where
+    GC: RepositoryHostClient<
+        Err = BitbucketClientError,
+        AuthenticationCredentials = BitbucketAppPassword,
+    >,
+{
+    pub fn new(bitbucket_client: GC) -> Self {
+        BitbucketCredentialsValidatorAdapter { bitbucket_client }
+    }
+}
+
+#[async_trait::async_trait]
+impl<GC> RepositoryCredentialsValidator for BitbucketCredentialsValidatorAdapter<GC>
+where
+    GC: RepositoryHostClient<
+            Err = BitbucketClientError,
+            AuthenticationCredentials = BitbucketAppPassword,
+        > + Send
+        + Sync,
+{
+    /// Expects the credentials as `<username>:<app password>`; anything else is invalid.
+    async fn validate(
+        &self,
+        credentials: AuthenticationToken,
+    ) -> Result<AuthenticationValidity, RepositoryCredentialsValidationError> {
+        let app_password = match BitbucketAppPassword::parse(credentials.value().as_str()) {
+            Some(app_password) => app_password,
+            None => return Ok(AuthenticationValidity::Invalid),
+        };
+        let validity = match self
+            .bitbucket_client
+            .validate_authentication_credentials(app_password)
+            .await
+            .map_err(|_| RepositoryCredentialsValidationError::FailedToValidate)?
+        {
+            AuthenticationCredentialValidity::Valid => AuthenticationValidity::Valid,
+            AuthenticationCredentialValidity::Invalid => AuthenticationValidity::Invalid,
+        };
+        Ok(validity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::predicate::eq;
+    use spectral::prelude::*;
+
+    use crate::ports::repository_hosting::MockRepositoryHostClient;
+
+    use super::*;
+
+    type MockRepositoryHostClientAlias =
+        MockRepositoryHostClient<BitbucketClientError, BitbucketAppPassword>;
+
+    fn under_test(
+        bitbucket_client: MockRepositoryHostClientAlias,
+    ) -> BitbucketCredentialsValidatorAdapter<MockRepositoryHostClientAlias> {
+        BitbucketCredentialsValidatorAdapter::new(bitbucket_client)
+    }
+
+    fn prepare_mock_client_validate_authentication_credentials(
+        mock_repository_host: &mut MockRepositoryHostClientAlias,
+        validity: AuthenticationCredentialValidity,
+    ) {
+        mock_repository_host
+            .expect_validate_authentication_credentials()
+            .with(eq(BitbucketAppPassword::new(
+                "user".to_string(),
+                "app-password".to_string(),
+            )))
+            .returning(move |_| Ok(validity));
+    }
+
+    #[async_std::test]
+    async fn validates_valid_app_password() {
+        let mut mock_bitbucket_client = MockRepositoryHostClientAlias::default();
+        prepare_mock_client_validate_authentication_credentials(
+            &mut mock_bitbucket_client,
+            AuthenticationCredentialValidity::Valid,
+        );
+
+        let validity = under_test(mock_bitbucket_client)
+            .validate(AuthenticationToken::new("user:app-password".to_string()))
+            .await
+            .unwrap();
+
+        assert_that(&matches!(validity, AuthenticationValidity::Valid)).is_equal_to(true);
+    }
+
+    #[async_std::test]
+    async fn validates_invalid_app_password() {
+        let mut mock_bitbucket_client = MockRepositoryHostClientAlias::default();
+        prepare_mock_client_validate_authentication_credentials(
+            &mut mock_bitbucket_client,
+            AuthenticationCredentialValidity::Invalid,
+        );
+
+        let validity = under_test(mock_bitbucket_client)
+            .validate(AuthenticationToken::new("user:app-password".to_string()))
+            .await
+            .unwrap();
+
+        assert_that(&matches!(validity, AuthenticationValidity::Invalid)).is_equal_to(true);
+    }
+
+    #[async_std::test]
+    async fn rejects_credentials_without_username_without_asking_bitbucket() {
+        let mut mock_bitbucket_client = MockRepositoryHostClientAlias::default();
+        mock_bitbucket_client
+            .expect_validate_authentication_credentials()
+            .never();
+
+        let validity = under_test(mock_bitbucket_client)
+            .validate(AuthenticationToken::new("app-password".to_string()))
+            .await
+            .unwrap();
+
+        assert_that(&matches!(validity, AuthenticationValidity::Invalid)).is_equal_to(true);
+    }
+}
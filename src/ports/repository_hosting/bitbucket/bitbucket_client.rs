@@ -0,0 +1,803 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use http_types::auth::BasicAuth;
+use http_types::headers::HeaderName;
+use http_types::{Method, StatusCode, Url};
+use serde::de::DeserializeOwned;
+
+use crate::ports::repository_hosting::bitbucket::app_password::BitbucketAppPassword;
+use crate::ports::repository_hosting::bitbucket::error::BitbucketClientError;
+use crate::ports::repository_hosting::bitbucket::parse_repository_url::BitbucketRepositoryUrlParser;
+use crate::ports::repository_hosting::bitbucket::repository::BitbucketRepository;
+use crate::ports::repository_hosting::bitbucket::responses::{
+    Branch as BranchResponse, CommitHash, CommitResponseBody, PageResponseBody,
+    RepositoryResponseBody,
+};
+use crate::ports::repository_hosting::repository::{
+    Branch, BranchComparison, BranchName, Commit, RepositoryUrl,
+};
+use crate::ports::repository_hosting::{
+    AuthenticationCredentialValidity, RepositoryHostClient, ResponseError,
+};
+use crate::utils::environment::EnvironmentReader;
+use crate::utils::http::{HttpClientFacade, Request};
+
+const PAGE_SIZE: u32 = 100;
+const SERVICE: &str = "Bitbucket";
+
+#[derive(Debug)]
+pub struct BitbucketClient<
+    HttpClient: HttpClientFacade,
+    UrlParser: BitbucketRepositoryUrlParser,
+    EnvReader: EnvironmentReader,
+> {
+    http_client: HttpClient,
+    url_parser: UrlParser,
+    environment_reader: EnvReader,
+    app_password: Option<BitbucketAppPassword>,
+}
+
+impl<HttpClient, UrlParser, EnvReader> BitbucketClient<HttpClient, UrlParser, EnvReader>
+where
+    HttpClient: HttpClientFacade,
+    UrlParser: BitbucketRepositoryUrlParser,
+    EnvReader: EnvironmentReader,
+{
+    pub fn new(
+        http_client: HttpClient,
+        url_parser: UrlParser,
+        environment_reader: EnvReader,
+    ) -> Self {
+        BitbucketClient {
+            http_client,
+            url_parser,
+            environment_reader,
+            app_password: None,
+        }
+    }
+
+    fn api_headers(&self) -> HashMap<HeaderName, String> {
+        match &self.app_password {
+            Some(app_password) => Self::basic_auth_headers(app_password),
+            None => HashMap::new(),
+        }
+    }
+
+    fn basic_auth_headers(app_password: &BitbucketAppPassword) -> HashMap<HeaderName, String> {
+        let basic_auth = BasicAuth::new(app_password.username(), app_password.app_password());
+        let mut headers = HashMap::new();
+        headers.insert(basic_auth.name(), basic_auth.value().to_string());
+        headers
+    }
+
+    fn parse_url(url_string: String) -> Result<Url, BitbucketClientError> {
+        match Url::parse(url_string.as_str()) {
+            Ok(url) => Ok(url),
+            Err(err) => Err(BitbucketClientError::ApiUrlParseError(err)),
+        }
+    }
+
+    fn api_base_url(&self) -> String {
+        match self
+            .environment_reader
+            .read("TIDY_REPO_BITBUCKET_API_BASE_URL")
+        {
+            Ok(env_value) => env_value,
+            Err(_) => "https://api.bitbucket.org/2.0".to_string(),
+        }
+    }
+
+    fn api_url(&self, path_segments: &[&str]) -> Result<Url, BitbucketClientError> {
+        let mut url = Self::parse_url(self.api_base_url())?;
+        url.path_segments_mut()
+            .map_err(|_| {
+                BitbucketClientError::ApiUrlParseError(
+                    http_types::url::ParseError::RelativeUrlWithCannotBeABaseBase,
+                )
+            })?
+            .pop_if_empty()
+            .extend(path_segments);
+        Ok(url)
+    }
+
+    fn repository_api_url(
+        &self,
+        repository: &BitbucketRepository,
+        path_segments: &[&str],
+    ) -> Result<Url, BitbucketClientError> {
+        let mut url = self.api_url(&[
+            "repositories",
+            repository.workspace().as_str(),
+            repository.slug().as_str(),
+        ])?;
+        url.path_segments_mut()
+            .map_err(|_| {
+                BitbucketClientError::ApiUrlParseError(
+                    http_types::url::ParseError::RelativeUrlWithCannotBeABaseBase,
+                )
+            })?
+            .extend(path_segments);
+        Ok(url)
+    }
+
+    fn list_branches_api_url(
+        &self,
+        repository: &BitbucketRepository,
+    ) -> Result<Url, BitbucketClientError> {
+        let mut url = self.repository_api_url(repository, &["refs", "branches"])?;
+        url.query_pairs_mut()
+            .append_pair("pagelen", PAGE_SIZE.to_string().as_str());
+        Ok(url)
+    }
+
+    fn list_commits_api_url(
+        &self,
+        repository: &BitbucketRepository,
+        include: &str,
+        exclude: &str,
+    ) -> Result<Url, BitbucketClientError> {
+        let mut url = self.repository_api_url(repository, &["commits"])?;
+        url.query_pairs_mut()
+            .append_pair("include", include)
+            .append_pair("exclude", exclude)
+            .append_pair("pagelen", PAGE_SIZE.to_string().as_str());
+        Ok(url)
+    }
+
+    /// Collects the values of every page, following the `next` link of each page.
+    async fn get_all_pages<T: DeserializeOwned + Send>(
+        &self,
+        repository_url: &RepositoryUrl,
+        first_page_url: Url,
+    ) -> Result<Vec<T>, BitbucketClientError> {
+        let mut values = Vec::new();
+        let mut next_page_url = Some(first_page_url);
+
+        while let Some(page_url) = next_page_url {
+            let response = self
+                .http_client
+                .send(Request::new(Method::Get, page_url, self.api_headers()))
+                .await?;
+
+            if response.status_code() != StatusCode::Ok {
+                return Err(ResponseError::from_status(
+                    SERVICE,
+                    response.status_code(),
+                    repository_url,
+                )
+                .into());
+            }
+
+            let page = response.body_json::<PageResponseBody<T>>()?;
+            next_page_url = match page.next() {
+                Some(next) => Some(Self::parse_url(next.clone())?),
+                None => None,
+            };
+            values.extend(page.into_values());
+        }
+
+        Ok(values)
+    }
+
+    async fn count_commits_between(
+        &self,
+        repository_url: &RepositoryUrl,
+        repository: &BitbucketRepository,
+        from: &BranchName,
+        to: &BranchName,
+    ) -> Result<u32, BitbucketClientError> {
+        let commits = self
+            .get_all_pages::<CommitHash>(
+                repository_url,
+                self.list_commits_api_url(repository, to.value(), from.value())?,
+            )
+            .await?;
+        Ok(commits.len() as u32)
+    }
+}
+
+#[async_trait]
+impl<HttpClient, UrlParser, EnvReader> RepositoryHostClient
+    for BitbucketClient<HttpClient, UrlParser, EnvReader>
+where
+    HttpClient: HttpClientFacade + Send + Sync,
+    UrlParser: BitbucketRepositoryUrlParser + Send + Sync,
+    EnvReader: EnvironmentReader + Send + Sync,
+{
+    type Err = BitbucketClientError;
+    type AuthenticationCredentials = BitbucketAppPassword;
+
+    async fn list_branches(
+        &self,
+        repository_url: &RepositoryUrl,
+    ) -> Result<Vec<Branch>, Self::Err> {
+        let repository = self.url_parser.parse(repository_url.clone())?;
+        let branches = self
+            .get_all_pages::<BranchResponse>(
+                repository_url,
+                self.list_branches_api_url(&repository)?,
+            )
+            .await?;
+
+        Ok(branches
+            .iter()
+            .map(|branch| {
+                Branch::new(
+                    BranchName::new(branch.name().to_string()),
+                    branch.sha().to_string(),
+                )
+            })
+            .collect())
+    }
+
+    async fn get_commit(
+        &self,
+        repository_url: &RepositoryUrl,
+        sha: &str,
+    ) -> Result<Commit, Self::Err> {
+        let repository = self.url_parser.parse(repository_url.clone())?;
+
+        let response = self
+            .http_client
+            .send(Request::new(
+                Method::Get,
+                self.repository_api_url(&repository, &["commit", sha])?,
+                self.api_headers(),
+            ))
+            .await?;
+
+        match response.status_code() {
+            StatusCode::Ok => {
+                let body = response.body_json::<CommitResponseBody>()?;
+                Ok(Commit::new(
+                    body.sha().to_string(),
+                    *body.date(),
+                    body.author().to_string(),
+                ))
+            }
+            status => Err(ResponseError::from_status(SERVICE, status, repository_url).into()),
+        }
+    }
+
+    async fn get_default_branch(
+        &self,
+        repository_url: &RepositoryUrl,
+    ) -> Result<BranchName, Self::Err> {
+        let repository = self.url_parser.parse(repository_url.clone())?;
+
+        let response = self
+            .http_client
+            .send(Request::new(
+                Method::Get,
+                self.repository_api_url(&repository, &[])?,
+                self.api_headers(),
+            ))
+            .await?;
+
+        match response.status_code() {
+            StatusCode::Ok => Ok(BranchName::new(
+                response
+                    .body_json::<RepositoryResponseBody>()?
+                    .main_branch()
+                    .to_string(),
+            )),
+            status => Err(ResponseError::from_status(SERVICE, status, repository_url).into()),
+        }
+    }
+
+    async fn compare_branches(
+        &self,
+        repository_url: &RepositoryUrl,
+        base: &BranchName,
+        head: &BranchName,
+    ) -> Result<BranchComparison, Self::Err> {
+        let repository = self.url_parser.parse(repository_url.clone())?;
+
+        let ahead_by = self
+            .count_commits_between(repository_url, &repository, base, head)
+            .await?;
+        let behind_by = self
+            .count_commits_between(repository_url, &repository, head, base)
+            .await?;
+
+        Ok(BranchComparison::new(ahead_by, behind_by))
+    }
+
+    async fn delete_branch(
+        &self,
+        repository_url: &RepositoryUrl,
+        branch: &BranchName,
+    ) -> Result<(), Self::Err> {
+        let repository = self.url_parser.parse(repository_url.clone())?;
+
+        let response = self
+            .http_client
+            .send(Request::new(
+                Method::Delete,
+                self.repository_api_url(&repository, &["refs", "branches", branch.value()])?,
+                self.api_headers(),
+            ))
+            .await?;
+
+        match response.status_code() {
+            StatusCode::NoContent => Ok(()),
+            StatusCode::NotFound => {
+                Err(
+                    ResponseError::from_status(SERVICE, StatusCode::NotFound, repository_url)
+                        .into(),
+                )
+            }
+            _ => Err(BitbucketClientError::BranchNotDeleted {
+                repository_url: repository_url.clone(),
+                branch: branch.value().clone(),
+            }),
+        }
+    }
+
+    fn set_authentication_credentials(&mut self, credentials: Self::AuthenticationCredentials) {
+        self.app_password = Some(credentials);
+    }
+
+    async fn validate_authentication_credentials(
+        &self,
+        credentials: Self::AuthenticationCredentials,
+    ) -> Result<AuthenticationCredentialValidity, Self::Err> {
+        let response = self
+            .http_client
+            .send(Request::new(
+                Method::Get,
+                self.api_url(&["user"])?,
+                Self::basic_auth_headers(&credentials),
+            ))
+            .await?;
+
+        match response.status_code() {
+            StatusCode::Ok => Ok(AuthenticationCredentialValidity::Valid),
+            StatusCode::Unauthorized => Ok(AuthenticationCredentialValidity::Invalid),
+            status => Err(ResponseError::Unexpected {
+                service: SERVICE,
+                status: status.into(),
+            }
+            .into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env::VarError;
+
+    use chrono::{TimeZone, Utc};
+    use http_types::StatusCode;
+    use mockall::predicate::eq;
+    use spectral::prelude::*;
+
+    use crate::ports::repository_hosting::bitbucket::MockBitbucketRepositoryUrlParser;
+    use crate::utils::environment::{EnvironmentReaderError, MockEnvironmentReader};
+    use crate::utils::http::{Error, MockHttpClientFacade, Response};
+
+    use super::*;
+
+    type BitbucketClientAlias = BitbucketClient<
+        MockHttpClientFacade,
+        MockBitbucketRepositoryUrlParser,
+        MockEnvironmentReader,
+    >;
+
+    const BRANCHES_URL: &str =
+        "https://api.bitbucket.org/2.0/repositories/workspace/repo/refs/branches?pagelen=100";
+
+    fn branch(name: &str) -> Branch {
+        Branch::new(BranchName::new(name.to_string()), "sha".to_string())
+    }
+
+    fn app_password() -> BitbucketAppPassword {
+        BitbucketAppPassword::new("user".to_string(), "app-password".to_string())
+    }
+
+    fn basic_auth_headers() -> HashMap<HeaderName, String> {
+        let mut headers = HashMap::new();
+        headers.insert(
+            HeaderName::from("authorization"),
+            "Basic dXNlcjphcHAtcGFzc3dvcmQ=".to_string(),
+        );
+        headers
+    }
+
+    fn mock_environment_reader() -> MockEnvironmentReader {
+        let mut reader = MockEnvironmentReader::default();
+        reader
+            .expect_read()
+            .returning(|_| Err(EnvironmentReaderError::ReadError(VarError::NotPresent)));
+        reader
+    }
+
+    fn mock_url_parser() -> MockBitbucketRepositoryUrlParser {
+        let mut mock_url_parser = MockBitbucketRepositoryUrlParser::default();
+        mock_url_parser.expect_parse().returning(|_| {
+            Ok(BitbucketRepository::new(
+                "workspace".to_string(),
+                "repo".to_string(),
+            ))
+        });
+        mock_url_parser
+    }
+
+    async fn wrap_response_in_future(response: Result<Response, Error>) -> Result<Response, Error> {
+        response
+    }
+
+    fn prepare_mock_http_client(
+        mock_http_client: &mut MockHttpClientFacade,
+        request: Request,
+        response: Response,
+    ) {
+        mock_http_client
+            .expect_send()
+            .with(eq(request))
+            .returning(move |_| Box::pin(wrap_response_in_future(Ok(response.clone()))));
+    }
+
+    fn api_request(method: Method, url: &str) -> Request {
+        Request::new(method, Url::parse(url).unwrap(), HashMap::new())
+    }
+
+    fn successful_json_response(body: serde_json::Value) -> Response {
+        Response::new(StatusCode::Ok, HashMap::new(), body.to_string())
+    }
+
+    fn under_test(mock_http_client: MockHttpClientFacade) -> BitbucketClientAlias {
+        BitbucketClient::new(
+            mock_http_client,
+            mock_url_parser(),
+            mock_environment_reader(),
+        )
+    }
+
+    fn repository_url() -> RepositoryUrl {
+        RepositoryUrl::new("https://bitbucket.org/workspace/repo".to_string())
+    }
+
+    #[test]
+    fn app_password_is_absent_by_default() {
+        assert_that(&under_test(MockHttpClientFacade::default()).app_password).is_none();
+    }
+
+    #[async_std::test]
+    async fn lists_branches() {
+        let mut mock_http_client = MockHttpClientFacade::default();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            api_request(Method::Get, BRANCHES_URL),
+            successful_json_response(serde_json::json!(PageResponseBody::new(
+                vec![
+                    BranchResponse::new("main".to_string(), "sha".to_string()),
+                    BranchResponse::new("feature".to_string(), "sha".to_string()),
+                ],
+                None
+            ))),
+        );
+
+        assert_that(
+            &under_test(mock_http_client)
+                .list_branches(&repository_url())
+                .await
+                .unwrap(),
+        )
+        .is_equal_to(vec![branch("main"), branch("feature")]);
+    }
+
+    #[async_std::test]
+    async fn lists_branches_across_all_pages_following_next_links() {
+        let next_page_url = format!("{}&page=2", BRANCHES_URL);
+        let mut mock_http_client = MockHttpClientFacade::default();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            api_request(Method::Get, BRANCHES_URL),
+            successful_json_response(serde_json::json!(PageResponseBody::new(
+                vec![BranchResponse::new(
+                    "branch1".to_string(),
+                    "sha".to_string()
+                )],
+                Some(next_page_url.clone())
+            ))),
+        );
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            api_request(Method::Get, next_page_url.as_str()),
+            successful_json_response(serde_json::json!(PageResponseBody::new(
+                vec![BranchResponse::new(
+                    "branch2".to_string(),
+                    "sha".to_string()
+                )],
+                None
+            ))),
+        );
+
+        assert_that(
+            &under_test(mock_http_client)
+                .list_branches(&repository_url())
+                .await
+                .unwrap(),
+        )
+        .is_equal_to(vec![branch("branch1"), branch("branch2")]);
+    }
+
+    #[async_std::test]
+    async fn lists_branches_with_app_password_set() {
+        let mut mock_http_client = MockHttpClientFacade::default();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            Request::new(
+                Method::Get,
+                Url::parse(BRANCHES_URL).unwrap(),
+                basic_auth_headers(),
+            ),
+            successful_json_response(serde_json::json!(PageResponseBody::new(
+                vec![BranchResponse::new("branch".to_string(), "sha".to_string())],
+                None
+            ))),
+        );
+        let mut under_test = under_test(mock_http_client);
+        under_test.set_authentication_credentials(app_password());
+
+        assert_that(&under_test.list_branches(&repository_url()).await.unwrap())
+            .is_equal_to(vec![branch("branch")]);
+    }
+
+    #[async_std::test]
+    async fn fails_to_list_branches_of_missing_repository() {
+        let mut mock_http_client = MockHttpClientFacade::default();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            api_request(Method::Get, BRANCHES_URL),
+            Response::new(StatusCode::NotFound, HashMap::new(), "".to_string()),
+        );
+
+        let result = under_test(mock_http_client)
+            .list_branches(&repository_url())
+            .await;
+
+        assert_that(&matches!(
+            result,
+            Err(BitbucketClientError::Response(
+                ResponseError::RepositoryNotFound(..)
+            ))
+        ))
+        .is_true();
+    }
+
+    #[async_std::test]
+    async fn fails_to_list_branches_with_rejected_app_password() {
+        let mut mock_http_client = MockHttpClientFacade::default();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            api_request(Method::Get, BRANCHES_URL),
+            Response::new(StatusCode::Unauthorized, HashMap::new(), "".to_string()),
+        );
+
+        let result = under_test(mock_http_client)
+            .list_branches(&repository_url())
+            .await;
+
+        assert_that(&matches!(
+            result,
+            Err(BitbucketClientError::Response(ResponseError::Unauthorized(
+                ..
+            )))
+        ))
+        .is_true();
+    }
+
+    #[async_std::test]
+    async fn fails_to_get_default_branch_when_rate_limited() {
+        let mut mock_http_client = MockHttpClientFacade::default();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            api_request(
+                Method::Get,
+                "https://api.bitbucket.org/2.0/repositories/workspace/repo",
+            ),
+            Response::new(StatusCode::TooManyRequests, HashMap::new(), "".to_string()),
+        );
+
+        let result = under_test(mock_http_client)
+            .get_default_branch(&repository_url())
+            .await;
+
+        assert_that(&matches!(
+            result,
+            Err(BitbucketClientError::Response(ResponseError::RateLimited(
+                ..
+            )))
+        ))
+        .is_true();
+    }
+
+    #[async_std::test]
+    async fn gets_commit_authored_by_bitbucket_user() {
+        let date = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+        let mut mock_http_client = MockHttpClientFacade::default();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            api_request(
+                Method::Get,
+                "https://api.bitbucket.org/2.0/repositories/workspace/repo/commit/sha",
+            ),
+            successful_json_response(serde_json::json!(CommitResponseBody::new(
+                "sha".to_string(),
+                "Author <author@example.com>".to_string(),
+                date
+            )
+            .with_user("Author".to_string()))),
+        );
+
+        assert_that(
+            &under_test(mock_http_client)
+                .get_commit(&repository_url(), "sha")
+                .await
+                .unwrap(),
+        )
+        .is_equal_to(Commit::new("sha".to_string(), date, "Author".to_string()));
+    }
+
+    #[async_std::test]
+    async fn gets_commit_with_raw_author_when_not_linked_to_bitbucket_user() {
+        let date = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+        let mut mock_http_client = MockHttpClientFacade::default();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            api_request(
+                Method::Get,
+                "https://api.bitbucket.org/2.0/repositories/workspace/repo/commit/sha",
+            ),
+            successful_json_response(serde_json::json!(CommitResponseBody::new(
+                "sha".to_string(),
+                "Author <author@example.com>".to_string(),
+                date
+            ))),
+        );
+
+        assert_that(
+            &under_test(mock_http_client)
+                .get_commit(&repository_url(), "sha")
+                .await
+                .unwrap()
+                .author(),
+        )
+        .is_equal_to(&"Author <author@example.com>".to_string());
+    }
+
+    #[async_std::test]
+    async fn gets_main_branch_as_default_branch() {
+        let mut mock_http_client = MockHttpClientFacade::default();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            api_request(
+                Method::Get,
+                "https://api.bitbucket.org/2.0/repositories/workspace/repo",
+            ),
+            successful_json_response(serde_json::json!(RepositoryResponseBody::new(
+                "main".to_string()
+            ))),
+        );
+
+        assert_that(
+            &under_test(mock_http_client)
+                .get_default_branch(&repository_url())
+                .await
+                .unwrap(),
+        )
+        .is_equal_to(BranchName::new("main".to_string()));
+    }
+
+    #[async_std::test]
+    async fn compares_branches() {
+        let mut mock_http_client = MockHttpClientFacade::default();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            api_request(
+                Method::Get,
+                "https://api.bitbucket.org/2.0/repositories/workspace/repo/commits?include=feature&exclude=main&pagelen=100",
+            ),
+            successful_json_response(serde_json::json!(PageResponseBody::new(
+                vec![CommitHash::new("a".to_string())],
+                None
+            ))),
+        );
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            api_request(
+                Method::Get,
+                "https://api.bitbucket.org/2.0/repositories/workspace/repo/commits?include=main&exclude=feature&pagelen=100",
+            ),
+            successful_json_response(serde_json::json!(PageResponseBody::new(
+                vec![
+                    CommitHash::new("b".to_string()),
+                    CommitHash::new("c".to_string())
+                ],
+                None
+            ))),
+        );
+
+        assert_that(
+            &under_test(mock_http_client)
+                .compare_branches(
+                    &repository_url(),
+                    &BranchName::new("main".to_string()),
+                    &BranchName::new("feature".to_string()),
+                )
+                .await
+                .unwrap(),
+        )
+        .is_equal_to(BranchComparison::new(1, 2));
+    }
+
+    #[async_std::test]
+    async fn deletes_branch_with_url_encoded_name() {
+        let mut mock_http_client = MockHttpClientFacade::default();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            api_request(
+                Method::Delete,
+                "https://api.bitbucket.org/2.0/repositories/workspace/repo/refs/branches/feature%2Fdone",
+            ),
+            Response::new(StatusCode::NoContent, HashMap::new(), "".to_string()),
+        );
+
+        assert_that(
+            &under_test(mock_http_client)
+                .delete_branch(
+                    &repository_url(),
+                    &BranchName::new("feature/done".to_string()),
+                )
+                .await,
+        )
+        .is_ok();
+    }
+
+    #[async_std::test]
+    async fn validates_app_password_with_basic_authentication() {
+        let mut mock_http_client = MockHttpClientFacade::default();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            Request::new(
+                Method::Get,
+                Url::parse("https://api.bitbucket.org/2.0/user").unwrap(),
+                basic_auth_headers(),
+            ),
+            Response::new(StatusCode::Ok, HashMap::new(), "{}".to_string()),
+        );
+
+        assert_that(
+            &under_test(mock_http_client)
+                .validate_authentication_credentials(app_password())
+                .await
+                .unwrap(),
+        )
+        .is_equal_to(AuthenticationCredentialValidity::Valid);
+    }
+
+    #[async_std::test]
+    async fn validates_rejected_app_password_as_invalid() {
+        let mut mock_http_client = MockHttpClientFacade::default();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            Request::new(
+                Method::Get,
+                Url::parse("https://api.bitbucket.org/2.0/user").unwrap(),
+                basic_auth_headers(),
+            ),
+            Response::new(StatusCode::Unauthorized, HashMap::new(), "".to_string()),
+        );
+
+        assert_that(
+            &under_test(mock_http_client)
+                .validate_authentication_credentials(app_password())
+                .await
+                .unwrap(),
+        )
+        .is_equal_to(AuthenticationCredentialValidity::Invalid);
+    }
+}
@@ -0,0 +1,438 @@
+use crate::application::DEFAULT_CONCURRENCY;
+use crate::domain::authentication::credential_repository::{CredentialKey, CredentialRepository};
+use crate::domain::repository::{Branch, BranchComparison, RepositoryProviderError};
+use crate::domain::repository::{
+    Repository, RepositoryMaintainer, RepositoryProvider, RepositoryUrl,
+};
+use crate::domain::value_object::ValueObject;
+use crate::ports::repository_hosting::bitbucket::app_password::BitbucketAppPassword;
+use crate::ports::repository_hosting::bitbucket::error::BitbucketClientError;
+use crate::ports::repository_hosting::bitbucket::RepositoryUrlParseError;
+use crate::ports::repository_hosting::repository::{
+    BranchName, RepositoryUrl as RepositoryClientRepositoryUrl,
+};
+use crate::ports::repository_hosting::{repository_branches, RepositoryHostClient, ResponseError};
+
+pub struct BitbucketRepositoryProviderAdapter<GC>
+where
+    GC: RepositoryHostClient<
+        Err = BitbucketClientError,
+        AuthenticationCredentials = BitbucketAppPassword,
+    >,
+{
+    bitbucket_client: GC,
+    concurrency: usize,
+}
+
+impl<GC> BitbucketRepositoryProviderAdapter<GC>
+where
+    GC: RepositoryHostClient<
+        Err = BitbucketClientError,
+        AuthenticationCredentials = BitbucketAppPassword,
+    >,
+{
+    pub fn new(bitbucket_client: GC) -> Self {
+        BitbucketRepositoryProviderAdapter {
+            bitbucket_client,
+            concurrency: DEFAULT_CONCURRENCY,
+        }
+    }
+
+    /// Limits how many head commits of a repository's branches are fetched at the same time.
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Authenticates the client with the app password stored for Bitbucket, if there is one.
+    pub fn with_stored_credentials<CR: CredentialRepository>(
+        mut self,
+        credential_repository: &CR,
+        credential_key: &CredentialKey,
+    ) -> Self {
+        if let Some(app_password) =
+            async_std::task::block_on(credential_repository.get(credential_key))
+                .ok()
+                .and_then(|credentials| BitbucketAppPassword::parse(credentials.value().as_str()))
+        {
+            self.bitbucket_client
+                .set_authentication_credentials(app_password);
+        }
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl<GC> RepositoryProvider for BitbucketRepositoryProviderAdapter<GC>
+where
+    GC: RepositoryHostClient<
+            Err = BitbucketClientError,
+            AuthenticationCredentials = BitbucketAppPassword,
+        > + Sync
+        + Send,
+{
+    async fn get_repository(
+        &self,
+        url: &RepositoryUrl,
+    ) -> Result<Repository, RepositoryProviderError> {
+        let url_dto = RepositoryClientRepositoryUrl::new(url.value().clone());
+        let branches = repository_branches::list_branches(&self.bitbucket_client, &url_dto)
+            .await
+            .map_err(map_repository_client_error(url))?;
+        Ok(Repository::new(url.clone(), branches))
+    }
+
+    async fn get_repository_with_head_commits(
+        &self,
+        url: &RepositoryUrl,
+    ) -> Result<Repository, RepositoryProviderError> {
+        let url_dto = RepositoryClientRepositoryUrl::new(url.value().clone());
+        let branches = repository_branches::list_branches_with_head_commits(
+            &self.bitbucket_client,
+            &url_dto,
+            self.concurrency,
+        )
+        .await
+        .map_err(map_repository_client_error(url))?;
+        Ok(Repository::new(url.clone(), branches))
+    }
+
+    async fn compare_branches(
+        &self,
+        url: &RepositoryUrl,
+        base: &Branch,
+        head: &Branch,
+    ) -> Result<BranchComparison, RepositoryProviderError> {
+        let url_dto = RepositoryClientRepositoryUrl::new(url.value().clone());
+        let comparison = self
+            .bitbucket_client
+            .compare_branches(
+                &url_dto,
+                &BranchName::new(base.value().clone()),
+                &BranchName::new(head.value().clone()),
+            )
+            .await
+            .map_err(map_repository_client_error(url))?;
+        Ok(BranchComparison::new(
+            head.clone(),
+            comparison.ahead_by(),
+            comparison.behind_by(),
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl<GC> RepositoryMaintainer for BitbucketRepositoryProviderAdapter<GC>
+where
+    GC: RepositoryHostClient<
+            Err = BitbucketClientError,
+            AuthenticationCredentials = BitbucketAppPassword,
+        > + Sync
+        + Send,
+{
+    async fn delete_branch(
+        &self,
+        url: &RepositoryUrl,
+        branch: &Branch,
+    ) -> Result<(), RepositoryProviderError> {
+        let url_dto = RepositoryClientRepositoryUrl::new(url.value().clone());
+        self.bitbucket_client
+            .delete_branch(&url_dto, &BranchName::new(branch.value().clone()))
+            .await
+            .map_err(map_repository_client_error(url))
+    }
+}
+
+fn map_repository_client_error(
+    url: &RepositoryUrl,
+) -> impl Fn(BitbucketClientError) -> RepositoryProviderError + '_ {
+    move |client_error| map_client_error(client_error).for_repository(url)
+}
+
+fn map_client_error(client_error: BitbucketClientError) -> RepositoryProviderError {
+    RepositoryProviderError::from(BitbucketRepositoryProviderError::from(client_error))
+}
+
+impl From<BitbucketClientError> for BitbucketRepositoryProviderError {
+    fn from(client_error: BitbucketClientError) -> Self {
+        match client_error {
+            BitbucketClientError::ApiUrlParseError(..)
+            | BitbucketClientError::HttpClientError(..)
+            | BitbucketClientError::BranchNotDeleted { .. }
+            | BitbucketClientError::JsonDeserializationError(..) => {
+                BitbucketRepositoryProviderError::BitbucketClient(client_error)
+            }
+            BitbucketClientError::Response(response_error) => {
+                BitbucketRepositoryProviderError::Response(response_error)
+            }
+            BitbucketClientError::RepositoryUrlParseError(parse_error) => {
+                BitbucketRepositoryProviderError::InvalidUrl(parse_error)
+            }
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum BitbucketRepositoryProviderError {
+    #[error("Bitbucket client error occurred ({0})")]
+    BitbucketClient(BitbucketClientError),
+    #[error(transparent)]
+    InvalidUrl(RepositoryUrlParseError),
+    #[error(transparent)]
+    Response(ResponseError),
+}
+
+impl From<BitbucketRepositoryProviderError> for RepositoryProviderError {
+    fn from(port_error: BitbucketRepositoryProviderError) -> Self {
+        let repository = None;
+        let message = port_error.to_string();
+        match port_error {
+            BitbucketRepositoryProviderError::Response(response_error) => response_error.into(),
+            BitbucketRepositoryProviderError::InvalidUrl(..) => {
+                RepositoryProviderError::InvalidUrl {
+                    repository,
+                    message,
+                }
+            }
+            BitbucketRepositoryProviderError::BitbucketClient(
+                BitbucketClientError::HttpClientError(..),
+            ) => RepositoryProviderError::Transport {
+                repository,
+                message,
+            },
+            BitbucketRepositoryProviderError::BitbucketClient(..) => {
+                RepositoryProviderError::Other {
+                    repository,
+                    message,
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use mockall::predicate::eq;
+    use spectral::prelude::*;
+
+    use crate::domain::authentication::credential_repository::{
+        CredentialRepositoryError, MockCredentialRepository,
+    };
+    use crate::domain::authentication::AuthenticationToken;
+    use crate::domain::repository::Commit;
+    use crate::ports::repository_hosting::repository::{
+        Branch as BranchDto, BranchComparison as BranchComparisonDto, Commit as CommitDto,
+    };
+    use crate::ports::repository_hosting::MockRepositoryHostClient;
+    use crate::utils::test_helpers::async_this;
+
+    use super::*;
+
+    type MockRepositoryHostClientAlias =
+        MockRepositoryHostClient<BitbucketClientError, BitbucketAppPassword>;
+
+    fn under_test(
+        repository_host_client: MockRepositoryHostClientAlias,
+    ) -> BitbucketRepositoryProviderAdapter<MockRepositoryHostClientAlias> {
+        BitbucketRepositoryProviderAdapter::new(repository_host_client)
+    }
+
+    fn url_dto() -> RepositoryClientRepositoryUrl {
+        RepositoryClientRepositoryUrl::new("url".to_string())
+    }
+
+    fn prepare_mock_client_for_project(
+        mock_repository_host: &mut MockRepositoryHostClientAlias,
+        branches: Vec<BranchDto>,
+        default_branch: &'static str,
+    ) {
+        mock_repository_host
+            .expect_list_branches()
+            .with(eq(url_dto()))
+            .returning(move |_| Ok(branches.clone()));
+        mock_repository_host
+            .expect_get_default_branch()
+            .with(eq(url_dto()))
+            .returning(move |_| Ok(BranchName::new(default_branch.to_string())));
+    }
+
+    #[async_std::test]
+    async fn gets_repository_with_default_branch_marked() {
+        let mut mock_repository_host_client = MockRepositoryHostClient::default();
+        prepare_mock_client_for_project(
+            &mut mock_repository_host_client,
+            vec![
+                BranchDto::new(BranchName::new("main".to_string()), "sha".to_string()),
+                BranchDto::new(BranchName::new("feature".to_string()), "sha".to_string()),
+            ],
+            "main",
+        );
+
+        assert_that(
+            &under_test(mock_repository_host_client)
+                .get_repository(&RepositoryUrl::new("url".to_string()))
+                .await
+                .unwrap(),
+        )
+        .is_equal_to(Repository::new(
+            RepositoryUrl::new("url".to_string()),
+            vec![
+                Branch::new("main".to_string()).marked_default(true),
+                Branch::new("feature".to_string()),
+            ],
+        ));
+    }
+
+    #[async_std::test]
+    async fn gets_repository_with_head_commits() {
+        let date = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+        let mut mock_repository_host_client = MockRepositoryHostClient::default();
+        prepare_mock_client_for_project(
+            &mut mock_repository_host_client,
+            vec![BranchDto::new(
+                BranchName::new("feature".to_string()),
+                "sha".to_string(),
+            )],
+            "main",
+        );
+        mock_repository_host_client
+            .expect_get_commit()
+            .with(eq(url_dto()), eq("sha"))
+            .returning(move |_, _| {
+                Ok(CommitDto::new(
+                    "sha".to_string(),
+                    date,
+                    "author".to_string(),
+                ))
+            });
+
+        assert_that(
+            &under_test(mock_repository_host_client)
+                .get_repository_with_head_commits(&RepositoryUrl::new("url".to_string()))
+                .await
+                .unwrap(),
+        )
+        .is_equal_to(Repository::new(
+            RepositoryUrl::new("url".to_string()),
+            vec![Branch::with_head_commit(
+                "feature".to_string(),
+                Commit::new("sha".to_string(), date, "author".to_string()),
+            )],
+        ));
+    }
+
+    #[async_std::test]
+    async fn compares_branches() {
+        let mut mock_repository_host_client = MockRepositoryHostClient::default();
+        mock_repository_host_client
+            .expect_compare_branches()
+            .with(
+                eq(url_dto()),
+                eq(BranchName::new("main".to_string())),
+                eq(BranchName::new("feature".to_string())),
+            )
+            .returning(|_, _, _| Ok(BranchComparisonDto::new(0, 2)));
+
+        assert_that(
+            &under_test(mock_repository_host_client)
+                .compare_branches(
+                    &RepositoryUrl::new("url".to_string()),
+                    &Branch::new("main".to_string()),
+                    &Branch::new("feature".to_string()),
+                )
+                .await
+                .unwrap(),
+        )
+        .is_equal_to(BranchComparison::new(
+            Branch::new("feature".to_string()),
+            0,
+            2,
+        ));
+    }
+
+    #[async_std::test]
+    async fn deletes_branch() {
+        let mut mock_repository_host_client = MockRepositoryHostClient::default();
+        mock_repository_host_client
+            .expect_delete_branch()
+            .with(eq(url_dto()), eq(BranchName::new("feature".to_string())))
+            .once()
+            .returning(|_, _| Ok(()));
+
+        assert_that(
+            &under_test(mock_repository_host_client)
+                .delete_branch(
+                    &RepositoryUrl::new("url".to_string()),
+                    &Branch::new("feature".to_string()),
+                )
+                .await,
+        )
+        .is_ok();
+    }
+
+    #[async_std::test]
+    async fn fails_to_get_repository_that_does_not_exist() {
+        let mut mock_repository_host_client = MockRepositoryHostClient::default();
+        mock_repository_host_client
+            .expect_list_branches()
+            .returning(|url| {
+                Err(BitbucketClientError::Response(
+                    ResponseError::RepositoryNotFound(url.clone()),
+                ))
+            });
+
+        let result = under_test(mock_repository_host_client)
+            .get_repository(&RepositoryUrl::new("url".to_string()))
+            .await;
+
+        assert_that(&result.err().unwrap().to_string())
+            .is_equal_to("repository 'url' not found".to_string());
+    }
+
+    #[test]
+    fn authenticates_client_with_stored_app_password() {
+        let mut mock_repository_host_client = MockRepositoryHostClientAlias::default();
+        mock_repository_host_client
+            .expect_set_authentication_credentials()
+            .with(eq(BitbucketAppPassword::new(
+                "user".to_string(),
+                "app-password".to_string(),
+            )))
+            .once()
+            .return_const(());
+        let mut mock_credential_repository = MockCredentialRepository::default();
+        mock_credential_repository
+            .expect_get()
+            .with(eq(CredentialKey::bitbucket("bitbucket.org")))
+            .returning(|_| {
+                Box::pin(async_this(Ok(AuthenticationToken::new(
+                    "user:app-password".to_string(),
+                ))))
+            });
+
+        under_test(mock_repository_host_client).with_stored_credentials(
+            &mock_credential_repository,
+            &CredentialKey::bitbucket("bitbucket.org"),
+        );
+    }
+
+    #[test]
+    fn leaves_client_unauthenticated_without_stored_credentials() {
+        let mut mock_repository_host_client = MockRepositoryHostClientAlias::default();
+        mock_repository_host_client
+            .expect_set_authentication_credentials()
+            .never();
+        let mut mock_credential_repository = MockCredentialRepository::default();
+        mock_credential_repository.expect_get().returning(|_| {
+            Box::pin(async_this(Err(
+                CredentialRepositoryError::CredentialDoesNotExist,
+            )))
+        });
+
+        under_test(mock_repository_host_client).with_stored_credentials(
+            &mock_credential_repository,
+            &CredentialKey::bitbucket("bitbucket.org"),
+        );
+    }
+}
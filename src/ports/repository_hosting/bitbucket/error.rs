@@ -0,0 +1,22 @@
+use crate::ports::repository_hosting::bitbucket::parse_repository_url::RepositoryUrlParseError;
+use crate::ports::repository_hosting::repository::RepositoryUrl;
+use crate::ports::repository_hosting::ResponseError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum BitbucketClientError {
+    #[error(transparent)]
+    RepositoryUrlParseError(#[from] RepositoryUrlParseError),
+    #[error(transparent)]
+    HttpClientError(#[from] crate::utils::http::Error),
+    #[error("JSON deserialization error: {0}")]
+    JsonDeserializationError(#[from] serde_json::Error),
+    #[error(transparent)]
+    ApiUrlParseError(http_types::url::ParseError),
+    #[error(transparent)]
+    Response(#[from] ResponseError),
+    #[error("branch '{branch}' in repository '{repository_url}' could not be deleted")]
+    BranchNotDeleted {
+        repository_url: RepositoryUrl,
+        branch: String,
+    },
+}
@@ -0,0 +1,13 @@
+pub use bitbucket_authentication_validation_adapter::*;
+pub use bitbucket_client::*;
+pub use bitbucket_repository_provider_adapter::*;
+pub use parse_repository_url::*;
+
+mod app_password;
+mod bitbucket_authentication_validation_adapter;
+mod bitbucket_client;
+mod bitbucket_repository_provider_adapter;
+mod error;
+mod parse_repository_url;
+mod repository;
+mod responses;
@@ -0,0 +1,99 @@
+use regex::Regex;
+
+use crate::ports::repository_hosting::bitbucket::repository::BitbucketRepository;
+use crate::ports::repository_hosting::repository::RepositoryUrl;
+
+#[cfg_attr(test, mockall::automock)]
+pub trait BitbucketRepositoryUrlParser {
+    fn parse(&self, url: RepositoryUrl) -> Result<BitbucketRepository, RepositoryUrlParseError>;
+}
+
+#[derive(Debug, Default)]
+pub struct BitbucketRepositoryUrlParserImpl;
+
+impl BitbucketRepositoryUrlParserImpl {
+    pub fn new() -> Self {
+        BitbucketRepositoryUrlParserImpl
+    }
+}
+
+impl BitbucketRepositoryUrlParser for BitbucketRepositoryUrlParserImpl {
+    fn parse(&self, url: RepositoryUrl) -> Result<BitbucketRepository, RepositoryUrlParseError> {
+        let re = Regex::new(
+            r"^(?:(?:(?:https?|ssh|git)://(?:[^@/\s]+@)?)?(?i:bitbucket\.org)(?::\d+)?/|[^@/\s]+@(?i:bitbucket\.org):)(?P<workspace>[^/\s]+)/(?P<slug>[^/\s]+)$",
+        )
+        .unwrap();
+
+        if let Some(captures) = re.captures(url.without_clone_suffix()) {
+            if let Some(workspace) = captures.name("workspace") {
+                if let Some(slug) = captures.name("slug") {
+                    return Ok(BitbucketRepository::new(
+                        workspace.as_str().to_string(),
+                        slug.as_str().to_string(),
+                    ));
+                }
+            }
+        }
+
+        Err(RepositoryUrlParseError(url.value().clone()))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("failed to parse Bitbucket repository from '{0}'")]
+pub struct RepositoryUrlParseError(String);
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    fn parse(url: &str) -> Result<BitbucketRepository, RepositoryUrlParseError> {
+        BitbucketRepositoryUrlParserImpl::new().parse(RepositoryUrl::new(url.to_string()))
+    }
+
+    fn repository(workspace: &str, slug: &str) -> BitbucketRepository {
+        BitbucketRepository::new(workspace.to_string(), slug.to_string())
+    }
+
+    #[test]
+    fn parses_bitbucket_repository_url() {
+        assert_that(&parse("https://bitbucket.org/workspace/repo").unwrap())
+            .is_equal_to(repository("workspace", "repo"));
+    }
+
+    #[test]
+    fn parses_bitbucket_repository_url_without_scheme() {
+        assert_that(&parse("bitbucket.org/workspace/repo").unwrap())
+            .is_equal_to(repository("workspace", "repo"));
+    }
+
+    #[test]
+    fn parses_https_clone_url_with_username() {
+        assert_that(&parse("https://user@bitbucket.org/workspace/repo.git").unwrap())
+            .is_equal_to(repository("workspace", "repo"));
+    }
+
+    #[test]
+    fn parses_scp_like_ssh_clone_url() {
+        assert_that(&parse("git@bitbucket.org:workspace/repo.git").unwrap())
+            .is_equal_to(repository("workspace", "repo"));
+    }
+
+    #[test]
+    fn parses_ssh_clone_url() {
+        assert_that(&parse("ssh://git@bitbucket.org/workspace/repo.git").unwrap())
+            .is_equal_to(repository("workspace", "repo"));
+    }
+
+    #[test]
+    fn fails_to_parse_repository_url_missing_repo() {
+        assert_that(&parse("https://bitbucket.org/workspace").is_err()).is_true();
+    }
+
+    #[test]
+    fn fails_to_parse_repository_url_of_other_host() {
+        assert_that(&parse("https://github.com/owner/repo").is_err()).is_true();
+    }
+}
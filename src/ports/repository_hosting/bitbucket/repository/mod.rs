@@ -0,0 +1,38 @@
+#[derive(Debug, Eq, PartialEq)]
+pub struct BitbucketRepository {
+    workspace: String,
+    slug: String,
+}
+
+impl BitbucketRepository {
+    pub fn new(workspace: String, slug: String) -> Self {
+        BitbucketRepository { workspace, slug }
+    }
+
+    pub fn workspace(&self) -> &String {
+        &self.workspace
+    }
+
+    pub fn slug(&self) -> &String {
+        &self.slug
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn returns_workspace() {
+        let under_test = BitbucketRepository::new("workspace".to_string(), "repo".to_string());
+        assert_that(&under_test.workspace()).is_equal_to(&"workspace".to_string());
+    }
+
+    #[test]
+    fn returns_slug() {
+        let under_test = BitbucketRepository::new("workspace".to_string(), "repo".to_string());
+        assert_that(&under_test.slug()).is_equal_to(&"repo".to_string());
+    }
+}
@@ -0,0 +1,157 @@
+pub use branch::*;
+pub use commit::*;
+pub use page::*;
+pub use repository::*;
+
+mod page {
+    /// A page of a paginated collection; `next` links to the following page, if there is one.
+    #[derive(Debug, serde::Serialize, serde::Deserialize)]
+    pub struct PageResponseBody<T> {
+        values: Vec<T>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        next: Option<String>,
+    }
+
+    impl<T> PageResponseBody<T> {
+        #[cfg(test)]
+        pub fn new(values: Vec<T>, next: Option<String>) -> Self {
+            PageResponseBody { values, next }
+        }
+
+        pub fn into_values(self) -> Vec<T> {
+            self.values
+        }
+
+        pub fn next(&self) -> Option<&String> {
+            self.next.as_ref()
+        }
+    }
+}
+
+mod branch {
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct Branch {
+        name: String,
+        target: BranchTarget,
+    }
+
+    impl Branch {
+        #[cfg(test)]
+        pub fn new(name: String, hash: String) -> Self {
+            Branch {
+                name,
+                target: BranchTarget { hash },
+            }
+        }
+
+        pub fn name(&self) -> &String {
+            &self.name
+        }
+
+        pub fn sha(&self) -> &String {
+            &self.target.hash
+        }
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    struct BranchTarget {
+        hash: String,
+    }
+}
+
+mod commit {
+    use chrono::{DateTime, Utc};
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct CommitResponseBody {
+        hash: String,
+        date: DateTime<Utc>,
+        author: CommitAuthor,
+    }
+
+    impl CommitResponseBody {
+        #[cfg(test)]
+        pub fn new(hash: String, raw_author: String, date: DateTime<Utc>) -> Self {
+            CommitResponseBody {
+                hash,
+                date,
+                author: CommitAuthor {
+                    raw: raw_author,
+                    user: None,
+                },
+            }
+        }
+
+        #[cfg(test)]
+        pub fn with_user(mut self, display_name: String) -> Self {
+            self.author.user = Some(CommitUser { display_name });
+            self
+        }
+
+        pub fn sha(&self) -> &String {
+            &self.hash
+        }
+
+        /// The display name of the Bitbucket user the commit is linked to, or the raw author
+        /// line of the commit otherwise.
+        pub fn author(&self) -> &String {
+            match &self.author.user {
+                Some(user) => &user.display_name,
+                None => &self.author.raw,
+            }
+        }
+
+        pub fn date(&self) -> &DateTime<Utc> {
+            &self.date
+        }
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    struct CommitAuthor {
+        raw: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        user: Option<CommitUser>,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    struct CommitUser {
+        display_name: String,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct CommitHash {
+        hash: String,
+    }
+
+    impl CommitHash {
+        #[cfg(test)]
+        pub fn new(hash: String) -> Self {
+            CommitHash { hash }
+        }
+    }
+}
+
+mod repository {
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct RepositoryResponseBody {
+        mainbranch: MainBranch,
+    }
+
+    impl RepositoryResponseBody {
+        #[cfg(test)]
+        pub fn new(main_branch: String) -> Self {
+            RepositoryResponseBody {
+                mainbranch: MainBranch { name: main_branch },
+            }
+        }
+
+        pub fn main_branch(&self) -> &String {
+            &self.mainbranch.name
+        }
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    struct MainBranch {
+        name: String,
+    }
+}
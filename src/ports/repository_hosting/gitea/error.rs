@@ -0,0 +1,62 @@
+use crate::domain::repository::RepositoryProviderError;
+use crate::ports::repository_hosting::github::RepositoryUrlParseError;
+use crate::ports::repository_hosting::ResponseError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum GiteaClientError {
+    #[error(transparent)]
+    RepositoryUrlParseError(#[from] RepositoryUrlParseError),
+    #[error(transparent)]
+    HttpClientError(#[from] crate::utils::http::Error),
+    #[error("JSON deserialization error: {0}")]
+    JsonDeserializationError(#[from] serde_json::Error),
+    #[error(transparent)]
+    ApiUrlParseError(http_types::url::ParseError),
+    #[error(transparent)]
+    Response(#[from] ResponseError),
+}
+
+impl From<GiteaClientError> for RepositoryProviderError {
+    fn from(client_error: GiteaClientError) -> Self {
+        let repository = None;
+        match client_error {
+            GiteaClientError::Response(response_error) => response_error.into(),
+            GiteaClientError::RepositoryUrlParseError(..) => RepositoryProviderError::InvalidUrl {
+                repository,
+                message: client_error.to_string(),
+            },
+            GiteaClientError::HttpClientError(..) => RepositoryProviderError::Transport {
+                repository,
+                message: format!("Gitea client error occurred ({})", client_error),
+            },
+            GiteaClientError::ApiUrlParseError(..)
+            | GiteaClientError::JsonDeserializationError(..) => RepositoryProviderError::Other {
+                repository,
+                message: format!("Gitea client error occurred ({})", client_error),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http_types::StatusCode;
+    use spectral::prelude::*;
+
+    use crate::ports::repository_hosting::repository::RepositoryUrl;
+
+    use super::*;
+
+    #[test]
+    fn reports_rejected_credentials_as_gitea_failure() {
+        let error =
+            RepositoryProviderError::from(GiteaClientError::from(ResponseError::from_status(
+                "Gitea",
+                StatusCode::Unauthorized,
+                &RepositoryUrl::new("https://gitea.example.com/owner/repo".to_string()),
+            )));
+
+        assert_that(&error.to_string())
+            .is_equal_to("Gitea rejected the authentication credentials".to_string());
+    }
+}
@@ -0,0 +1,707 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use http_types::headers::HeaderName;
+use http_types::{Method, StatusCode, Url};
+
+use crate::ports::repository_hosting::gitea::error::GiteaClientError;
+use crate::ports::repository_hosting::gitea::responses::CompareResponseBody;
+use crate::ports::repository_hosting::github::{
+    CommitResponseBody, GitHubAuthenticationToken, GitHubRepositoryUrlParser,
+    ListBranchesResponseBody, RepositoryResponseBody,
+};
+use crate::ports::repository_hosting::repository::{
+    Branch, BranchComparison, BranchName, Commit, RepositoryUrl,
+};
+use crate::ports::repository_hosting::{
+    AuthenticationCredentialValidity, RepositoryHostClient, ResponseError,
+};
+use crate::utils::http::{HttpClientFacade, Request};
+
+/// Gitea caps pages at 50 items unless the instance lowers `MAX_RESPONSE_ITEMS`, so the pages
+/// are followed through their `Link` headers rather than counted.
+const PAGE_SIZE: usize = 50;
+const SERVICE: &str = "Gitea";
+
+/// Client of the REST API of a self-hosted Gitea or Forgejo instance.
+///
+/// The API is modelled on GitHub's, so repositories are parsed and authenticated like GitHub
+/// repositories, and the client plugs into the GitHub adapters.
+#[derive(Debug)]
+pub struct GiteaClient<HttpClient: HttpClientFacade, UrlParser: GitHubRepositoryUrlParser> {
+    http_client: HttpClient,
+    url_parser: UrlParser,
+    api_base_url: String,
+    access_token: Option<GitHubAuthenticationToken>,
+}
+
+impl<HttpClient, UrlParser> GiteaClient<HttpClient, UrlParser>
+where
+    HttpClient: HttpClientFacade,
+    UrlParser: GitHubRepositoryUrlParser,
+{
+    pub fn new(http_client: HttpClient, url_parser: UrlParser, api_base_url: String) -> Self {
+        GiteaClient {
+            http_client,
+            url_parser,
+            api_base_url,
+            access_token: None,
+        }
+    }
+
+    fn api_headers(&self) -> HashMap<HeaderName, String> {
+        match &self.access_token {
+            Some(access_token) => Self::authorization_headers(access_token),
+            None => HashMap::new(),
+        }
+    }
+
+    fn authorization_headers(token: &GitHubAuthenticationToken) -> HashMap<HeaderName, String> {
+        let mut headers = HashMap::new();
+        headers.insert(
+            http_types::headers::AUTHORIZATION,
+            format!("token {}", token.value()),
+        );
+        headers
+    }
+
+    fn parse_url(url_string: String) -> Result<Url, GiteaClientError> {
+        match Url::parse(url_string.as_str()) {
+            Ok(url) => Ok(url),
+            Err(err) => Err(GiteaClientError::ApiUrlParseError(err)),
+        }
+    }
+
+    /// Appends `path_segments` to the repository's API URL, escaping each segment so that
+    /// characters git allows in branch names, such as `#`, `%` and `?`, stay part of the path.
+    fn repository_api_url(
+        &self,
+        owner: &str,
+        repo: &str,
+        path_segments: &[&str],
+    ) -> Result<Url, GiteaClientError> {
+        let mut url = Self::parse_url(self.api_base_url.clone())?;
+        url.path_segments_mut()
+            .map_err(|_| {
+                GiteaClientError::ApiUrlParseError(
+                    http_types::url::ParseError::RelativeUrlWithCannotBeABaseBase,
+                )
+            })?
+            .pop_if_empty()
+            .extend(&["repos", owner, repo])
+            .extend(path_segments);
+        Ok(url)
+    }
+
+    fn list_branches_api_url(&self, owner: &str, repo: &str) -> Result<Url, GiteaClientError> {
+        let mut url = self.repository_api_url(owner, repo, &["branches"])?;
+        url.query_pairs_mut()
+            .append_pair("page", "1")
+            .append_pair("limit", PAGE_SIZE.to_string().as_str());
+        Ok(url)
+    }
+
+    async fn count_commits_between(
+        &self,
+        repository_url: &RepositoryUrl,
+        owner: &str,
+        repo: &str,
+        base: &BranchName,
+        head: &BranchName,
+    ) -> Result<u32, GiteaClientError> {
+        let comparison = format!("{}...{}", base.value(), head.value());
+        let mut path_segments = vec!["compare"];
+        path_segments.extend(comparison.split('/'));
+
+        let response = self
+            .http_client
+            .send(Request::new(
+                Method::Get,
+                self.repository_api_url(owner, repo, &path_segments)?,
+                self.api_headers(),
+            ))
+            .await?;
+
+        match response.status_code() {
+            StatusCode::Ok => Ok(response.body_json::<CompareResponseBody>()?.total_commits()),
+            status => Err(ResponseError::from_status(SERVICE, status, repository_url).into()),
+        }
+    }
+}
+
+#[async_trait]
+impl<HttpClient, UrlParser> RepositoryHostClient for GiteaClient<HttpClient, UrlParser>
+where
+    HttpClient: HttpClientFacade + Send + Sync,
+    UrlParser: GitHubRepositoryUrlParser + Send + Sync,
+{
+    type Err = GiteaClientError;
+    type AuthenticationCredentials = GitHubAuthenticationToken;
+
+    async fn list_branches(
+        &self,
+        repository_url: &RepositoryUrl,
+    ) -> Result<Vec<Branch>, Self::Err> {
+        let repository = self.url_parser.parse(repository_url.clone())?;
+        let mut branches = Vec::new();
+        let mut next_page_url =
+            Some(self.list_branches_api_url(repository.owner(), repository.name())?);
+
+        while let Some(page_url) = next_page_url {
+            let response = self
+                .http_client
+                .send(Request::new(Method::Get, page_url, self.api_headers()))
+                .await?;
+
+            if response.status_code() != StatusCode::Ok {
+                return Err(ResponseError::from_status(
+                    SERVICE,
+                    response.status_code(),
+                    repository_url,
+                )
+                .into());
+            }
+
+            branches.extend(
+                response
+                    .body_json::<ListBranchesResponseBody>()?
+                    .branches()
+                    .iter()
+                    .map(|branch| {
+                        Branch::new(
+                            BranchName::new(branch.name().to_string()),
+                            branch.sha().to_string(),
+                        )
+                        .marked_protected(branch.is_protected())
+                    }),
+            );
+            next_page_url = response
+                .link_header()
+                .and_then(|link_header| link_header.next().cloned());
+        }
+
+        Ok(branches)
+    }
+
+    async fn get_commit(
+        &self,
+        repository_url: &RepositoryUrl,
+        sha: &str,
+    ) -> Result<Commit, Self::Err> {
+        let repository = self.url_parser.parse(repository_url.clone())?;
+
+        let response = self
+            .http_client
+            .send(Request::new(
+                Method::Get,
+                self.repository_api_url(
+                    repository.owner(),
+                    repository.name(),
+                    &["git", "commits", sha],
+                )?,
+                self.api_headers(),
+            ))
+            .await?;
+
+        match response.status_code() {
+            StatusCode::Ok => {
+                let body = response.body_json::<CommitResponseBody>()?;
+                Ok(Commit::new(
+                    body.sha().to_string(),
+                    *body.date(),
+                    body.author().to_string(),
+                ))
+            }
+            status => Err(ResponseError::from_status(SERVICE, status, repository_url).into()),
+        }
+    }
+
+    async fn get_default_branch(
+        &self,
+        repository_url: &RepositoryUrl,
+    ) -> Result<BranchName, Self::Err> {
+        let repository = self.url_parser.parse(repository_url.clone())?;
+
+        let response = self
+            .http_client
+            .send(Request::new(
+                Method::Get,
+                self.repository_api_url(repository.owner(), repository.name(), &[])?,
+                self.api_headers(),
+            ))
+            .await?;
+
+        match response.status_code() {
+            StatusCode::Ok => Ok(BranchName::new(
+                response
+                    .body_json::<RepositoryResponseBody>()?
+                    .default_branch()
+                    .to_string(),
+            )),
+            status => Err(ResponseError::from_status(SERVICE, status, repository_url).into()),
+        }
+    }
+
+    async fn compare_branches(
+        &self,
+        repository_url: &RepositoryUrl,
+        base: &BranchName,
+        head: &BranchName,
+    ) -> Result<BranchComparison, Self::Err> {
+        let repository = self.url_parser.parse(repository_url.clone())?;
+        let (owner, repo) = (repository.owner(), repository.name());
+
+        let ahead_by = self
+            .count_commits_between(repository_url, owner, repo, base, head)
+            .await?;
+        let behind_by = self
+            .count_commits_between(repository_url, owner, repo, head, base)
+            .await?;
+
+        Ok(BranchComparison::new(ahead_by, behind_by))
+    }
+
+    async fn delete_branch(
+        &self,
+        repository_url: &RepositoryUrl,
+        branch: &BranchName,
+    ) -> Result<(), Self::Err> {
+        let repository = self.url_parser.parse(repository_url.clone())?;
+
+        let mut path_segments = vec!["branches"];
+        path_segments.extend(branch.value().split('/'));
+
+        let response = self
+            .http_client
+            .send(Request::new(
+                Method::Delete,
+                self.repository_api_url(repository.owner(), repository.name(), &path_segments)?,
+                self.api_headers(),
+            ))
+            .await?;
+
+        match response.status_code() {
+            StatusCode::NoContent => Ok(()),
+            status => Err(ResponseError::from_status(SERVICE, status, repository_url).into()),
+        }
+    }
+
+    fn set_authentication_credentials(&mut self, credentials: Self::AuthenticationCredentials) {
+        self.access_token = Some(credentials);
+    }
+
+    async fn validate_authentication_credentials(
+        &self,
+        credentials: Self::AuthenticationCredentials,
+    ) -> Result<AuthenticationCredentialValidity, Self::Err> {
+        let response = self
+            .http_client
+            .send(Request::new(
+                Method::Get,
+                Self::parse_url(format!("{}/user", self.api_base_url))?,
+                Self::authorization_headers(&credentials),
+            ))
+            .await?;
+
+        match response.status_code() {
+            StatusCode::Ok => Ok(AuthenticationCredentialValidity::Valid),
+            StatusCode::Unauthorized => Ok(AuthenticationCredentialValidity::Invalid),
+            status => Err(ResponseError::Unexpected {
+                service: SERVICE,
+                status: status.into(),
+            }
+            .into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use mockall::predicate::eq;
+    use spectral::prelude::*;
+
+    use crate::ports::repository_hosting::github::GitHubRepositoryUrlParserImpl;
+    use crate::utils::http::{Error, MockHttpClientFacade, Response};
+
+    use super::*;
+
+    type GiteaClientAlias = GiteaClient<MockHttpClientFacade, GitHubRepositoryUrlParserImpl>;
+
+    const API_BASE_URL: &str = "https://gitea.example.com/api/v1";
+    const FIRST_PAGE_URL: &str =
+        "https://gitea.example.com/api/v1/repos/owner/repo/branches?page=1&limit=50";
+    const SECOND_PAGE_URL: &str =
+        "https://gitea.example.com/api/v1/repos/owner/repo/branches?page=2&limit=50";
+
+    fn branch(name: &str) -> Branch {
+        Branch::new(BranchName::new(name.to_string()), format!("{}-sha", name))
+    }
+
+    fn branch_json(name: &str) -> serde_json::Value {
+        serde_json::json!({
+            "name": name,
+            "commit": { "id": format!("{}-sha", name), "message": "message" },
+            "protected": false
+        })
+    }
+
+    async fn wrap_response_in_future(response: Result<Response, Error>) -> Result<Response, Error> {
+        response
+    }
+
+    fn prepare_mock_http_client(
+        mock_http_client: &mut MockHttpClientFacade,
+        request: Request,
+        response: Response,
+    ) {
+        mock_http_client
+            .expect_send()
+            .with(eq(request))
+            .returning(move |_| Box::pin(wrap_response_in_future(Ok(response.clone()))));
+    }
+
+    fn api_request(method: Method, url: &str) -> Request {
+        Request::new(method, Url::parse(url).unwrap(), HashMap::new())
+    }
+
+    fn successful_json_response(body: serde_json::Value) -> Response {
+        Response::new(StatusCode::Ok, HashMap::new(), body.to_string())
+    }
+
+    fn under_test(mock_http_client: MockHttpClientFacade) -> GiteaClientAlias {
+        GiteaClient::new(
+            mock_http_client,
+            GitHubRepositoryUrlParserImpl::for_host("gitea.example.com"),
+            API_BASE_URL.to_string(),
+        )
+    }
+
+    fn repository_url() -> RepositoryUrl {
+        RepositoryUrl::new("https://gitea.example.com/owner/repo".to_string())
+    }
+
+    fn token_headers() -> HashMap<HeaderName, String> {
+        let mut headers = HashMap::new();
+        headers.insert(
+            http_types::headers::AUTHORIZATION,
+            "token token".to_string(),
+        );
+        headers
+    }
+
+    #[test]
+    fn access_token_is_absent_by_default() {
+        assert_that(&under_test(MockHttpClientFacade::default()).access_token).is_none();
+    }
+
+    #[async_std::test]
+    async fn lists_branches_with_protected_branches_marked() {
+        let mut mock_http_client = MockHttpClientFacade::default();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            api_request(
+                Method::Get,
+                "https://gitea.example.com/api/v1/repos/owner/repo/branches?page=1&limit=50",
+            ),
+            successful_json_response(serde_json::json!([
+                branch_json("main"),
+                serde_json::json!({"name": "release", "commit": {"id": "release-sha"}, "protected": true}),
+            ])),
+        );
+
+        assert_that(
+            &under_test(mock_http_client)
+                .list_branches(&repository_url())
+                .await
+                .unwrap(),
+        )
+        .is_equal_to(vec![
+            branch("main"),
+            branch("release").marked_protected(true),
+        ]);
+    }
+
+    #[async_std::test]
+    async fn lists_branches_across_all_pages_following_next_links() {
+        let mut mock_http_client = MockHttpClientFacade::default();
+        let mut first_page_headers = HashMap::new();
+        first_page_headers.insert(
+            HeaderName::from("link"),
+            format!("<{}>; rel=\"next\"", SECOND_PAGE_URL),
+        );
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            api_request(Method::Get, FIRST_PAGE_URL),
+            Response::new(
+                StatusCode::Ok,
+                first_page_headers,
+                serde_json::json!([branch_json("first")]).to_string(),
+            ),
+        );
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            api_request(Method::Get, SECOND_PAGE_URL),
+            successful_json_response(serde_json::json!([branch_json("last")])),
+        );
+
+        assert_that(
+            &under_test(mock_http_client)
+                .list_branches(&repository_url())
+                .await
+                .unwrap(),
+        )
+        .is_equal_to(vec![branch("first"), branch("last")]);
+    }
+
+    #[async_std::test]
+    async fn lists_branches_of_a_full_page_without_next_link() {
+        let names = (0..PAGE_SIZE)
+            .map(|index| format!("branch{}", index))
+            .collect::<Vec<String>>();
+        let mut mock_http_client = MockHttpClientFacade::default();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            api_request(Method::Get, FIRST_PAGE_URL),
+            successful_json_response(serde_json::json!(names
+                .iter()
+                .map(|name| branch_json(name))
+                .collect::<Vec<serde_json::Value>>())),
+        );
+
+        assert_that(
+            &under_test(mock_http_client)
+                .list_branches(&repository_url())
+                .await
+                .unwrap(),
+        )
+        .has_length(PAGE_SIZE);
+    }
+
+    #[async_std::test]
+    async fn lists_branches_with_access_token_set() {
+        let mut mock_http_client = MockHttpClientFacade::default();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            Request::new(
+                Method::Get,
+                Url::parse(
+                    "https://gitea.example.com/api/v1/repos/owner/repo/branches?page=1&limit=50",
+                )
+                .unwrap(),
+                token_headers(),
+            ),
+            successful_json_response(serde_json::json!([branch_json("main")])),
+        );
+        let mut under_test = under_test(mock_http_client);
+        under_test
+            .set_authentication_credentials(GitHubAuthenticationToken::new("token".to_string()));
+
+        assert_that(&under_test.list_branches(&repository_url()).await.unwrap())
+            .is_equal_to(vec![branch("main")]);
+    }
+
+    #[async_std::test]
+    async fn fails_to_list_branches_of_missing_repository() {
+        let mut mock_http_client = MockHttpClientFacade::default();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            api_request(
+                Method::Get,
+                "https://gitea.example.com/api/v1/repos/owner/repo/branches?page=1&limit=50",
+            ),
+            Response::new(StatusCode::NotFound, HashMap::new(), "".to_string()),
+        );
+
+        let result = under_test(mock_http_client)
+            .list_branches(&repository_url())
+            .await;
+
+        assert_that(&matches!(
+            result,
+            Err(GiteaClientError::Response(
+                ResponseError::RepositoryNotFound(..)
+            ))
+        ))
+        .is_true();
+    }
+
+    #[async_std::test]
+    async fn gets_commit() {
+        let mut mock_http_client = MockHttpClientFacade::default();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            api_request(
+                Method::Get,
+                "https://gitea.example.com/api/v1/repos/owner/repo/git/commits/sha",
+            ),
+            successful_json_response(serde_json::json!({
+                "sha": "sha",
+                "commit": {
+                    "author": { "name": "author", "email": "author@example.com", "date": "2020-01-01T00:00:00Z" },
+                    "committer": { "name": "author", "email": "author@example.com", "date": "2020-01-01T00:00:00Z" }
+                }
+            })),
+        );
+
+        assert_that(
+            &under_test(mock_http_client)
+                .get_commit(&repository_url(), "sha")
+                .await
+                .unwrap(),
+        )
+        .is_equal_to(Commit::new(
+            "sha".to_string(),
+            Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap(),
+            "author".to_string(),
+        ));
+    }
+
+    #[async_std::test]
+    async fn gets_default_branch() {
+        let mut mock_http_client = MockHttpClientFacade::default();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            api_request(
+                Method::Get,
+                "https://gitea.example.com/api/v1/repos/owner/repo",
+            ),
+            successful_json_response(serde_json::json!({ "default_branch": "main" })),
+        );
+
+        assert_that(
+            &under_test(mock_http_client)
+                .get_default_branch(&repository_url())
+                .await
+                .unwrap(),
+        )
+        .is_equal_to(BranchName::new("main".to_string()));
+    }
+
+    #[async_std::test]
+    async fn compares_branches_in_both_directions() {
+        let mut mock_http_client = MockHttpClientFacade::default();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            api_request(
+                Method::Get,
+                "https://gitea.example.com/api/v1/repos/owner/repo/compare/main...feature",
+            ),
+            successful_json_response(serde_json::json!(CompareResponseBody::new(1))),
+        );
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            api_request(
+                Method::Get,
+                "https://gitea.example.com/api/v1/repos/owner/repo/compare/feature...main",
+            ),
+            successful_json_response(serde_json::json!(CompareResponseBody::new(2))),
+        );
+
+        assert_that(
+            &under_test(mock_http_client)
+                .compare_branches(
+                    &repository_url(),
+                    &BranchName::new("main".to_string()),
+                    &BranchName::new("feature".to_string()),
+                )
+                .await
+                .unwrap(),
+        )
+        .is_equal_to(BranchComparison::new(1, 2));
+    }
+
+    #[async_std::test]
+    async fn deletes_branch() {
+        let mut mock_http_client = MockHttpClientFacade::default();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            api_request(
+                Method::Delete,
+                "https://gitea.example.com/api/v1/repos/owner/repo/branches/feature",
+            ),
+            Response::new(StatusCode::NoContent, HashMap::new(), "".to_string()),
+        );
+
+        assert_that(
+            &under_test(mock_http_client)
+                .delete_branch(&repository_url(), &BranchName::new("feature".to_string()))
+                .await,
+        )
+        .is_ok();
+    }
+
+    #[async_std::test]
+    async fn escapes_branch_name_when_deleting_branch() {
+        let mut mock_http_client = MockHttpClientFacade::default();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            api_request(
+                Method::Delete,
+                "https://gitea.example.com/api/v1/repos/owner/repo/branches/feature/fix%2312%25",
+            ),
+            Response::new(StatusCode::NoContent, HashMap::new(), "".to_string()),
+        );
+
+        assert_that(
+            &under_test(mock_http_client)
+                .delete_branch(
+                    &repository_url(),
+                    &BranchName::new("feature/fix#12%".to_string()),
+                )
+                .await,
+        )
+        .is_ok();
+    }
+
+    #[async_std::test]
+    async fn fails_to_delete_protected_branch() {
+        let mut mock_http_client = MockHttpClientFacade::default();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            api_request(
+                Method::Delete,
+                "https://gitea.example.com/api/v1/repos/owner/repo/branches/main",
+            ),
+            Response::new(
+                StatusCode::Forbidden,
+                HashMap::new(),
+                serde_json::json!({ "message": "branch protected" }).to_string(),
+            ),
+        );
+
+        let result = under_test(mock_http_client)
+            .delete_branch(&repository_url(), &BranchName::new("main".to_string()))
+            .await;
+
+        assert_that(&matches!(
+            result,
+            Err(GiteaClientError::Response(ResponseError::Forbidden(..)))
+        ))
+        .is_true();
+    }
+
+    #[async_std::test]
+    async fn validates_authentication_credentials_against_user_endpoint() {
+        let mut mock_http_client = MockHttpClientFacade::default();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            Request::new(
+                Method::Get,
+                Url::parse("https://gitea.example.com/api/v1/user").unwrap(),
+                token_headers(),
+            ),
+            Response::new(StatusCode::Unauthorized, HashMap::new(), "".to_string()),
+        );
+
+        assert_that(
+            &under_test(mock_http_client)
+                .validate_authentication_credentials(GitHubAuthenticationToken::new(
+                    "token".to_string(),
+                ))
+                .await
+                .unwrap(),
+        )
+        .is_equal_to(AuthenticationCredentialValidity::Invalid);
+    }
+}
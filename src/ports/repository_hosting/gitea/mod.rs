@@ -0,0 +1,6 @@
+pub use error::*;
+pub use gitea_client::*;
+
+mod error;
+mod gitea_client;
+mod responses;
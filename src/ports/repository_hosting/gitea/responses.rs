@@ -0,0 +1,20 @@
+pub use compare::*;
+
+mod compare {
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct CompareResponseBody {
+        total_commits: u32,
+    }
+
+    impl CompareResponseBody {
+        #[cfg(test)]
+        pub fn new(total_commits: u32) -> Self {
+            CompareResponseBody { total_commits }
+        }
+
+        /// The number of commits on the head that are not on the base.
+        pub fn total_commits(&self) -> u32 {
+            self.total_commits
+        }
+    }
+}
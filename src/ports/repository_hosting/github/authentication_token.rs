@@ -46,15 +46,16 @@ mod tests {
     #[test]
     fn parses_from_a_non_empty_string() {
         assert_that(&GitHubAuthenticationToken::from_str("token").unwrap())
-            .is_equal_to(&GitHubAuthenticationToken::new("token".to_string()));
+            .is_equal_to(GitHubAuthenticationToken::new("token".to_string()));
     }
 
     #[test]
     fn fails_to_parse_from_an_empty_string() {
         let result = GitHubAuthenticationToken::from_str("");
-        assert_that(
-            &matches!(result.err().unwrap(), GitHubAuthenticationTokenParseError::Empty {..}),
-        )
+        assert_that(&matches!(
+            result.err().unwrap(),
+            GitHubAuthenticationTokenParseError::Empty
+        ))
         .is_true();
     }
 }
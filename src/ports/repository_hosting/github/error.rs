@@ -1,5 +1,5 @@
 use crate::ports::repository_hosting::github::parse_repository_url::RepositoryUrlParseError;
-use crate::ports::repository_hosting::github::repository::RepositoryUrl;
+use crate::ports::repository_hosting::repository::{RepositoryOwner, RepositoryUrl};
 
 #[derive(Debug, thiserror::Error)]
 pub enum GitHubClientError {
@@ -13,6 +13,21 @@ pub enum GitHubClientError {
     ApiUrlParseError(http_types::url::ParseError),
     #[error("repository '{0}' not found")]
     RepositoryNotFound(RepositoryUrl),
-    #[error("unexpected response from GitHub")]
-    Unexpected,
+    #[error("{0} not found")]
+    OwnerNotFound(RepositoryOwner),
+    #[error("branch '{branch}' in repository '{repository_url}' could not be deleted")]
+    BranchNotDeleted {
+        repository_url: RepositoryUrl,
+        branch: String,
+    },
+    #[error("GitHub rejected the authentication token: {0}")]
+    Unauthorized(String),
+    #[error("GitHub denied access: {0}")]
+    Forbidden(String),
+    #[error("GitHub rate limit exceeded: {0}")]
+    RateLimited(String),
+    #[error("GitHub server error ({status}): {message}")]
+    ServerError { status: u16, message: String },
+    #[error("unexpected response from GitHub ({status}): {message}")]
+    UnexpectedResponse { status: u16, message: String },
 }
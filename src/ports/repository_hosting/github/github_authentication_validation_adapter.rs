@@ -1,27 +1,20 @@
 use crate::domain::authentication::{
-    AuthenticationValidity, GitHubAuthenticationToken, RepositoryCredentialsValidationError,
+    AuthenticationToken, AuthenticationValidity, RepositoryCredentialsValidationError,
     RepositoryCredentialsValidator,
 };
 use crate::ports::repository_hosting::github::authentication_token::GitHubAuthenticationToken as RepositoryClientGitHubAuthenticationToken;
-use crate::ports::repository_hosting::github::error::GitHubClientError;
-use crate::ports::repository_hosting::github::RepositoryHostClient;
+use crate::ports::repository_hosting::{AuthenticationCredentialValidity, RepositoryHostClient};
 
 pub struct GitHubCredentialsValidatorAdapter<GC>
 where
-    GC: RepositoryHostClient<
-        Err = GitHubClientError,
-        AuthenticationCredentials = RepositoryClientGitHubAuthenticationToken,
-    >,
+    GC: RepositoryHostClient<AuthenticationCredentials = RepositoryClientGitHubAuthenticationToken>,
 {
     github_client: GC,
 }
 
 impl<GC> GitHubCredentialsValidatorAdapter<GC>
 where
-    GC: RepositoryHostClient<
-        Err = GitHubClientError,
-        AuthenticationCredentials = RepositoryClientGitHubAuthenticationToken,
-    >,
+    GC: RepositoryHostClient<AuthenticationCredentials = RepositoryClientGitHubAuthenticationToken>,
 {
     pub fn new(github_client: GC) -> Self {
         GitHubCredentialsValidatorAdapter { github_client }
@@ -31,15 +24,13 @@ where
 #[async_trait::async_trait]
 impl<GC> RepositoryCredentialsValidator for GitHubCredentialsValidatorAdapter<GC>
 where
-    GC: RepositoryHostClient<
-            Err = GitHubClientError,
-            AuthenticationCredentials = RepositoryClientGitHubAuthenticationToken,
-        > + Send
+    GC: RepositoryHostClient<AuthenticationCredentials = RepositoryClientGitHubAuthenticationToken>
+        + Send
         + Sync,
 {
     async fn validate(
         &self,
-        credentials: GitHubAuthenticationToken,
+        credentials: AuthenticationToken,
     ) -> Result<AuthenticationValidity, RepositoryCredentialsValidationError> {
         let validity = match self
             .github_client
@@ -56,19 +47,15 @@ where
     }
 }
 
-#[derive(Debug, Eq, PartialEq, Copy, Clone)]
-pub enum AuthenticationCredentialValidity {
-    Valid,
-    Invalid,
-}
-
 #[cfg(test)]
 mod tests {
     use mockall::predicate::eq;
     use spectral::prelude::*;
 
     use crate::ports::repository_hosting::github::authentication_token::GitHubAuthenticationToken as RepositoryClientGitHubAuthenticationToken;
-    use crate::ports::repository_hosting::github::MockRepositoryHostClient;
+    use crate::ports::repository_hosting::MockRepositoryHostClient;
+
+    use crate::ports::repository_hosting::github::error::GitHubClientError;
 
     use super::*;
 
@@ -102,7 +89,7 @@ mod tests {
         );
 
         let validity = under_test(mock_github_client)
-            .validate(GitHubAuthenticationToken::new("token".to_string()))
+            .validate(AuthenticationToken::new("token".to_string()))
             .await
             .unwrap();
 
@@ -119,7 +106,7 @@ mod tests {
         );
 
         let validity = under_test(mock_github_client)
-            .validate(GitHubAuthenticationToken::new("token".to_string()))
+            .validate(AuthenticationToken::new("token".to_string()))
             .await
             .unwrap();
 
@@ -2,56 +2,61 @@ use std::collections::HashMap;
 
 use async_trait::async_trait;
 use http_types::headers::HeaderName;
-use http_types::{Method, Url};
+use http_types::{Method, StatusCode, Url};
 
 use crate::ports::repository_hosting::github::authentication_token::GitHubAuthenticationToken;
 use crate::ports::repository_hosting::github::error::GitHubClientError;
-use crate::ports::repository_hosting::github::repository::{BranchName, RepositoryUrl};
-use crate::ports::repository_hosting::github::{
-    parse_repository_url::GitHubRepositoryUrlParser, responses::ListBranchesResponseBody,
-    AuthenticationCredentialValidity,
+use crate::ports::repository_hosting::github::parse_repository_url::GitHubRepositoryUrlParser;
+use crate::ports::repository_hosting::github::responses::{
+    CommitResponseBody, CompareResponseBody, ErrorResponseBody, ListBranchesResponseBody,
+    ListRepositoriesResponseBody, RepositoryResponseBody,
+};
+use crate::ports::repository_hosting::repository::{
+    Branch, BranchComparison, BranchName, Commit, RepositoryOwner, RepositoryUrl,
+};
+use crate::ports::repository_hosting::{
+    AuthenticationCredentialValidity, RepositoryHostClient, RepositoryListingClient,
 };
 use crate::utils::environment::EnvironmentReader;
-use crate::utils::http::{HttpClientFacade, Request};
-
-#[async_trait]
-pub trait RepositoryHostClient {
-    type Err;
-    type AuthenticationCredentials;
-
-    async fn list_branches(
-        &self,
-        repository_url: &RepositoryUrl,
-    ) -> Result<Vec<BranchName>, Self::Err>;
-
-    fn set_authentication_credentials(&mut self, credentials: Self::AuthenticationCredentials);
-
-    async fn validate_authentication_credentials(
-        &self,
-        credentials: Self::AuthenticationCredentials,
-    ) -> Result<AuthenticationCredentialValidity, Self::Err>;
-}
-
-#[cfg(test)]
-mockall::mock! {
-    pub RepositoryHostClient<Err: 'static + Send + Sync, C: 'static + Send + Sync> {}
-
-    #[async_trait::async_trait]
-    impl<Err: 'static + Send + Sync, C: 'static + Send + Sync> RepositoryHostClient for RepositoryClient<Err, C> {
-        type Err = Err;
-        type AuthenticationCredentials = C;
+use crate::utils::http::{HttpClientFacade, Request, Response};
 
-        async fn list_branches(
-            &self,
-            repository_url: &RepositoryUrl,
-        ) -> Result<Vec<BranchName>, Err>;
+const PAGE_SIZE: u32 = 100;
 
-        fn set_authentication_credentials(&mut self, credentials: C);
+/// Maps an unsuccessful GitHub API response to an error, keeping the `message` of its body.
+///
+/// A 404 becomes `not_found` because GitHub also answers 404 for private repositories the token
+/// cannot see.
+pub(super) fn response_error(
+    response: &Response,
+    not_found: GitHubClientError,
+) -> GitHubClientError {
+    let status = response.status_code();
+    let message = response
+        .body_json::<ErrorResponseBody>()
+        .map(|body| body.message().to_string())
+        .unwrap_or_else(|_| status.canonical_reason().to_string());
+    let rate_limit_exhausted = response
+        .header(&HeaderName::from("x-ratelimit-remaining"))
+        .is_some_and(|remaining| remaining.trim() == "0");
 
-        async fn validate_authentication_credentials(
-            &self,
-            credentials: C,
-        ) -> Result<AuthenticationCredentialValidity, Err>;
+    match status {
+        StatusCode::NotFound => not_found,
+        StatusCode::Unauthorized => GitHubClientError::Unauthorized(message),
+        StatusCode::TooManyRequests => GitHubClientError::RateLimited(message),
+        StatusCode::Forbidden
+            if rate_limit_exhausted || message.to_lowercase().contains("rate limit") =>
+        {
+            GitHubClientError::RateLimited(message)
+        }
+        StatusCode::Forbidden => GitHubClientError::Forbidden(message),
+        status if status.is_server_error() => GitHubClientError::ServerError {
+            status: status.into(),
+            message,
+        },
+        status => GitHubClientError::UnexpectedResponse {
+            status: status.into(),
+            message,
+        },
     }
 }
 
@@ -64,6 +69,7 @@ pub struct GitHubClient<
     http_client: HttpClient,
     url_parser: UrlParser,
     environment_reader: EnvReader,
+    api_base_url: Option<String>,
     personal_access_token: Option<GitHubAuthenticationToken>,
 }
 
@@ -82,11 +88,19 @@ where
             http_client,
             url_parser,
             environment_reader,
+            api_base_url: None,
             personal_access_token: None,
         }
     }
 
-    fn list_branches_headers(&self) -> HashMap<HeaderName, String> {
+    /// Sends requests to the REST API of a GitHub Enterprise Server instance instead of
+    /// github.com.
+    pub fn with_api_base_url(mut self, api_base_url: String) -> Self {
+        self.api_base_url = Some(api_base_url);
+        self
+    }
+
+    fn repository_api_headers(&self) -> HashMap<HeaderName, String> {
         let mut headers = HashMap::new();
         headers.insert(
             http_types::headers::ACCEPT,
@@ -121,6 +135,9 @@ where
     }
 
     fn api_base_url(&self) -> String {
+        if let Some(api_base_url) = &self.api_base_url {
+            return api_base_url.clone();
+        }
         match self
             .environment_reader
             .read("TIDY_REPO_GITHUB_API_BASE_URL")
@@ -132,9 +149,91 @@ where
 
     fn list_branches_api_url(&self, owner: &str, repo: &str) -> Result<Url, GitHubClientError> {
         let url_string = format!("{}/repos/{}/{}/branches", self.api_base_url(), owner, repo);
+        let mut url = Self::parse_url(url_string)?;
+        url.query_pairs_mut()
+            .append_pair("per_page", PAGE_SIZE.to_string().as_str());
+        Ok(url)
+    }
+
+    fn get_commit_api_url(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+    ) -> Result<Url, GitHubClientError> {
+        let url_string = format!(
+            "{}/repos/{}/{}/commits/{}",
+            self.api_base_url(),
+            owner,
+            repo,
+            sha
+        );
         Self::parse_url(url_string)
     }
 
+    fn get_repository_api_url(&self, owner: &str, repo: &str) -> Result<Url, GitHubClientError> {
+        let url_string = format!("{}/repos/{}/{}", self.api_base_url(), owner, repo);
+        Self::parse_url(url_string)
+    }
+
+    /// Appends `path_segments` to the repository's API URL, escaping each segment so that
+    /// characters git allows in branch names, such as `#`, `%` and `?`, stay part of the path.
+    fn repository_api_url(
+        &self,
+        owner: &str,
+        repo: &str,
+        path_segments: &[&str],
+    ) -> Result<Url, GitHubClientError> {
+        let mut url = Self::parse_url(self.api_base_url())?;
+        url.path_segments_mut()
+            .map_err(|_| {
+                GitHubClientError::ApiUrlParseError(
+                    http_types::url::ParseError::RelativeUrlWithCannotBeABaseBase,
+                )
+            })?
+            .pop_if_empty()
+            .extend(&["repos", owner, repo])
+            .extend(path_segments);
+        Ok(url)
+    }
+
+    fn compare_api_url(
+        &self,
+        owner: &str,
+        repo: &str,
+        base: &str,
+        head: &str,
+    ) -> Result<Url, GitHubClientError> {
+        let comparison = format!("{}...{}", base, head);
+        let mut path_segments = vec!["compare"];
+        path_segments.extend(comparison.split('/'));
+        self.repository_api_url(owner, repo, &path_segments)
+    }
+
+    fn branch_ref_api_url(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<Url, GitHubClientError> {
+        let mut path_segments = vec!["git", "refs", "heads"];
+        path_segments.extend(branch.split('/'));
+        self.repository_api_url(owner, repo, &path_segments)
+    }
+
+    fn list_repositories_api_url(&self, owner: &RepositoryOwner) -> Result<Url, GitHubClientError> {
+        let url_string = match owner {
+            RepositoryOwner::Organization(org) => {
+                format!("{}/orgs/{}/repos", self.api_base_url(), org)
+            }
+            RepositoryOwner::User(user) => format!("{}/users/{}/repos", self.api_base_url(), user),
+        };
+        let mut url = Self::parse_url(url_string)?;
+        url.query_pairs_mut()
+            .append_pair("per_page", PAGE_SIZE.to_string().as_str());
+        Ok(url)
+    }
+
     fn validate_authentication_credentials_api_url(&self) -> Result<Url, GitHubClientError> {
         Self::parse_url(self.api_base_url())
     }
@@ -154,27 +253,170 @@ where
     async fn list_branches(
         &self,
         repository_url: &RepositoryUrl,
-    ) -> Result<Vec<BranchName>, Self::Err> {
+    ) -> Result<Vec<Branch>, Self::Err> {
+        let repository = self.url_parser.parse(repository_url.clone())?;
+        let mut branches = Vec::new();
+        let mut next_page_url =
+            Some(self.list_branches_api_url(repository.owner(), repository.name())?);
+
+        while let Some(page_url) = next_page_url {
+            let response = self
+                .http_client
+                .send(Request::new(
+                    Method::Get,
+                    page_url,
+                    self.repository_api_headers(),
+                ))
+                .await?;
+
+            if response.status_code() != StatusCode::Ok {
+                return Err(response_error(
+                    &response,
+                    GitHubClientError::RepositoryNotFound(repository_url.clone()),
+                ));
+            }
+
+            branches.extend(
+                response
+                    .body_json::<ListBranchesResponseBody>()?
+                    .branches()
+                    .iter()
+                    .map(|branch| {
+                        Branch::new(
+                            BranchName::new(branch.name().to_string()),
+                            branch.sha().to_string(),
+                        )
+                        .marked_protected(branch.is_protected())
+                    }),
+            );
+            next_page_url = response
+                .link_header()
+                .and_then(|link_header| link_header.next().cloned());
+        }
+
+        Ok(branches)
+    }
+
+    async fn get_commit(
+        &self,
+        repository_url: &RepositoryUrl,
+        sha: &str,
+    ) -> Result<Commit, Self::Err> {
+        let repository = self.url_parser.parse(repository_url.clone())?;
+
+        let response = self
+            .http_client
+            .send(Request::new(
+                Method::Get,
+                self.get_commit_api_url(repository.owner(), repository.name(), sha)?,
+                self.repository_api_headers(),
+            ))
+            .await?;
+
+        match response.status_code() {
+            StatusCode::Ok => {
+                let body = response.body_json::<CommitResponseBody>()?;
+                Ok(Commit::new(
+                    body.sha().to_string(),
+                    *body.date(),
+                    body.author().to_string(),
+                ))
+            }
+            _ => Err(response_error(
+                &response,
+                GitHubClientError::RepositoryNotFound(repository_url.clone()),
+            )),
+        }
+    }
+
+    async fn get_default_branch(
+        &self,
+        repository_url: &RepositoryUrl,
+    ) -> Result<BranchName, Self::Err> {
+        let repository = self.url_parser.parse(repository_url.clone())?;
+
+        let response = self
+            .http_client
+            .send(Request::new(
+                Method::Get,
+                self.get_repository_api_url(repository.owner(), repository.name())?,
+                self.repository_api_headers(),
+            ))
+            .await?;
+
+        match response.status_code() {
+            StatusCode::Ok => Ok(BranchName::new(
+                response
+                    .body_json::<RepositoryResponseBody>()?
+                    .default_branch()
+                    .to_string(),
+            )),
+            _ => Err(response_error(
+                &response,
+                GitHubClientError::RepositoryNotFound(repository_url.clone()),
+            )),
+        }
+    }
+
+    async fn compare_branches(
+        &self,
+        repository_url: &RepositoryUrl,
+        base: &BranchName,
+        head: &BranchName,
+    ) -> Result<BranchComparison, Self::Err> {
         let repository = self.url_parser.parse(repository_url.clone())?;
 
         let response = self
             .http_client
             .send(Request::new(
                 Method::Get,
-                self.list_branches_api_url(repository.owner(), repository.name())?,
-                self.list_branches_headers(),
+                self.compare_api_url(
+                    repository.owner(),
+                    repository.name(),
+                    base.value(),
+                    head.value(),
+                )?,
+                self.repository_api_headers(),
             ))
             .await?;
 
         match response.status_code() {
-            http_types::StatusCode::Ok => Ok(response
-                .body_json::<ListBranchesResponseBody>()?
-                .branches()
-                .iter()
-                .map(|branch| BranchName::new(branch.name().to_string()))
-                .collect()),
-            _ => Err(GitHubClientError::RepositoryNotFound(
-                repository_url.clone(),
+            StatusCode::Ok => {
+                let body = response.body_json::<CompareResponseBody>()?;
+                Ok(BranchComparison::new(body.ahead_by(), body.behind_by()))
+            }
+            _ => Err(response_error(
+                &response,
+                GitHubClientError::RepositoryNotFound(repository_url.clone()),
+            )),
+        }
+    }
+
+    async fn delete_branch(
+        &self,
+        repository_url: &RepositoryUrl,
+        branch: &BranchName,
+    ) -> Result<(), Self::Err> {
+        let repository = self.url_parser.parse(repository_url.clone())?;
+
+        let response = self
+            .http_client
+            .send(Request::new(
+                Method::Delete,
+                self.branch_ref_api_url(repository.owner(), repository.name(), branch.value())?,
+                self.repository_api_headers(),
+            ))
+            .await?;
+
+        match response.status_code() {
+            StatusCode::NoContent => Ok(()),
+            StatusCode::UnprocessableEntity => Err(GitHubClientError::BranchNotDeleted {
+                repository_url: repository_url.clone(),
+                branch: branch.value().clone(),
+            }),
+            _ => Err(response_error(
+                &response,
+                GitHubClientError::RepositoryNotFound(repository_url.clone()),
             )),
         }
     }
@@ -197,30 +439,89 @@ where
             .await?;
 
         match response.status_code() {
-            http_types::StatusCode::Ok => Ok(AuthenticationCredentialValidity::Valid),
-            http_types::StatusCode::Unauthorized => Ok(AuthenticationCredentialValidity::Invalid),
-            _ => Err(GitHubClientError::Unexpected),
+            StatusCode::Ok => Ok(AuthenticationCredentialValidity::Valid),
+            StatusCode::Unauthorized => Ok(AuthenticationCredentialValidity::Invalid),
+            status => {
+                let unexpected = GitHubClientError::UnexpectedResponse {
+                    status: status.into(),
+                    message: status.canonical_reason().to_string(),
+                };
+                Err(response_error(&response, unexpected))
+            }
         }
     }
 }
 
+#[async_trait]
+impl<HttpClient, UrlParser, EnvReader> RepositoryListingClient
+    for GitHubClient<HttpClient, UrlParser, EnvReader>
+where
+    HttpClient: HttpClientFacade + Send + Sync,
+    UrlParser: GitHubRepositoryUrlParser + Send + Sync,
+    EnvReader: EnvironmentReader + Send + Sync,
+{
+    async fn list_repositories(
+        &self,
+        owner: &RepositoryOwner,
+    ) -> Result<Vec<RepositoryUrl>, GitHubClientError> {
+        let mut repository_urls = Vec::new();
+        let mut next_page_url = Some(self.list_repositories_api_url(owner)?);
+
+        while let Some(page_url) = next_page_url {
+            let response = self
+                .http_client
+                .send(Request::new(
+                    Method::Get,
+                    page_url,
+                    self.repository_api_headers(),
+                ))
+                .await?;
+
+            if response.status_code() != StatusCode::Ok {
+                return Err(response_error(
+                    &response,
+                    GitHubClientError::OwnerNotFound(owner.clone()),
+                ));
+            }
+
+            repository_urls.extend(
+                response
+                    .body_json::<ListRepositoriesResponseBody>()?
+                    .html_urls()
+                    .into_iter()
+                    .map(|html_url| RepositoryUrl::new(html_url.clone())),
+            );
+            next_page_url = response
+                .link_header()
+                .and_then(|link_header| link_header.next().cloned());
+        }
+
+        Ok(repository_urls)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
     use std::env::VarError;
 
+    use chrono::{TimeZone, Utc};
     use http_types::{Method, StatusCode};
     use mockall::predicate::eq;
     use spectral::prelude::*;
 
     use crate::ports::repository_hosting::github::repository::GitHubRepository;
-    use crate::ports::repository_hosting::github::responses::Branch;
+    use crate::ports::repository_hosting::github::responses::Branch as BranchResponse;
     use crate::ports::repository_hosting::github::MockGitHubRepositoryUrlParser;
     use crate::utils::environment::{EnvironmentReaderError, MockEnvironmentReader};
     use crate::utils::http::{Error, MockHttpClientFacade, Request, Response};
 
     use super::*;
 
+    fn branch(name: &str) -> Branch {
+        Branch::new(BranchName::new(name.to_string()), "sha".to_string())
+    }
+
     fn mock_http_client() -> MockHttpClientFacade {
         MockHttpClientFacade::default()
     }
@@ -253,25 +554,42 @@ mod tests {
     }
 
     fn fake_list_branches_api_url(owner: &str, repo: &str) -> Url {
-        Url::parse(format!("https://api.github.com/repos/{}/{}/branches", owner, repo).as_str())
-            .unwrap()
+        Url::parse(
+            format!(
+                "https://api.github.com/repos/{}/{}/branches?per_page=100",
+                owner, repo
+            )
+            .as_str(),
+        )
+        .unwrap()
+    }
+
+    fn fake_list_branches_next_page_api_url(page: u32) -> Url {
+        Url::parse(
+            format!(
+                "https://api.github.com/repositories/1/branches?per_page=100&page={}",
+                page
+            )
+            .as_str(),
+        )
+        .unwrap()
     }
 
     fn fake_validate_authentication_credentials_api_url() -> Url {
         Url::parse("https://api.github.com/").unwrap()
     }
 
-    fn valid_list_branches_request(owner: &str, repo: &str) -> Request {
+    fn list_branches_request(url: Url) -> Request {
         let mut headers = HashMap::new();
         headers.insert(
             http_types::headers::ACCEPT,
             "application/vnd.github.v3+json".to_string(),
         );
-        Request::new(
-            Method::Get,
-            fake_list_branches_api_url(owner, repo),
-            headers,
-        )
+        Request::new(Method::Get, url, headers)
+    }
+
+    fn valid_list_branches_request(owner: &str, repo: &str) -> Request {
+        list_branches_request(fake_list_branches_api_url(owner, repo))
     }
 
     fn authenticated_list_branches_request(
@@ -316,14 +634,47 @@ mod tests {
     ) -> Response {
         Response::new(
             StatusCode::Ok,
+            HashMap::new(),
+            serde_json::json!(list_branches_response_body).to_string(),
+        )
+    }
+
+    fn successful_list_branches_response_with_next_page(
+        list_branches_response_body: ListBranchesResponseBody,
+        next_page_url: Url,
+    ) -> Response {
+        let mut headers = HashMap::new();
+        headers.insert(
+            HeaderName::from("link"),
+            format!("<{}>; rel=\"next\"", next_page_url),
+        );
+        Response::new(
+            StatusCode::Ok,
+            headers,
             serde_json::json!(list_branches_response_body).to_string(),
         )
     }
 
+    fn mock_url_parser_for_owner_and_repo() -> MockGitHubRepositoryUrlParser {
+        let mut mock_url_parser = mock_repository_url_parser();
+        mock_url_parser
+            .expect_parse()
+            .with(eq(RepositoryUrl::new(
+                "https://github.com/owner/repo".to_string(),
+            )))
+            .returning(|_| {
+                Ok(GitHubRepository::new(
+                    "owner".to_string(),
+                    "repo".to_string(),
+                ))
+            });
+        mock_url_parser
+    }
+
     fn validate_authentication_credentials_response(
         status_code: http_types::StatusCode,
     ) -> Response {
-        Response::new(status_code, "".to_string())
+        Response::new(status_code, HashMap::new(), "".to_string())
     }
 
     #[test]
@@ -358,9 +709,9 @@ mod tests {
         prepare_mock_http_client(
             &mut mock_http_client,
             valid_list_branches_request("owner", "repo"),
-            successful_list_branches_response(ListBranchesResponseBody::new(vec![Branch::new(
-                "branch".to_string(),
-            )])),
+            successful_list_branches_response(ListBranchesResponseBody::new(vec![
+                BranchResponse::new("branch".to_string(), "sha".to_string()),
+            ])),
         );
         let mut mock_url_parser = mock_repository_url_parser();
         mock_url_parser
@@ -386,7 +737,7 @@ mod tests {
                 .await
                 .unwrap(),
         )
-        .is_equal_to(&vec![BranchName::new("branch".to_string())]);
+        .is_equal_to(vec![branch("branch")]);
     }
 
     #[async_std::test]
@@ -399,9 +750,9 @@ mod tests {
                 "repo",
                 GitHubAuthenticationToken::new("token".to_string()),
             ),
-            successful_list_branches_response(ListBranchesResponseBody::new(vec![Branch::new(
-                "branch".to_string(),
-            )])),
+            successful_list_branches_response(ListBranchesResponseBody::new(vec![
+                BranchResponse::new("branch".to_string(), "sha".to_string()),
+            ])),
         );
         let mut mock_url_parser = mock_repository_url_parser();
         mock_url_parser
@@ -429,7 +780,547 @@ mod tests {
                 .await
                 .unwrap(),
         )
-        .is_equal_to(&vec![BranchName::new("branch".to_string())]);
+        .is_equal_to(vec![branch("branch")]);
+    }
+
+    #[async_std::test]
+    async fn lists_branches_across_all_pages() {
+        let mut mock_http_client = mock_http_client();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            valid_list_branches_request("owner", "repo"),
+            successful_list_branches_response_with_next_page(
+                ListBranchesResponseBody::new(vec![BranchResponse::new(
+                    "branch1".to_string(),
+                    "sha".to_string(),
+                )]),
+                fake_list_branches_next_page_api_url(2),
+            ),
+        );
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            list_branches_request(fake_list_branches_next_page_api_url(2)),
+            successful_list_branches_response_with_next_page(
+                ListBranchesResponseBody::new(vec![BranchResponse::new(
+                    "branch2".to_string(),
+                    "sha".to_string(),
+                )]),
+                fake_list_branches_next_page_api_url(3),
+            ),
+        );
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            list_branches_request(fake_list_branches_next_page_api_url(3)),
+            successful_list_branches_response(ListBranchesResponseBody::new(vec![
+                BranchResponse::new("branch3".to_string(), "sha".to_string()),
+            ])),
+        );
+
+        let under_test = GitHubClient::new(
+            mock_http_client,
+            mock_url_parser_for_owner_and_repo(),
+            mock_environment_reader(),
+        );
+
+        assert_that(
+            &under_test
+                .list_branches(&RepositoryUrl::new(
+                    "https://github.com/owner/repo".to_string(),
+                ))
+                .await
+                .unwrap(),
+        )
+        .is_equal_to(vec![
+            branch("branch1"),
+            branch("branch2"),
+            branch("branch3"),
+        ]);
+    }
+
+    #[async_std::test]
+    async fn lists_branches_with_protection_status() {
+        let mut mock_http_client = mock_http_client();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            valid_list_branches_request("owner", "repo"),
+            successful_list_branches_response(ListBranchesResponseBody::new(vec![
+                BranchResponse::new("main".to_string(), "sha".to_string()).protected(),
+                BranchResponse::new("feature".to_string(), "sha".to_string()),
+            ])),
+        );
+
+        let under_test = GitHubClient::new(
+            mock_http_client,
+            mock_url_parser_for_owner_and_repo(),
+            mock_environment_reader(),
+        );
+
+        assert_that(
+            &under_test
+                .list_branches(&RepositoryUrl::new(
+                    "https://github.com/owner/repo".to_string(),
+                ))
+                .await
+                .unwrap(),
+        )
+        .is_equal_to(vec![
+            branch("main").marked_protected(true),
+            branch("feature"),
+        ]);
+    }
+
+    #[async_std::test]
+    async fn gets_commit() {
+        let date = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+        let mut mock_http_client = mock_http_client();
+        let mut headers = HashMap::new();
+        headers.insert(
+            http_types::headers::ACCEPT,
+            "application/vnd.github.v3+json".to_string(),
+        );
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            Request::new(
+                Method::Get,
+                Url::parse("https://api.github.com/repos/owner/repo/commits/sha").unwrap(),
+                headers,
+            ),
+            Response::new(
+                StatusCode::Ok,
+                HashMap::new(),
+                serde_json::json!(CommitResponseBody::new(
+                    "sha".to_string(),
+                    "author".to_string(),
+                    date
+                ))
+                .to_string(),
+            ),
+        );
+
+        let under_test = GitHubClient::new(
+            mock_http_client,
+            mock_url_parser_for_owner_and_repo(),
+            mock_environment_reader(),
+        );
+
+        assert_that(
+            &under_test
+                .get_commit(
+                    &RepositoryUrl::new("https://github.com/owner/repo".to_string()),
+                    "sha",
+                )
+                .await
+                .unwrap(),
+        )
+        .is_equal_to(Commit::new("sha".to_string(), date, "author".to_string()));
+    }
+
+    fn repository_api_request(url: &str) -> Request {
+        list_branches_request(Url::parse(url).unwrap())
+    }
+
+    fn successful_json_response(body: serde_json::Value) -> Response {
+        Response::new(StatusCode::Ok, HashMap::new(), body.to_string())
+    }
+
+    #[async_std::test]
+    async fn gets_default_branch() {
+        let mut mock_http_client = mock_http_client();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            repository_api_request("https://api.github.com/repos/owner/repo"),
+            successful_json_response(serde_json::json!(RepositoryResponseBody::new(
+                "main".to_string()
+            ))),
+        );
+
+        let under_test = GitHubClient::new(
+            mock_http_client,
+            mock_url_parser_for_owner_and_repo(),
+            mock_environment_reader(),
+        );
+
+        assert_that(
+            &under_test
+                .get_default_branch(&RepositoryUrl::new(
+                    "https://github.com/owner/repo".to_string(),
+                ))
+                .await
+                .unwrap(),
+        )
+        .is_equal_to(BranchName::new("main".to_string()));
+    }
+
+    async fn get_default_branch_error(response: Response) -> GitHubClientError {
+        let mut mock_http_client = mock_http_client();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            repository_api_request("https://api.github.com/repos/owner/repo"),
+            response,
+        );
+        let under_test = GitHubClient::new(
+            mock_http_client,
+            mock_url_parser_for_owner_and_repo(),
+            mock_environment_reader(),
+        );
+
+        under_test
+            .get_default_branch(&RepositoryUrl::new(
+                "https://github.com/owner/repo".to_string(),
+            ))
+            .await
+            .unwrap_err()
+    }
+
+    fn error_response(
+        status_code: StatusCode,
+        headers: HashMap<HeaderName, String>,
+        message: &str,
+    ) -> Response {
+        Response::new(
+            status_code,
+            headers,
+            serde_json::json!(ErrorResponseBody::new(message.to_string())).to_string(),
+        )
+    }
+
+    #[async_std::test]
+    async fn fails_with_repository_not_found_when_github_responds_not_found() {
+        let error = get_default_branch_error(error_response(
+            StatusCode::NotFound,
+            HashMap::new(),
+            "Not Found",
+        ))
+        .await;
+
+        assert_that(&matches!(error, GitHubClientError::RepositoryNotFound(..))).is_true();
+    }
+
+    #[async_std::test]
+    async fn fails_as_unauthorized_keeping_api_message() {
+        let error = get_default_branch_error(error_response(
+            StatusCode::Unauthorized,
+            HashMap::new(),
+            "Bad credentials",
+        ))
+        .await;
+
+        assert_that(&error.to_string())
+            .is_equal_to("GitHub rejected the authentication token: Bad credentials".to_string());
+    }
+
+    #[async_std::test]
+    async fn fails_as_forbidden_keeping_api_message() {
+        let error = get_default_branch_error(error_response(
+            StatusCode::Forbidden,
+            HashMap::new(),
+            "Resource not accessible by personal access token",
+        ))
+        .await;
+
+        assert_that(&matches!(error, GitHubClientError::Forbidden(message) if message == "Resource not accessible by personal access token")).is_true();
+    }
+
+    #[async_std::test]
+    async fn fails_as_rate_limited_when_rate_limit_is_exhausted() {
+        let mut headers = HashMap::new();
+        headers.insert(HeaderName::from("x-ratelimit-remaining"), "0".to_string());
+        let error = get_default_branch_error(error_response(
+            StatusCode::Forbidden,
+            headers,
+            "API rate limit exceeded for user ID 1.",
+        ))
+        .await;
+
+        assert_that(&matches!(error, GitHubClientError::RateLimited(..))).is_true();
+    }
+
+    #[async_std::test]
+    async fn fails_as_rate_limited_when_secondary_rate_limit_is_exceeded() {
+        let error = get_default_branch_error(error_response(
+            StatusCode::Forbidden,
+            HashMap::new(),
+            "You have exceeded a secondary rate limit.",
+        ))
+        .await;
+
+        assert_that(&matches!(error, GitHubClientError::RateLimited(..))).is_true();
+    }
+
+    #[async_std::test]
+    async fn fails_as_rate_limited_when_github_responds_too_many_requests() {
+        let error = get_default_branch_error(error_response(
+            StatusCode::TooManyRequests,
+            HashMap::new(),
+            "Too many requests",
+        ))
+        .await;
+
+        assert_that(&matches!(error, GitHubClientError::RateLimited(..))).is_true();
+    }
+
+    #[async_std::test]
+    async fn fails_as_server_error_with_status_reason_when_body_has_no_message() {
+        let error = get_default_branch_error(Response::new(
+            StatusCode::BadGateway,
+            HashMap::new(),
+            "<html>bad gateway</html>".to_string(),
+        ))
+        .await;
+
+        assert_that(&error.to_string())
+            .is_equal_to("GitHub server error (502): Bad Gateway".to_string());
+    }
+
+    #[async_std::test]
+    async fn compares_branches() {
+        let mut mock_http_client = mock_http_client();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            repository_api_request(
+                "https://api.github.com/repos/owner/repo/compare/main...feature",
+            ),
+            successful_json_response(serde_json::json!(CompareResponseBody::new(1, 2))),
+        );
+
+        let under_test = GitHubClient::new(
+            mock_http_client,
+            mock_url_parser_for_owner_and_repo(),
+            mock_environment_reader(),
+        );
+
+        assert_that(
+            &under_test
+                .compare_branches(
+                    &RepositoryUrl::new("https://github.com/owner/repo".to_string()),
+                    &BranchName::new("main".to_string()),
+                    &BranchName::new("feature".to_string()),
+                )
+                .await
+                .unwrap(),
+        )
+        .is_equal_to(BranchComparison::new(1, 2));
+    }
+
+    #[async_std::test]
+    async fn escapes_branch_names_when_comparing_branches() {
+        let mut mock_http_client = mock_http_client();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            repository_api_request(
+                "https://api.github.com/repos/owner/repo/compare/main...feature/fix%2312%25",
+            ),
+            successful_json_response(serde_json::json!(CompareResponseBody::new(1, 2))),
+        );
+
+        let under_test = GitHubClient::new(
+            mock_http_client,
+            mock_url_parser_for_owner_and_repo(),
+            mock_environment_reader(),
+        );
+
+        assert_that(
+            &under_test
+                .compare_branches(
+                    &RepositoryUrl::new("https://github.com/owner/repo".to_string()),
+                    &BranchName::new("main".to_string()),
+                    &BranchName::new("feature/fix#12%".to_string()),
+                )
+                .await
+                .unwrap(),
+        )
+        .is_equal_to(BranchComparison::new(1, 2));
+    }
+
+    fn delete_branch_request(url: &str) -> Request {
+        let mut headers = HashMap::new();
+        headers.insert(
+            http_types::headers::ACCEPT,
+            "application/vnd.github.v3+json".to_string(),
+        );
+        Request::new(Method::Delete, Url::parse(url).unwrap(), headers)
+    }
+
+    #[async_std::test]
+    async fn deletes_branch() {
+        let mut mock_http_client = mock_http_client();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            delete_branch_request("https://api.github.com/repos/owner/repo/git/refs/heads/feature"),
+            Response::new(StatusCode::NoContent, HashMap::new(), "".to_string()),
+        );
+
+        let under_test = GitHubClient::new(
+            mock_http_client,
+            mock_url_parser_for_owner_and_repo(),
+            mock_environment_reader(),
+        );
+
+        assert_that(
+            &under_test
+                .delete_branch(
+                    &RepositoryUrl::new("https://github.com/owner/repo".to_string()),
+                    &BranchName::new("feature".to_string()),
+                )
+                .await,
+        )
+        .is_ok();
+    }
+
+    #[async_std::test]
+    async fn escapes_branch_name_when_deleting_branch() {
+        let mut mock_http_client = mock_http_client();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            delete_branch_request(
+                "https://api.github.com/repos/owner/repo/git/refs/heads/feature/fix%2312%25",
+            ),
+            Response::new(StatusCode::NoContent, HashMap::new(), "".to_string()),
+        );
+
+        let under_test = GitHubClient::new(
+            mock_http_client,
+            mock_url_parser_for_owner_and_repo(),
+            mock_environment_reader(),
+        );
+
+        assert_that(
+            &under_test
+                .delete_branch(
+                    &RepositoryUrl::new("https://github.com/owner/repo".to_string()),
+                    &BranchName::new("feature/fix#12%".to_string()),
+                )
+                .await,
+        )
+        .is_ok();
+    }
+
+    #[async_std::test]
+    async fn fails_to_delete_branch_when_github_refuses() {
+        let mut mock_http_client = mock_http_client();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            delete_branch_request("https://api.github.com/repos/owner/repo/git/refs/heads/feature"),
+            Response::new(
+                StatusCode::UnprocessableEntity,
+                HashMap::new(),
+                "".to_string(),
+            ),
+        );
+
+        let under_test = GitHubClient::new(
+            mock_http_client,
+            mock_url_parser_for_owner_and_repo(),
+            mock_environment_reader(),
+        );
+
+        let result = under_test
+            .delete_branch(
+                &RepositoryUrl::new("https://github.com/owner/repo".to_string()),
+                &BranchName::new("feature".to_string()),
+            )
+            .await;
+
+        assert_that(&matches!(
+            result,
+            Err(GitHubClientError::BranchNotDeleted { .. })
+        ))
+        .is_true();
+    }
+
+    #[async_std::test]
+    async fn lists_repositories_of_organization_across_all_pages() {
+        let next_page_url = "https://api.github.com/organizations/1/repos?per_page=100&page=2";
+        let mut first_page_headers = HashMap::new();
+        first_page_headers.insert(
+            HeaderName::from("link"),
+            format!("<{}>; rel=\"next\"", next_page_url),
+        );
+        let mut mock_http_client = mock_http_client();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            repository_api_request("https://api.github.com/orgs/org/repos?per_page=100"),
+            Response::new(
+                StatusCode::Ok,
+                first_page_headers,
+                serde_json::json!(ListRepositoriesResponseBody::new(vec![
+                    "https://github.com/org/repo1".to_string()
+                ]))
+                .to_string(),
+            ),
+        );
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            repository_api_request(next_page_url),
+            successful_json_response(serde_json::json!(ListRepositoriesResponseBody::new(vec![
+                "https://github.com/org/repo2".to_string()
+            ]))),
+        );
+        let under_test = GitHubClient::new(
+            mock_http_client,
+            mock_repository_url_parser(),
+            mock_environment_reader(),
+        );
+
+        assert_that(
+            &under_test
+                .list_repositories(&RepositoryOwner::Organization("org".to_string()))
+                .await
+                .unwrap(),
+        )
+        .is_equal_to(vec![
+            RepositoryUrl::new("https://github.com/org/repo1".to_string()),
+            RepositoryUrl::new("https://github.com/org/repo2".to_string()),
+        ]);
+    }
+
+    #[async_std::test]
+    async fn lists_repositories_of_user() {
+        let mut mock_http_client = mock_http_client();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            repository_api_request("https://api.github.com/users/someone/repos?per_page=100"),
+            successful_json_response(serde_json::json!(ListRepositoriesResponseBody::new(vec![
+                "https://github.com/someone/repo".to_string()
+            ]))),
+        );
+        let under_test = GitHubClient::new(
+            mock_http_client,
+            mock_repository_url_parser(),
+            mock_environment_reader(),
+        );
+
+        assert_that(
+            &under_test
+                .list_repositories(&RepositoryOwner::User("someone".to_string()))
+                .await
+                .unwrap(),
+        )
+        .is_equal_to(vec![RepositoryUrl::new(
+            "https://github.com/someone/repo".to_string(),
+        )]);
+    }
+
+    #[async_std::test]
+    async fn fails_to_list_repositories_of_missing_organization() {
+        let mut mock_http_client = mock_http_client();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            repository_api_request("https://api.github.com/orgs/missing/repos?per_page=100"),
+            Response::new(StatusCode::NotFound, HashMap::new(), "".to_string()),
+        );
+        let under_test = GitHubClient::new(
+            mock_http_client,
+            mock_repository_url_parser(),
+            mock_environment_reader(),
+        );
+
+        let result = under_test
+            .list_repositories(&RepositoryOwner::Organization("missing".to_string()))
+            .await;
+
+        assert_that(&result.err().unwrap().to_string())
+            .is_equal_to("organization 'missing' not found".to_string());
     }
 
     #[async_std::test]
@@ -477,4 +1368,21 @@ mod tests {
         )
         .is_equal_to(AuthenticationCredentialValidity::Invalid);
     }
+
+    #[test]
+    fn sends_requests_to_configured_github_enterprise_api() {
+        let mut mock_environment_reader = MockEnvironmentReader::default();
+        mock_environment_reader
+            .expect_read()
+            .returning(|_| Ok("https://api.github.test".to_string()));
+        let under_test = GitHubClient::new(
+            mock_http_client(),
+            mock_repository_url_parser(),
+            mock_environment_reader,
+        )
+        .with_api_base_url("https://github.corp.example/api/v3".to_string());
+
+        assert_that(&under_test.api_base_url())
+            .is_equal_to("https://github.corp.example/api/v3".to_string());
+    }
 }
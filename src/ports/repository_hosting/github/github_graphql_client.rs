@@ -0,0 +1,974 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use http_types::headers::HeaderName;
+use http_types::{Method, StatusCode, Url};
+use serde::de::DeserializeOwned;
+
+use crate::ports::repository_hosting::github::authentication_token::GitHubAuthenticationToken;
+use crate::ports::repository_hosting::github::error::GitHubClientError;
+use crate::ports::repository_hosting::github::github_client::response_error;
+use crate::ports::repository_hosting::github::graphql_responses::{
+    CommitNode, DeleteRefData, GraphQlError, GraphQlResponseBody, OwnerRepositoriesData, RefNode,
+    RepositoryData, RepositoryNode, ViewerData,
+};
+use crate::ports::repository_hosting::github::parse_repository_url::GitHubRepositoryUrlParser;
+use crate::ports::repository_hosting::github::repository::GitHubRepository;
+use crate::ports::repository_hosting::repository::{
+    Branch, BranchComparison, BranchName, Commit, PullRequest, RepositoryOwner, RepositoryUrl,
+};
+use crate::ports::repository_hosting::{
+    AuthenticationCredentialValidity, RepositoryHostClient, RepositoryListingClient,
+};
+use crate::utils::environment::EnvironmentReader;
+use crate::utils::http::{HttpClientFacade, Request};
+
+/// Selects a repository's default branch and a page of its branches, each with its head commit
+/// and most recent pull requests.
+const REPOSITORY_BRANCHES_FRAGMENT: &str = r#"
+fragment RepositoryBranches on Repository {
+  url
+  defaultBranchRef { name }
+  refs(refPrefix: "refs/heads/", first: 100, after: $branchesAfter, orderBy: {field: ALPHABETICAL, direction: ASC}) {
+    pageInfo { hasNextPage endCursor }
+    nodes {
+      name
+      branchProtectionRule { id }
+      target { oid ... on Commit { committedDate author { name } } }
+      associatedPullRequests(first: 5, orderBy: {field: CREATED_AT, direction: DESC}) {
+        nodes { number state url }
+      }
+    }
+  }
+}"#;
+
+const REPOSITORY_BRANCHES_QUERY: &str = r#"
+query($owner: String!, $name: String!, $branchesAfter: String) {
+  repository(owner: $owner, name: $name) { ...RepositoryBranches }
+}"#;
+
+/// Lists repositories 25 at a time so that a page, including the first page of branches of each
+/// repository, stays well within GitHub's limits on the size of a query.
+const OWNER_REPOSITORIES_QUERY: &str = r#"
+query($login: String!, $after: String, $branchesAfter: String) {
+  owner: OWNER_FIELD(login: $login) {
+    repositories(first: 25, after: $after, orderBy: {field: NAME, direction: ASC}) {
+      pageInfo { hasNextPage endCursor }
+      nodes { ...RepositoryBranches }
+    }
+  }
+}"#;
+
+const DEFAULT_BRANCH_QUERY: &str = r#"
+query($owner: String!, $name: String!) {
+  repository(owner: $owner, name: $name) { defaultBranchRef { name } }
+}"#;
+
+const COMMIT_QUERY: &str = r#"
+query($owner: String!, $name: String!, $sha: String!) {
+  repository(owner: $owner, name: $name) {
+    object(expression: $sha) { oid ... on Commit { committedDate author { name } } }
+  }
+}"#;
+
+const COMPARE_QUERY: &str = r#"
+query($owner: String!, $name: String!, $base: String!, $head: String!) {
+  repository(owner: $owner, name: $name) {
+    ref(qualifiedName: $base) { compare(headRef: $head) { aheadBy behindBy } }
+  }
+}"#;
+
+const REF_ID_QUERY: &str = r#"
+query($owner: String!, $name: String!, $ref: String!) {
+  repository(owner: $owner, name: $name) { ref(qualifiedName: $ref) { id } }
+}"#;
+
+const DELETE_REF_MUTATION: &str = r#"
+mutation($id: ID!) {
+  deleteRef(input: {refId: $id}) { clientMutationId }
+}"#;
+
+const VIEWER_QUERY: &str = "query { viewer { login } }";
+
+/// What has been learned about a repository from branch listings, so that later requests for
+/// its default branch or head commits need no further queries.
+#[derive(Debug, Default)]
+struct RepositorySnapshot {
+    default_branch: Option<String>,
+    branches: Vec<Branch>,
+    commits: HashMap<String, Commit>,
+    next_branches_cursor: Option<String>,
+}
+
+/// A GitHub client for the GraphQL API.
+///
+/// Listing branches also fetches their head commits and pull requests, and listing the
+/// repositories of an owner also fetches the first page of branches of every repository, so a
+/// scan of a whole organization takes a few queries instead of a request per branch.
+#[derive(Debug)]
+pub struct GitHubGraphQlClient<
+    HttpClient: HttpClientFacade,
+    UrlParser: GitHubRepositoryUrlParser,
+    EnvReader: EnvironmentReader,
+> {
+    http_client: HttpClient,
+    url_parser: UrlParser,
+    environment_reader: EnvReader,
+    api_base_url: Option<String>,
+    personal_access_token: Option<GitHubAuthenticationToken>,
+    snapshots: Mutex<HashMap<String, RepositorySnapshot>>,
+}
+
+impl<HttpClient, UrlParser, EnvReader> GitHubGraphQlClient<HttpClient, UrlParser, EnvReader>
+where
+    HttpClient: HttpClientFacade,
+    UrlParser: GitHubRepositoryUrlParser,
+    EnvReader: EnvironmentReader,
+{
+    pub fn new(
+        http_client: HttpClient,
+        url_parser: UrlParser,
+        environment_reader: EnvReader,
+    ) -> Self {
+        GitHubGraphQlClient {
+            http_client,
+            url_parser,
+            environment_reader,
+            api_base_url: None,
+            personal_access_token: None,
+            snapshots: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Queries a GitHub Enterprise Server instance, given the URL of its REST API, instead of
+    /// github.com. Enterprise servers serve GraphQL at `/api/graphql` next to `/api/v3`.
+    pub fn with_api_base_url(mut self, api_base_url: String) -> Self {
+        self.api_base_url = Some(api_base_url);
+        self
+    }
+
+    fn graphql_api_url(&self) -> Result<Url, GitHubClientError> {
+        let api_base_url = match self.api_base_url.as_deref() {
+            Some(api_base_url) => api_base_url.trim_end_matches("/v3").to_string(),
+            None => self.default_api_base_url(),
+        };
+        Url::parse(format!("{}/graphql", api_base_url).as_str())
+            .map_err(GitHubClientError::ApiUrlParseError)
+    }
+
+    fn default_api_base_url(&self) -> String {
+        match self
+            .environment_reader
+            .read("TIDY_REPO_GITHUB_API_BASE_URL")
+        {
+            Ok(env_value) => env_value,
+            Err(_) => "https://api.github.com".to_string(),
+        }
+    }
+
+    fn graphql_api_headers(
+        token: Option<&GitHubAuthenticationToken>,
+    ) -> HashMap<HeaderName, String> {
+        let mut headers = HashMap::new();
+        headers.insert(
+            http_types::headers::CONTENT_TYPE,
+            "application/json".to_string(),
+        );
+        if let Some(token) = token {
+            headers.insert(
+                http_types::headers::AUTHORIZATION,
+                format!("bearer {}", token.value()),
+            );
+        }
+        headers
+    }
+
+    fn graphql_request(
+        &self,
+        query: &str,
+        variables: serde_json::Value,
+        token: Option<&GitHubAuthenticationToken>,
+    ) -> Result<Request, GitHubClientError> {
+        let body = serde_json::json!({ "query": query, "variables": variables });
+        Ok(Request::new(
+            Method::Post,
+            self.graphql_api_url()?,
+            Self::graphql_api_headers(token),
+        )
+        .with_body(body.to_string()))
+    }
+
+    /// Maps the first error of a GraphQL response, which GitHub sends with a 200 status.
+    fn graphql_error(error: &GraphQlError, not_found: GitHubClientError) -> GitHubClientError {
+        let message = error.message().clone();
+        match error.error_type().map(String::as_str) {
+            Some("NOT_FOUND") => not_found,
+            Some("RATE_LIMITED") => GitHubClientError::RateLimited(message),
+            Some("FORBIDDEN") | Some("INSUFFICIENT_SCOPES") => {
+                GitHubClientError::Forbidden(message)
+            }
+            _ => GitHubClientError::UnexpectedResponse {
+                status: StatusCode::Ok.into(),
+                message,
+            },
+        }
+    }
+
+    async fn query<T: DeserializeOwned>(
+        &self,
+        query: &str,
+        variables: serde_json::Value,
+        not_found: GitHubClientError,
+    ) -> Result<T, GitHubClientError> {
+        let response = self
+            .http_client
+            .send(self.graphql_request(query, variables, self.personal_access_token.as_ref())?)
+            .await?;
+
+        if response.status_code() != StatusCode::Ok {
+            return Err(response_error(&response, not_found));
+        }
+
+        match response.body_json::<GraphQlResponseBody<T>>()?.into_parts() {
+            (_, errors) if !errors.is_empty() => Err(Self::graphql_error(&errors[0], not_found)),
+            (Some(data), _) => Ok(data),
+            (None, _) => Err(GitHubClientError::UnexpectedResponse {
+                status: StatusCode::Ok.into(),
+                message: "response contained no data".to_string(),
+            }),
+        }
+    }
+
+    async fn query_repository(
+        &self,
+        query: &str,
+        repository: &GitHubRepository,
+        variables: serde_json::Value,
+        repository_url: &RepositoryUrl,
+    ) -> Result<RepositoryNode, GitHubClientError> {
+        let mut all_variables = serde_json::json!({
+            "owner": repository.owner(),
+            "name": repository.name(),
+        });
+        if let (Some(all), Some(extra)) = (all_variables.as_object_mut(), variables.as_object()) {
+            all.extend(extra.clone());
+        }
+        self.query::<RepositoryData>(
+            query,
+            all_variables,
+            GitHubClientError::RepositoryNotFound(repository_url.clone()),
+        )
+        .await?
+        .repository()
+        .ok_or_else(|| GitHubClientError::RepositoryNotFound(repository_url.clone()))
+    }
+
+    fn snapshot_key(repository: &GitHubRepository) -> String {
+        format!("{}/{}", repository.owner(), repository.name()).to_lowercase()
+    }
+
+    fn commit(node: &CommitNode) -> Option<Commit> {
+        node.committed_date().map(|date| {
+            Commit::new(
+                node.oid().clone(),
+                *date,
+                node.author().cloned().unwrap_or_default(),
+            )
+        })
+    }
+
+    fn branch(node: &RefNode) -> Branch {
+        Branch::new(
+            BranchName::new(node.name().clone()),
+            node.target().oid().clone(),
+        )
+        .marked_protected(node.is_protected())
+        .with_pull_requests(
+            node.pull_requests()
+                .iter()
+                .map(|pull_request| {
+                    PullRequest::new(
+                        pull_request.number(),
+                        pull_request.state().clone(),
+                        pull_request.url().clone(),
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    /// Records a page of branches. The first page replaces anything known about the repository,
+    /// later pages extend it. Returns the cursor of the next page, if there is one.
+    fn remember(&self, key: String, node: &RepositoryNode, first_page: bool) -> Option<String> {
+        let refs = node.refs()?;
+        let mut snapshots = self.snapshots.lock().unwrap();
+        if first_page {
+            snapshots.remove(&key);
+        }
+        let snapshot = snapshots.entry(key).or_default();
+        if let Some(default_branch) = node.default_branch() {
+            snapshot.default_branch = Some(default_branch.clone());
+        }
+        refs.nodes().iter().for_each(|ref_node| {
+            snapshot.branches.push(Self::branch(ref_node));
+            if let Some(commit) = Self::commit(ref_node.target()) {
+                snapshot.commits.insert(commit.sha().clone(), commit);
+            }
+        });
+        snapshot.next_branches_cursor = refs.page_info().next_cursor().cloned();
+        snapshot.next_branches_cursor.clone()
+    }
+
+    /// Branches already listed, or the cursor to continue listing them from. `None` when the
+    /// repository has not been listed yet.
+    fn remembered_branches(&self, key: &str) -> Option<Result<Vec<Branch>, String>> {
+        let snapshots = self.snapshots.lock().unwrap();
+        snapshots
+            .get(key)
+            .map(|snapshot| match &snapshot.next_branches_cursor {
+                Some(cursor) => Err(cursor.clone()),
+                None => Ok(snapshot.branches.clone()),
+            })
+    }
+}
+
+#[async_trait]
+impl<HttpClient, UrlParser, EnvReader> RepositoryHostClient
+    for GitHubGraphQlClient<HttpClient, UrlParser, EnvReader>
+where
+    HttpClient: HttpClientFacade + Send + Sync,
+    UrlParser: GitHubRepositoryUrlParser + Send + Sync,
+    EnvReader: EnvironmentReader + Send + Sync,
+{
+    type Err = GitHubClientError;
+    type AuthenticationCredentials = GitHubAuthenticationToken;
+
+    async fn list_branches(
+        &self,
+        repository_url: &RepositoryUrl,
+    ) -> Result<Vec<Branch>, Self::Err> {
+        let repository = self.url_parser.parse(repository_url.clone())?;
+        let key = Self::snapshot_key(&repository);
+        let mut cursor = match self.remembered_branches(&key) {
+            Some(Ok(branches)) => return Ok(branches),
+            Some(Err(cursor)) => Some(cursor),
+            None => None,
+        };
+
+        let mut first_page = cursor.is_none();
+        loop {
+            let node = self
+                .query_repository(
+                    format!(
+                        "{}{}",
+                        REPOSITORY_BRANCHES_QUERY, REPOSITORY_BRANCHES_FRAGMENT
+                    )
+                    .as_str(),
+                    &repository,
+                    serde_json::json!({ "branchesAfter": cursor }),
+                    repository_url,
+                )
+                .await?;
+            cursor = self.remember(key.clone(), &node, first_page);
+            first_page = false;
+            if cursor.is_none() {
+                break;
+            }
+        }
+
+        Ok(self
+            .remembered_branches(&key)
+            .and_then(Result::ok)
+            .unwrap_or_default())
+    }
+
+    async fn get_commit(
+        &self,
+        repository_url: &RepositoryUrl,
+        sha: &str,
+    ) -> Result<Commit, Self::Err> {
+        let repository = self.url_parser.parse(repository_url.clone())?;
+        let remembered = self
+            .snapshots
+            .lock()
+            .unwrap()
+            .get(&Self::snapshot_key(&repository))
+            .and_then(|snapshot| snapshot.commits.get(sha).cloned());
+        if let Some(commit) = remembered {
+            return Ok(commit);
+        }
+
+        let node = self
+            .query_repository(
+                COMMIT_QUERY,
+                &repository,
+                serde_json::json!({ "sha": sha }),
+                repository_url,
+            )
+            .await?;
+        node.object()
+            .and_then(Self::commit)
+            .ok_or_else(|| GitHubClientError::RepositoryNotFound(repository_url.clone()))
+    }
+
+    /// An empty repository has no default branch, and no branches to compare with one, so its
+    /// default branch name is empty.
+    async fn get_default_branch(
+        &self,
+        repository_url: &RepositoryUrl,
+    ) -> Result<BranchName, Self::Err> {
+        let repository = self.url_parser.parse(repository_url.clone())?;
+        let remembered = self
+            .snapshots
+            .lock()
+            .unwrap()
+            .get(&Self::snapshot_key(&repository))
+            .map(|snapshot| snapshot.default_branch.clone().unwrap_or_default());
+        if let Some(default_branch) = remembered {
+            return Ok(BranchName::new(default_branch));
+        }
+
+        let node = self
+            .query_repository(
+                DEFAULT_BRANCH_QUERY,
+                &repository,
+                serde_json::json!({}),
+                repository_url,
+            )
+            .await?;
+        Ok(BranchName::new(
+            node.default_branch().cloned().unwrap_or_default(),
+        ))
+    }
+
+    async fn compare_branches(
+        &self,
+        repository_url: &RepositoryUrl,
+        base: &BranchName,
+        head: &BranchName,
+    ) -> Result<BranchComparison, Self::Err> {
+        let repository = self.url_parser.parse(repository_url.clone())?;
+
+        let node = self
+            .query_repository(
+                COMPARE_QUERY,
+                &repository,
+                serde_json::json!({
+                    "base": format!("refs/heads/{}", base.value()),
+                    "head": head.value(),
+                }),
+                repository_url,
+            )
+            .await?;
+        node.named_ref()
+            .and_then(|named_ref| named_ref.compare())
+            .map(|comparison| BranchComparison::new(comparison.ahead_by(), comparison.behind_by()))
+            .ok_or_else(|| GitHubClientError::RepositoryNotFound(repository_url.clone()))
+    }
+
+    async fn delete_branch(
+        &self,
+        repository_url: &RepositoryUrl,
+        branch: &BranchName,
+    ) -> Result<(), Self::Err> {
+        let repository = self.url_parser.parse(repository_url.clone())?;
+        let not_deleted = || GitHubClientError::BranchNotDeleted {
+            repository_url: repository_url.clone(),
+            branch: branch.value().clone(),
+        };
+
+        let node = self
+            .query_repository(
+                REF_ID_QUERY,
+                &repository,
+                serde_json::json!({ "ref": format!("refs/heads/{}", branch.value()) }),
+                repository_url,
+            )
+            .await?;
+        let id = node
+            .named_ref()
+            .and_then(|named_ref| named_ref.id())
+            .ok_or_else(not_deleted)?;
+
+        self.query::<DeleteRefData>(
+            DELETE_REF_MUTATION,
+            serde_json::json!({ "id": id }),
+            GitHubClientError::RepositoryNotFound(repository_url.clone()),
+        )
+        .await
+        .map(|_| ())
+        .map_err(|err| match err {
+            GitHubClientError::UnexpectedResponse { .. } => not_deleted(),
+            err => err,
+        })
+    }
+
+    fn set_authentication_credentials(&mut self, credentials: Self::AuthenticationCredentials) {
+        self.personal_access_token = Some(credentials);
+    }
+
+    async fn validate_authentication_credentials(
+        &self,
+        credentials: Self::AuthenticationCredentials,
+    ) -> Result<AuthenticationCredentialValidity, Self::Err> {
+        let response = self
+            .http_client
+            .send(self.graphql_request(VIEWER_QUERY, serde_json::json!({}), Some(&credentials))?)
+            .await?;
+
+        match response.status_code() {
+            StatusCode::Ok => {
+                response.body_json::<GraphQlResponseBody<ViewerData>>()?;
+                Ok(AuthenticationCredentialValidity::Valid)
+            }
+            StatusCode::Unauthorized => Ok(AuthenticationCredentialValidity::Invalid),
+            status => {
+                let unexpected = GitHubClientError::UnexpectedResponse {
+                    status: status.into(),
+                    message: status.canonical_reason().to_string(),
+                };
+                Err(response_error(&response, unexpected))
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl<HttpClient, UrlParser, EnvReader> RepositoryListingClient
+    for GitHubGraphQlClient<HttpClient, UrlParser, EnvReader>
+where
+    HttpClient: HttpClientFacade + Send + Sync,
+    UrlParser: GitHubRepositoryUrlParser + Send + Sync,
+    EnvReader: EnvironmentReader + Send + Sync,
+{
+    async fn list_repositories(
+        &self,
+        owner: &RepositoryOwner,
+    ) -> Result<Vec<RepositoryUrl>, GitHubClientError> {
+        let (owner_field, login) = match owner {
+            RepositoryOwner::Organization(org) => ("organization", org),
+            RepositoryOwner::User(user) => ("user", user),
+        };
+        let query = format!(
+            "{}{}",
+            OWNER_REPOSITORIES_QUERY.replace("OWNER_FIELD", owner_field),
+            REPOSITORY_BRANCHES_FRAGMENT
+        );
+        let mut repository_urls = Vec::new();
+        let mut cursor: Option<String> = None;
+
+        loop {
+            let repositories = self
+                .query::<OwnerRepositoriesData>(
+                    query.as_str(),
+                    serde_json::json!({ "login": login, "after": cursor }),
+                    GitHubClientError::OwnerNotFound(owner.clone()),
+                )
+                .await?
+                .repositories()
+                .ok_or_else(|| GitHubClientError::OwnerNotFound(owner.clone()))?;
+
+            repositories.nodes().iter().for_each(|node| {
+                if let Some(url) = node.url() {
+                    let url = RepositoryUrl::new(url.clone());
+                    if let Ok(repository) = self.url_parser.parse(url.clone()) {
+                        self.remember(Self::snapshot_key(&repository), node, true);
+                    }
+                    repository_urls.push(url);
+                }
+            });
+            cursor = repositories.page_info().next_cursor().cloned();
+            if cursor.is_none() {
+                break;
+            }
+        }
+
+        Ok(repository_urls)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env::VarError;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use chrono::{TimeZone, Utc};
+    use spectral::prelude::*;
+
+    use crate::ports::repository_hosting::github::graphql_responses::{
+        PullRequestNode, RefWithComparison,
+    };
+    use crate::ports::repository_hosting::github::GitHubRepositoryUrlParserImpl;
+    use crate::utils::environment::{EnvironmentReaderError, MockEnvironmentReader};
+    use crate::utils::http::{MockHttpClientFacade, Response};
+    use crate::utils::test_helpers::async_this;
+
+    use super::*;
+
+    const URL: &str = "https://github.com/owner/repo";
+
+    type UnderTest = GitHubGraphQlClient<
+        MockHttpClientFacade,
+        GitHubRepositoryUrlParserImpl,
+        MockEnvironmentReader,
+    >;
+
+    fn mock_environment_reader() -> MockEnvironmentReader {
+        let mut reader = MockEnvironmentReader::default();
+        reader
+            .expect_read()
+            .returning(|_| Err(EnvironmentReaderError::ReadError(VarError::NotPresent)));
+        reader
+    }
+
+    fn response(status_code: StatusCode, body: serde_json::Value) -> Response {
+        Response::new(status_code, HashMap::new(), body.to_string())
+    }
+
+    fn data(data: impl serde::Serialize) -> Response {
+        response(
+            StatusCode::Ok,
+            serde_json::json!(GraphQlResponseBody::new(Some(data), vec![])),
+        )
+    }
+
+    /// A client whose GraphQL endpoint answers with the given responses in order, and which
+    /// records the variables of every query it is sent.
+    fn under_test(responses: Vec<Response>) -> (UnderTest, Arc<Mutex<Vec<serde_json::Value>>>) {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&requests);
+        let calls = AtomicUsize::new(0);
+        let mut mock_http_client = MockHttpClientFacade::default();
+        mock_http_client
+            .expect_send()
+            .times(responses.len())
+            .returning(move |req| {
+                assert_that(&req.method()).is_equal_to(Method::Post);
+                assert_that(&req.url().as_str()).is_equal_to("https://api.github.com/graphql");
+                let body: serde_json::Value =
+                    serde_json::from_str(req.body().unwrap().as_str()).unwrap();
+                recorded.lock().unwrap().push(body["variables"].clone());
+                let response = responses[calls.fetch_add(1, Ordering::SeqCst)].clone();
+                Box::pin(async_this(Ok(response)))
+            });
+        let mut client = GitHubGraphQlClient::new(
+            mock_http_client,
+            GitHubRepositoryUrlParserImpl::new(),
+            mock_environment_reader(),
+        );
+        client.set_authentication_credentials(GitHubAuthenticationToken::new("token".to_string()));
+        (client, requests)
+    }
+
+    fn repository_url() -> RepositoryUrl {
+        RepositoryUrl::new(URL.to_string())
+    }
+
+    fn commit_node(sha: &str, year: i32) -> CommitNode {
+        CommitNode::new(
+            sha,
+            Utc.with_ymd_and_hms(year, 1, 1, 0, 0, 0).unwrap(),
+            "author",
+        )
+    }
+
+    fn branches_page(url: &str, names: &[&str], end_cursor: Option<&str>) -> RepositoryNode {
+        RepositoryNode::with_branches(
+            url,
+            "main",
+            names
+                .iter()
+                .map(|name| RefNode::new(name, commit_node(&format!("{}-sha", name), 2020), vec![]))
+                .collect(),
+            end_cursor.map(str::to_string),
+        )
+    }
+
+    fn branch(name: &str) -> Branch {
+        Branch::new(BranchName::new(name.to_string()), format!("{}-sha", name))
+    }
+
+    #[async_std::test]
+    async fn lists_branches_with_protection_and_pull_requests() {
+        let node = RepositoryNode::with_branches(
+            URL,
+            "main",
+            vec![
+                RefNode::new("main", commit_node("main-sha", 2020), vec![]).protected(),
+                RefNode::new(
+                    "feature",
+                    commit_node("feature-sha", 2021),
+                    vec![PullRequestNode::new(
+                        7,
+                        "OPEN",
+                        "https://github.com/owner/repo/pull/7",
+                    )],
+                ),
+            ],
+            None,
+        );
+        let (under_test, _) = under_test(vec![data(RepositoryData::new(Some(node)))]);
+
+        assert_that(&under_test.list_branches(&repository_url()).await.unwrap()).is_equal_to(vec![
+            branch("main").marked_protected(true),
+            branch("feature").with_pull_requests(vec![PullRequest::new(
+                7,
+                "OPEN".to_string(),
+                "https://github.com/owner/repo/pull/7".to_string(),
+            )]),
+        ]);
+    }
+
+    #[async_std::test]
+    async fn lists_branches_across_multiple_pages() {
+        let (under_test, requests) = under_test(vec![
+            data(RepositoryData::new(Some(branches_page(
+                URL,
+                &["a"],
+                Some("cursor-1"),
+            )))),
+            data(RepositoryData::new(Some(branches_page(URL, &["b"], None)))),
+        ]);
+
+        assert_that(&under_test.list_branches(&repository_url()).await.unwrap())
+            .is_equal_to(vec![branch("a"), branch("b")]);
+        assert_that(&requests.lock().unwrap()[1]["branchesAfter"])
+            .is_equal_to(serde_json::json!("cursor-1"));
+    }
+
+    #[async_std::test]
+    async fn answers_default_branch_and_head_commits_from_listed_branches() {
+        let (under_test, _) = under_test(vec![data(RepositoryData::new(Some(branches_page(
+            URL,
+            &["main"],
+            None,
+        ))))]);
+        under_test.list_branches(&repository_url()).await.unwrap();
+
+        assert_that(
+            &under_test
+                .get_default_branch(&repository_url())
+                .await
+                .unwrap(),
+        )
+        .is_equal_to(BranchName::new("main".to_string()));
+        assert_that(
+            &under_test
+                .get_commit(&repository_url(), "main-sha")
+                .await
+                .unwrap(),
+        )
+        .is_equal_to(Commit::new(
+            "main-sha".to_string(),
+            Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap(),
+            "author".to_string(),
+        ));
+        assert_that(&under_test.list_branches(&repository_url()).await.unwrap())
+            .is_equal_to(vec![branch("main")]);
+    }
+
+    #[async_std::test]
+    async fn queries_commit_that_was_not_listed() {
+        let (under_test, requests) = under_test(vec![data(RepositoryData::new(Some(
+            RepositoryNode::with_object(Some(commit_node("sha", 2019))),
+        )))]);
+
+        assert_that(
+            &under_test
+                .get_commit(&repository_url(), "sha")
+                .await
+                .unwrap()
+                .sha(),
+        )
+        .is_equal_to(&"sha".to_string());
+        assert_that(&requests.lock().unwrap()[0]).is_equal_to(serde_json::json!({
+            "owner": "owner",
+            "name": "repo",
+            "sha": "sha",
+        }));
+    }
+
+    #[async_std::test]
+    async fn fails_when_repository_does_not_exist() {
+        let (under_test, _) = under_test(vec![response(
+            StatusCode::Ok,
+            serde_json::json!(GraphQlResponseBody::<RepositoryData>::new(
+                Some(RepositoryData::new(None)),
+                vec![GraphQlError::new(
+                    Some("NOT_FOUND".to_string()),
+                    "Could not resolve to a Repository with the name 'owner/repo'.".to_string(),
+                )],
+            )),
+        )]);
+
+        let result = under_test.list_branches(&repository_url()).await;
+
+        assert_that(&result.err().unwrap().to_string())
+            .is_equal_to(format!("repository '{}' not found", URL));
+    }
+
+    #[async_std::test]
+    async fn fails_when_rate_limited() {
+        let (under_test, _) = under_test(vec![response(
+            StatusCode::Ok,
+            serde_json::json!(GraphQlResponseBody::<RepositoryData>::new(
+                None,
+                vec![GraphQlError::new(
+                    Some("RATE_LIMITED".to_string()),
+                    "API rate limit exceeded".to_string(),
+                )],
+            )),
+        )]);
+
+        let result = under_test.get_default_branch(&repository_url()).await;
+
+        assert_that(&matches!(
+            result.err().unwrap(),
+            GitHubClientError::RateLimited(..)
+        ))
+        .is_true();
+    }
+
+    #[async_std::test]
+    async fn fails_when_token_is_rejected() {
+        let (under_test, _) = under_test(vec![response(
+            StatusCode::Unauthorized,
+            serde_json::json!({ "message": "Bad credentials" }),
+        )]);
+
+        let result = under_test.list_branches(&repository_url()).await;
+
+        assert_that(&result.err().unwrap().to_string())
+            .is_equal_to("GitHub rejected the authentication token: Bad credentials".to_string());
+    }
+
+    #[async_std::test]
+    async fn compares_branches() {
+        let (under_test, requests) = under_test(vec![data(RepositoryData::new(Some(
+            RepositoryNode::with_ref(Some(RefWithComparison::new(None, Some((2, 3))))),
+        )))]);
+
+        assert_that(
+            &under_test
+                .compare_branches(
+                    &repository_url(),
+                    &BranchName::new("main".to_string()),
+                    &BranchName::new("feature".to_string()),
+                )
+                .await
+                .unwrap(),
+        )
+        .is_equal_to(BranchComparison::new(2, 3));
+        assert_that(&requests.lock().unwrap()[0]["base"])
+            .is_equal_to(serde_json::json!("refs/heads/main"));
+    }
+
+    #[async_std::test]
+    async fn deletes_branch_by_its_ref_id() {
+        let (under_test, requests) = under_test(vec![
+            data(RepositoryData::new(Some(RepositoryNode::with_ref(Some(
+                RefWithComparison::new(Some("ref-id"), None),
+            ))))),
+            data(serde_json::json!({ "deleteRef": { "clientMutationId": null } })),
+        ]);
+
+        assert_that(
+            &under_test
+                .delete_branch(&repository_url(), &BranchName::new("merged".to_string()))
+                .await,
+        )
+        .is_ok();
+        assert_that(&requests.lock().unwrap()[1])
+            .is_equal_to(serde_json::json!({ "id": "ref-id" }));
+    }
+
+    #[async_std::test]
+    async fn fails_to_delete_branch_that_does_not_exist() {
+        let (under_test, _) = under_test(vec![data(RepositoryData::new(Some(
+            RepositoryNode::with_ref(None),
+        )))]);
+
+        let result = under_test
+            .delete_branch(&repository_url(), &BranchName::new("gone".to_string()))
+            .await;
+
+        assert_that(&matches!(
+            result.err().unwrap(),
+            GitHubClientError::BranchNotDeleted { .. }
+        ))
+        .is_true();
+    }
+
+    #[async_std::test]
+    async fn lists_repositories_of_organization_with_their_branches() {
+        let (under_test, requests) = under_test(vec![
+            data(OwnerRepositoriesData::new(
+                vec![branches_page("https://github.com/org/one", &["main"], None)],
+                Some("cursor-1".to_string()),
+            )),
+            data(OwnerRepositoriesData::new(
+                vec![branches_page(
+                    "https://github.com/org/two",
+                    &["main", "b"],
+                    None,
+                )],
+                None,
+            )),
+        ]);
+
+        assert_that(
+            &under_test
+                .list_repositories(&RepositoryOwner::Organization("org".to_string()))
+                .await
+                .unwrap(),
+        )
+        .is_equal_to(vec![
+            RepositoryUrl::new("https://github.com/org/one".to_string()),
+            RepositoryUrl::new("https://github.com/org/two".to_string()),
+        ]);
+        assert_that(
+            &under_test
+                .list_branches(&RepositoryUrl::new(
+                    "https://github.com/org/two".to_string(),
+                ))
+                .await
+                .unwrap(),
+        )
+        .is_equal_to(vec![branch("main"), branch("b")]);
+        assert_that(&requests.lock().unwrap()[1]["after"])
+            .is_equal_to(serde_json::json!("cursor-1"));
+    }
+
+    #[async_std::test]
+    async fn validates_authentication_credentials() {
+        let (under_test, _) = under_test(vec![response(
+            StatusCode::Unauthorized,
+            serde_json::json!({ "message": "Bad credentials" }),
+        )]);
+
+        assert_that(
+            &under_test
+                .validate_authentication_credentials(GitHubAuthenticationToken::new(
+                    "bad".to_string(),
+                ))
+                .await
+                .unwrap(),
+        )
+        .is_equal_to(AuthenticationCredentialValidity::Invalid);
+    }
+
+    #[test]
+    fn queries_graphql_api_next_to_rest_api_of_github_enterprise_server() {
+        let under_test = GitHubGraphQlClient::new(
+            MockHttpClientFacade::default(),
+            GitHubRepositoryUrlParserImpl::for_host("github.corp.example"),
+            mock_environment_reader(),
+        )
+        .with_api_base_url("https://github.corp.example/api/v3".to_string());
+
+        assert_that(&under_test.graphql_api_url().unwrap().as_str())
+            .is_equal_to("https://github.corp.example/api/graphql");
+    }
+}
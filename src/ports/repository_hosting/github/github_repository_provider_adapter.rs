@@ -1,48 +1,74 @@
 use std::marker::PhantomData;
 
-use crate::domain::authentication::credential_repository::CredentialRepository;
-use crate::domain::repository::{Branch, RepositoryProviderError};
-use crate::domain::repository::{Repository, RepositoryProvider, RepositoryUrl};
+use crate::application::DEFAULT_CONCURRENCY;
+use crate::domain::authentication::credential_repository::{CredentialKey, CredentialRepository};
+use crate::domain::repository::{Branch, BranchComparison, RepositoryProviderError};
+use crate::domain::repository::{
+    Repository, RepositoryCatalog, RepositoryMaintainer, RepositoryOwner, RepositoryProvider,
+    RepositoryUrl,
+};
 use crate::domain::value_object::ValueObject;
 use crate::ports::repository_hosting::github::error::GitHubClientError;
-use crate::ports::repository_hosting::github::repository::RepositoryUrl as RepositoryClientRepositoryUrl;
 use crate::ports::repository_hosting::github::{
     authentication_token::GitHubAuthenticationToken as RepositoryClientGitHubAuthenticationToken,
-    RepositoryHostClient, RepositoryUrlParseError,
+    RepositoryUrlParseError,
+};
+use crate::ports::repository_hosting::repository::{
+    BranchName, RepositoryOwner as RepositoryClientRepositoryOwner,
+    RepositoryUrl as RepositoryClientRepositoryUrl,
+};
+use crate::ports::repository_hosting::{
+    repository_branches, RepositoryHostClient, RepositoryListingClient,
 };
 
 #[derive(Default)]
 pub struct GitHubRepositoryProviderAdapter<GC, CR>
 where
-    GC: RepositoryHostClient<
-        Err = GitHubClientError,
-        AuthenticationCredentials = RepositoryClientGitHubAuthenticationToken,
-    >,
+    GC: RepositoryHostClient<AuthenticationCredentials = RepositoryClientGitHubAuthenticationToken>,
     CR: CredentialRepository,
 {
     github_client: GC,
+    concurrency: usize,
     credential_repository_type_marker: PhantomData<CR>,
 }
 
 impl<GC, CR> GitHubRepositoryProviderAdapter<GC, CR>
 where
-    GC: RepositoryHostClient<
-        Err = GitHubClientError,
-        AuthenticationCredentials = RepositoryClientGitHubAuthenticationToken,
-    >,
+    GC: RepositoryHostClient<AuthenticationCredentials = RepositoryClientGitHubAuthenticationToken>,
     CR: CredentialRepository,
 {
-    pub fn new(mut github_client: GC, credential_repository: CR) -> Self {
-        Self::authenticate_github_client(&mut github_client, &credential_repository);
+    pub fn new(
+        mut github_client: GC,
+        credential_repository: CR,
+        credential_key: &CredentialKey,
+    ) -> Self {
+        Self::authenticate_github_client(
+            &mut github_client,
+            &credential_repository,
+            credential_key,
+        );
 
         GitHubRepositoryProviderAdapter {
             github_client,
-            credential_repository_type_marker: PhantomData::default(),
+            concurrency: DEFAULT_CONCURRENCY,
+            credential_repository_type_marker: PhantomData,
         }
     }
 
-    fn authenticate_github_client(github_client: &mut GC, credential_repository: &CR) {
-        if let Ok(credentials) = async_std::task::block_on(credential_repository.get()) {
+    /// Limits how many head commits of a repository's branches are fetched at the same time.
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    fn authenticate_github_client(
+        github_client: &mut GC,
+        credential_repository: &CR,
+        credential_key: &CredentialKey,
+    ) {
+        if let Ok(credentials) =
+            async_std::task::block_on(credential_repository.get(credential_key))
+        {
             github_client.set_authentication_credentials(
                 RepositoryClientGitHubAuthenticationToken::new(credentials.value()),
             );
@@ -53,11 +79,11 @@ where
 #[async_trait::async_trait]
 impl<GC, CR> RepositoryProvider for GitHubRepositoryProviderAdapter<GC, CR>
 where
-    GC: RepositoryHostClient<
-            Err = GitHubClientError,
-            AuthenticationCredentials = RepositoryClientGitHubAuthenticationToken,
-        > + Sync
+    GC: RepositoryHostClient<AuthenticationCredentials = RepositoryClientGitHubAuthenticationToken>
+        + Sync
         + Send,
+    GC::Err: Send,
+    RepositoryProviderError: From<GC::Err>,
     CR: CredentialRepository + Sync + Send,
 {
     async fn get_repository(
@@ -65,16 +91,117 @@ where
         url: &RepositoryUrl,
     ) -> Result<Repository, RepositoryProviderError> {
         let url_dto = RepositoryClientRepositoryUrl::new(url.value().clone());
-        let branches = self
-            .github_client
-            .list_branches(&url_dto)
+        let branches = repository_branches::list_branches(&self.github_client, &url_dto)
             .await
-            .map_err(|err| RepositoryProviderError::from(GitHubRepositoryProviderError::from(err)))?
-            .iter()
-            .map(|branch_dto| Branch::new(branch_dto.value().clone()))
-            .collect();
+            .map_err(map_repository_client_error(url))?;
         Ok(Repository::new(url.clone(), branches))
     }
+
+    async fn get_repository_with_head_commits(
+        &self,
+        url: &RepositoryUrl,
+    ) -> Result<Repository, RepositoryProviderError> {
+        let url_dto = RepositoryClientRepositoryUrl::new(url.value().clone());
+        let branches = repository_branches::list_branches_with_head_commits(
+            &self.github_client,
+            &url_dto,
+            self.concurrency,
+        )
+        .await
+        .map_err(map_repository_client_error(url))?;
+        Ok(Repository::new(url.clone(), branches))
+    }
+
+    async fn compare_branches(
+        &self,
+        url: &RepositoryUrl,
+        base: &Branch,
+        head: &Branch,
+    ) -> Result<BranchComparison, RepositoryProviderError> {
+        let url_dto = RepositoryClientRepositoryUrl::new(url.value().clone());
+        let comparison = self
+            .github_client
+            .compare_branches(
+                &url_dto,
+                &BranchName::new(base.value().clone()),
+                &BranchName::new(head.value().clone()),
+            )
+            .await
+            .map_err(map_repository_client_error(url))?;
+        Ok(BranchComparison::new(
+            head.clone(),
+            comparison.ahead_by(),
+            comparison.behind_by(),
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl<GC, CR> RepositoryMaintainer for GitHubRepositoryProviderAdapter<GC, CR>
+where
+    GC: RepositoryHostClient<AuthenticationCredentials = RepositoryClientGitHubAuthenticationToken>
+        + Sync
+        + Send,
+    GC::Err: Send,
+    RepositoryProviderError: From<GC::Err>,
+    CR: CredentialRepository + Sync + Send,
+{
+    async fn delete_branch(
+        &self,
+        url: &RepositoryUrl,
+        branch: &Branch,
+    ) -> Result<(), RepositoryProviderError> {
+        let url_dto = RepositoryClientRepositoryUrl::new(url.value().clone());
+        self.github_client
+            .delete_branch(&url_dto, &BranchName::new(branch.value().clone()))
+            .await
+            .map_err(map_repository_client_error(url))
+    }
+}
+
+#[async_trait::async_trait]
+impl<GC, CR> RepositoryCatalog for GitHubRepositoryProviderAdapter<GC, CR>
+where
+    GC: RepositoryListingClient<
+            Err = GitHubClientError,
+            AuthenticationCredentials = RepositoryClientGitHubAuthenticationToken,
+        > + Sync
+        + Send,
+    CR: CredentialRepository + Sync + Send,
+{
+    async fn list_repositories(
+        &self,
+        owner: &RepositoryOwner,
+    ) -> Result<Vec<RepositoryUrl>, RepositoryProviderError> {
+        let owner_dto = match owner {
+            RepositoryOwner::Organization(name) => {
+                RepositoryClientRepositoryOwner::Organization(name.clone())
+            }
+            RepositoryOwner::User(name) => RepositoryClientRepositoryOwner::User(name.clone()),
+        };
+        let url_dtos = self
+            .github_client
+            .list_repositories(&owner_dto)
+            .await
+            .map_err(RepositoryProviderError::from)?;
+        Ok(url_dtos
+            .into_iter()
+            .map(|url_dto| RepositoryUrl::new(url_dto.value().clone()))
+            .collect())
+    }
+}
+
+fn map_repository_client_error<E>(url: &RepositoryUrl) -> impl Fn(E) -> RepositoryProviderError + '_
+where
+    RepositoryProviderError: From<E>,
+{
+    move |client_error| RepositoryProviderError::from(client_error).for_repository(url)
+}
+
+impl From<GitHubClientError> for RepositoryProviderError {
+    fn from(client_error: GitHubClientError) -> Self {
+        RepositoryProviderError::from(GitHubRepositoryProviderError::from(client_error))
+    }
 }
 
 impl From<GitHubClientError> for GitHubRepositoryProviderError {
@@ -82,13 +209,29 @@ impl From<GitHubClientError> for GitHubRepositoryProviderError {
         match client_error {
             GitHubClientError::ApiUrlParseError(..)
             | GitHubClientError::HttpClientError(..)
-            | GitHubClientError::Unexpected
+            | GitHubClientError::BranchNotDeleted { .. }
+            | GitHubClientError::UnexpectedResponse { .. }
             | GitHubClientError::JsonDeserializationError(..) => {
                 GitHubRepositoryProviderError::GitHubClient(client_error)
             }
             GitHubClientError::RepositoryNotFound(url) => {
                 GitHubRepositoryProviderError::RepositoryNotFound(url)
             }
+            GitHubClientError::OwnerNotFound(owner) => {
+                GitHubRepositoryProviderError::OwnerNotFound(owner)
+            }
+            GitHubClientError::Unauthorized(message) => {
+                GitHubRepositoryProviderError::Unauthorized(message)
+            }
+            GitHubClientError::Forbidden(message) => {
+                GitHubRepositoryProviderError::Forbidden(message)
+            }
+            GitHubClientError::RateLimited(message) => {
+                GitHubRepositoryProviderError::RateLimited(message)
+            }
+            GitHubClientError::ServerError { status, message } => {
+                GitHubRepositoryProviderError::ServerError { status, message }
+            }
             GitHubClientError::RepositoryUrlParseError(parse_error) => {
                 GitHubRepositoryProviderError::InvalidUrl(parse_error)
             }
@@ -104,26 +247,81 @@ pub enum GitHubRepositoryProviderError {
     InvalidUrl(RepositoryUrlParseError),
     #[error("repository '{0}' not found")]
     RepositoryNotFound(RepositoryClientRepositoryUrl),
+    #[error("{0} not found")]
+    OwnerNotFound(RepositoryClientRepositoryOwner),
+    #[error("GitHub rejected the authentication token: {0}")]
+    Unauthorized(String),
+    #[error("GitHub denied access: {0}")]
+    Forbidden(String),
+    #[error("GitHub rate limit exceeded: {0}")]
+    RateLimited(String),
+    #[error("GitHub server error ({status}): {message}")]
+    ServerError { status: u16, message: String },
 }
 
 impl From<GitHubRepositoryProviderError> for RepositoryProviderError {
     fn from(port_error: GitHubRepositoryProviderError) -> Self {
-        RepositoryProviderError::new(format!("{}", port_error))
+        let repository = None;
+        let message = port_error.to_string();
+        match port_error {
+            GitHubRepositoryProviderError::RepositoryNotFound(..)
+            | GitHubRepositoryProviderError::OwnerNotFound(..) => {
+                RepositoryProviderError::NotFound {
+                    repository,
+                    message,
+                }
+            }
+            GitHubRepositoryProviderError::Unauthorized(..) => {
+                RepositoryProviderError::Unauthorized {
+                    repository,
+                    message,
+                }
+            }
+            GitHubRepositoryProviderError::Forbidden(..) => RepositoryProviderError::Forbidden {
+                repository,
+                message,
+            },
+            GitHubRepositoryProviderError::RateLimited(..) => {
+                RepositoryProviderError::RateLimited {
+                    repository,
+                    message,
+                }
+            }
+            GitHubRepositoryProviderError::InvalidUrl(..) => RepositoryProviderError::InvalidUrl {
+                repository,
+                message,
+            },
+            GitHubRepositoryProviderError::GitHubClient(GitHubClientError::HttpClientError(..)) => {
+                RepositoryProviderError::Transport {
+                    repository,
+                    message,
+                }
+            }
+            GitHubRepositoryProviderError::GitHubClient(..)
+            | GitHubRepositoryProviderError::ServerError { .. } => RepositoryProviderError::Other {
+                repository,
+                message,
+            },
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use chrono::{TimeZone, Utc};
     use mockall::predicate::eq;
     use spectral::prelude::*;
 
     use crate::domain::authentication::credential_repository::{
         CredentialRepositoryError, MockCredentialRepository,
     };
-    use crate::domain::authentication::GitHubAuthenticationToken;
+    use crate::domain::authentication::AuthenticationToken;
+    use crate::domain::repository::Commit;
     use crate::ports::repository_hosting::github::authentication_token::GitHubAuthenticationToken as RepositoryClientGitHubAuthenticationToken;
-    use crate::ports::repository_hosting::github::repository::BranchName;
-    use crate::ports::repository_hosting::github::MockRepositoryHostClient;
+    use crate::ports::repository_hosting::repository::{
+        Branch as BranchDto, BranchComparison as BranchComparisonDto, Commit as CommitDto,
+    };
+    use crate::ports::repository_hosting::MockRepositoryHostClient;
     use crate::utils::test_helpers::async_this;
 
     use super::*;
@@ -136,13 +334,17 @@ mod tests {
         credential_repository: MockCredentialRepository,
     ) -> GitHubRepositoryProviderAdapter<MockRepositoryHostClientAlias, MockCredentialRepository>
     {
-        GitHubRepositoryProviderAdapter::new(repository_host_client, credential_repository)
+        GitHubRepositoryProviderAdapter::new(
+            repository_host_client,
+            credential_repository,
+            &CredentialKey::github("github.com"),
+        )
     }
 
     fn prepare_mock_client_list_branches(
         mock_repository_host: &mut MockRepositoryHostClientAlias,
         url: RepositoryClientRepositoryUrl,
-        branches: Vec<BranchName>,
+        branches: Vec<BranchDto>,
     ) {
         mock_repository_host
             .expect_list_branches()
@@ -150,6 +352,17 @@ mod tests {
             .returning(move |_| Ok(branches.clone()));
     }
 
+    fn prepare_mock_client_get_default_branch(
+        mock_repository_host: &mut MockRepositoryHostClientAlias,
+        url: RepositoryClientRepositoryUrl,
+        default_branch: &'static str,
+    ) {
+        mock_repository_host
+            .expect_get_default_branch()
+            .with(eq(url))
+            .returning(move |_| Ok(BranchName::new(default_branch.to_string())));
+    }
+
     fn prepare_mock_client_set_authentication(
         mock_repository_host: &mut MockRepositoryHostClientAlias,
         credentials: RepositoryClientGitHubAuthenticationToken,
@@ -167,16 +380,17 @@ mod tests {
     ) {
         mock_credential_repository
             .expect_get()
-            .returning(move || Box::pin(async_this(Err(error))));
+            .returning(move |_| Box::pin(async_this(Err(error))));
     }
 
     fn prepare_mock_credential_repository_to_succeed(
         mock_credential_repository: &mut MockCredentialRepository,
-        credentials: GitHubAuthenticationToken,
+        credentials: AuthenticationToken,
     ) {
         mock_credential_repository
             .expect_get()
-            .returning(move || Box::pin(async_this(Ok(credentials.clone()))));
+            .with(eq(CredentialKey::github("github.com")))
+            .returning(move |_| Box::pin(async_this(Ok(credentials.clone()))));
     }
 
     #[async_std::test]
@@ -190,7 +404,15 @@ mod tests {
         prepare_mock_client_list_branches(
             &mut mock_repository_host_client,
             RepositoryClientRepositoryUrl::new("url".to_string()),
-            vec![BranchName::new("1".to_string())],
+            vec![BranchDto::new(
+                BranchName::new("1".to_string()),
+                "sha".to_string(),
+            )],
+        );
+        prepare_mock_client_get_default_branch(
+            &mut mock_repository_host_client,
+            RepositoryClientRepositoryUrl::new("url".to_string()),
+            "main",
         );
 
         assert_that(
@@ -199,12 +421,163 @@ mod tests {
                 .await
                 .unwrap(),
         )
-        .is_equal_to(&Repository::new(
+        .is_equal_to(Repository::new(
             RepositoryUrl::new("url".to_string()),
             vec![Branch::new("1".to_string())],
         ));
     }
 
+    #[async_std::test]
+    async fn marks_protected_and_default_branches() {
+        let mut mock_repository_host_client = MockRepositoryHostClient::default();
+        let mut mock_credential_repository = MockCredentialRepository::default();
+        prepare_mock_credential_repository_to_fail(
+            &mut mock_credential_repository,
+            CredentialRepositoryError::CredentialDoesNotExist,
+        );
+        prepare_mock_client_list_branches(
+            &mut mock_repository_host_client,
+            RepositoryClientRepositoryUrl::new("url".to_string()),
+            vec![
+                BranchDto::new(BranchName::new("main".to_string()), "sha".to_string()),
+                BranchDto::new(BranchName::new("release".to_string()), "sha".to_string())
+                    .marked_protected(true),
+            ],
+        );
+        prepare_mock_client_get_default_branch(
+            &mut mock_repository_host_client,
+            RepositoryClientRepositoryUrl::new("url".to_string()),
+            "main",
+        );
+
+        assert_that(
+            &under_test(mock_repository_host_client, mock_credential_repository)
+                .get_repository(&RepositoryUrl::new("url".to_string()))
+                .await
+                .unwrap(),
+        )
+        .is_equal_to(Repository::new(
+            RepositoryUrl::new("url".to_string()),
+            vec![
+                Branch::new("main".to_string()).marked_default(true),
+                Branch::new("release".to_string()).marked_protected(true),
+            ],
+        ));
+    }
+
+    #[async_std::test]
+    async fn gets_repository_with_head_commits() {
+        let date = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+        let mut mock_repository_host_client = MockRepositoryHostClient::default();
+        let mut mock_credential_repository = MockCredentialRepository::default();
+        prepare_mock_credential_repository_to_fail(
+            &mut mock_credential_repository,
+            CredentialRepositoryError::CredentialDoesNotExist,
+        );
+        prepare_mock_client_list_branches(
+            &mut mock_repository_host_client,
+            RepositoryClientRepositoryUrl::new("url".to_string()),
+            vec![BranchDto::new(
+                BranchName::new("1".to_string()),
+                "sha".to_string(),
+            )],
+        );
+        prepare_mock_client_get_default_branch(
+            &mut mock_repository_host_client,
+            RepositoryClientRepositoryUrl::new("url".to_string()),
+            "main",
+        );
+        mock_repository_host_client
+            .expect_get_commit()
+            .with(
+                eq(RepositoryClientRepositoryUrl::new("url".to_string())),
+                eq("sha"),
+            )
+            .returning(move |_, _| {
+                Ok(CommitDto::new(
+                    "sha".to_string(),
+                    date,
+                    "author".to_string(),
+                ))
+            });
+
+        assert_that(
+            &under_test(mock_repository_host_client, mock_credential_repository)
+                .get_repository_with_head_commits(&RepositoryUrl::new("url".to_string()))
+                .await
+                .unwrap(),
+        )
+        .is_equal_to(Repository::new(
+            RepositoryUrl::new("url".to_string()),
+            vec![Branch::with_head_commit(
+                "1".to_string(),
+                Commit::new("sha".to_string(), date, "author".to_string()),
+            )],
+        ));
+    }
+
+    #[async_std::test]
+    async fn compares_branches() {
+        let mut mock_repository_host_client = MockRepositoryHostClient::default();
+        let mut mock_credential_repository = MockCredentialRepository::default();
+        prepare_mock_credential_repository_to_fail(
+            &mut mock_credential_repository,
+            CredentialRepositoryError::CredentialDoesNotExist,
+        );
+        mock_repository_host_client
+            .expect_compare_branches()
+            .with(
+                eq(RepositoryClientRepositoryUrl::new("url".to_string())),
+                eq(BranchName::new("main".to_string())),
+                eq(BranchName::new("feature".to_string())),
+            )
+            .returning(|_, _, _| Ok(BranchComparisonDto::new(0, 2)));
+
+        assert_that(
+            &under_test(mock_repository_host_client, mock_credential_repository)
+                .compare_branches(
+                    &RepositoryUrl::new("url".to_string()),
+                    &Branch::new("main".to_string()),
+                    &Branch::new("feature".to_string()),
+                )
+                .await
+                .unwrap(),
+        )
+        .is_equal_to(BranchComparison::new(
+            Branch::new("feature".to_string()),
+            0,
+            2,
+        ));
+    }
+
+    #[async_std::test]
+    async fn deletes_branch() {
+        let mut mock_repository_host_client = MockRepositoryHostClient::default();
+        let mut mock_credential_repository = MockCredentialRepository::default();
+        prepare_mock_credential_repository_to_fail(
+            &mut mock_credential_repository,
+            CredentialRepositoryError::CredentialDoesNotExist,
+        );
+        mock_repository_host_client
+            .expect_delete_branch()
+            .with(
+                eq(RepositoryClientRepositoryUrl::new("url".to_string())),
+                eq(BranchName::new("feature".to_string())),
+            )
+            .once()
+            .returning(|_, _| Ok(()));
+
+        assert_that(
+            &under_test(mock_repository_host_client, mock_credential_repository)
+                .delete_branch(
+                    &RepositoryUrl::new("url".to_string()),
+                    &Branch::new("feature".to_string()),
+                )
+                .await,
+        )
+        .is_ok();
+    }
+
     #[async_std::test]
     async fn authenticates_client_when_credentials_are_available() {
         let mut mock_repository_host_client = MockRepositoryHostClient::default();
@@ -215,9 +588,136 @@ mod tests {
         );
         prepare_mock_credential_repository_to_succeed(
             &mut mock_credential_repository,
-            GitHubAuthenticationToken::new("token".to_string()),
+            AuthenticationToken::new("token".to_string()),
         );
 
         under_test(mock_repository_host_client, mock_credential_repository);
     }
+
+    #[async_std::test]
+    async fn lists_repositories_of_organization() {
+        let mut mock_repository_host_client = MockRepositoryHostClient::default();
+        let mut mock_credential_repository = MockCredentialRepository::default();
+        prepare_mock_credential_repository_to_fail(
+            &mut mock_credential_repository,
+            CredentialRepositoryError::CredentialDoesNotExist,
+        );
+        mock_repository_host_client
+            .expect_list_repositories()
+            .with(eq(RepositoryClientRepositoryOwner::Organization(
+                "org".to_string(),
+            )))
+            .returning(|_| {
+                Ok(vec![RepositoryClientRepositoryUrl::new(
+                    "https://github.com/org/repo".to_string(),
+                )])
+            });
+
+        assert_that(
+            &under_test(mock_repository_host_client, mock_credential_repository)
+                .list_repositories(&RepositoryOwner::Organization("org".to_string()))
+                .await
+                .unwrap(),
+        )
+        .is_equal_to(vec![RepositoryUrl::new(
+            "https://github.com/org/repo".to_string(),
+        )]);
+    }
+
+    #[async_std::test]
+    async fn fails_to_list_repositories_of_missing_user() {
+        let mut mock_repository_host_client = MockRepositoryHostClient::default();
+        let mut mock_credential_repository = MockCredentialRepository::default();
+        prepare_mock_credential_repository_to_fail(
+            &mut mock_credential_repository,
+            CredentialRepositoryError::CredentialDoesNotExist,
+        );
+        mock_repository_host_client
+            .expect_list_repositories()
+            .returning(|owner| Err(GitHubClientError::OwnerNotFound(owner.clone())));
+
+        let result = under_test(mock_repository_host_client, mock_credential_repository)
+            .list_repositories(&RepositoryOwner::User("missing".to_string()))
+            .await;
+
+        assert_that(&result.err().unwrap().to_string())
+            .is_equal_to("user 'missing' not found".to_string());
+    }
+
+    #[async_std::test]
+    async fn fails_to_get_repository_when_rate_limited() {
+        let mut mock_repository_host_client = MockRepositoryHostClient::default();
+        let mut mock_credential_repository = MockCredentialRepository::default();
+        prepare_mock_credential_repository_to_fail(
+            &mut mock_credential_repository,
+            CredentialRepositoryError::CredentialDoesNotExist,
+        );
+        mock_repository_host_client
+            .expect_list_branches()
+            .returning(|_| {
+                Err(GitHubClientError::RateLimited(
+                    "API rate limit exceeded".to_string(),
+                ))
+            });
+
+        let result = under_test(mock_repository_host_client, mock_credential_repository)
+            .get_repository(&RepositoryUrl::new("url".to_string()))
+            .await;
+
+        assert_that(&result.err().unwrap().to_string())
+            .is_equal_to("GitHub rate limit exceeded: API rate limit exceeded".to_string());
+    }
+
+    #[async_std::test]
+    async fn reports_missing_repository_as_not_found_with_its_identity() {
+        let mut mock_repository_host_client = MockRepositoryHostClient::default();
+        let mut mock_credential_repository = MockCredentialRepository::default();
+        prepare_mock_credential_repository_to_fail(
+            &mut mock_credential_repository,
+            CredentialRepositoryError::CredentialDoesNotExist,
+        );
+        mock_repository_host_client
+            .expect_list_branches()
+            .returning(|url| Err(GitHubClientError::RepositoryNotFound(url.clone())));
+
+        let result = under_test(mock_repository_host_client, mock_credential_repository)
+            .get_repository(&RepositoryUrl::new(
+                "https://github.com/Owner/repo.git".to_string(),
+            ))
+            .await;
+
+        match result.err().unwrap() {
+            error @ RepositoryProviderError::NotFound { .. } => {
+                assert_that(&error.repository().unwrap().to_string())
+                    .is_equal_to("github.com/owner/repo".to_string())
+            }
+            error => panic!("unexpected error {:?}", error),
+        }
+    }
+
+    #[test]
+    fn maps_client_errors_to_matching_provider_errors() {
+        assert_that(&matches!(
+            GitHubRepositoryProviderError::from(GitHubClientError::Unauthorized(
+                "Bad credentials".to_string()
+            )),
+            GitHubRepositoryProviderError::Unauthorized(..)
+        ))
+        .is_true();
+        assert_that(&matches!(
+            GitHubRepositoryProviderError::from(GitHubClientError::Forbidden(
+                "Forbidden".to_string()
+            )),
+            GitHubRepositoryProviderError::Forbidden(..)
+        ))
+        .is_true();
+        assert_that(&matches!(
+            GitHubRepositoryProviderError::from(GitHubClientError::ServerError {
+                status: 502,
+                message: "Bad Gateway".to_string()
+            }),
+            GitHubRepositoryProviderError::ServerError { status: 502, .. }
+        ))
+        .is_true();
+    }
 }
@@ -0,0 +1,395 @@
+use chrono::{DateTime, Utc};
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct GraphQlResponseBody<T> {
+    data: Option<T>,
+    #[serde(default)]
+    errors: Vec<GraphQlError>,
+}
+
+impl<T> GraphQlResponseBody<T> {
+    #[cfg(test)]
+    pub fn new(data: Option<T>, errors: Vec<GraphQlError>) -> Self {
+        GraphQlResponseBody { data, errors }
+    }
+
+    pub fn into_parts(self) -> (Option<T>, Vec<GraphQlError>) {
+        (self.data, self.errors)
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct GraphQlError {
+    #[serde(rename = "type", default)]
+    error_type: Option<String>,
+    message: String,
+}
+
+impl GraphQlError {
+    #[cfg(test)]
+    pub fn new(error_type: Option<String>, message: String) -> Self {
+        GraphQlError {
+            error_type,
+            message,
+        }
+    }
+
+    pub fn error_type(&self) -> Option<&String> {
+        self.error_type.as_ref()
+    }
+
+    pub fn message(&self) -> &String {
+        &self.message
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PageInfo {
+    has_next_page: bool,
+    end_cursor: Option<String>,
+}
+
+impl PageInfo {
+    #[cfg(test)]
+    pub fn new(end_cursor: Option<String>) -> Self {
+        PageInfo {
+            has_next_page: end_cursor.is_some(),
+            end_cursor,
+        }
+    }
+
+    /// The cursor to request the next page with, if there is one.
+    pub fn next_cursor(&self) -> Option<&String> {
+        self.end_cursor.as_ref().filter(|_| self.has_next_page)
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RepositoryData {
+    repository: Option<RepositoryNode>,
+}
+
+impl RepositoryData {
+    #[cfg(test)]
+    pub fn new(repository: Option<RepositoryNode>) -> Self {
+        RepositoryData { repository }
+    }
+
+    pub fn repository(self) -> Option<RepositoryNode> {
+        self.repository
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct OwnerRepositoriesData {
+    owner: Option<OwnerNode>,
+}
+
+impl OwnerRepositoriesData {
+    #[cfg(test)]
+    pub fn new(repositories: Vec<RepositoryNode>, end_cursor: Option<String>) -> Self {
+        OwnerRepositoriesData {
+            owner: Some(OwnerNode {
+                repositories: Connection {
+                    page_info: PageInfo::new(end_cursor),
+                    nodes: repositories,
+                },
+            }),
+        }
+    }
+
+    pub fn repositories(self) -> Option<Connection<RepositoryNode>> {
+        self.owner.map(|owner| owner.repositories)
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct OwnerNode {
+    repositories: Connection<RepositoryNode>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Connection<T> {
+    page_info: PageInfo,
+    nodes: Vec<T>,
+}
+
+impl<T> Connection<T> {
+    pub fn page_info(&self) -> &PageInfo {
+        &self.page_info
+    }
+
+    pub fn nodes(&self) -> &Vec<T> {
+        &self.nodes
+    }
+}
+
+/// A repository with its default branch and a page of its branches. Queries that only need some
+/// of these fields leave the others out.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepositoryNode {
+    #[serde(default)]
+    url: Option<String>,
+    #[serde(default)]
+    default_branch_ref: Option<NamedRef>,
+    #[serde(default)]
+    refs: Option<Connection<RefNode>>,
+    #[serde(default, rename = "ref")]
+    named_ref: Option<RefWithComparison>,
+    #[serde(default)]
+    object: Option<CommitNode>,
+}
+
+impl RepositoryNode {
+    #[cfg(test)]
+    pub fn with_branches(
+        url: &str,
+        default_branch: &str,
+        branches: Vec<RefNode>,
+        end_cursor: Option<String>,
+    ) -> Self {
+        RepositoryNode {
+            url: Some(url.to_string()),
+            default_branch_ref: Some(NamedRef {
+                name: default_branch.to_string(),
+            }),
+            refs: Some(Connection {
+                page_info: PageInfo::new(end_cursor),
+                nodes: branches,
+            }),
+            named_ref: None,
+            object: None,
+        }
+    }
+
+    #[cfg(test)]
+    pub fn with_ref(named_ref: Option<RefWithComparison>) -> Self {
+        RepositoryNode {
+            url: None,
+            default_branch_ref: None,
+            refs: None,
+            named_ref,
+            object: None,
+        }
+    }
+
+    #[cfg(test)]
+    pub fn with_object(object: Option<CommitNode>) -> Self {
+        RepositoryNode {
+            url: None,
+            default_branch_ref: None,
+            refs: None,
+            named_ref: None,
+            object,
+        }
+    }
+
+    pub fn url(&self) -> Option<&String> {
+        self.url.as_ref()
+    }
+
+    pub fn default_branch(&self) -> Option<&String> {
+        self.default_branch_ref.as_ref().map(|named| &named.name)
+    }
+
+    pub fn refs(&self) -> Option<&Connection<RefNode>> {
+        self.refs.as_ref()
+    }
+
+    pub fn named_ref(&self) -> Option<&RefWithComparison> {
+        self.named_ref.as_ref()
+    }
+
+    pub fn object(&self) -> Option<&CommitNode> {
+        self.object.as_ref()
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct NamedRef {
+    name: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RefNode {
+    name: String,
+    branch_protection_rule: Option<serde_json::Value>,
+    target: CommitNode,
+    associated_pull_requests: PullRequestConnection,
+}
+
+impl RefNode {
+    #[cfg(test)]
+    pub fn new(name: &str, target: CommitNode, pull_requests: Vec<PullRequestNode>) -> Self {
+        RefNode {
+            name: name.to_string(),
+            branch_protection_rule: None,
+            target,
+            associated_pull_requests: PullRequestConnection {
+                nodes: pull_requests,
+            },
+        }
+    }
+
+    #[cfg(test)]
+    pub fn protected(mut self) -> Self {
+        self.branch_protection_rule = Some(serde_json::json!({ "id": "rule" }));
+        self
+    }
+
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+
+    pub fn is_protected(&self) -> bool {
+        self.branch_protection_rule.is_some()
+    }
+
+    pub fn target(&self) -> &CommitNode {
+        &self.target
+    }
+
+    pub fn pull_requests(&self) -> &Vec<PullRequestNode> {
+        &self.associated_pull_requests.nodes
+    }
+}
+
+/// The object a ref points to. Branches point to commits, for which the date and author are
+/// filled in.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitNode {
+    oid: String,
+    #[serde(default)]
+    committed_date: Option<DateTime<Utc>>,
+    #[serde(default)]
+    author: Option<CommitAuthor>,
+}
+
+impl CommitNode {
+    #[cfg(test)]
+    pub fn new(oid: &str, committed_date: DateTime<Utc>, author: &str) -> Self {
+        CommitNode {
+            oid: oid.to_string(),
+            committed_date: Some(committed_date),
+            author: Some(CommitAuthor {
+                name: Some(author.to_string()),
+            }),
+        }
+    }
+
+    pub fn oid(&self) -> &String {
+        &self.oid
+    }
+
+    pub fn committed_date(&self) -> Option<&DateTime<Utc>> {
+        self.committed_date.as_ref()
+    }
+
+    pub fn author(&self) -> Option<&String> {
+        self.author.as_ref().and_then(|author| author.name.as_ref())
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct CommitAuthor {
+    name: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct PullRequestConnection {
+    nodes: Vec<PullRequestNode>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PullRequestNode {
+    number: u32,
+    state: String,
+    url: String,
+}
+
+impl PullRequestNode {
+    #[cfg(test)]
+    pub fn new(number: u32, state: &str, url: &str) -> Self {
+        PullRequestNode {
+            number,
+            state: state.to_string(),
+            url: url.to_string(),
+        }
+    }
+
+    pub fn number(&self) -> u32 {
+        self.number
+    }
+
+    pub fn state(&self) -> &String {
+        &self.state
+    }
+
+    pub fn url(&self) -> &String {
+        &self.url
+    }
+}
+
+/// A ref looked up by name, with its node ID for mutations and, when requested, how a branch
+/// compares to it.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RefWithComparison {
+    #[serde(default)]
+    id: Option<String>,
+    #[serde(default)]
+    compare: Option<ComparisonNode>,
+}
+
+impl RefWithComparison {
+    #[cfg(test)]
+    pub fn new(id: Option<&str>, ahead_and_behind: Option<(u32, u32)>) -> Self {
+        RefWithComparison {
+            id: id.map(str::to_string),
+            compare: ahead_and_behind.map(|(ahead_by, behind_by)| ComparisonNode {
+                ahead_by,
+                behind_by,
+            }),
+        }
+    }
+
+    pub fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+
+    pub fn compare(&self) -> Option<&ComparisonNode> {
+        self.compare.as_ref()
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComparisonNode {
+    ahead_by: u32,
+    behind_by: u32,
+}
+
+impl ComparisonNode {
+    pub fn ahead_by(&self) -> u32 {
+        self.ahead_by
+    }
+
+    pub fn behind_by(&self) -> u32 {
+        self.behind_by
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ViewerData {
+    viewer: serde_json::Value,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteRefData {
+    delete_ref: Option<serde_json::Value>,
+}
@@ -1,13 +1,19 @@
 pub use github_authentication_validation_adapter::*;
 pub use github_client::*;
+pub use github_graphql_client::*;
 pub use github_repository_provider_adapter::*;
 pub use parse_repository_url::*;
+// The Gitea client speaks an API modelled on GitHub's REST API and shares these.
+pub(super) use authentication_token::GitHubAuthenticationToken;
+pub(super) use responses::{CommitResponseBody, ListBranchesResponseBody, RepositoryResponseBody};
 
 mod authentication_token;
 mod error;
 mod github_authentication_validation_adapter;
 mod github_client;
+mod github_graphql_client;
 mod github_repository_provider_adapter;
+mod graphql_responses;
 mod parse_repository_url;
 mod repository;
 mod responses;
@@ -1,36 +1,51 @@
-use std::convert::{TryFrom, TryInto};
-
 use regex::Regex;
 
-use crate::ports::repository_hosting::github::repository::{GitHubRepository, RepositoryUrl};
+use crate::ports::repository_hosting::github::repository::GitHubRepository;
+use crate::ports::repository_hosting::repository::RepositoryUrl;
 
 #[cfg_attr(test, mockall::automock)]
 pub trait GitHubRepositoryUrlParser {
     fn parse(&self, url: RepositoryUrl) -> Result<GitHubRepository, RepositoryUrlParseError>;
 }
 
-#[derive(Debug, Default)]
-pub struct GitHubRepositoryUrlParserImpl;
+/// Parses repository URLs of github.com, or of the GitHub Enterprise Server host it was created
+/// for.
+#[derive(Debug)]
+pub struct GitHubRepositoryUrlParserImpl {
+    host: String,
+}
 
 impl GitHubRepositoryUrlParserImpl {
     pub fn new() -> Self {
-        GitHubRepositoryUrlParserImpl::default()
+        Self::for_host("github.com")
     }
-}
 
-impl GitHubRepositoryUrlParser for GitHubRepositoryUrlParserImpl {
-    fn parse(&self, url: RepositoryUrl) -> Result<GitHubRepository, RepositoryUrlParseError> {
-        url.try_into()
+    pub fn for_host(host: &str) -> Self {
+        GitHubRepositoryUrlParserImpl {
+            host: host.to_string(),
+        }
     }
 }
 
-impl TryFrom<RepositoryUrl> for GitHubRepository {
-    type Error = RepositoryUrlParseError;
-
-    fn try_from(url: RepositoryUrl) -> Result<Self, Self::Error> {
-        let re = Regex::new(r"^(?:https?://)?github\.com/(?P<owner>\S+)/(?P<name>\S+)$").unwrap();
+impl Default for GitHubRepositoryUrlParserImpl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
-        if let Some(captures) = re.captures(url.value()) {
+impl GitHubRepositoryUrlParser for GitHubRepositoryUrlParserImpl {
+    fn parse(&self, url: RepositoryUrl) -> Result<GitHubRepository, RepositoryUrlParseError> {
+        let host = regex::escape(self.host.as_str());
+        let re = Regex::new(
+            format!(
+                r"^(?:(?:(?:https?|ssh|git)://(?:[^@/\s]+@)?)?(?i:{host})(?::\d+)?/|[^@/\s]+@(?i:{host}):)(?P<owner>[^/\s]+)/(?P<name>[^/\s]+)$",
+                host = host
+            )
+            .as_str(),
+        )
+        .unwrap();
+
+        if let Some(captures) = re.captures(url.without_clone_suffix()) {
             if let Some(owner) = captures.name("owner") {
                 if let Some(name) = captures.name("name") {
                     return Ok(GitHubRepository::new(
@@ -85,12 +100,61 @@ mod tests {
         ));
     }
 
+    fn assert_parses_to_owner_and_repo(url: &str) {
+        let under_test = GitHubRepositoryUrlParserImpl::new();
+        assert_that(
+            &under_test
+                .parse(RepositoryUrl::new(url.to_string()))
+                .unwrap(),
+        )
+        .is_equal_to(GitHubRepository::new(
+            "owner".to_string(),
+            "repo".to_string(),
+        ));
+    }
+
+    #[test]
+    fn parses_github_repository_url_with_git_suffix() {
+        assert_parses_to_owner_and_repo("https://github.com/owner/repo.git");
+    }
+
+    #[test]
+    fn parses_github_repository_url_with_trailing_slash() {
+        assert_parses_to_owner_and_repo("https://github.com/owner/repo/");
+    }
+
+    #[test]
+    fn parses_scp_like_ssh_github_repository_url() {
+        assert_parses_to_owner_and_repo("git@github.com:owner/repo.git");
+    }
+
+    #[test]
+    fn parses_ssh_github_repository_url() {
+        assert_parses_to_owner_and_repo("ssh://git@github.com/owner/repo");
+    }
+
+    #[test]
+    fn parses_ssh_github_repository_url_with_port() {
+        assert_parses_to_owner_and_repo("ssh://git@github.com:22/owner/repo.git");
+    }
+
+    #[test]
+    fn fails_to_parse_github_repository_url_with_extra_path_segments() {
+        let url = RepositoryUrl::new("https://github.com/owner/repo/tree/main".to_string());
+        let under_test = GitHubRepositoryUrlParserImpl::new();
+        assert_that(&under_test.parse(url).is_err()).is_true();
+    }
+
     #[test]
     fn fails_to_parse_github_repository_url_missing_owner() {
         let url = RepositoryUrl::new("https://github.com//repo".to_string());
         let under_test = GitHubRepositoryUrlParserImpl::new();
         let result = under_test.parse(url);
-        assert_that(&matches!(result.err().unwrap(), RepositoryUrlParseError {..})).is_true();
+        assert_that(&matches!(
+            result.err().unwrap(),
+            RepositoryUrlParseError { .. }
+        ))
+        .is_true();
     }
 
     #[test]
@@ -98,7 +162,11 @@ mod tests {
         let url = RepositoryUrl::new("https://github.com/owner".to_string());
         let under_test = GitHubRepositoryUrlParserImpl::new();
         let result = under_test.parse(url);
-        assert_that(&matches!(result.err().unwrap(), RepositoryUrlParseError {..})).is_true();
+        assert_that(&matches!(
+            result.err().unwrap(),
+            RepositoryUrlParseError { .. }
+        ))
+        .is_true();
     }
 
     #[test]
@@ -106,6 +174,37 @@ mod tests {
         let url = RepositoryUrl::new("https://not-github.com/owner/repo".to_string());
         let under_test = GitHubRepositoryUrlParserImpl::new();
         let result = under_test.parse(url);
-        assert_that(&matches!(result.err().unwrap(), RepositoryUrlParseError {..})).is_true();
+        assert_that(&matches!(
+            result.err().unwrap(),
+            RepositoryUrlParseError { .. }
+        ))
+        .is_true();
+    }
+
+    #[test]
+    fn parses_github_enterprise_repository_url() {
+        let url = RepositoryUrl::new("https://github.corp.example/owner/repo.git".to_string());
+        let under_test = GitHubRepositoryUrlParserImpl::for_host("github.corp.example");
+        assert_that(&under_test.parse(url).unwrap()).is_equal_to(GitHubRepository::new(
+            "owner".to_string(),
+            "repo".to_string(),
+        ));
+    }
+
+    #[test]
+    fn parses_scp_like_ssh_github_enterprise_repository_url() {
+        let url = RepositoryUrl::new("git@github.corp.example:owner/repo.git".to_string());
+        let under_test = GitHubRepositoryUrlParserImpl::for_host("github.corp.example");
+        assert_that(&under_test.parse(url).unwrap()).is_equal_to(GitHubRepository::new(
+            "owner".to_string(),
+            "repo".to_string(),
+        ));
+    }
+
+    #[test]
+    fn fails_to_parse_github_repository_url_with_github_enterprise_parser() {
+        let url = RepositoryUrl::new("https://github.com/owner/repo".to_string());
+        let under_test = GitHubRepositoryUrlParserImpl::for_host("github.corp.example");
+        assert_that(&under_test.parse(url).is_err()).is_true();
     }
 }
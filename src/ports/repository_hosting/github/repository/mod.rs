@@ -1,9 +1,3 @@
-pub use branch::*;
-pub use url::*;
-
-mod branch;
-mod url;
-
 #[derive(Debug, Eq, PartialEq)]
 pub struct GitHubRepository {
     owner: String,
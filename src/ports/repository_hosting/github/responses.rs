@@ -1,5 +1,28 @@
 pub use branch::*;
+pub use commit::*;
+pub use compare::*;
+pub use error_response::*;
 pub use list_branches_response::*;
+pub use list_repositories_response::*;
+pub use repository::*;
+
+mod error_response {
+    #[derive(Debug, serde::Serialize, serde::Deserialize)]
+    pub struct ErrorResponseBody {
+        message: String,
+    }
+
+    impl ErrorResponseBody {
+        #[cfg(test)]
+        pub fn new(message: String) -> Self {
+            ErrorResponseBody { message }
+        }
+
+        pub fn message(&self) -> &String {
+            &self.message
+        }
+    }
+}
 
 mod list_branches_response {
     use super::Branch;
@@ -22,20 +45,174 @@ mod list_branches_response {
     }
 }
 
+mod list_repositories_response {
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    #[serde(transparent)]
+    pub struct ListRepositoriesResponseBody {
+        repositories: Vec<RepositorySummary>,
+    }
+
+    impl ListRepositoriesResponseBody {
+        #[cfg(test)]
+        pub fn new(html_urls: Vec<String>) -> Self {
+            ListRepositoriesResponseBody {
+                repositories: html_urls
+                    .into_iter()
+                    .map(|html_url| RepositorySummary { html_url })
+                    .collect(),
+            }
+        }
+
+        pub fn html_urls(&self) -> Vec<&String> {
+            self.repositories
+                .iter()
+                .map(|repository| &repository.html_url)
+                .collect()
+        }
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    struct RepositorySummary {
+        html_url: String,
+    }
+}
+
 mod branch {
     #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
     pub struct Branch {
         name: String,
+        commit: BranchCommit,
+        #[serde(default)]
+        protected: bool,
     }
 
     impl Branch {
         #[cfg(test)]
-        pub fn new(name: String) -> Self {
-            Branch { name }
+        pub fn new(name: String, sha: String) -> Self {
+            Branch {
+                name,
+                commit: BranchCommit { sha },
+                protected: false,
+            }
+        }
+
+        #[cfg(test)]
+        pub fn protected(mut self) -> Self {
+            self.protected = true;
+            self
         }
 
         pub fn name(&self) -> &String {
             &self.name
         }
+
+        pub fn sha(&self) -> &String {
+            &self.commit.sha
+        }
+
+        pub fn is_protected(&self) -> bool {
+            self.protected
+        }
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    struct BranchCommit {
+        /// Gitea names the head commit's hash `id`.
+        #[serde(alias = "id")]
+        sha: String,
+    }
+}
+
+mod commit {
+    use chrono::{DateTime, Utc};
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct CommitResponseBody {
+        sha: String,
+        commit: CommitDetails,
+    }
+
+    impl CommitResponseBody {
+        #[cfg(test)]
+        pub fn new(sha: String, author: String, date: DateTime<Utc>) -> Self {
+            CommitResponseBody {
+                sha,
+                commit: CommitDetails {
+                    author: GitActor {
+                        name: author.clone(),
+                        date,
+                    },
+                    committer: GitActor { name: author, date },
+                },
+            }
+        }
+
+        pub fn sha(&self) -> &String {
+            &self.sha
+        }
+
+        pub fn author(&self) -> &String {
+            &self.commit.author.name
+        }
+
+        pub fn date(&self) -> &DateTime<Utc> {
+            &self.commit.committer.date
+        }
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    struct CommitDetails {
+        author: GitActor,
+        committer: GitActor,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    struct GitActor {
+        name: String,
+        date: DateTime<Utc>,
+    }
+}
+
+mod repository {
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct RepositoryResponseBody {
+        default_branch: String,
+    }
+
+    impl RepositoryResponseBody {
+        #[cfg(test)]
+        pub fn new(default_branch: String) -> Self {
+            RepositoryResponseBody { default_branch }
+        }
+
+        pub fn default_branch(&self) -> &String {
+            &self.default_branch
+        }
+    }
+}
+
+mod compare {
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct CompareResponseBody {
+        ahead_by: u32,
+        behind_by: u32,
+    }
+
+    impl CompareResponseBody {
+        #[cfg(test)]
+        pub fn new(ahead_by: u32, behind_by: u32) -> Self {
+            CompareResponseBody {
+                ahead_by,
+                behind_by,
+            }
+        }
+
+        pub fn ahead_by(&self) -> u32 {
+            self.ahead_by
+        }
+
+        pub fn behind_by(&self) -> u32 {
+            self.behind_by
+        }
     }
 }
@@ -0,0 +1,25 @@
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct GitLabAuthenticationToken(String);
+
+impl GitLabAuthenticationToken {
+    pub fn new(token: String) -> Self {
+        GitLabAuthenticationToken(token)
+    }
+
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn returns_string_value() {
+        assert_that(&GitLabAuthenticationToken::new("token".to_string()).value())
+            .is_equal_to("token");
+    }
+}
@@ -0,0 +1,122 @@
+use crate::domain::authentication::{
+    AuthenticationToken, AuthenticationValidity, RepositoryCredentialsValidationError,
+    RepositoryCredentialsValidator,
+};
+use crate::ports::repository_hosting::gitlab::authentication_token::GitLabAuthenticationToken as RepositoryClientGitLabAuthenticationToken;
+use crate::ports::repository_hosting::gitlab::error::GitLabClientError;
+use crate::ports::repository_hosting::{AuthenticationCredentialValidity, RepositoryHostClient};
+
+pub struct GitLabCredentialsValidatorAdapter<GC>
+where
+    GC: RepositoryHostClient<
+        Err = GitLabClientError,
+        AuthenticationCredentials = RepositoryClientGitLabAuthenticationToken,
+    >,
+{
+    gitlab_client: GC,
+}
+
+impl<GC> GitLabCredentialsValidatorAdapter<GC>
+where
+    GC: RepositoryHostClient<
+        Err = GitLabClientError,
+        AuthenticationCredentials = RepositoryClientGitLabAuthenticationToken,
+    >,
+{
+    pub fn new(gitlab_client: GC) -> Self {
+        GitLabCredentialsValidatorAdapter { gitlab_client }
+    }
+}
+
+#[async_trait::async_trait]
+impl<GC> RepositoryCredentialsValidator for GitLabCredentialsValidatorAdapter<GC>
+where
+    GC: RepositoryHostClient<
+            Err = GitLabClientError,
+            AuthenticationCredentials = RepositoryClientGitLabAuthenticationToken,
+        > + Send
+        + Sync,
+{
+    async fn validate(
+        &self,
+        credentials: AuthenticationToken,
+    ) -> Result<AuthenticationValidity, RepositoryCredentialsValidationError> {
+        let validity = match self
+            .gitlab_client
+            .validate_authentication_credentials(RepositoryClientGitLabAuthenticationToken::new(
+                credentials.value(),
+            ))
+            .await
+            .map_err(|_| RepositoryCredentialsValidationError::FailedToValidate)?
+        {
+            AuthenticationCredentialValidity::Valid => AuthenticationValidity::Valid,
+            AuthenticationCredentialValidity::Invalid => AuthenticationValidity::Invalid,
+        };
+        Ok(validity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::predicate::eq;
+    use spectral::prelude::*;
+
+    use crate::ports::repository_hosting::gitlab::authentication_token::GitLabAuthenticationToken as RepositoryClientGitLabAuthenticationToken;
+    use crate::ports::repository_hosting::MockRepositoryHostClient;
+
+    use super::*;
+
+    type MockRepositoryHostClientAlias =
+        MockRepositoryHostClient<GitLabClientError, RepositoryClientGitLabAuthenticationToken>;
+
+    fn under_test(
+        gitlab_client: MockRepositoryHostClientAlias,
+    ) -> GitLabCredentialsValidatorAdapter<MockRepositoryHostClientAlias> {
+        GitLabCredentialsValidatorAdapter::new(gitlab_client)
+    }
+
+    fn prepare_mock_client_validate_authentication_credentials(
+        mock_repository_host: &mut MockRepositoryHostClientAlias,
+        credentials: RepositoryClientGitLabAuthenticationToken,
+        validity: AuthenticationCredentialValidity,
+    ) {
+        mock_repository_host
+            .expect_validate_authentication_credentials()
+            .with(eq(credentials))
+            .returning(move |_| Ok(validity));
+    }
+
+    #[async_std::test]
+    async fn validates_valid_authentication_credentials() {
+        let mut mock_gitlab_client = MockRepositoryHostClientAlias::default();
+        prepare_mock_client_validate_authentication_credentials(
+            &mut mock_gitlab_client,
+            RepositoryClientGitLabAuthenticationToken::new("token".to_string()),
+            AuthenticationCredentialValidity::Valid,
+        );
+
+        let validity = under_test(mock_gitlab_client)
+            .validate(AuthenticationToken::new("token".to_string()))
+            .await
+            .unwrap();
+
+        assert_that(&matches!(validity, AuthenticationValidity::Valid)).is_equal_to(true);
+    }
+
+    #[async_std::test]
+    async fn validates_invalid_authentication_credentials() {
+        let mut mock_gitlab_client = MockRepositoryHostClientAlias::default();
+        prepare_mock_client_validate_authentication_credentials(
+            &mut mock_gitlab_client,
+            RepositoryClientGitLabAuthenticationToken::new("token".to_string()),
+            AuthenticationCredentialValidity::Invalid,
+        );
+
+        let validity = under_test(mock_gitlab_client)
+            .validate(AuthenticationToken::new("token".to_string()))
+            .await
+            .unwrap();
+
+        assert_that(&matches!(validity, AuthenticationValidity::Invalid)).is_equal_to(true);
+    }
+}
@@ -0,0 +1,839 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use http_types::headers::HeaderName;
+use http_types::{Method, StatusCode, Url};
+
+use crate::ports::repository_hosting::gitlab::authentication_token::GitLabAuthenticationToken;
+use crate::ports::repository_hosting::gitlab::error::GitLabClientError;
+use crate::ports::repository_hosting::gitlab::parse_repository_url::GitLabRepositoryUrlParser;
+use crate::ports::repository_hosting::gitlab::repository::GitLabProject;
+use crate::ports::repository_hosting::gitlab::responses::{
+    CommitResponseBody, CompareResponseBody, ListBranchesResponseBody, ProjectResponseBody,
+};
+use crate::ports::repository_hosting::repository::{
+    Branch, BranchComparison, BranchName, Commit, RepositoryUrl,
+};
+use crate::ports::repository_hosting::{
+    AuthenticationCredentialValidity, RepositoryHostClient, ResponseError,
+};
+use crate::utils::environment::EnvironmentReader;
+use crate::utils::http::{HttpClientFacade, Request};
+
+const PAGE_SIZE: u32 = 100;
+const DEFAULT_HOST: &str = "gitlab.com";
+const SERVICE: &str = "GitLab";
+
+#[derive(Debug)]
+pub struct GitLabClient<
+    HttpClient: HttpClientFacade,
+    UrlParser: GitLabRepositoryUrlParser,
+    EnvReader: EnvironmentReader,
+> {
+    http_client: HttpClient,
+    url_parser: UrlParser,
+    environment_reader: EnvReader,
+    host: String,
+    private_token: Option<GitLabAuthenticationToken>,
+}
+
+impl<HttpClient, UrlParser, EnvReader> GitLabClient<HttpClient, UrlParser, EnvReader>
+where
+    HttpClient: HttpClientFacade,
+    UrlParser: GitLabRepositoryUrlParser,
+    EnvReader: EnvironmentReader,
+{
+    pub fn new(
+        http_client: HttpClient,
+        url_parser: UrlParser,
+        environment_reader: EnvReader,
+    ) -> Self {
+        GitLabClient {
+            http_client,
+            url_parser,
+            environment_reader,
+            host: DEFAULT_HOST.to_string(),
+            private_token: None,
+        }
+    }
+
+    /// Validates credentials with a self-hosted instance instead of gitlab.com.
+    pub fn with_host(mut self, host: String) -> Self {
+        self.host = host;
+        self
+    }
+
+    fn api_headers(&self) -> HashMap<HeaderName, String> {
+        let mut headers = HashMap::new();
+        if let Some(authentication_token) = &self.private_token {
+            headers.insert(
+                HeaderName::from("private-token"),
+                authentication_token.value().to_string(),
+            );
+        }
+        headers
+    }
+
+    fn validate_authentication_credentials_headers(
+        &self,
+        token: GitLabAuthenticationToken,
+    ) -> HashMap<HeaderName, String> {
+        let mut headers = HashMap::new();
+        headers.insert(HeaderName::from("private-token"), token.value().to_string());
+        headers
+    }
+
+    fn parse_url(url_string: String) -> Result<Url, GitLabClientError> {
+        match Url::parse(url_string.as_str()) {
+            Ok(url) => Ok(url),
+            Err(err) => Err(GitLabClientError::ApiUrlParseError(err)),
+        }
+    }
+
+    /// `TIDY_REPO_GITLAB_API_BASE_URL` only redirects requests for gitlab.com, so self-hosted
+    /// instances are always reached on their own host.
+    fn api_base_url(&self, host: &str) -> String {
+        let default_api_base_url = format!("https://{}/api/v4", host);
+        if !host.eq_ignore_ascii_case(DEFAULT_HOST) {
+            return default_api_base_url;
+        }
+        self.environment_reader
+            .read("TIDY_REPO_GITLAB_API_BASE_URL")
+            .unwrap_or(default_api_base_url)
+    }
+
+    fn project_api_url(
+        &self,
+        project: &GitLabProject,
+        path_segments: &[&str],
+    ) -> Result<Url, GitLabClientError> {
+        let mut url = Self::parse_url(self.api_base_url(project.host()))?;
+        url.path_segments_mut()
+            .map_err(|_| {
+                GitLabClientError::ApiUrlParseError(
+                    http_types::url::ParseError::RelativeUrlWithCannotBeABaseBase,
+                )
+            })?
+            .pop_if_empty()
+            .push("projects")
+            .push(project.path())
+            .extend(path_segments);
+        Ok(url)
+    }
+
+    fn list_branches_api_url(&self, project: &GitLabProject) -> Result<Url, GitLabClientError> {
+        let mut url = self.project_api_url(project, &["repository", "branches"])?;
+        url.query_pairs_mut()
+            .append_pair("per_page", PAGE_SIZE.to_string().as_str());
+        Ok(url)
+    }
+
+    fn compare_api_url(
+        &self,
+        project: &GitLabProject,
+        from: &str,
+        to: &str,
+    ) -> Result<Url, GitLabClientError> {
+        let mut url = self.project_api_url(project, &["repository", "compare"])?;
+        url.query_pairs_mut()
+            .append_pair("from", from)
+            .append_pair("to", to);
+        Ok(url)
+    }
+
+    fn validate_authentication_credentials_api_url(&self) -> Result<Url, GitLabClientError> {
+        Self::parse_url(format!("{}/user", self.api_base_url(self.host.as_str())))
+    }
+
+    async fn count_commits_between(
+        &self,
+        repository_url: &RepositoryUrl,
+        project: &GitLabProject,
+        from: &BranchName,
+        to: &BranchName,
+    ) -> Result<u32, GitLabClientError> {
+        let response = self
+            .http_client
+            .send(Request::new(
+                Method::Get,
+                self.compare_api_url(project, from.value(), to.value())?,
+                self.api_headers(),
+            ))
+            .await?;
+
+        match response.status_code() {
+            StatusCode::Ok => Ok(response.body_json::<CompareResponseBody>()?.commit_count()),
+            status => Err(ResponseError::from_status(SERVICE, status, repository_url).into()),
+        }
+    }
+}
+
+#[async_trait]
+impl<HttpClient, UrlParser, EnvReader> RepositoryHostClient
+    for GitLabClient<HttpClient, UrlParser, EnvReader>
+where
+    HttpClient: HttpClientFacade + Send + Sync,
+    UrlParser: GitLabRepositoryUrlParser + Send + Sync,
+    EnvReader: EnvironmentReader + Send + Sync,
+{
+    type Err = GitLabClientError;
+    type AuthenticationCredentials = GitLabAuthenticationToken;
+
+    async fn list_branches(
+        &self,
+        repository_url: &RepositoryUrl,
+    ) -> Result<Vec<Branch>, Self::Err> {
+        let project = self.url_parser.parse(repository_url.clone())?;
+        let mut branches = Vec::new();
+        let mut next_page_url = Some(self.list_branches_api_url(&project)?);
+
+        while let Some(page_url) = next_page_url {
+            let response = self
+                .http_client
+                .send(Request::new(Method::Get, page_url, self.api_headers()))
+                .await?;
+
+            if response.status_code() != StatusCode::Ok {
+                return Err(ResponseError::from_status(
+                    SERVICE,
+                    response.status_code(),
+                    repository_url,
+                )
+                .into());
+            }
+
+            branches.extend(
+                response
+                    .body_json::<ListBranchesResponseBody>()?
+                    .branches()
+                    .iter()
+                    .map(|branch| {
+                        Branch::new(
+                            BranchName::new(branch.name().to_string()),
+                            branch.sha().to_string(),
+                        )
+                        .marked_protected(branch.is_protected())
+                    }),
+            );
+            next_page_url = response
+                .link_header()
+                .and_then(|link_header| link_header.next().cloned());
+        }
+
+        Ok(branches)
+    }
+
+    async fn get_commit(
+        &self,
+        repository_url: &RepositoryUrl,
+        sha: &str,
+    ) -> Result<Commit, Self::Err> {
+        let project = self.url_parser.parse(repository_url.clone())?;
+
+        let response = self
+            .http_client
+            .send(Request::new(
+                Method::Get,
+                self.project_api_url(&project, &["repository", "commits", sha])?,
+                self.api_headers(),
+            ))
+            .await?;
+
+        match response.status_code() {
+            StatusCode::Ok => {
+                let body = response.body_json::<CommitResponseBody>()?;
+                Ok(Commit::new(
+                    body.sha().to_string(),
+                    *body.date(),
+                    body.author().to_string(),
+                ))
+            }
+            status => Err(ResponseError::from_status(SERVICE, status, repository_url).into()),
+        }
+    }
+
+    async fn get_default_branch(
+        &self,
+        repository_url: &RepositoryUrl,
+    ) -> Result<BranchName, Self::Err> {
+        let project = self.url_parser.parse(repository_url.clone())?;
+
+        let response = self
+            .http_client
+            .send(Request::new(
+                Method::Get,
+                self.project_api_url(&project, &[])?,
+                self.api_headers(),
+            ))
+            .await?;
+
+        match response.status_code() {
+            StatusCode::Ok => Ok(BranchName::new(
+                response
+                    .body_json::<ProjectResponseBody>()?
+                    .default_branch()
+                    .to_string(),
+            )),
+            status => Err(ResponseError::from_status(SERVICE, status, repository_url).into()),
+        }
+    }
+
+    async fn compare_branches(
+        &self,
+        repository_url: &RepositoryUrl,
+        base: &BranchName,
+        head: &BranchName,
+    ) -> Result<BranchComparison, Self::Err> {
+        let project = self.url_parser.parse(repository_url.clone())?;
+
+        let ahead_by = self
+            .count_commits_between(repository_url, &project, base, head)
+            .await?;
+        let behind_by = self
+            .count_commits_between(repository_url, &project, head, base)
+            .await?;
+
+        Ok(BranchComparison::new(ahead_by, behind_by))
+    }
+
+    async fn delete_branch(
+        &self,
+        repository_url: &RepositoryUrl,
+        branch: &BranchName,
+    ) -> Result<(), Self::Err> {
+        let project = self.url_parser.parse(repository_url.clone())?;
+
+        let response = self
+            .http_client
+            .send(Request::new(
+                Method::Delete,
+                self.project_api_url(&project, &["repository", "branches", branch.value()])?,
+                self.api_headers(),
+            ))
+            .await?;
+
+        match response.status_code() {
+            StatusCode::NoContent => Ok(()),
+            StatusCode::NotFound => {
+                Err(
+                    ResponseError::from_status(SERVICE, StatusCode::NotFound, repository_url)
+                        .into(),
+                )
+            }
+            _ => Err(GitLabClientError::BranchNotDeleted {
+                repository_url: repository_url.clone(),
+                branch: branch.value().clone(),
+            }),
+        }
+    }
+
+    fn set_authentication_credentials(&mut self, credentials: Self::AuthenticationCredentials) {
+        self.private_token = Some(credentials);
+    }
+
+    async fn validate_authentication_credentials(
+        &self,
+        credentials: Self::AuthenticationCredentials,
+    ) -> Result<AuthenticationCredentialValidity, Self::Err> {
+        let response = self
+            .http_client
+            .send(Request::new(
+                Method::Get,
+                self.validate_authentication_credentials_api_url()?,
+                self.validate_authentication_credentials_headers(credentials),
+            ))
+            .await?;
+
+        match response.status_code() {
+            StatusCode::Ok => Ok(AuthenticationCredentialValidity::Valid),
+            StatusCode::Unauthorized => Ok(AuthenticationCredentialValidity::Invalid),
+            status => Err(ResponseError::Unexpected {
+                service: SERVICE,
+                status: status.into(),
+            }
+            .into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env::VarError;
+
+    use chrono::{TimeZone, Utc};
+    use http_types::StatusCode;
+    use mockall::predicate::eq;
+    use spectral::prelude::*;
+
+    use crate::ports::repository_hosting::gitlab::responses::Branch as BranchResponse;
+    use crate::ports::repository_hosting::gitlab::MockGitLabRepositoryUrlParser;
+    use crate::utils::environment::{EnvironmentReaderError, MockEnvironmentReader};
+    use crate::utils::http::{Error, MockHttpClientFacade, Response};
+
+    use super::*;
+
+    type GitLabClientAlias =
+        GitLabClient<MockHttpClientFacade, MockGitLabRepositoryUrlParser, MockEnvironmentReader>;
+
+    fn branch(name: &str) -> Branch {
+        Branch::new(BranchName::new(name.to_string()), "sha".to_string())
+    }
+
+    fn mock_environment_reader() -> MockEnvironmentReader {
+        let mut reader = MockEnvironmentReader::default();
+        reader
+            .expect_read()
+            .returning(|_| Err(EnvironmentReaderError::ReadError(VarError::NotPresent)));
+        reader
+    }
+
+    fn mock_url_parser_for_project(
+        host: &'static str,
+        path: &'static str,
+    ) -> MockGitLabRepositoryUrlParser {
+        let mut mock_url_parser = MockGitLabRepositoryUrlParser::default();
+        mock_url_parser
+            .expect_parse()
+            .returning(move |_| Ok(GitLabProject::new(host.to_string(), path.to_string())));
+        mock_url_parser
+    }
+
+    async fn wrap_response_in_future(response: Result<Response, Error>) -> Result<Response, Error> {
+        response
+    }
+
+    fn prepare_mock_http_client(
+        mock_http_client: &mut MockHttpClientFacade,
+        request: Request,
+        response: Response,
+    ) {
+        mock_http_client
+            .expect_send()
+            .with(eq(request))
+            .returning(move |_| Box::pin(wrap_response_in_future(Ok(response.clone()))));
+    }
+
+    fn api_request(method: Method, url: &str) -> Request {
+        Request::new(method, Url::parse(url).unwrap(), HashMap::new())
+    }
+
+    fn successful_json_response(body: serde_json::Value) -> Response {
+        Response::new(StatusCode::Ok, HashMap::new(), body.to_string())
+    }
+
+    fn under_test(mock_http_client: MockHttpClientFacade) -> GitLabClientAlias {
+        GitLabClient::new(
+            mock_http_client,
+            mock_url_parser_for_project("gitlab.com", "group/sub/project"),
+            mock_environment_reader(),
+        )
+    }
+
+    fn repository_url() -> RepositoryUrl {
+        RepositoryUrl::new("https://gitlab.com/group/sub/project".to_string())
+    }
+
+    #[test]
+    fn auth_token_is_absent_by_default() {
+        assert_that(&under_test(MockHttpClientFacade::default()).private_token).is_none();
+    }
+
+    #[async_std::test]
+    async fn lists_branches_of_project_in_nested_group() {
+        let mut mock_http_client = MockHttpClientFacade::default();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            api_request(
+                Method::Get,
+                "https://gitlab.com/api/v4/projects/group%2Fsub%2Fproject/repository/branches?per_page=100",
+            ),
+            successful_json_response(serde_json::json!(ListBranchesResponseBody::new(vec![
+                BranchResponse::new("main".to_string(), "sha".to_string()).protected(),
+                BranchResponse::new("feature".to_string(), "sha".to_string()),
+            ]))),
+        );
+
+        assert_that(
+            &under_test(mock_http_client)
+                .list_branches(&repository_url())
+                .await
+                .unwrap(),
+        )
+        .is_equal_to(vec![
+            branch("main").marked_protected(true),
+            branch("feature"),
+        ]);
+    }
+
+    #[async_std::test]
+    async fn lists_branches_across_all_pages() {
+        let next_page_url =
+            "https://gitlab.com/api/v4/projects/1/repository/branches?per_page=100&page=2";
+        let mut first_page_headers = HashMap::new();
+        first_page_headers.insert(
+            HeaderName::from("link"),
+            format!("<{}>; rel=\"next\"", next_page_url),
+        );
+        let mut mock_http_client = MockHttpClientFacade::default();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            api_request(
+                Method::Get,
+                "https://gitlab.com/api/v4/projects/group%2Fsub%2Fproject/repository/branches?per_page=100",
+            ),
+            Response::new(
+                StatusCode::Ok,
+                first_page_headers,
+                serde_json::json!(ListBranchesResponseBody::new(vec![BranchResponse::new(
+                    "branch1".to_string(),
+                    "sha".to_string()
+                )]))
+                .to_string(),
+            ),
+        );
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            api_request(Method::Get, next_page_url),
+            successful_json_response(serde_json::json!(ListBranchesResponseBody::new(vec![
+                BranchResponse::new("branch2".to_string(), "sha".to_string()),
+            ]))),
+        );
+
+        assert_that(
+            &under_test(mock_http_client)
+                .list_branches(&repository_url())
+                .await
+                .unwrap(),
+        )
+        .is_equal_to(vec![branch("branch1"), branch("branch2")]);
+    }
+
+    #[async_std::test]
+    async fn lists_branches_of_self_hosted_project_with_authentication_credentials_set() {
+        let mut headers = HashMap::new();
+        headers.insert(HeaderName::from("private-token"), "token".to_string());
+        let mut mock_http_client = MockHttpClientFacade::default();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            Request::new(
+                Method::Get,
+                Url::parse("https://gitlab.example.com/api/v4/projects/group%2Fproject/repository/branches?per_page=100").unwrap(),
+                headers,
+            ),
+            successful_json_response(serde_json::json!(ListBranchesResponseBody::new(vec![
+                BranchResponse::new("branch".to_string(), "sha".to_string()),
+            ]))),
+        );
+        let mut under_test = GitLabClient::new(
+            mock_http_client,
+            mock_url_parser_for_project("gitlab.example.com", "group/project"),
+            mock_environment_reader(),
+        );
+        under_test
+            .set_authentication_credentials(GitLabAuthenticationToken::new("token".to_string()));
+
+        assert_that(
+            &under_test
+                .list_branches(&RepositoryUrl::new(
+                    "https://gitlab.example.com/group/project".to_string(),
+                ))
+                .await
+                .unwrap(),
+        )
+        .is_equal_to(vec![branch("branch")]);
+    }
+
+    #[async_std::test]
+    async fn fails_to_list_branches_of_missing_project() {
+        let mut mock_http_client = MockHttpClientFacade::default();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            api_request(
+                Method::Get,
+                "https://gitlab.com/api/v4/projects/group%2Fsub%2Fproject/repository/branches?per_page=100",
+            ),
+            Response::new(StatusCode::NotFound, HashMap::new(), "".to_string()),
+        );
+
+        let result = under_test(mock_http_client)
+            .list_branches(&repository_url())
+            .await;
+
+        assert_that(&matches!(
+            result,
+            Err(GitLabClientError::Response(
+                ResponseError::RepositoryNotFound(..)
+            ))
+        ))
+        .is_true();
+    }
+
+    #[async_std::test]
+    async fn fails_to_list_branches_when_token_is_rejected() {
+        let mut mock_http_client = MockHttpClientFacade::default();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            api_request(
+                Method::Get,
+                "https://gitlab.com/api/v4/projects/group%2Fsub%2Fproject/repository/branches?per_page=100",
+            ),
+            Response::new(StatusCode::Unauthorized, HashMap::new(), "".to_string()),
+        );
+
+        let result = under_test(mock_http_client)
+            .list_branches(&repository_url())
+            .await;
+
+        assert_that(&matches!(
+            result,
+            Err(GitLabClientError::Response(ResponseError::Unauthorized(..)))
+        ))
+        .is_true();
+    }
+
+    #[async_std::test]
+    async fn fails_to_get_default_branch_when_rate_limited() {
+        let mut mock_http_client = MockHttpClientFacade::default();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            api_request(
+                Method::Get,
+                "https://gitlab.com/api/v4/projects/group%2Fsub%2Fproject",
+            ),
+            Response::new(StatusCode::TooManyRequests, HashMap::new(), "".to_string()),
+        );
+
+        let result = under_test(mock_http_client)
+            .get_default_branch(&repository_url())
+            .await;
+
+        assert_that(&matches!(
+            result,
+            Err(GitLabClientError::Response(ResponseError::RateLimited(..)))
+        ))
+        .is_true();
+    }
+
+    #[async_std::test]
+    async fn fails_to_get_commit_on_server_error() {
+        let mut mock_http_client = MockHttpClientFacade::default();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            api_request(
+                Method::Get,
+                "https://gitlab.com/api/v4/projects/group%2Fsub%2Fproject/repository/commits/sha",
+            ),
+            Response::new(StatusCode::BadGateway, HashMap::new(), "".to_string()),
+        );
+
+        let result = under_test(mock_http_client)
+            .get_commit(&repository_url(), "sha")
+            .await;
+
+        assert_that(&matches!(
+            result,
+            Err(GitLabClientError::Response(ResponseError::Unexpected {
+                status: 502,
+                ..
+            }))
+        ))
+        .is_true();
+    }
+
+    #[async_std::test]
+    async fn reaches_self_hosted_instance_on_its_own_host_when_api_base_url_is_overridden() {
+        let mut mock_http_client = MockHttpClientFacade::default();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            api_request(
+                Method::Get,
+                "https://gitlab.example.com/api/v4/projects/group%2Fproject",
+            ),
+            successful_json_response(serde_json::json!(ProjectResponseBody::new(
+                "main".to_string()
+            ))),
+        );
+        let mut mock_environment_reader = MockEnvironmentReader::default();
+        mock_environment_reader
+            .expect_read()
+            .returning(|_| Ok("http://127.0.0.1:1234".to_string()));
+        let under_test = GitLabClient::new(
+            mock_http_client,
+            mock_url_parser_for_project("gitlab.example.com", "group/project"),
+            mock_environment_reader,
+        );
+
+        assert_that(
+            &under_test
+                .get_default_branch(&RepositoryUrl::new(
+                    "https://gitlab.example.com/group/project".to_string(),
+                ))
+                .await
+                .unwrap(),
+        )
+        .is_equal_to(BranchName::new("main".to_string()));
+    }
+
+    #[async_std::test]
+    async fn gets_commit() {
+        let date = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+        let mut mock_http_client = MockHttpClientFacade::default();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            api_request(
+                Method::Get,
+                "https://gitlab.com/api/v4/projects/group%2Fsub%2Fproject/repository/commits/sha",
+            ),
+            successful_json_response(serde_json::json!(CommitResponseBody::new(
+                "sha".to_string(),
+                "author".to_string(),
+                date
+            ))),
+        );
+
+        assert_that(
+            &under_test(mock_http_client)
+                .get_commit(&repository_url(), "sha")
+                .await
+                .unwrap(),
+        )
+        .is_equal_to(Commit::new("sha".to_string(), date, "author".to_string()));
+    }
+
+    #[async_std::test]
+    async fn gets_default_branch() {
+        let mut mock_http_client = MockHttpClientFacade::default();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            api_request(
+                Method::Get,
+                "https://gitlab.com/api/v4/projects/group%2Fsub%2Fproject",
+            ),
+            successful_json_response(serde_json::json!(ProjectResponseBody::new(
+                "main".to_string()
+            ))),
+        );
+
+        assert_that(
+            &under_test(mock_http_client)
+                .get_default_branch(&repository_url())
+                .await
+                .unwrap(),
+        )
+        .is_equal_to(BranchName::new("main".to_string()));
+    }
+
+    #[async_std::test]
+    async fn compares_branches() {
+        let mut mock_http_client = MockHttpClientFacade::default();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            api_request(
+                Method::Get,
+                "https://gitlab.com/api/v4/projects/group%2Fsub%2Fproject/repository/compare?from=main&to=feature",
+            ),
+            successful_json_response(serde_json::json!(CompareResponseBody::new(vec![
+                "a".to_string()
+            ]))),
+        );
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            api_request(
+                Method::Get,
+                "https://gitlab.com/api/v4/projects/group%2Fsub%2Fproject/repository/compare?from=feature&to=main",
+            ),
+            successful_json_response(serde_json::json!(CompareResponseBody::new(vec![
+                "b".to_string(),
+                "c".to_string()
+            ]))),
+        );
+
+        assert_that(
+            &under_test(mock_http_client)
+                .compare_branches(
+                    &repository_url(),
+                    &BranchName::new("main".to_string()),
+                    &BranchName::new("feature".to_string()),
+                )
+                .await
+                .unwrap(),
+        )
+        .is_equal_to(BranchComparison::new(1, 2));
+    }
+
+    #[async_std::test]
+    async fn deletes_branch_with_url_encoded_name() {
+        let mut mock_http_client = MockHttpClientFacade::default();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            api_request(
+                Method::Delete,
+                "https://gitlab.com/api/v4/projects/group%2Fsub%2Fproject/repository/branches/feature%2Fdone",
+            ),
+            Response::new(StatusCode::NoContent, HashMap::new(), "".to_string()),
+        );
+
+        assert_that(
+            &under_test(mock_http_client)
+                .delete_branch(
+                    &repository_url(),
+                    &BranchName::new("feature/done".to_string()),
+                )
+                .await,
+        )
+        .is_ok();
+    }
+
+    #[async_std::test]
+    async fn validates_authentication_credentials() {
+        let mut headers = HashMap::new();
+        headers.insert(HeaderName::from("private-token"), "token".to_string());
+        let mut mock_http_client = MockHttpClientFacade::default();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            Request::new(
+                Method::Get,
+                Url::parse("https://gitlab.com/api/v4/user").unwrap(),
+                headers,
+            ),
+            Response::new(StatusCode::Unauthorized, HashMap::new(), "".to_string()),
+        );
+
+        assert_that(
+            &under_test(mock_http_client)
+                .validate_authentication_credentials(GitLabAuthenticationToken::new(
+                    "token".to_string(),
+                ))
+                .await
+                .unwrap(),
+        )
+        .is_equal_to(AuthenticationCredentialValidity::Invalid);
+    }
+
+    #[async_std::test]
+    async fn validates_authentication_credentials_with_self_hosted_instance() {
+        let mut headers = HashMap::new();
+        headers.insert(HeaderName::from("private-token"), "token".to_string());
+        let mut mock_http_client = MockHttpClientFacade::default();
+        prepare_mock_http_client(
+            &mut mock_http_client,
+            Request::new(
+                Method::Get,
+                Url::parse("https://gitlab.example.com/api/v4/user").unwrap(),
+                headers,
+            ),
+            Response::new(StatusCode::Ok, HashMap::new(), "{}".to_string()),
+        );
+
+        assert_that(
+            &under_test(mock_http_client)
+                .with_host("gitlab.example.com".to_string())
+                .validate_authentication_credentials(GitLabAuthenticationToken::new(
+                    "token".to_string(),
+                ))
+                .await
+                .unwrap(),
+        )
+        .is_equal_to(AuthenticationCredentialValidity::Valid);
+    }
+}
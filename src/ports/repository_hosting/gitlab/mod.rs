@@ -0,0 +1,13 @@
+pub use gitlab_authentication_validation_adapter::*;
+pub use gitlab_client::*;
+pub use gitlab_repository_provider_adapter::*;
+pub use parse_repository_url::*;
+
+mod authentication_token;
+mod error;
+mod gitlab_authentication_validation_adapter;
+mod gitlab_client;
+mod gitlab_repository_provider_adapter;
+mod parse_repository_url;
+mod repository;
+mod responses;
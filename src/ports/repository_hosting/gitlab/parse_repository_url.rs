@@ -0,0 +1,137 @@
+use std::convert::{TryFrom, TryInto};
+
+use regex::Regex;
+
+use crate::ports::repository_hosting::gitlab::repository::GitLabProject;
+use crate::ports::repository_hosting::repository::RepositoryUrl;
+
+#[cfg_attr(test, mockall::automock)]
+pub trait GitLabRepositoryUrlParser {
+    fn parse(&self, url: RepositoryUrl) -> Result<GitLabProject, RepositoryUrlParseError>;
+}
+
+#[derive(Debug, Default)]
+pub struct GitLabRepositoryUrlParserImpl;
+
+impl GitLabRepositoryUrlParserImpl {
+    pub fn new() -> Self {
+        GitLabRepositoryUrlParserImpl
+    }
+}
+
+impl GitLabRepositoryUrlParser for GitLabRepositoryUrlParserImpl {
+    fn parse(&self, url: RepositoryUrl) -> Result<GitLabProject, RepositoryUrlParseError> {
+        url.try_into()
+    }
+}
+
+impl TryFrom<RepositoryUrl> for GitLabProject {
+    type Error = RepositoryUrlParseError;
+
+    fn try_from(url: RepositoryUrl) -> Result<Self, Self::Error> {
+        let re = Regex::new(
+            r"^(?:(?:https?://)?(?P<host>[^/@\s]+)/|(?:ssh|git)://(?:[^@/\s]+@)?(?P<ssh_host>[^/:\s]+)(?::\d+)?/|[^@/\s]+@(?P<scp_host>[^/:\s]+):)(?P<path>[^/\s]+(?:/[^/\s]+)+)$",
+        )
+        .unwrap();
+
+        if let Some(captures) = re.captures(url.without_clone_suffix()) {
+            let host = captures
+                .name("host")
+                .or_else(|| captures.name("ssh_host"))
+                .or_else(|| captures.name("scp_host"));
+            if let Some(host) = host {
+                if let Some(path) = captures.name("path") {
+                    return Ok(GitLabProject::new(
+                        host.as_str().to_string(),
+                        path.as_str().to_string(),
+                    ));
+                }
+            }
+        }
+
+        Err(RepositoryUrlParseError(url.value().clone()))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("failed to parse GitLab project from '{0}'")]
+pub struct RepositoryUrlParseError(String);
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    fn parse(url: &str) -> Result<GitLabProject, RepositoryUrlParseError> {
+        GitLabRepositoryUrlParserImpl::new().parse(RepositoryUrl::new(url.to_string()))
+    }
+
+    #[test]
+    fn parses_gitlab_project_url() {
+        assert_that(&parse("https://gitlab.com/group/project").unwrap()).is_equal_to(
+            GitLabProject::new("gitlab.com".to_string(), "group/project".to_string()),
+        );
+    }
+
+    #[test]
+    fn parses_gitlab_project_url_without_scheme() {
+        assert_that(&parse("gitlab.com/group/project").unwrap()).is_equal_to(GitLabProject::new(
+            "gitlab.com".to_string(),
+            "group/project".to_string(),
+        ));
+    }
+
+    #[test]
+    fn parses_gitlab_project_url_with_nested_groups() {
+        assert_that(&parse("https://gitlab.com/group/sub/project").unwrap()).is_equal_to(
+            GitLabProject::new("gitlab.com".to_string(), "group/sub/project".to_string()),
+        );
+    }
+
+    #[test]
+    fn parses_self_hosted_gitlab_project_url() {
+        assert_that(&parse("https://gitlab.example.com:8443/group/project").unwrap()).is_equal_to(
+            GitLabProject::new(
+                "gitlab.example.com:8443".to_string(),
+                "group/project".to_string(),
+            ),
+        );
+    }
+
+    #[test]
+    fn parses_gitlab_project_url_with_git_suffix_and_trailing_slash() {
+        assert_that(&parse("https://gitlab.com/group/project.git/").unwrap()).is_equal_to(
+            GitLabProject::new("gitlab.com".to_string(), "group/project".to_string()),
+        );
+    }
+
+    #[test]
+    fn parses_scp_like_ssh_gitlab_project_url() {
+        assert_that(&parse("git@gitlab.example.com:group/sub/project.git").unwrap()).is_equal_to(
+            GitLabProject::new(
+                "gitlab.example.com".to_string(),
+                "group/sub/project".to_string(),
+            ),
+        );
+    }
+
+    #[test]
+    fn parses_ssh_gitlab_project_url_ignoring_ssh_port() {
+        assert_that(&parse("ssh://git@gitlab.example.com:2222/group/project.git").unwrap())
+            .is_equal_to(GitLabProject::new(
+                "gitlab.example.com".to_string(),
+                "group/project".to_string(),
+            ));
+    }
+
+    #[test]
+    fn fails_to_parse_gitlab_project_url_missing_group() {
+        assert_that(&parse("https://gitlab.com/project").is_err()).is_true();
+    }
+
+    #[test]
+    fn fails_to_parse_gitlab_project_url_with_empty_path_segment() {
+        assert_that(&parse("https://gitlab.com//project").is_err()).is_true();
+    }
+}
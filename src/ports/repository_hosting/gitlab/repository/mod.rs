@@ -0,0 +1,38 @@
+#[derive(Debug, Eq, PartialEq)]
+pub struct GitLabProject {
+    host: String,
+    path: String,
+}
+
+impl GitLabProject {
+    pub fn new(host: String, path: String) -> Self {
+        GitLabProject { host, path }
+    }
+
+    pub fn host(&self) -> &String {
+        &self.host
+    }
+
+    pub fn path(&self) -> &String {
+        &self.path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn returns_host() {
+        let under_test = GitLabProject::new("gitlab.com".to_string(), "group/project".to_string());
+        assert_that(&under_test.host()).is_equal_to(&"gitlab.com".to_string());
+    }
+
+    #[test]
+    fn returns_path() {
+        let under_test = GitLabProject::new("gitlab.com".to_string(), "group/project".to_string());
+        assert_that(&under_test.path()).is_equal_to(&"group/project".to_string());
+    }
+}
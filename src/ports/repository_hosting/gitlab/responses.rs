@@ -0,0 +1,150 @@
+pub use branch::*;
+pub use commit::*;
+pub use compare::*;
+pub use list_branches_response::*;
+pub use project::*;
+
+mod list_branches_response {
+    use super::Branch;
+
+    #[derive(Debug, serde::Serialize, serde::Deserialize)]
+    #[serde(transparent)]
+    pub struct ListBranchesResponseBody {
+        branches: Vec<Branch>,
+    }
+
+    impl ListBranchesResponseBody {
+        #[cfg(test)]
+        pub fn new(branches: Vec<Branch>) -> Self {
+            ListBranchesResponseBody { branches }
+        }
+
+        pub fn branches(&self) -> &Vec<Branch> {
+            &self.branches
+        }
+    }
+}
+
+mod branch {
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct Branch {
+        name: String,
+        commit: BranchCommit,
+        #[serde(default)]
+        protected: bool,
+    }
+
+    impl Branch {
+        #[cfg(test)]
+        pub fn new(name: String, id: String) -> Self {
+            Branch {
+                name,
+                commit: BranchCommit { id },
+                protected: false,
+            }
+        }
+
+        #[cfg(test)]
+        pub fn protected(mut self) -> Self {
+            self.protected = true;
+            self
+        }
+
+        pub fn name(&self) -> &String {
+            &self.name
+        }
+
+        pub fn sha(&self) -> &String {
+            &self.commit.id
+        }
+
+        pub fn is_protected(&self) -> bool {
+            self.protected
+        }
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    struct BranchCommit {
+        id: String,
+    }
+}
+
+mod commit {
+    use chrono::{DateTime, Utc};
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct CommitResponseBody {
+        id: String,
+        author_name: String,
+        committed_date: DateTime<Utc>,
+    }
+
+    impl CommitResponseBody {
+        #[cfg(test)]
+        pub fn new(id: String, author_name: String, committed_date: DateTime<Utc>) -> Self {
+            CommitResponseBody {
+                id,
+                author_name,
+                committed_date,
+            }
+        }
+
+        pub fn sha(&self) -> &String {
+            &self.id
+        }
+
+        pub fn author(&self) -> &String {
+            &self.author_name
+        }
+
+        pub fn date(&self) -> &DateTime<Utc> {
+            &self.committed_date
+        }
+    }
+}
+
+mod project {
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct ProjectResponseBody {
+        default_branch: String,
+    }
+
+    impl ProjectResponseBody {
+        #[cfg(test)]
+        pub fn new(default_branch: String) -> Self {
+            ProjectResponseBody { default_branch }
+        }
+
+        pub fn default_branch(&self) -> &String {
+            &self.default_branch
+        }
+    }
+}
+
+mod compare {
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct CompareResponseBody {
+        commits: Vec<CompareCommit>,
+    }
+
+    impl CompareResponseBody {
+        #[cfg(test)]
+        pub fn new(commit_ids: Vec<String>) -> Self {
+            CompareResponseBody {
+                commits: commit_ids
+                    .into_iter()
+                    .map(|id| CompareCommit { id })
+                    .collect(),
+            }
+        }
+
+        pub fn commit_count(&self) -> u32 {
+            self.commits.len() as u32
+        }
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    struct CompareCommit {
+        id: String,
+    }
+}
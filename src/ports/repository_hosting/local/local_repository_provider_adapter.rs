@@ -0,0 +1,303 @@
+use std::path::PathBuf;
+
+use crate::domain::repository::{Branch, BranchComparison, Commit, RepositoryProviderError};
+use crate::domain::repository::{
+    Repository, RepositoryMaintainer, RepositoryProvider, RepositoryUrl,
+};
+use crate::domain::value_object::ValueObject;
+use crate::ports::git::{BranchReference, GitDirectory, GitError};
+
+/// Serves repositories given as local paths or `file://` URLs by reading their git directory.
+///
+/// Git reads block, so they run on the blocking thread pool.
+#[derive(Default)]
+pub struct LocalRepositoryProviderAdapter;
+
+impl LocalRepositoryProviderAdapter {
+    pub fn new() -> Self {
+        LocalRepositoryProviderAdapter
+    }
+}
+
+#[async_trait::async_trait]
+impl RepositoryProvider for LocalRepositoryProviderAdapter {
+    async fn get_repository(
+        &self,
+        url: &RepositoryUrl,
+    ) -> Result<Repository, RepositoryProviderError> {
+        let branches = with_git_directory(url, |git_directory| {
+            let head_branch = git_directory.head_branch()?;
+            Ok(git_directory
+                .branches()?
+                .iter()
+                .map(|reference| mark_branch(Branch::new(reference.name().clone()), &head_branch))
+                .collect())
+        })
+        .await?;
+        Ok(Repository::new(url.clone(), branches))
+    }
+
+    async fn get_repository_with_head_commits(
+        &self,
+        url: &RepositoryUrl,
+    ) -> Result<Repository, RepositoryProviderError> {
+        let branches = with_git_directory(url, |git_directory| {
+            let head_branch = git_directory.head_branch()?;
+            git_directory
+                .branches()?
+                .iter()
+                .map(|reference| {
+                    let commit = git_directory.commit(reference.sha())?;
+                    Ok(mark_branch(
+                        Branch::with_head_commit(
+                            reference.name().clone(),
+                            Commit::new(
+                                commit.sha().clone(),
+                                *commit.date(),
+                                commit.author().clone(),
+                            ),
+                        ),
+                        &head_branch,
+                    ))
+                })
+                .collect()
+        })
+        .await?;
+        Ok(Repository::new(url.clone(), branches))
+    }
+
+    async fn compare_branches(
+        &self,
+        url: &RepositoryUrl,
+        base: &Branch,
+        head: &Branch,
+    ) -> Result<BranchComparison, RepositoryProviderError> {
+        let base_name = base.value().clone();
+        let head_name = head.value().clone();
+        let divergence = with_git_directory(url, move |git_directory| {
+            let branches = git_directory.branches()?;
+            git_directory.compare(
+                branch_sha(&branches, &base_name)?,
+                branch_sha(&branches, &head_name)?,
+            )
+        })
+        .await?;
+        Ok(BranchComparison::new(
+            head.clone(),
+            divergence.ahead_by(),
+            divergence.behind_by(),
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl RepositoryMaintainer for LocalRepositoryProviderAdapter {
+    async fn delete_branch(
+        &self,
+        url: &RepositoryUrl,
+        branch: &Branch,
+    ) -> Result<(), RepositoryProviderError> {
+        let name = branch.value().clone();
+        with_git_directory(url, move |git_directory| git_directory.delete_branch(&name)).await
+    }
+}
+
+pub(super) async fn with_git_directory<T, F>(
+    url: &RepositoryUrl,
+    read: F,
+) -> Result<T, RepositoryProviderError>
+where
+    T: Send + 'static,
+    F: FnOnce(&GitDirectory) -> Result<T, GitError> + Send + 'static,
+{
+    let path = repository_path(url);
+    blocking::unblock(move || read(&GitDirectory::open(&path)?))
+        .await
+        .map_err(|git_error| map_git_error(git_error).for_repository(url))
+}
+
+fn repository_path(url: &RepositoryUrl) -> PathBuf {
+    let value = url.value().trim();
+    let path = value.strip_prefix("file://").unwrap_or(value);
+    PathBuf::from(shellexpand::tilde(path).to_string())
+}
+
+pub(super) fn branch_sha<'a>(
+    branches: &'a [BranchReference],
+    name: &str,
+) -> Result<&'a String, GitError> {
+    branches
+        .iter()
+        .find(|reference| reference.name() == name)
+        .map(BranchReference::sha)
+        .ok_or_else(|| GitError::BranchNotFound(name.to_string()))
+}
+
+fn mark_branch(branch: Branch, head_branch: &Option<String>) -> Branch {
+    let is_default = head_branch.as_ref() == Some(branch.value());
+    branch.marked_default(is_default)
+}
+
+fn map_git_error(git_error: GitError) -> RepositoryProviderError {
+    let repository = None;
+    let message = git_error.to_string();
+    match git_error {
+        GitError::NotARepository(..)
+        | GitError::BranchNotFound(..)
+        | GitError::RemoteNotFound(..) => RepositoryProviderError::NotFound {
+            repository,
+            message,
+        },
+        GitError::ObjectNotFound(..) | GitError::CorruptObject { .. } | GitError::IO(..) => {
+            RepositoryProviderError::Other {
+                repository,
+                message,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use spectral::prelude::*;
+
+    use crate::ports::git::TestRepository;
+
+    use super::*;
+
+    fn sha(number: u32) -> String {
+        TestRepository::sha(number)
+    }
+
+    fn repository(directory: &assert_fs::TempDir) -> TestRepository {
+        let repository = TestRepository::bare(directory.path(), "main");
+        repository.commit(&sha(1), &[], "Ann", 946684800);
+        repository.commit(&sha(2), &[&sha(1)], "Bob", 978307200);
+        repository.commit(&sha(3), &[&sha(1)], "Cid", 1009843200);
+        repository.branch("main", &sha(2));
+        repository.packed_branches(&[("done", &sha(1)), ("wip", &sha(3))]);
+        repository
+    }
+
+    fn url_of(directory: &assert_fs::TempDir) -> RepositoryUrl {
+        RepositoryUrl::new(format!("file://{}", directory.path().display()))
+    }
+
+    #[async_std::test]
+    async fn gets_repository_with_default_branch_marked() {
+        let directory = assert_fs::TempDir::new().unwrap();
+        repository(&directory);
+
+        let repository = LocalRepositoryProviderAdapter::new()
+            .get_repository(&url_of(&directory))
+            .await
+            .unwrap();
+
+        assert_that(repository.branches()).is_equal_to(vec![
+            Branch::new("done".to_string()),
+            Branch::new("main".to_string()).marked_default(true),
+            Branch::new("wip".to_string()),
+        ]);
+        assert_that(&repository.default_branch().unwrap().value().as_str()).is_equal_to("main");
+    }
+
+    #[async_std::test]
+    async fn gets_repository_with_head_commits() {
+        let directory = assert_fs::TempDir::new().unwrap();
+        repository(&directory);
+
+        let repository = LocalRepositoryProviderAdapter::new()
+            .get_repository_with_head_commits(&RepositoryUrl::new(
+                directory.path().display().to_string(),
+            ))
+            .await
+            .unwrap();
+
+        let wip = &repository.branches()[2];
+        assert_that(&wip.head_commit()).is_equal_to(Some(&Commit::new(
+            sha(3),
+            Utc.with_ymd_and_hms(2002, 1, 1, 0, 0, 0).unwrap(),
+            "Cid".to_string(),
+        )));
+    }
+
+    #[async_std::test]
+    async fn compares_branches() {
+        let directory = assert_fs::TempDir::new().unwrap();
+        repository(&directory);
+        let under_test = LocalRepositoryProviderAdapter::new();
+        let main = Branch::new("main".to_string());
+        let url = url_of(&directory);
+
+        let merged = under_test
+            .compare_branches(&url, &main, &Branch::new("done".to_string()))
+            .await
+            .unwrap();
+        let unmerged = under_test
+            .compare_branches(&url, &main, &Branch::new("wip".to_string()))
+            .await
+            .unwrap();
+
+        assert_that(&merged.ahead_by()).is_equal_to(0);
+        assert_that(&unmerged.ahead_by()).is_equal_to(1);
+        assert_that(&unmerged.behind_by()).is_equal_to(1);
+    }
+
+    #[async_std::test]
+    async fn fails_to_compare_branch_that_does_not_exist() {
+        let directory = assert_fs::TempDir::new().unwrap();
+        repository(&directory);
+
+        let result = LocalRepositoryProviderAdapter::new()
+            .compare_branches(
+                &url_of(&directory),
+                &Branch::new("main".to_string()),
+                &Branch::new("gone".to_string()),
+            )
+            .await;
+
+        match result.err().unwrap() {
+            error @ RepositoryProviderError::NotFound { .. } => {
+                assert_that(&error.to_string()).is_equal_to("branch 'gone' not found".to_string())
+            }
+            error => panic!("unexpected error {:?}", error),
+        }
+    }
+
+    #[async_std::test]
+    async fn deletes_packed_branch() {
+        let directory = assert_fs::TempDir::new().unwrap();
+        repository(&directory);
+        let under_test = LocalRepositoryProviderAdapter::new();
+        let url = url_of(&directory);
+
+        under_test
+            .delete_branch(&url, &Branch::new("wip".to_string()))
+            .await
+            .unwrap();
+
+        assert_that(under_test.get_repository(&url).await.unwrap().branches()).is_equal_to(vec![
+            Branch::new("done".to_string()),
+            Branch::new("main".to_string()).marked_default(true),
+        ]);
+    }
+
+    #[async_std::test]
+    async fn fails_with_repository_identity_when_path_is_not_a_repository() {
+        let directory = assert_fs::TempDir::new().unwrap();
+        let url = url_of(&directory);
+
+        let error = LocalRepositoryProviderAdapter::new()
+            .get_repository(&url)
+            .await
+            .err()
+            .unwrap();
+
+        assert_that(&error.repository()).is_equal_to(Some(url.identity()));
+        assert_that(&error.to_string()).is_equal_to(format!(
+            "'{}' is not a git repository",
+            directory.path().display()
+        ));
+    }
+}
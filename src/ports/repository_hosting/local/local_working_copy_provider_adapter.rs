@@ -0,0 +1,357 @@
+use std::path::PathBuf;
+
+use crate::domain::repository::{
+    Branch, BranchComparison, LocalBranch, LocalRepository, LocalRepositoryProvider,
+    RepositoryProviderError, RepositoryUrl, UpstreamStatus,
+};
+use crate::domain::value_object::ValueObject;
+use crate::ports::git::{BranchReference, GitConfig, GitDirectory, GitError};
+use crate::ports::repository_hosting::local::local_repository_provider_adapter::{
+    branch_sha, with_git_directory,
+};
+
+const DEFAULT_REMOTE: &str = "origin";
+const CONVENTIONAL_DEFAULT_BRANCHES: &[&str] = &["main", "master"];
+
+/// Serves the working copies of clones on this machine, including their upstream branches.
+#[derive(Default)]
+pub struct LocalWorkingCopyProviderAdapter {
+    global_config_files: Vec<PathBuf>,
+}
+
+impl LocalWorkingCopyProviderAdapter {
+    pub fn new() -> Self {
+        LocalWorkingCopyProviderAdapter::default()
+    }
+
+    /// Reads the user's git config files, in the order git reads them, before a clone's own
+    /// config when resolving remote URLs. Files that do not exist are skipped.
+    pub fn with_global_config_files(mut self, global_config_files: Vec<PathBuf>) -> Self {
+        self.global_config_files = global_config_files;
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl LocalRepositoryProvider for LocalWorkingCopyProviderAdapter {
+    async fn get_local_repository(
+        &self,
+        url: &RepositoryUrl,
+    ) -> Result<LocalRepository, RepositoryProviderError> {
+        let branches = with_git_directory(url, |git_directory| {
+            let config = git_directory.config()?;
+            let checked_out_branch = git_directory.head_branch()?;
+            let references = git_directory.branches()?;
+            let default_branch = default_branch(git_directory, &references)?;
+            references
+                .iter()
+                .map(|reference| {
+                    let name = reference.name();
+                    Ok(LocalBranch::new(
+                        Branch::new(name.clone())
+                            .marked_default(default_branch.as_ref() == Some(name)),
+                    )
+                    .with_upstream(upstream_status(git_directory, &config, name)?)
+                    .marked_checked_out(checked_out_branch.as_ref() == Some(name)))
+                })
+                .collect()
+        })
+        .await?;
+        Ok(LocalRepository::new(url.clone(), branches))
+    }
+
+    async fn compare_branches(
+        &self,
+        url: &RepositoryUrl,
+        base: &Branch,
+        head: &Branch,
+    ) -> Result<BranchComparison, RepositoryProviderError> {
+        let base_name = base.value().clone();
+        let head_name = head.value().clone();
+        let divergence = with_git_directory(url, move |git_directory| {
+            let branches = git_directory.branches()?;
+            git_directory.compare(
+                branch_sha(&branches, &base_name)?,
+                branch_sha(&branches, &head_name)?,
+            )
+        })
+        .await?;
+        Ok(BranchComparison::new(
+            head.clone(),
+            divergence.ahead_by(),
+            divergence.behind_by(),
+        ))
+    }
+
+    async fn delete_branch(
+        &self,
+        url: &RepositoryUrl,
+        branch: &Branch,
+    ) -> Result<(), RepositoryProviderError> {
+        let name = branch.value().clone();
+        with_git_directory(url, move |git_directory| git_directory.delete_branch(&name)).await
+    }
+
+    async fn remote_url(
+        &self,
+        url: &RepositoryUrl,
+        remote: &str,
+    ) -> Result<RepositoryUrl, RepositoryProviderError> {
+        let remote = remote.to_string();
+        let global_config_files = self.global_config_files.clone();
+        let remote_url = with_git_directory(url, move |git_directory| {
+            let mut layers = global_config_files
+                .iter()
+                .map(read_config_file)
+                .collect::<Result<Vec<GitConfig>, GitError>>()?;
+            layers.push(git_directory.config()?);
+            GitConfig::layered(layers)
+                .remote_url(&remote)
+                .ok_or(GitError::RemoteNotFound(remote))
+        })
+        .await?;
+        Ok(RepositoryUrl::new(remote_url))
+    }
+}
+
+fn read_config_file(path: &PathBuf) -> Result<GitConfig, GitError> {
+    match std::fs::read_to_string(path) {
+        Ok(content) => Ok(GitConfig::parse(&content)),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(GitConfig::default()),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// The branch `origin`'s `HEAD` points at, or else `main` or `master` if there is one.
+fn default_branch(
+    git_directory: &GitDirectory,
+    references: &[BranchReference],
+) -> Result<Option<String>, GitError> {
+    if let Some(remote_head_branch) = git_directory.remote_head_branch(DEFAULT_REMOTE)? {
+        return Ok(Some(remote_head_branch));
+    }
+    Ok(CONVENTIONAL_DEFAULT_BRANCHES
+        .iter()
+        .find(|name| references.iter().any(|reference| reference.name() == *name))
+        .map(|name| name.to_string()))
+}
+
+fn upstream_status(
+    git_directory: &GitDirectory,
+    config: &GitConfig,
+    branch: &str,
+) -> Result<UpstreamStatus, GitError> {
+    match config.upstream_reference(branch) {
+        Some(reference) if git_directory.reference_exists(&reference)? => {
+            Ok(UpstreamStatus::Present)
+        }
+        Some(_) => Ok(UpstreamStatus::Gone),
+        None => Ok(UpstreamStatus::Untracked),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use crate::ports::git::TestRepository;
+
+    use super::*;
+
+    fn sha(number: u32) -> String {
+        TestRepository::sha(number)
+    }
+
+    /// A clone of a repository whose `gone` branch was deleted on the remote after merging.
+    fn working_copy(directory: &assert_fs::TempDir) -> TestRepository {
+        let repository = TestRepository::bare(&directory.path().join(".git"), "feature");
+        repository.commit(&sha(1), &[], "Ann", 100);
+        repository.commit(&sha(2), &[&sha(1)], "Ann", 200);
+        repository.commit(&sha(3), &[&sha(2)], "Bob", 300);
+        repository.branch("main", &sha(2));
+        repository.branch("merged", &sha(1));
+        repository.branch("gone", &sha(3));
+        repository.branch("feature", &sha(3));
+        std::fs::create_dir_all(repository.path().join("refs/remotes/origin")).unwrap();
+        std::fs::write(
+            repository.path().join("refs/remotes/origin/HEAD"),
+            "ref: refs/remotes/origin/main\n",
+        )
+        .unwrap();
+        std::fs::write(
+            repository.path().join("refs/remotes/origin/feature"),
+            format!("{}\n", sha(3)),
+        )
+        .unwrap();
+        std::fs::write(
+            repository.path().join("config"),
+            "[remote \"origin\"]\n\
+             \turl = https://github.com/owner/repo\n\
+             \tfetch = +refs/heads/*:refs/remotes/origin/*\n\
+             [branch \"gone\"]\n\
+             \tremote = origin\n\
+             \tmerge = refs/heads/gone\n\
+             [branch \"feature\"]\n\
+             \tremote = origin\n\
+             \tmerge = refs/heads/feature\n",
+        )
+        .unwrap();
+        repository
+    }
+
+    fn url_of(directory: &assert_fs::TempDir) -> RepositoryUrl {
+        RepositoryUrl::new(directory.path().display().to_string())
+    }
+
+    #[async_std::test]
+    async fn gets_local_repository_with_upstreams_default_and_checked_out_branches() {
+        let directory = assert_fs::TempDir::new().unwrap();
+        working_copy(&directory);
+
+        let repository = LocalWorkingCopyProviderAdapter::new()
+            .get_local_repository(&url_of(&directory))
+            .await
+            .unwrap();
+
+        assert_that(repository.branches()).is_equal_to(vec![
+            LocalBranch::new(Branch::new("feature".to_string()))
+                .with_upstream(UpstreamStatus::Present)
+                .marked_checked_out(true),
+            LocalBranch::new(Branch::new("gone".to_string())).with_upstream(UpstreamStatus::Gone),
+            LocalBranch::new(Branch::new("main".to_string()).marked_default(true)),
+            LocalBranch::new(Branch::new("merged".to_string())),
+        ]);
+    }
+
+    #[async_std::test]
+    async fn falls_back_to_conventional_default_branch_without_remote_head() {
+        let directory = assert_fs::TempDir::new().unwrap();
+        let repository = working_copy(&directory);
+        std::fs::remove_file(repository.path().join("refs/remotes/origin/HEAD")).unwrap();
+
+        let repository = LocalWorkingCopyProviderAdapter::new()
+            .get_local_repository(&url_of(&directory))
+            .await
+            .unwrap();
+
+        assert_that(&repository.default_branch())
+            .is_equal_to(Some(&Branch::new("main".to_string()).marked_default(true)));
+    }
+
+    #[async_std::test]
+    async fn compares_branch_with_default_branch() {
+        let directory = assert_fs::TempDir::new().unwrap();
+        working_copy(&directory);
+
+        let comparison = LocalWorkingCopyProviderAdapter::new()
+            .compare_branches(
+                &url_of(&directory),
+                &Branch::new("main".to_string()),
+                &Branch::new("merged".to_string()),
+            )
+            .await
+            .unwrap();
+
+        assert_that(&comparison.ahead_by()).is_equal_to(0);
+        assert_that(&comparison.behind_by()).is_equal_to(1);
+    }
+
+    #[async_std::test]
+    async fn deletes_branch_with_its_upstream_configuration() {
+        let directory = assert_fs::TempDir::new().unwrap();
+        let repository = working_copy(&directory);
+        let under_test = LocalWorkingCopyProviderAdapter::new();
+
+        under_test
+            .delete_branch(&url_of(&directory), &Branch::new("gone".to_string()))
+            .await
+            .unwrap();
+
+        assert_that(&repository.path().join("refs/heads/gone").exists()).is_false();
+        assert_that(
+            &std::fs::read_to_string(repository.path().join("config"))
+                .unwrap()
+                .contains("[branch \"gone\"]"),
+        )
+        .is_false();
+    }
+
+    #[async_std::test]
+    async fn reads_url_of_remote() {
+        let directory = assert_fs::TempDir::new().unwrap();
+        working_copy(&directory);
+
+        let remote_url = LocalWorkingCopyProviderAdapter::new()
+            .remote_url(&url_of(&directory), "origin")
+            .await
+            .unwrap();
+
+        assert_that(&remote_url).is_equal_to(RepositoryUrl::new(
+            "https://github.com/owner/repo".to_string(),
+        ));
+    }
+
+    #[async_std::test]
+    async fn rewrites_url_of_remote_with_rule_of_global_config() {
+        let directory = assert_fs::TempDir::new().unwrap();
+        let repository = working_copy(&directory);
+        let config_file = repository.path().join("config");
+        let config = std::fs::read_to_string(&config_file).unwrap();
+        std::fs::write(
+            &config_file,
+            format!(
+                "{}[remote \"upstream\"]\n\turl = gh:upstream-owner/repo\n",
+                config
+            ),
+        )
+        .unwrap();
+        let global_directory = assert_fs::TempDir::new().unwrap();
+        let global_config_file = global_directory.path().join(".gitconfig");
+        std::fs::write(
+            &global_config_file,
+            "[url \"https://github.com/\"]\n\tinsteadOf = gh:\n",
+        )
+        .unwrap();
+
+        let remote_url = LocalWorkingCopyProviderAdapter::new()
+            .with_global_config_files(vec![
+                global_directory.path().join("missing"),
+                global_config_file,
+            ])
+            .remote_url(&url_of(&directory), "upstream")
+            .await
+            .unwrap();
+
+        assert_that(&remote_url).is_equal_to(RepositoryUrl::new(
+            "https://github.com/upstream-owner/repo".to_string(),
+        ));
+    }
+
+    #[async_std::test]
+    async fn fails_to_read_url_of_remote_that_is_not_configured() {
+        let directory = assert_fs::TempDir::new().unwrap();
+        working_copy(&directory);
+
+        let result = LocalWorkingCopyProviderAdapter::new()
+            .remote_url(&url_of(&directory), "upstream")
+            .await;
+
+        assert_that(&result.err().unwrap().to_string())
+            .is_equal_to("remote 'upstream' not found".to_string());
+    }
+
+    #[async_std::test]
+    async fn fails_when_directory_is_not_a_repository() {
+        let directory = assert_fs::TempDir::new().unwrap();
+
+        let result = LocalWorkingCopyProviderAdapter::new()
+            .get_local_repository(&url_of(&directory))
+            .await;
+
+        assert_that(&matches!(
+            result,
+            Err(RepositoryProviderError::NotFound { .. })
+        ))
+        .is_true();
+    }
+}
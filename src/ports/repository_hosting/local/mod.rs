@@ -0,0 +1,5 @@
+pub use local_repository_provider_adapter::*;
+pub use local_working_copy_provider_adapter::*;
+
+mod local_repository_provider_adapter;
+mod local_working_copy_provider_adapter;
@@ -1 +1,12 @@
+pub use repository_host_client::*;
+pub use response_error::*;
+
+pub mod bitbucket;
+pub mod gitea;
 pub mod github;
+pub mod gitlab;
+pub mod local;
+mod repository;
+mod repository_branches;
+mod repository_host_client;
+mod response_error;
@@ -0,0 +1,105 @@
+use crate::ports::repository_hosting::repository::PullRequest;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Branch {
+    name: BranchName,
+    head_commit_sha: String,
+    protected: bool,
+    pull_requests: Vec<PullRequest>,
+}
+
+impl Branch {
+    pub fn new(name: BranchName, head_commit_sha: String) -> Self {
+        Branch {
+            name,
+            head_commit_sha,
+            protected: false,
+            pull_requests: vec![],
+        }
+    }
+
+    pub fn marked_protected(mut self, protected: bool) -> Self {
+        self.protected = protected;
+        self
+    }
+
+    pub fn with_pull_requests(mut self, pull_requests: Vec<PullRequest>) -> Self {
+        self.pull_requests = pull_requests;
+        self
+    }
+
+    pub fn name(&self) -> &BranchName {
+        &self.name
+    }
+
+    pub fn head_commit_sha(&self) -> &String {
+        &self.head_commit_sha
+    }
+
+    pub fn is_protected(&self) -> bool {
+        self.protected
+    }
+
+    pub fn pull_requests(&self) -> &Vec<PullRequest> {
+        &self.pull_requests
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct BranchName(String);
+
+impl BranchName {
+    pub fn new(value: String) -> Self {
+        BranchName(value)
+    }
+
+    pub fn value(&self) -> &String {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn returns_value() {
+        assert_that(&BranchName("branch name".to_string()).value())
+            .is_equal_to(&"branch name".to_string());
+    }
+
+    #[test]
+    fn returns_name() {
+        let under_test = Branch::new(BranchName::new("name".to_string()), "sha".to_string());
+        assert_that(&under_test.name()).is_equal_to(&BranchName::new("name".to_string()));
+    }
+
+    #[test]
+    fn returns_head_commit_sha() {
+        let under_test = Branch::new(BranchName::new("name".to_string()), "sha".to_string());
+        assert_that(&under_test.head_commit_sha()).is_equal_to(&"sha".to_string());
+    }
+
+    #[test]
+    fn is_unprotected_by_default() {
+        let under_test = Branch::new(BranchName::new("name".to_string()), "sha".to_string());
+        assert_that(&under_test.is_protected()).is_false();
+    }
+
+    #[test]
+    fn marks_branch_as_protected() {
+        let under_test = Branch::new(BranchName::new("name".to_string()), "sha".to_string())
+            .marked_protected(true);
+        assert_that(&under_test.is_protected()).is_true();
+    }
+
+    #[test]
+    fn returns_pull_requests() {
+        let pull_request = PullRequest::new(1, "OPEN".to_string(), "url".to_string());
+        let under_test = Branch::new(BranchName::new("name".to_string()), "sha".to_string())
+            .with_pull_requests(vec![pull_request.clone()]);
+        assert_that(&under_test.pull_requests()).is_equal_to(&vec![pull_request]);
+    }
+}
@@ -0,0 +1,22 @@
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct BranchComparison {
+    ahead_by: u32,
+    behind_by: u32,
+}
+
+impl BranchComparison {
+    pub fn new(ahead_by: u32, behind_by: u32) -> Self {
+        BranchComparison {
+            ahead_by,
+            behind_by,
+        }
+    }
+
+    pub fn ahead_by(&self) -> u32 {
+        self.ahead_by
+    }
+
+    pub fn behind_by(&self) -> u32 {
+        self.behind_by
+    }
+}
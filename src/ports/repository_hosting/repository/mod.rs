@@ -0,0 +1,13 @@
+pub use branch::*;
+pub use commit::*;
+pub use comparison::*;
+pub use owner::*;
+pub use pull_request::*;
+pub use url::*;
+
+mod branch;
+mod commit;
+mod comparison;
+mod owner;
+mod pull_request;
+mod url;
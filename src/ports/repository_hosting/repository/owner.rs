@@ -0,0 +1,45 @@
+use std::fmt::Formatter;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum RepositoryOwner {
+    Organization(String),
+    User(String),
+}
+
+impl RepositoryOwner {
+    pub fn name(&self) -> &String {
+        match self {
+            RepositoryOwner::Organization(name) | RepositoryOwner::User(name) => name,
+        }
+    }
+}
+
+impl core::fmt::Display for RepositoryOwner {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RepositoryOwner::Organization(name) => write!(f, "organization '{}'", name),
+            RepositoryOwner::User(name) => write!(f, "user '{}'", name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn returns_name() {
+        assert_that(&RepositoryOwner::Organization("org".to_string()).name())
+            .is_equal_to(&"org".to_string());
+    }
+
+    #[test]
+    fn implements_display() {
+        assert_that(&RepositoryOwner::Organization("org".to_string()).to_string())
+            .is_equal_to("organization 'org'".to_string());
+        assert_that(&RepositoryOwner::User("someone".to_string()).to_string())
+            .is_equal_to("user 'someone'".to_string());
+    }
+}
@@ -0,0 +1,24 @@
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PullRequest {
+    number: u32,
+    state: String,
+    url: String,
+}
+
+impl PullRequest {
+    pub fn new(number: u32, state: String, url: String) -> Self {
+        PullRequest { number, state, url }
+    }
+
+    pub fn number(&self) -> u32 {
+        self.number
+    }
+
+    pub fn state(&self) -> &String {
+        &self.state
+    }
+
+    pub fn url(&self) -> &String {
+        &self.url
+    }
+}
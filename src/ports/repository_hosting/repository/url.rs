@@ -11,6 +11,11 @@ impl RepositoryUrl {
     pub fn value(&self) -> &String {
         &self.0
     }
+
+    pub fn without_clone_suffix(&self) -> &str {
+        let trimmed = self.0.trim_end_matches('/');
+        trimmed.strip_suffix(".git").unwrap_or(trimmed)
+    }
 }
 
 impl core::fmt::Display for RepositoryUrl {
@@ -34,8 +39,24 @@ mod tests {
         assert_that(&under_test().value()).is_equal_to(&"url".to_string());
     }
 
+    #[test]
+    fn strips_git_suffix_and_trailing_slashes() {
+        assert_that(
+            &RepositoryUrl::new("git@host:owner/repo.git/".to_string()).without_clone_suffix(),
+        )
+        .is_equal_to("git@host:owner/repo");
+    }
+
+    #[test]
+    fn keeps_url_without_clone_suffix_unchanged() {
+        assert_that(
+            &RepositoryUrl::new("https://host/owner/repo".to_string()).without_clone_suffix(),
+        )
+        .is_equal_to("https://host/owner/repo");
+    }
+
     #[test]
     fn implements_display() {
-        assert_that(&under_test().to_string()).is_equal_to(&"url".to_string());
+        assert_that(&under_test().to_string()).is_equal_to("url".to_string());
     }
 }
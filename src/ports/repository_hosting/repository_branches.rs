@@ -0,0 +1,83 @@
+use futures::stream::{self, StreamExt, TryStreamExt};
+
+use crate::domain::repository::{Branch, Commit};
+use crate::ports::repository_hosting::repository::{
+    Branch as BranchDto, BranchName, RepositoryUrl,
+};
+use crate::ports::repository_hosting::RepositoryHostClient;
+
+/// Lists the branches of a repository, marking its protected branches and its default branch.
+pub(in crate::ports::repository_hosting) async fn list_branches<C>(
+    client: &C,
+    url: &RepositoryUrl,
+) -> Result<Vec<Branch>, C::Err>
+where
+    C: RepositoryHostClient + Sync,
+{
+    let (branch_dtos, default_branch_name) = list_branches_and_default_branch(client, url).await?;
+    Ok(branch_dtos
+        .iter()
+        .map(|branch_dto| {
+            mark_branch(
+                Branch::new(branch_dto.name().value().clone()),
+                branch_dto,
+                &default_branch_name,
+            )
+        })
+        .collect())
+}
+
+/// Lists the branches of a repository like [`list_branches`] together with their head commits,
+/// fetching at most `concurrency` commits at the same time.
+pub(in crate::ports::repository_hosting) async fn list_branches_with_head_commits<C>(
+    client: &C,
+    url: &RepositoryUrl,
+    concurrency: usize,
+) -> Result<Vec<Branch>, C::Err>
+where
+    C: RepositoryHostClient + Sync,
+    C::Err: Send,
+{
+    let (branch_dtos, default_branch_name) = list_branches_and_default_branch(client, url).await?;
+    let default_branch_name = &default_branch_name;
+    let requests: Vec<_> = branch_dtos
+        .iter()
+        .map(|branch_dto| async move {
+            let commit_dto = client.get_commit(url, branch_dto.head_commit_sha()).await?;
+            Ok(mark_branch(
+                Branch::with_head_commit(
+                    branch_dto.name().value().clone(),
+                    Commit::new(
+                        commit_dto.sha().clone(),
+                        *commit_dto.date(),
+                        commit_dto.author().clone(),
+                    ),
+                ),
+                branch_dto,
+                default_branch_name,
+            ))
+        })
+        .collect();
+    stream::iter(requests)
+        .buffered(concurrency)
+        .try_collect()
+        .await
+}
+
+async fn list_branches_and_default_branch<C>(
+    client: &C,
+    url: &RepositoryUrl,
+) -> Result<(Vec<BranchDto>, BranchName), C::Err>
+where
+    C: RepositoryHostClient + Sync,
+{
+    let branch_dtos = client.list_branches(url).await?;
+    let default_branch_name = client.get_default_branch(url).await?;
+    Ok((branch_dtos, default_branch_name))
+}
+
+fn mark_branch(branch: Branch, branch_dto: &BranchDto, default_branch_name: &BranchName) -> Branch {
+    branch
+        .marked_protected(branch_dto.is_protected())
+        .marked_default(branch_dto.name() == default_branch_name)
+}
@@ -0,0 +1,114 @@
+use async_trait::async_trait;
+
+use crate::ports::repository_hosting::repository::{
+    Branch, BranchComparison, BranchName, Commit, RepositoryOwner, RepositoryUrl,
+};
+
+#[async_trait]
+pub trait RepositoryHostClient {
+    type Err;
+    type AuthenticationCredentials;
+
+    async fn list_branches(&self, repository_url: &RepositoryUrl)
+        -> Result<Vec<Branch>, Self::Err>;
+
+    async fn get_commit(
+        &self,
+        repository_url: &RepositoryUrl,
+        sha: &str,
+    ) -> Result<Commit, Self::Err>;
+
+    async fn get_default_branch(
+        &self,
+        repository_url: &RepositoryUrl,
+    ) -> Result<BranchName, Self::Err>;
+
+    async fn compare_branches(
+        &self,
+        repository_url: &RepositoryUrl,
+        base: &BranchName,
+        head: &BranchName,
+    ) -> Result<BranchComparison, Self::Err>;
+
+    async fn delete_branch(
+        &self,
+        repository_url: &RepositoryUrl,
+        branch: &BranchName,
+    ) -> Result<(), Self::Err>;
+
+    fn set_authentication_credentials(&mut self, credentials: Self::AuthenticationCredentials);
+
+    async fn validate_authentication_credentials(
+        &self,
+        credentials: Self::AuthenticationCredentials,
+    ) -> Result<AuthenticationCredentialValidity, Self::Err>;
+}
+
+#[async_trait]
+pub trait RepositoryListingClient: RepositoryHostClient {
+    async fn list_repositories(
+        &self,
+        owner: &RepositoryOwner,
+    ) -> Result<Vec<RepositoryUrl>, Self::Err>;
+}
+
+#[cfg(test)]
+mockall::mock! {
+    pub RepositoryHostClient<Err: 'static + Send + Sync, C: 'static + Send + Sync> {}
+
+    #[async_trait::async_trait]
+    impl<Err: 'static + Send + Sync, C: 'static + Send + Sync> RepositoryHostClient for RepositoryClient<Err, C> {
+        type Err = Err;
+        type AuthenticationCredentials = C;
+
+        async fn list_branches(
+            &self,
+            repository_url: &RepositoryUrl,
+        ) -> Result<Vec<Branch>, Err>;
+
+        async fn get_commit(
+            &self,
+            repository_url: &RepositoryUrl,
+            sha: &str,
+        ) -> Result<Commit, Err>;
+
+        async fn get_default_branch(
+            &self,
+            repository_url: &RepositoryUrl,
+        ) -> Result<BranchName, Err>;
+
+        async fn compare_branches(
+            &self,
+            repository_url: &RepositoryUrl,
+            base: &BranchName,
+            head: &BranchName,
+        ) -> Result<BranchComparison, Err>;
+
+        async fn delete_branch(
+            &self,
+            repository_url: &RepositoryUrl,
+            branch: &BranchName,
+        ) -> Result<(), Err>;
+
+        fn set_authentication_credentials(&mut self, credentials: C);
+
+        async fn validate_authentication_credentials(
+            &self,
+            credentials: C,
+        ) -> Result<AuthenticationCredentialValidity, Err>;
+    }
+
+    #[async_trait::async_trait]
+    impl<Err: 'static + Send + Sync, C: 'static + Send + Sync> RepositoryListingClient for RepositoryClient<Err, C> {
+        async fn list_repositories(
+            &self,
+            owner: &RepositoryOwner,
+        ) -> Result<Vec<RepositoryUrl>, Err>;
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum AuthenticationCredentialValidity {
+    Valid,
+    Invalid,
+}
@@ -0,0 +1,120 @@
+use http_types::StatusCode;
+
+use crate::domain::repository::RepositoryProviderError;
+use crate::ports::repository_hosting::repository::RepositoryUrl;
+
+/// An unsuccessful response of a hosting service's REST API, shared by the clients whose APIs
+/// report failures through the status alone.
+#[derive(Debug, thiserror::Error)]
+pub enum ResponseError {
+    #[error("repository '{0}' not found")]
+    RepositoryNotFound(RepositoryUrl),
+    #[error("{0} rejected the authentication credentials")]
+    Unauthorized(&'static str),
+    #[error("{0} denied access")]
+    Forbidden(&'static str),
+    #[error("{0} rate limit exceeded")]
+    RateLimited(&'static str),
+    #[error("unexpected response from {service} ({status})")]
+    Unexpected { service: &'static str, status: u16 },
+}
+
+impl ResponseError {
+    /// Maps the status of an unsuccessful response from `service` to a request about a repository.
+    pub fn from_status(
+        service: &'static str,
+        status: StatusCode,
+        repository_url: &RepositoryUrl,
+    ) -> Self {
+        match status {
+            StatusCode::NotFound => ResponseError::RepositoryNotFound(repository_url.clone()),
+            StatusCode::Unauthorized => ResponseError::Unauthorized(service),
+            StatusCode::Forbidden => ResponseError::Forbidden(service),
+            StatusCode::TooManyRequests => ResponseError::RateLimited(service),
+            status => ResponseError::Unexpected {
+                service,
+                status: status.into(),
+            },
+        }
+    }
+}
+
+impl From<ResponseError> for RepositoryProviderError {
+    fn from(response_error: ResponseError) -> Self {
+        let repository = None;
+        let message = response_error.to_string();
+        match response_error {
+            ResponseError::RepositoryNotFound(..) => RepositoryProviderError::NotFound {
+                repository,
+                message,
+            },
+            ResponseError::Unauthorized(..) => RepositoryProviderError::Unauthorized {
+                repository,
+                message,
+            },
+            ResponseError::Forbidden(..) => RepositoryProviderError::Forbidden {
+                repository,
+                message,
+            },
+            ResponseError::RateLimited(..) => RepositoryProviderError::RateLimited {
+                repository,
+                message,
+            },
+            ResponseError::Unexpected { .. } => RepositoryProviderError::Other {
+                repository,
+                message,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    fn repository_url() -> RepositoryUrl {
+        RepositoryUrl::new("https://gitlab.com/group/project".to_string())
+    }
+
+    #[test]
+    fn maps_not_found_status_to_missing_repository() {
+        let error = ResponseError::from_status("GitLab", StatusCode::NotFound, &repository_url());
+
+        assert_that(&error.to_string())
+            .is_equal_to("repository 'https://gitlab.com/group/project' not found".to_string());
+    }
+
+    #[test]
+    fn maps_unauthorized_status_to_rejected_credentials() {
+        let error =
+            ResponseError::from_status("Bitbucket", StatusCode::Unauthorized, &repository_url());
+
+        assert_that(&matches!(
+            RepositoryProviderError::from(error),
+            RepositoryProviderError::Unauthorized { .. }
+        ))
+        .is_true();
+    }
+
+    #[test]
+    fn maps_too_many_requests_status_to_rate_limit() {
+        let error =
+            ResponseError::from_status("GitLab", StatusCode::TooManyRequests, &repository_url());
+
+        assert_that(&matches!(
+            RepositoryProviderError::from(error),
+            RepositoryProviderError::RateLimited { .. }
+        ))
+        .is_true();
+    }
+
+    #[test]
+    fn keeps_status_of_unexpected_response() {
+        let error = ResponseError::from_status("GitLab", StatusCode::BadGateway, &repository_url());
+
+        assert_that(&error.to_string())
+            .is_equal_to("unexpected response from GitLab (502)".to_string());
+    }
+}
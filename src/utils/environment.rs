@@ -10,7 +10,7 @@ pub struct EnvironmentReaderStd;
 
 impl EnvironmentReaderStd {
     pub fn new() -> Self {
-        EnvironmentReaderStd::default()
+        EnvironmentReaderStd
     }
 }
 
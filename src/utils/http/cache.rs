@@ -0,0 +1,448 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+use http_types::headers::{HeaderName, ETAG, LAST_MODIFIED};
+use http_types::{Method, StatusCode};
+use serde::{Deserialize, Serialize};
+
+use crate::utils::http::{Error, HttpClientFacade, Request, Response};
+
+/// A successful response kept for revalidation, together with the request URL it answered.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CachedResponse {
+    url: String,
+    headers: HashMap<String, String>,
+    body: String,
+}
+
+impl CachedResponse {
+    pub fn new(url: String, headers: HashMap<String, String>, body: String) -> Self {
+        CachedResponse { url, headers, body }
+    }
+
+    fn from_response(url: String, response: &Response) -> Self {
+        CachedResponse::new(
+            url,
+            response
+                .headers()
+                .iter()
+                .map(|(name, value)| (name.as_str().to_string(), value.clone()))
+                .collect(),
+            response.body_string().clone(),
+        )
+    }
+
+    fn header(&self, name: &HeaderName) -> Option<&String> {
+        self.headers.get(name.as_str())
+    }
+
+    /// The cached response, with the headers of the `304 Not Modified` that revalidated it
+    /// taking precedence over the stored ones.
+    fn revalidated_by(self, not_modified: &Response) -> Response {
+        let mut headers: HashMap<HeaderName, String> = self
+            .headers
+            .into_iter()
+            .map(|(name, value)| (HeaderName::from(name.as_str()), value))
+            .collect();
+        headers.extend(not_modified.headers().clone());
+        Response::new(StatusCode::Ok, headers, self.body)
+    }
+}
+
+/// Storage for cached responses. Failing to read or write the cache never fails a request, so
+/// implementations report a miss instead of an error.
+#[async_trait]
+#[cfg_attr(test, mockall::automock)]
+pub trait HttpCache {
+    async fn get(&self, key: &str) -> Option<CachedResponse>;
+
+    async fn put(&self, key: &str, response: CachedResponse);
+}
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Keeps one JSON file per cache key in a directory.
+#[derive(Debug)]
+pub struct FilesystemHttpCache {
+    directory: PathBuf,
+}
+
+impl FilesystemHttpCache {
+    pub fn new(directory: PathBuf) -> Self {
+        FilesystemHttpCache { directory }
+    }
+
+    /// Names the file after the 64-bit FNV-1a hash of the key, which unlike the standard
+    /// library's hasher stays the same across Rust versions, so the cache outlives upgrades.
+    fn filepath(&self, key: &str) -> PathBuf {
+        let hash = key.bytes().fold(FNV_OFFSET_BASIS, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+        });
+        self.directory.join(format!("{:016x}.json", hash))
+    }
+}
+
+#[async_trait]
+impl HttpCache for FilesystemHttpCache {
+    async fn get(&self, key: &str) -> Option<CachedResponse> {
+        let contents = async_std::fs::read_to_string(self.filepath(key))
+            .await
+            .ok()?;
+        serde_json::from_str(contents.as_str()).ok()
+    }
+
+    async fn put(&self, key: &str, response: CachedResponse) {
+        if let Ok(contents) = serde_json::to_string(&response) {
+            if async_std::fs::create_dir_all(&self.directory).await.is_ok() {
+                let _ = async_std::fs::write(self.filepath(key), contents).await;
+            }
+        }
+    }
+}
+
+/// The headers providers send credentials in: `Authorization` for GitHub, Gitea and Bitbucket,
+/// `Private-Token` for GitLab.
+const CREDENTIAL_HEADERS: [&str; 2] = ["authorization", "private-token"];
+
+/// Makes `GET` requests conditional on the `ETag` or `Last-Modified` header of the last
+/// successful response to the same request, and answers from the cache on a `304 Not Modified`.
+pub struct CachingHttpClientFacade<HttpClient: HttpClientFacade, Cache: HttpCache> {
+    http_client: HttpClient,
+    cache: Cache,
+}
+
+impl<HttpClient, Cache> CachingHttpClientFacade<HttpClient, Cache>
+where
+    HttpClient: HttpClientFacade,
+    Cache: HttpCache,
+{
+    pub fn new(http_client: HttpClient, cache: Cache) -> Self {
+        CachingHttpClientFacade { http_client, cache }
+    }
+
+    /// Responses depend on the credentials they were requested with, so every header a
+    /// provider sends credentials in is part of the key alongside the URL and the requested
+    /// media type.
+    fn cache_key(req: &Request) -> String {
+        let header = |name: &str| {
+            req.headers()
+                .get(&HeaderName::from(name))
+                .cloned()
+                .unwrap_or_default()
+        };
+        std::iter::once(req.url().to_string())
+            .chain(std::iter::once(header("accept")))
+            .chain(CREDENTIAL_HEADERS.iter().map(|name| header(name)))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    fn conditional_request(req: Request, cached: &CachedResponse) -> Request {
+        let req = match cached.header(&ETAG) {
+            Some(etag) => req.with_header(HeaderName::from("if-none-match"), etag.clone()),
+            None => req,
+        };
+        match cached.header(&LAST_MODIFIED) {
+            Some(last_modified) => {
+                req.with_header(HeaderName::from("if-modified-since"), last_modified.clone())
+            }
+            None => req,
+        }
+    }
+
+    fn is_cacheable(response: &Response) -> bool {
+        response.status_code() == StatusCode::Ok
+            && (response.header(&ETAG).is_some() || response.header(&LAST_MODIFIED).is_some())
+    }
+}
+
+#[async_trait]
+impl<HttpClient, Cache> HttpClientFacade for CachingHttpClientFacade<HttpClient, Cache>
+where
+    HttpClient: HttpClientFacade + Send + Sync,
+    Cache: HttpCache + Send + Sync,
+{
+    async fn send(&self, req: Request) -> Result<Response, Error> {
+        if req.method() != Method::Get {
+            return self.http_client.send(req).await;
+        }
+        let key = Self::cache_key(&req);
+        let url = req.url().to_string();
+        let cached = self
+            .cache
+            .get(key.as_str())
+            .await
+            .filter(|cached| cached.url == url);
+        let req = match &cached {
+            Some(cached) => Self::conditional_request(req, cached),
+            None => req,
+        };
+
+        let response = self.http_client.send(req).await?;
+        match cached {
+            Some(cached) if response.status_code() == StatusCode::NotModified => {
+                Ok(cached.revalidated_by(&response))
+            }
+            _ => {
+                if Self::is_cacheable(&response) {
+                    self.cache
+                        .put(key.as_str(), CachedResponse::from_response(url, &response))
+                        .await;
+                }
+                Ok(response)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http_types::headers::AUTHORIZATION;
+    use http_types::Url;
+    use mockall::predicate::{always, eq, function};
+    use spectral::prelude::*;
+
+    use crate::utils::http::MockHttpClientFacade;
+    use crate::utils::test_helpers::async_this;
+
+    use super::*;
+
+    const URL: &str = "https://api.github.com/repos/owner/repo";
+
+    fn request(method: Method) -> Request {
+        Request::new(method, Url::parse(URL).unwrap(), HashMap::new())
+            .with_header(AUTHORIZATION, "token secret".to_string())
+    }
+
+    fn response(status_code: StatusCode, headers: Vec<(&str, &str)>, body: &str) -> Response {
+        Response::new(
+            status_code,
+            headers
+                .into_iter()
+                .map(|(name, value)| (HeaderName::from(name), value.to_string()))
+                .collect(),
+            body.to_string(),
+        )
+    }
+
+    fn cached_response(headers: Vec<(&str, &str)>, body: &str) -> CachedResponse {
+        CachedResponse::new(
+            URL.to_string(),
+            headers
+                .into_iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+            body.to_string(),
+        )
+    }
+
+    fn mock_cache(cached: Option<CachedResponse>) -> MockHttpCache {
+        let mut mock_cache = MockHttpCache::default();
+        mock_cache
+            .expect_get()
+            .returning(move |_| Box::pin(async_this(cached.clone())));
+        mock_cache
+    }
+
+    fn mock_http_client<F>(matcher: F, response: Response) -> MockHttpClientFacade
+    where
+        F: Fn(&Request) -> bool + Send + 'static,
+    {
+        let mut mock_http_client = MockHttpClientFacade::default();
+        mock_http_client
+            .expect_send()
+            .with(function(matcher))
+            .returning(move |_| Box::pin(async_this(Ok(response.clone()))));
+        mock_http_client
+    }
+
+    fn header<'a>(req: &'a Request, name: &str) -> Option<&'a String> {
+        req.headers().get(&HeaderName::from(name))
+    }
+
+    #[async_std::test]
+    async fn stores_response_with_etag() {
+        let mut mock_cache = mock_cache(None);
+        mock_cache
+            .expect_put()
+            .with(
+                always(),
+                eq(cached_response(vec![("etag", "\"v1\"")], "body")),
+            )
+            .once()
+            .returning(|_, _| Box::pin(async_this(())));
+        let mock_http_client = mock_http_client(
+            |req| header(req, "if-none-match").is_none(),
+            response(StatusCode::Ok, vec![("etag", "\"v1\"")], "body"),
+        );
+
+        let result = CachingHttpClientFacade::new(mock_http_client, mock_cache)
+            .send(request(Method::Get))
+            .await
+            .unwrap();
+
+        assert_that(&result.body_string()).is_equal_to(&"body".to_string());
+    }
+
+    #[async_std::test]
+    async fn does_not_store_response_without_validators() {
+        let mock_cache = mock_cache(None);
+        let mock_http_client = mock_http_client(|_| true, response(StatusCode::Ok, vec![], "body"));
+
+        let result = CachingHttpClientFacade::new(mock_http_client, mock_cache)
+            .send(request(Method::Get))
+            .await;
+
+        assert_that(&result).is_ok();
+    }
+
+    #[async_std::test]
+    async fn reuses_cached_body_when_not_modified() {
+        let mock_cache = mock_cache(Some(cached_response(
+            vec![("etag", "\"v1\""), ("link", "<next>; rel=\"next\"")],
+            "cached body",
+        )));
+        let mock_http_client = mock_http_client(
+            |req| header(req, "if-none-match") == Some(&"\"v1\"".to_string()),
+            response(
+                StatusCode::NotModified,
+                vec![("x-ratelimit-remaining", "4999")],
+                "",
+            ),
+        );
+
+        let result = CachingHttpClientFacade::new(mock_http_client, mock_cache)
+            .send(request(Method::Get))
+            .await
+            .unwrap();
+
+        assert_that(&result.status_code()).is_equal_to(StatusCode::Ok);
+        assert_that(&result.body_string()).is_equal_to(&"cached body".to_string());
+        assert_that(&result.header(&HeaderName::from("link"))).is_some();
+        assert_that(&result.header(&HeaderName::from("x-ratelimit-remaining")))
+            .is_some()
+            .is_equal_to(&"4999".to_string());
+    }
+
+    #[async_std::test]
+    async fn revalidates_with_last_modified_date() {
+        let mut mock_cache = mock_cache(Some(cached_response(
+            vec![("last-modified", "Wed, 21 Oct 2015 07:28:00 GMT")],
+            "cached body",
+        )));
+        mock_cache
+            .expect_put()
+            .once()
+            .returning(|_, _| Box::pin(async_this(())));
+        let mock_http_client = mock_http_client(
+            |req| {
+                header(req, "if-modified-since")
+                    == Some(&"Wed, 21 Oct 2015 07:28:00 GMT".to_string())
+            },
+            response(
+                StatusCode::Ok,
+                vec![("last-modified", "Thu, 22 Oct 2015 07:28:00 GMT")],
+                "new body",
+            ),
+        );
+
+        let result = CachingHttpClientFacade::new(mock_http_client, mock_cache)
+            .send(request(Method::Get))
+            .await
+            .unwrap();
+
+        assert_that(&result.body_string()).is_equal_to(&"new body".to_string());
+    }
+
+    #[async_std::test]
+    async fn ignores_cached_response_for_another_url() {
+        let mut other = cached_response(vec![("etag", "\"v1\"")], "other body");
+        other.url = "https://api.github.com/repos/owner/other".to_string();
+        let mock_cache = mock_cache(Some(other));
+        let mock_http_client = mock_http_client(
+            |req| header(req, "if-none-match").is_none(),
+            response(StatusCode::Ok, vec![], "body"),
+        );
+
+        let result = CachingHttpClientFacade::new(mock_http_client, mock_cache)
+            .send(request(Method::Get))
+            .await
+            .unwrap();
+
+        assert_that(&result.body_string()).is_equal_to(&"body".to_string());
+    }
+
+    #[async_std::test]
+    async fn passes_through_requests_other_than_get() {
+        let mock_http_client = mock_http_client(
+            |req| req.method() == Method::Delete,
+            response(StatusCode::NoContent, vec![("etag", "\"v1\"")], ""),
+        );
+
+        let result = CachingHttpClientFacade::new(mock_http_client, MockHttpCache::default())
+            .send(request(Method::Delete))
+            .await
+            .unwrap();
+
+        assert_that(&result.status_code()).is_equal_to(StatusCode::NoContent);
+    }
+
+    #[test]
+    fn keys_cache_by_credentials() {
+        let other_credentials =
+            request(Method::Get).with_header(AUTHORIZATION, "token other".into());
+
+        assert_that(&CachingHttpClientFacade::<
+            MockHttpClientFacade,
+            MockHttpCache,
+        >::cache_key(&request(Method::Get)))
+        .is_not_equal_to(
+            CachingHttpClientFacade::<MockHttpClientFacade, MockHttpCache>::cache_key(
+                &other_credentials,
+            ),
+        );
+    }
+
+    #[test]
+    fn keys_cache_by_gitlab_private_token() {
+        let with_private_token = |token: &str| {
+            Request::new(Method::Get, Url::parse(URL).unwrap(), HashMap::new())
+                .with_header(HeaderName::from("private-token"), token.to_string())
+        };
+
+        assert_that(&CachingHttpClientFacade::<
+            MockHttpClientFacade,
+            MockHttpCache,
+        >::cache_key(&with_private_token("secret")))
+        .is_not_equal_to(
+            CachingHttpClientFacade::<MockHttpClientFacade, MockHttpCache>::cache_key(
+                &with_private_token("other"),
+            ),
+        );
+    }
+
+    #[test]
+    fn names_cache_files_after_stable_hash_of_key() {
+        let under_test = FilesystemHttpCache::new(PathBuf::from("cache"));
+
+        assert_that(&under_test.filepath("a"))
+            .is_equal_to(PathBuf::from("cache").join("af63dc4c8601ec8c.json"));
+    }
+
+    #[async_std::test]
+    async fn reads_back_responses_stored_on_filesystem() {
+        let temp_directory = assert_fs::TempDir::new().unwrap();
+        let under_test = FilesystemHttpCache::new(temp_directory.path().join("http-cache"));
+        let cached = cached_response(vec![("etag", "\"v1\"")], "body");
+
+        under_test.put("key", cached.clone()).await;
+
+        assert_that(&under_test.get("key").await)
+            .is_some()
+            .is_equal_to(cached);
+        assert_that(&under_test.get("other key").await).is_none();
+        temp_directory.close().unwrap();
+    }
+}
@@ -0,0 +1,105 @@
+use std::sync::Arc;
+
+use async_lock::Semaphore;
+use async_trait::async_trait;
+
+use crate::utils::http::{Error, HttpClientFacade, Request, Response};
+
+/// Caps how many requests are in flight at once across every client it is shared with.
+#[derive(Clone)]
+pub struct RequestLimiter {
+    semaphore: Arc<Semaphore>,
+}
+
+impl RequestLimiter {
+    pub fn new(limit: usize) -> Self {
+        RequestLimiter {
+            semaphore: Arc::new(Semaphore::new(limit.max(1))),
+        }
+    }
+}
+
+/// Holds a permit of a shared `RequestLimiter` while each request is sent, so that nested
+/// per-repository and per-branch work never has more requests in flight than the limit.
+pub struct ConcurrencyLimitingHttpClientFacade<HttpClient: HttpClientFacade> {
+    http_client: HttpClient,
+    limiter: RequestLimiter,
+}
+
+impl<HttpClient: HttpClientFacade> ConcurrencyLimitingHttpClientFacade<HttpClient> {
+    pub fn new(http_client: HttpClient, limiter: RequestLimiter) -> Self {
+        ConcurrencyLimitingHttpClientFacade {
+            http_client,
+            limiter,
+        }
+    }
+}
+
+#[async_trait]
+impl<HttpClient> HttpClientFacade for ConcurrencyLimitingHttpClientFacade<HttpClient>
+where
+    HttpClient: HttpClientFacade + Send + Sync,
+{
+    async fn send(&self, req: Request) -> Result<Response, Error> {
+        let _permit = self.limiter.semaphore.acquire().await;
+        self.http_client.send(req).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use futures::future::join_all;
+    use http_types::{Method, StatusCode, Url};
+    use spectral::prelude::*;
+
+    use super::*;
+
+    /// Records the highest number of requests it was sent at the same time.
+    #[derive(Default)]
+    struct InFlightCountingHttpClient {
+        in_flight: AtomicUsize,
+        peak: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl HttpClientFacade for Arc<InFlightCountingHttpClient> {
+        async fn send(&self, _req: Request) -> Result<Response, Error> {
+            self.peak.fetch_max(
+                self.in_flight.fetch_add(1, Ordering::SeqCst) + 1,
+                Ordering::SeqCst,
+            );
+            async_std::task::yield_now().await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            Ok(Response::new(StatusCode::Ok, HashMap::new(), String::new()))
+        }
+    }
+
+    fn request() -> Request {
+        Request::new(
+            Method::Get,
+            Url::parse("https://example.com").unwrap(),
+            HashMap::new(),
+        )
+    }
+
+    #[async_std::test]
+    async fn sends_at_most_limit_requests_at_once_across_clients_sharing_limiter() {
+        let http_client = Arc::new(InFlightCountingHttpClient::default());
+        let limiter = RequestLimiter::new(3);
+        let first = ConcurrencyLimitingHttpClientFacade::new(http_client.clone(), limiter.clone());
+        let second = ConcurrencyLimitingHttpClientFacade::new(http_client.clone(), limiter);
+
+        let responses = join_all(
+            (0..10)
+                .map(|_| first.send(request()))
+                .chain((0..10).map(|_| second.send(request()))),
+        )
+        .await;
+
+        assert_that(&responses.iter().all(Result::is_ok)).is_true();
+        assert_that(&http_client.peak.load(Ordering::SeqCst)).is_equal_to(3);
+    }
+}
@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+
+use http_types::Url;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct LinkHeader {
+    links: HashMap<String, Url>,
+}
+
+impl LinkHeader {
+    pub fn parse(value: &str) -> Self {
+        let links = value
+            .split(',')
+            .filter_map(|link| {
+                let mut parts = link.split(';').map(str::trim);
+                let target = parts.next()?.strip_prefix('<')?.strip_suffix('>')?;
+                let url = Url::parse(target).ok()?;
+                parts
+                    .filter_map(|param| param.strip_prefix("rel="))
+                    .map(|rel| rel.trim_matches('"').to_string())
+                    .next()
+                    .map(|rel| (rel, url))
+            })
+            .collect();
+
+        LinkHeader { links }
+    }
+
+    pub fn rel(&self, rel: &str) -> Option<&Url> {
+        self.links.get(rel)
+    }
+
+    pub fn next(&self) -> Option<&Url> {
+        self.rel("next")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    fn url(s: &str) -> Url {
+        Url::parse(s).unwrap()
+    }
+
+    #[test]
+    fn parses_next_link() {
+        let under_test = LinkHeader::parse(
+            "<https://api.github.com/repositories/1/branches?page=2>; rel=\"next\", \
+             <https://api.github.com/repositories/1/branches?page=5>; rel=\"last\"",
+        );
+
+        assert_that(&under_test.next()).is_some().is_equal_to(&url(
+            "https://api.github.com/repositories/1/branches?page=2",
+        ));
+    }
+
+    #[test]
+    fn parses_other_links_by_relation() {
+        let under_test = LinkHeader::parse(
+            "<https://example.com/?page=1>; rel=\"prev\", <https://example.com/?page=3>; rel=\"last\"",
+        );
+
+        assert_that(&under_test.rel("last"))
+            .is_some()
+            .is_equal_to(&url("https://example.com/?page=3"));
+    }
+
+    #[test]
+    fn has_no_next_link_on_last_page() {
+        let under_test = LinkHeader::parse("<https://example.com/?page=1>; rel=\"prev\"");
+
+        assert_that(&under_test.next()).is_none();
+    }
+
+    #[test]
+    fn ignores_malformed_links() {
+        let under_test = LinkHeader::parse("https://example.com/?page=2; rel=\"next\"");
+
+        assert_that(&under_test.next()).is_none();
+    }
+}
@@ -1,9 +1,17 @@
+pub use cache::*;
 pub use client::*;
 pub use error::*;
+pub use limit::*;
+pub use link_header::*;
 pub use request::*;
 pub use response::*;
+pub use retry::*;
 
+mod cache;
 mod client;
 mod error;
+mod limit;
+mod link_header;
 mod request;
 mod response;
+mod retry;
@@ -3,11 +3,12 @@ use std::collections::HashMap;
 use http_types::headers::HeaderName;
 use http_types::{Method, Url};
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Request {
     method: Method,
     url: Url,
     headers: HashMap<HeaderName, String>,
+    body: Option<String>,
 }
 
 impl Request {
@@ -16,8 +17,35 @@ impl Request {
             method,
             url,
             headers,
+            body: None,
         }
     }
+
+    pub fn with_body(mut self, body: String) -> Self {
+        self.body = Some(body);
+        self
+    }
+
+    pub fn with_header(mut self, name: HeaderName, value: String) -> Self {
+        self.headers.insert(name, value);
+        self
+    }
+
+    pub fn method(&self) -> Method {
+        self.method
+    }
+
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    pub fn headers(&self) -> &HashMap<HeaderName, String> {
+        &self.headers
+    }
+
+    pub fn body(&self) -> Option<&String> {
+        self.body.as_ref()
+    }
 }
 
 impl From<Request> for surf::Request {
@@ -28,6 +56,58 @@ impl From<Request> for surf::Request {
             new_req.insert_header(k, v.as_str());
         });
 
+        if let Some(body) = req.body {
+            new_req.set_body(body);
+        }
+
         new_req
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    fn under_test(method: Method) -> Request {
+        Request::new(
+            method,
+            Url::parse("https://example.com/").unwrap(),
+            HashMap::new(),
+        )
+    }
+
+    #[test]
+    fn has_no_body_by_default() {
+        assert_that(&under_test(Method::Get).body()).is_none();
+    }
+
+    #[test]
+    fn returns_body() {
+        let request = under_test(Method::Post).with_body("body".to_string());
+        assert_that(&request.body())
+            .is_some()
+            .is_equal_to(&"body".to_string());
+    }
+
+    #[test]
+    fn adds_header() {
+        let request =
+            under_test(Method::Get).with_header(HeaderName::from("if-none-match"), "etag".into());
+        assert_that(&request.headers().get(&HeaderName::from("if-none-match")))
+            .is_some()
+            .is_equal_to(&"etag".to_string());
+    }
+
+    #[test]
+    fn converts_method_and_body_into_surf_request() {
+        let mut surf_request: surf::Request = under_test(Method::Delete)
+            .with_body("body".to_string())
+            .into();
+
+        assert_that(&surf_request.method()).is_equal_to(Method::Delete);
+        assert_that(&async_std::task::block_on(surf_request.take_body().into_string()).unwrap())
+            .is_equal_to("body".to_string());
+    }
+}
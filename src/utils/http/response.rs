@@ -1,16 +1,27 @@
+use std::collections::HashMap;
+
+use http_types::headers::HeaderName;
 use http_types::StatusCode;
 use serde_json::Error;
 
+use crate::utils::http::LinkHeader;
+
 #[derive(Debug, Clone)]
 pub struct Response {
     status_code: StatusCode,
+    headers: HashMap<HeaderName, String>,
     body_string: String,
 }
 
 impl Response {
-    pub fn new(status_code: StatusCode, body_string: String) -> Self {
+    pub fn new(
+        status_code: StatusCode,
+        headers: HashMap<HeaderName, String>,
+        body_string: String,
+    ) -> Self {
         Response {
             status_code,
+            headers,
             body_string,
         }
     }
@@ -19,6 +30,19 @@ impl Response {
         self.status_code
     }
 
+    pub fn headers(&self) -> &HashMap<HeaderName, String> {
+        &self.headers
+    }
+
+    pub fn header(&self, name: &HeaderName) -> Option<&String> {
+        self.headers.get(name)
+    }
+
+    pub fn link_header(&self) -> Option<LinkHeader> {
+        self.header(&HeaderName::from("link"))
+            .map(|value| LinkHeader::parse(value.as_str()))
+    }
+
     pub fn body_string(&self) -> &String {
         &self.body_string
     }
@@ -28,15 +52,21 @@ impl Response {
     }
 }
 
-impl Into<Response> for surf::Response {
-    fn into(self) -> Response {
-        async_std::task::block_on(response_from_surf_response(self))
+impl From<surf::Response> for Response {
+    fn from(surf_response: surf::Response) -> Self {
+        async_std::task::block_on(response_from_surf_response(surf_response))
     }
 }
 
 async fn response_from_surf_response(mut surf_response: surf::Response) -> Response {
+    let headers = surf_response
+        .iter()
+        .map(|(name, values)| (name.clone(), values.last().as_str().to_string()))
+        .collect();
+
     Response::new(
         surf_response.status(),
+        headers,
         surf_response.body_string().await.unwrap(),
     )
 }
@@ -47,21 +77,54 @@ mod tests {
 
     use super::*;
 
+    fn under_test(headers: HashMap<HeaderName, String>, body_string: &str) -> Response {
+        Response::new(StatusCode::Ok, headers, body_string.to_string())
+    }
+
     #[test]
     fn returns_status_code() {
-        let under_test = Response::new(StatusCode::Ok, "body_string".to_string());
-        assert_that(&under_test.status_code()).is_equal_to(&StatusCode::Ok);
+        let under_test = under_test(HashMap::new(), "body_string");
+        assert_that(&under_test.status_code()).is_equal_to(StatusCode::Ok);
+    }
+
+    #[test]
+    fn returns_header() {
+        let mut headers = HashMap::new();
+        headers.insert(http_types::headers::ETAG, "\"etag\"".to_string());
+        let under_test = under_test(headers, "body string");
+        assert_that(&under_test.header(&http_types::headers::ETAG))
+            .is_some()
+            .is_equal_to(&"\"etag\"".to_string());
+    }
+
+    #[test]
+    fn returns_none_when_header_is_absent() {
+        let under_test = under_test(HashMap::new(), "body string");
+        assert_that(&under_test.header(&http_types::headers::ETAG)).is_none();
+    }
+
+    #[test]
+    fn returns_link_header() {
+        let mut headers = HashMap::new();
+        headers.insert(
+            HeaderName::from("link"),
+            "<https://example.com/?page=2>; rel=\"next\"".to_string(),
+        );
+        let under_test = under_test(headers, "body string");
+        assert_that(&under_test.link_header().unwrap().next())
+            .is_some()
+            .is_equal_to(&http_types::Url::parse("https://example.com/?page=2").unwrap());
     }
 
     #[test]
     fn returns_body_string() {
-        let under_test = Response::new(StatusCode::Ok, "body string".to_string());
+        let under_test = under_test(HashMap::new(), "body string");
         assert_that(&under_test.body_string()).is_equal_to(&"body string".to_string());
     }
 
     #[test]
     fn returns_body_json() {
-        let under_test = Response::new(StatusCode::Ok, "1".to_string());
+        let under_test = under_test(HashMap::new(), "1");
         assert_that(&under_test.body_json().unwrap()).is_equal_to(1);
     }
 }
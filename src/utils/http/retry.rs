@@ -0,0 +1,422 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use http_types::headers::HeaderName;
+use http_types::StatusCode;
+
+use crate::utils::http::{Error, HttpClientFacade, Request, Response};
+
+#[async_trait]
+#[cfg_attr(test, mockall::automock)]
+pub trait Sleeper {
+    async fn sleep(&self, duration: Duration);
+}
+
+#[derive(Debug, Default)]
+pub struct AsyncStdSleeper;
+
+impl AsyncStdSleeper {
+    pub fn new() -> Self {
+        AsyncStdSleeper
+    }
+}
+
+#[async_trait]
+impl Sleeper for AsyncStdSleeper {
+    async fn sleep(&self, duration: Duration) {
+        async_std::task::sleep(duration).await
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    max_rate_limit_wait: Duration,
+}
+
+impl RetryPolicy {
+    pub fn new(
+        max_retries: u32,
+        base_delay: Duration,
+        max_delay: Duration,
+        max_rate_limit_wait: Duration,
+    ) -> Self {
+        RetryPolicy {
+            max_retries,
+            base_delay,
+            max_delay,
+            max_rate_limit_wait,
+        }
+    }
+
+    /// Exponential backoff for the given zero-based retry, with jitter of up to half the delay.
+    fn backoff(&self, retry: u32) -> Duration {
+        let delay = self
+            .base_delay
+            .checked_mul(2u32.saturating_pow(retry))
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay);
+        let half = delay / 2;
+        half + half.mul_f64(random_fraction())
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy::new(
+            4,
+            Duration::from_millis(500),
+            Duration::from_secs(30),
+            Duration::from_secs(15 * 60),
+        )
+    }
+}
+
+fn random_fraction() -> f64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u64(0);
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// Retries requests that failed for transient reasons.
+///
+/// Server errors and transport failures are retried with exponential backoff. Rate-limited
+/// responses wait for as long as the `Retry-After` or `X-RateLimit-Reset` header asks, unless
+/// that is longer than the policy allows.
+pub struct RetryingHttpClientFacade<HttpClient: HttpClientFacade, S: Sleeper> {
+    http_client: HttpClient,
+    policy: RetryPolicy,
+    sleeper: S,
+}
+
+impl<HttpClient, S> RetryingHttpClientFacade<HttpClient, S>
+where
+    HttpClient: HttpClientFacade,
+    S: Sleeper,
+{
+    pub fn new(http_client: HttpClient, policy: RetryPolicy, sleeper: S) -> Self {
+        RetryingHttpClientFacade {
+            http_client,
+            policy,
+            sleeper,
+        }
+    }
+
+    /// `Retry-After` holds either a number of seconds or an HTTP date to retry after.
+    fn rate_limit_wait(response: &Response) -> Option<Duration> {
+        if let Some(retry_after) = response.header(&HeaderName::from("retry-after")) {
+            let retry_after = retry_after.trim();
+            return match retry_after.parse::<u64>() {
+                Ok(seconds) => Some(Duration::from_secs(seconds)),
+                Err(_) => DateTime::parse_from_rfc2822(retry_after)
+                    .ok()
+                    .map(|date| Self::wait_until(date.timestamp())),
+            };
+        }
+        let remaining = response.header(&HeaderName::from("x-ratelimit-remaining"))?;
+        if remaining.trim() != "0" {
+            return None;
+        }
+        let reset = response
+            .header(&HeaderName::from("x-ratelimit-reset"))?
+            .trim()
+            .parse::<i64>()
+            .ok()?;
+        Some(Self::wait_until(reset))
+    }
+
+    /// Waits a second longer than the whole seconds left, so the wait never ends early.
+    fn wait_until(timestamp: i64) -> Duration {
+        let seconds_left = (timestamp - Utc::now().timestamp()).max(0) as u64;
+        Duration::from_secs(seconds_left + 1)
+    }
+
+    fn delay_before_retry(&self, result: &Result<Response, Error>, retry: u32) -> Option<Duration> {
+        match result {
+            Err(_) => Some(self.policy.backoff(retry)),
+            Ok(response) => match response.status_code() {
+                StatusCode::Forbidden | StatusCode::TooManyRequests => {
+                    Self::rate_limit_wait(response)
+                        .filter(|wait| *wait <= self.policy.max_rate_limit_wait)
+                }
+                status if status.is_server_error() => Some(self.policy.backoff(retry)),
+                _ => None,
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl<HttpClient, S> HttpClientFacade for RetryingHttpClientFacade<HttpClient, S>
+where
+    HttpClient: HttpClientFacade + Send + Sync,
+    S: Sleeper + Send + Sync,
+{
+    async fn send(&self, req: Request) -> Result<Response, Error> {
+        let mut retry = 0;
+        loop {
+            let result = self.http_client.send(req.clone()).await;
+            if retry >= self.policy.max_retries {
+                return result;
+            }
+            match self.delay_before_retry(&result, retry) {
+                Some(delay) => self.sleeper.sleep(delay).await,
+                None => return result,
+            }
+            retry += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Arc;
+
+    use http_types::{Method, Url};
+    use spectral::prelude::*;
+
+    use crate::utils::http::MockHttpClientFacade;
+    use crate::utils::test_helpers::async_this;
+
+    use super::*;
+
+    fn request() -> Request {
+        Request::new(
+            Method::Get,
+            Url::parse("https://api.github.com/repos/owner/repo").unwrap(),
+            HashMap::new(),
+        )
+    }
+
+    fn response(status_code: StatusCode, headers: Vec<(&str, String)>) -> Response {
+        Response::new(
+            status_code,
+            headers
+                .into_iter()
+                .map(|(name, value)| (HeaderName::from(name), value))
+                .collect(),
+            "".to_string(),
+        )
+    }
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy::new(
+            2,
+            Duration::from_secs(1),
+            Duration::from_secs(10),
+            Duration::from_secs(60),
+        )
+    }
+
+    /// An HTTP client that answers with the given responses in order, repeating the last one.
+    fn mock_http_client(responses: Vec<Response>) -> (MockHttpClientFacade, Arc<AtomicU32>) {
+        let calls = Arc::new(AtomicU32::new(0));
+        let counter = Arc::clone(&calls);
+        let mut mock_http_client = MockHttpClientFacade::default();
+        mock_http_client.expect_send().returning(move |_| {
+            let call = counter.fetch_add(1, Ordering::SeqCst) as usize;
+            let response = responses[call.min(responses.len() - 1)].clone();
+            Box::pin(async_this(Ok(response)))
+        });
+        (mock_http_client, calls)
+    }
+
+    fn mock_sleeper(expected_sleeps: usize) -> MockSleeper {
+        let mut mock_sleeper = MockSleeper::default();
+        mock_sleeper
+            .expect_sleep()
+            .times(expected_sleeps)
+            .returning(|_| Box::pin(async_this(())));
+        mock_sleeper
+    }
+
+    #[async_std::test]
+    async fn returns_successful_response_without_retrying() {
+        let (mock_http_client, calls) = mock_http_client(vec![response(StatusCode::Ok, vec![])]);
+        let under_test = RetryingHttpClientFacade::new(mock_http_client, policy(), mock_sleeper(0));
+
+        let result = under_test.send(request()).await.unwrap();
+
+        assert_that(&result.status_code()).is_equal_to(StatusCode::Ok);
+        assert_that(&calls.load(Ordering::SeqCst)).is_equal_to(1);
+    }
+
+    #[async_std::test]
+    async fn retries_server_errors_until_success() {
+        let (mock_http_client, calls) = mock_http_client(vec![
+            response(StatusCode::BadGateway, vec![]),
+            response(StatusCode::Ok, vec![]),
+        ]);
+        let under_test = RetryingHttpClientFacade::new(mock_http_client, policy(), mock_sleeper(1));
+
+        let result = under_test.send(request()).await.unwrap();
+
+        assert_that(&result.status_code()).is_equal_to(StatusCode::Ok);
+        assert_that(&calls.load(Ordering::SeqCst)).is_equal_to(2);
+    }
+
+    #[async_std::test]
+    async fn gives_up_after_max_retries() {
+        let (mock_http_client, calls) =
+            mock_http_client(vec![response(StatusCode::ServiceUnavailable, vec![])]);
+        let under_test = RetryingHttpClientFacade::new(mock_http_client, policy(), mock_sleeper(2));
+
+        let result = under_test.send(request()).await.unwrap();
+
+        assert_that(&result.status_code()).is_equal_to(StatusCode::ServiceUnavailable);
+        assert_that(&calls.load(Ordering::SeqCst)).is_equal_to(3);
+    }
+
+    #[async_std::test]
+    async fn retries_transport_errors() {
+        let calls = Arc::new(AtomicU32::new(0));
+        let counter = Arc::clone(&calls);
+        let mut mock_http_client = MockHttpClientFacade::default();
+        mock_http_client.expect_send().returning(move |_| {
+            let result = match counter.fetch_add(1, Ordering::SeqCst) {
+                0 => Err(Error::from(http_types::Error::from_str(
+                    StatusCode::InternalServerError,
+                    "connection reset by peer",
+                ))),
+                _ => Ok(response(StatusCode::Ok, vec![])),
+            };
+            Box::pin(async_this(result))
+        });
+        let under_test = RetryingHttpClientFacade::new(mock_http_client, policy(), mock_sleeper(1));
+
+        let result = under_test.send(request()).await.unwrap();
+
+        assert_that(&result.status_code()).is_equal_to(StatusCode::Ok);
+        assert_that(&calls.load(Ordering::SeqCst)).is_equal_to(2);
+    }
+
+    #[async_std::test]
+    async fn does_not_retry_client_errors() {
+        let (mock_http_client, calls) =
+            mock_http_client(vec![response(StatusCode::NotFound, vec![])]);
+        let under_test = RetryingHttpClientFacade::new(mock_http_client, policy(), mock_sleeper(0));
+
+        let result = under_test.send(request()).await.unwrap();
+
+        assert_that(&result.status_code()).is_equal_to(StatusCode::NotFound);
+        assert_that(&calls.load(Ordering::SeqCst)).is_equal_to(1);
+    }
+
+    #[async_std::test]
+    async fn waits_for_retry_after_when_rate_limited() {
+        let (mock_http_client, _) = mock_http_client(vec![
+            response(
+                StatusCode::TooManyRequests,
+                vec![("retry-after", "7".to_string())],
+            ),
+            response(StatusCode::Ok, vec![]),
+        ]);
+        let mut mock_sleeper = MockSleeper::default();
+        mock_sleeper
+            .expect_sleep()
+            .withf(|duration| *duration == Duration::from_secs(7))
+            .times(1)
+            .returning(|_| Box::pin(async_this(())));
+        let under_test = RetryingHttpClientFacade::new(mock_http_client, policy(), mock_sleeper);
+
+        let result = under_test.send(request()).await.unwrap();
+
+        assert_that(&result.status_code()).is_equal_to(StatusCode::Ok);
+    }
+
+    #[async_std::test]
+    async fn waits_until_http_date_of_retry_after_when_rate_limited() {
+        let retry_at = (Utc::now() + chrono::Duration::seconds(30)).to_rfc2822();
+        let (mock_http_client, _) = mock_http_client(vec![
+            response(
+                StatusCode::TooManyRequests,
+                vec![("retry-after", retry_at.replace("+0000", "GMT"))],
+            ),
+            response(StatusCode::Ok, vec![]),
+        ]);
+        let mut mock_sleeper = MockSleeper::default();
+        mock_sleeper
+            .expect_sleep()
+            .withf(|duration| {
+                *duration >= Duration::from_secs(29) && *duration <= Duration::from_secs(31)
+            })
+            .times(1)
+            .returning(|_| Box::pin(async_this(())));
+        let under_test = RetryingHttpClientFacade::new(mock_http_client, policy(), mock_sleeper);
+
+        let result = under_test.send(request()).await.unwrap();
+
+        assert_that(&result.status_code()).is_equal_to(StatusCode::Ok);
+    }
+
+    #[async_std::test]
+    async fn waits_until_rate_limit_resets() {
+        let reset = Utc::now().timestamp() + 30;
+        let (mock_http_client, _) = mock_http_client(vec![
+            response(
+                StatusCode::Forbidden,
+                vec![
+                    ("x-ratelimit-remaining", "0".to_string()),
+                    ("x-ratelimit-reset", reset.to_string()),
+                ],
+            ),
+            response(StatusCode::Ok, vec![]),
+        ]);
+        let mut mock_sleeper = MockSleeper::default();
+        mock_sleeper
+            .expect_sleep()
+            .withf(|duration| {
+                *duration >= Duration::from_secs(29) && *duration <= Duration::from_secs(31)
+            })
+            .times(1)
+            .returning(|_| Box::pin(async_this(())));
+        let under_test = RetryingHttpClientFacade::new(mock_http_client, policy(), mock_sleeper);
+
+        let result = under_test.send(request()).await.unwrap();
+
+        assert_that(&result.status_code()).is_equal_to(StatusCode::Ok);
+    }
+
+    #[async_std::test]
+    async fn fails_instead_of_waiting_longer_than_allowed_for_rate_limit() {
+        let (mock_http_client, calls) = mock_http_client(vec![response(
+            StatusCode::TooManyRequests,
+            vec![("retry-after", "3600".to_string())],
+        )]);
+        let under_test = RetryingHttpClientFacade::new(mock_http_client, policy(), mock_sleeper(0));
+
+        let result = under_test.send(request()).await.unwrap();
+
+        assert_that(&result.status_code()).is_equal_to(StatusCode::TooManyRequests);
+        assert_that(&calls.load(Ordering::SeqCst)).is_equal_to(1);
+    }
+
+    #[async_std::test]
+    async fn does_not_retry_forbidden_response_that_is_not_rate_limited() {
+        let (mock_http_client, calls) =
+            mock_http_client(vec![response(StatusCode::Forbidden, vec![])]);
+        let under_test = RetryingHttpClientFacade::new(mock_http_client, policy(), mock_sleeper(0));
+
+        under_test.send(request()).await.unwrap();
+
+        assert_that(&calls.load(Ordering::SeqCst)).is_equal_to(1);
+    }
+
+    #[test]
+    fn backs_off_exponentially_with_jitter_up_to_max_delay() {
+        let policy = policy();
+
+        assert_that(&policy.backoff(0)).is_greater_than_or_equal_to(Duration::from_millis(500));
+        assert_that(&policy.backoff(0)).is_less_than_or_equal_to(Duration::from_secs(1));
+        assert_that(&policy.backoff(2)).is_greater_than_or_equal_to(Duration::from_secs(2));
+        assert_that(&policy.backoff(2)).is_less_than_or_equal_to(Duration::from_secs(4));
+        assert_that(&policy.backoff(10)).is_less_than_or_equal_to(Duration::from_secs(10));
+    }
+}
@@ -0,0 +1,26 @@
+use crate::authenticate::{
+    authenticate_command, mock_bitbucket_api_server_for_successful_authentication_check,
+};
+use crate::common::{BITBUCKET_APP_PASSWORD, BITBUCKET_USERNAME};
+
+#[test]
+fn authenticates_with_bitbucket() {
+    let temp_home_directory = assert_fs::TempDir::new().unwrap();
+    let _mock = mock_bitbucket_api_server_for_successful_authentication_check()
+        .expect(1)
+        .create();
+
+    let assert = authenticate_command(temp_home_directory.path())
+        .arg("bitbucket")
+        .arg("--username")
+        .arg(BITBUCKET_USERNAME)
+        .arg("--app-password")
+        .arg(BITBUCKET_APP_PASSWORD)
+        .assert();
+
+    assert
+        .success()
+        .stdout("Successfully authenticated with Bitbucket\n");
+    _mock.assert();
+    temp_home_directory.close().unwrap();
+}
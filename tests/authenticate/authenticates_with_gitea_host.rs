@@ -0,0 +1,28 @@
+use crate::authenticate::{
+    authenticate_command, mock_gitea_api_server_for_successful_authentication_check,
+};
+use crate::common::{configure_gitea_host, GITEA_ACCESS_TOKEN, GITEA_HOST};
+
+#[test]
+fn authenticates_with_gitea_host() {
+    let temp_home_directory = assert_fs::TempDir::new().unwrap();
+    configure_gitea_host(temp_home_directory.path());
+    let _mock = mock_gitea_api_server_for_successful_authentication_check(GITEA_ACCESS_TOKEN)
+        .expect(1)
+        .create();
+
+    let assert = authenticate_command(temp_home_directory.path())
+        .arg("gitea")
+        .arg("--token")
+        .arg(GITEA_ACCESS_TOKEN)
+        .arg("--host")
+        .arg(GITEA_HOST)
+        .assert();
+
+    assert.success().stdout(format!(
+        "Successfully authenticated with Gitea at {}\n",
+        GITEA_HOST
+    ));
+    _mock.assert();
+    temp_home_directory.close().unwrap();
+}
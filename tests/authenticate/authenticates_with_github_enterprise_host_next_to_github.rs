@@ -0,0 +1,36 @@
+use crate::authenticate::{
+    authenticate_command, mock_github_enterprise_api_server_for_successful_authentication_check,
+};
+use crate::common::{
+    authenticate_session_with_github, configure_github_enterprise_host, GITHUB_ENTERPRISE_HOST,
+    GITHUB_ENTERPRISE_OAUTH_TOKEN, GITHUB_OAUTH_TOKEN,
+};
+
+#[test]
+fn authenticates_with_github_enterprise_host_next_to_github() {
+    let temp_home_directory = assert_fs::TempDir::new().unwrap();
+    configure_github_enterprise_host(temp_home_directory.path());
+    authenticate_session_with_github(temp_home_directory.path()).unwrap();
+    let _mock = mock_github_enterprise_api_server_for_successful_authentication_check(
+        GITHUB_ENTERPRISE_OAUTH_TOKEN,
+    )
+    .create();
+
+    let assert = authenticate_command(temp_home_directory.path())
+        .arg("github")
+        .arg("--token")
+        .arg(GITHUB_ENTERPRISE_OAUTH_TOKEN)
+        .arg("--host")
+        .arg(GITHUB_ENTERPRISE_HOST)
+        .assert();
+
+    assert.success().stdout(format!(
+        "Successfully authenticated with GitHub at {}\n",
+        GITHUB_ENTERPRISE_HOST
+    ));
+    let credentials =
+        std::fs::read_to_string(temp_home_directory.path().join("credentials.yml")).unwrap();
+    assert!(credentials.contains(GITHUB_OAUTH_TOKEN));
+    assert!(credentials.contains(GITHUB_ENTERPRISE_OAUTH_TOKEN));
+    temp_home_directory.close().unwrap();
+}
@@ -0,0 +1,24 @@
+use crate::authenticate::{
+    authenticate_command, mock_gitlab_api_server_for_successful_authentication_check,
+};
+use crate::common::GITLAB_PRIVATE_TOKEN;
+
+#[test]
+fn authenticates_with_gitlab() {
+    let temp_home_directory = assert_fs::TempDir::new().unwrap();
+    let _mock = mock_gitlab_api_server_for_successful_authentication_check(GITLAB_PRIVATE_TOKEN)
+        .expect(1)
+        .create();
+
+    let assert = authenticate_command(temp_home_directory.path())
+        .arg("gitlab")
+        .arg("--token")
+        .arg(GITLAB_PRIVATE_TOKEN)
+        .assert();
+
+    assert
+        .success()
+        .stdout("Successfully authenticated with GitLab\n");
+    _mock.assert();
+    temp_home_directory.close().unwrap();
+}
@@ -0,0 +1,34 @@
+use crate::common::{
+    authenticate_session_with_github, authenticate_session_with_github_enterprise,
+    authenticate_session_with_gitlab, configure_github_enterprise_host, GITHUB_ENTERPRISE_HOST,
+    GITHUB_ENTERPRISE_OAUTH_TOKEN, GITHUB_OAUTH_TOKEN, GITLAB_PRIVATE_TOKEN,
+};
+
+#[test]
+fn authentication_with_gitlab_keeps_github_authentication() {
+    let temp_home_directory = assert_fs::TempDir::new().unwrap();
+    configure_github_enterprise_host(temp_home_directory.path());
+    authenticate_session_with_github(temp_home_directory.path()).unwrap();
+    authenticate_session_with_github_enterprise(temp_home_directory.path()).unwrap();
+
+    authenticate_session_with_gitlab(temp_home_directory.path()).unwrap();
+
+    let credentials: serde_yaml::Value = serde_yaml::from_str(
+        &std::fs::read_to_string(temp_home_directory.path().join("credentials.yml")).unwrap(),
+    )
+    .unwrap();
+    let tokens = &credentials["tokens"];
+    assert_eq!(
+        tokens["github"]["github.com"].as_str(),
+        Some(GITHUB_OAUTH_TOKEN)
+    );
+    assert_eq!(
+        tokens["github"][GITHUB_ENTERPRISE_HOST].as_str(),
+        Some(GITHUB_ENTERPRISE_OAUTH_TOKEN)
+    );
+    assert_eq!(
+        tokens["gitlab"]["gitlab.com"].as_str(),
+        Some(GITLAB_PRIVATE_TOKEN)
+    );
+    temp_home_directory.close().unwrap();
+}
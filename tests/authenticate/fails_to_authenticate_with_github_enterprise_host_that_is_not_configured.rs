@@ -0,0 +1,23 @@
+use predicates::str::starts_with;
+
+use crate::authenticate::authenticate_command;
+use crate::common::{GITHUB_ENTERPRISE_HOST, GITHUB_OAUTH_TOKEN};
+
+#[test]
+fn fails_to_authenticate_with_github_enterprise_host_that_is_not_configured() {
+    let temp_home_directory = assert_fs::TempDir::new().unwrap();
+
+    let assert = authenticate_command(temp_home_directory.path())
+        .arg("github")
+        .arg("--token")
+        .arg(GITHUB_OAUTH_TOKEN)
+        .arg("--host")
+        .arg(GITHUB_ENTERPRISE_HOST)
+        .assert();
+
+    assert.failure().stderr(starts_with(format!(
+        "Error: GitHub Enterprise Server host '{}' is not configured",
+        GITHUB_ENTERPRISE_HOST
+    )));
+    temp_home_directory.close().unwrap();
+}
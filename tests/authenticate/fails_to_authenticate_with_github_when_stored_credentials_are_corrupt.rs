@@ -0,0 +1,28 @@
+use predicates::str::ends_with;
+
+use crate::authenticate::{
+    authenticate_command, mock_github_api_server_for_successful_authentication_check,
+};
+
+#[test]
+fn fails_to_authenticate_with_github_when_stored_credentials_are_corrupt() {
+    let temp_home_directory = assert_fs::TempDir::new().unwrap();
+    let credentials_file = temp_home_directory.path().join("credentials.yml");
+    std::fs::write(&credentials_file, "tokens: [").unwrap();
+    let _mock = mock_github_api_server_for_successful_authentication_check("OAUTH-TOKEN").create();
+
+    let assert = authenticate_command(temp_home_directory.path())
+        .arg("github")
+        .arg("--token")
+        .arg("OAUTH-TOKEN")
+        .assert();
+
+    assert
+        .failure()
+        .stderr(ends_with("Storage contains corrupted data\n"));
+    assert_eq!(
+        std::fs::read_to_string(&credentials_file).unwrap(),
+        "tokens: ["
+    );
+    temp_home_directory.close().unwrap();
+}
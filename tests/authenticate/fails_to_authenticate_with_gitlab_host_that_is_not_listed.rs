@@ -0,0 +1,22 @@
+use predicates::str::starts_with;
+
+use crate::authenticate::authenticate_command;
+use crate::common::GITLAB_PRIVATE_TOKEN;
+
+#[test]
+fn fails_to_authenticate_with_gitlab_host_that_is_not_listed() {
+    let temp_home_directory = assert_fs::TempDir::new().unwrap();
+
+    let assert = authenticate_command(temp_home_directory.path())
+        .arg("gitlab")
+        .arg("--token")
+        .arg(GITLAB_PRIVATE_TOKEN)
+        .arg("--host")
+        .arg("gitlab.example.com")
+        .assert();
+
+    assert.failure().stderr(starts_with(
+        "Error: GitLab host 'gitlab.example.com' is not listed in TIDY_REPO_GITLAB_HOSTS",
+    ));
+    temp_home_directory.close().unwrap();
+}
@@ -3,12 +3,22 @@ use std::path::Path;
 use assert_cmd::Command;
 use http_types::Method;
 
-use crate::common::test_command;
+use crate::common::{
+    require_bitbucket_auth_for_mock, test_command, GITEA_API_PATH, GITHUB_ENTERPRISE_API_PATH,
+};
 
+mod authenticates_with_bitbucket;
+mod authenticates_with_gitea_host;
 mod authenticates_with_github;
+mod authenticates_with_github_enterprise_host_next_to_github;
+mod authenticates_with_gitlab;
 mod authentication_with_github_overwrites_previous_github_authentication;
+mod authentication_with_gitlab_keeps_github_authentication;
+mod fails_to_authenticate_with_github_enterprise_host_that_is_not_configured;
 mod fails_to_authenticate_with_github_when_app_home_directory_does_not_exist;
 mod fails_to_authenticate_with_github_when_passed_invalid_token;
+mod fails_to_authenticate_with_github_when_stored_credentials_are_corrupt;
+mod fails_to_authenticate_with_gitlab_host_that_is_not_listed;
 mod fails_when_app_home_environment_variable_is_not_set;
 
 pub(crate) fn authenticate_command(temp_home_directory: &Path) -> Command {
@@ -38,3 +48,40 @@ pub(crate) fn mock_github_api_server_for_unsuccessful_authentication_check(
         )
         .with_status(401)
 }
+
+pub(crate) fn mock_github_enterprise_api_server_for_successful_authentication_check(
+    oauth_token: &str,
+) -> mockito::Mock {
+    mockito::mock(Method::Get.as_ref(), GITHUB_ENTERPRISE_API_PATH)
+        .match_header(
+            http_types::headers::AUTHORIZATION.as_str(),
+            format!("token {}", oauth_token).as_str(),
+        )
+        .with_status(200)
+}
+
+pub(crate) fn mock_gitea_api_server_for_successful_authentication_check(
+    access_token: &str,
+) -> mockito::Mock {
+    mockito::mock(
+        Method::Get.as_ref(),
+        format!("{}/user", GITEA_API_PATH).as_str(),
+    )
+    .match_header(
+        http_types::headers::AUTHORIZATION.as_str(),
+        format!("token {}", access_token).as_str(),
+    )
+    .with_status(200)
+}
+
+pub(crate) fn mock_gitlab_api_server_for_successful_authentication_check(
+    private_token: &str,
+) -> mockito::Mock {
+    mockito::mock(Method::Get.as_ref(), "/user")
+        .match_header("private-token", private_token)
+        .with_status(200)
+}
+
+pub(crate) fn mock_bitbucket_api_server_for_successful_authentication_check() -> mockito::Mock {
+    require_bitbucket_auth_for_mock(mockito::mock(Method::Get.as_ref(), "/user")).with_status(200)
+}
@@ -0,0 +1,57 @@
+use http_types::headers::ACCEPT;
+use http_types::Method;
+use mockito::Matcher;
+
+use crate::branches::{
+    branches_command, count_results_with_header, list_branches_body,
+    mock_github_api_server_for_get_repository, mock_github_api_server_for_successful_list_branches,
+};
+
+fn mock_github_api_server_for_list_branches_page(page: u32, body: &str) -> mockito::Mock {
+    mockito::mock(Method::Get.as_ref(), "/repositories/1/branches")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("per_page".into(), "100".into()),
+            Matcher::UrlEncoded("page".into(), page.to_string()),
+        ]))
+        .match_header(ACCEPT.as_str(), "application/vnd.github.v3+json")
+        .with_body(body)
+        .with_status(200)
+}
+
+fn next_page_link(page: u32) -> String {
+    format!(
+        "<{}/repositories/1/branches?per_page=100&page={}>; rel=\"next\"",
+        mockito::server_url(),
+        page
+    )
+}
+
+#[test]
+fn counts_branches_across_multiple_pages() {
+    let temp_home_directory = assert_fs::TempDir::new().unwrap();
+    let _mock_1 = mock_github_api_server_for_successful_list_branches(
+        "owner",
+        "paginated-repo",
+        &list_branches_body(&["branch1", "branch2"]),
+    )
+    .with_header("link", next_page_link(2).as_str())
+    .create();
+    let _mock_2 =
+        mock_github_api_server_for_list_branches_page(2, &list_branches_body(&["branch3"]))
+            .with_header("link", next_page_link(3).as_str())
+            .create();
+    let _mock_3 =
+        mock_github_api_server_for_list_branches_page(3, &list_branches_body(&["branch4"]))
+            .create();
+    let _repository_mock =
+        mock_github_api_server_for_get_repository("owner", "paginated-repo", "branch1").create();
+
+    let assert = branches_command(temp_home_directory.path())
+        .arg("https://github.com/owner/paginated-repo")
+        .assert();
+
+    assert.success().stdout(count_results_with_header(
+        "https://github.com/owner/paginated-repo: 4\n",
+    ));
+    temp_home_directory.close().unwrap();
+}
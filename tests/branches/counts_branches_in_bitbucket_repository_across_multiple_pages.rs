@@ -0,0 +1,44 @@
+use crate::branches::{
+    bitbucket_list_branches_body, branches_command, count_results_with_header,
+    mock_bitbucket_api_server_for_get_repository, mock_bitbucket_api_server_for_list_branches_page,
+};
+use crate::common::{authenticate_session_with_bitbucket, require_bitbucket_auth_for_mock};
+
+#[test]
+fn counts_branches_in_bitbucket_repository_across_multiple_pages() {
+    let temp_home_directory = assert_fs::TempDir::new().unwrap();
+    authenticate_session_with_bitbucket(temp_home_directory.path()).unwrap();
+    let next_page_url = format!(
+        "{}/repositories/workspace/repo/refs/branches?pagelen=100&page=2",
+        mockito::server_url()
+    );
+    let _first_page_mock =
+        require_bitbucket_auth_for_mock(mock_bitbucket_api_server_for_list_branches_page(
+            "workspace",
+            "repo",
+            None,
+            &bitbucket_list_branches_body(&["main", "feature"], Some(next_page_url)),
+        ))
+        .create();
+    let _second_page_mock =
+        require_bitbucket_auth_for_mock(mock_bitbucket_api_server_for_list_branches_page(
+            "workspace",
+            "repo",
+            Some("2"),
+            &bitbucket_list_branches_body(&["bugfix"], None),
+        ))
+        .create();
+    let _repository_mock = require_bitbucket_auth_for_mock(
+        mock_bitbucket_api_server_for_get_repository("workspace", "repo", "main"),
+    )
+    .create();
+
+    let assert = branches_command(temp_home_directory.path())
+        .arg("https://bitbucket.org/workspace/repo")
+        .assert();
+
+    assert.success().stdout(count_results_with_header(
+        "https://bitbucket.org/workspace/repo: 3\n",
+    ));
+    temp_home_directory.close().unwrap();
+}
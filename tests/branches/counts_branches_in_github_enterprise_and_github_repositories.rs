@@ -0,0 +1,62 @@
+use http_types::headers::{ACCEPT, AUTHORIZATION};
+use http_types::Method;
+use mockito::Matcher;
+
+use crate::branches::{
+    branches_command, count_results_with_header, list_branches_body,
+    mock_github_api_server_for_get_repository, mock_github_api_server_for_successful_list_branches,
+};
+use crate::common::{
+    authenticate_session_with_github, authenticate_session_with_github_enterprise,
+    configure_github_enterprise_host, require_github_auth_for_mock, GITHUB_ENTERPRISE_API_PATH,
+    GITHUB_ENTERPRISE_OAUTH_TOKEN,
+};
+
+fn require_github_enterprise_auth_for_mock(mock: mockito::Mock) -> mockito::Mock {
+    mock.match_header(ACCEPT.as_str(), "application/vnd.github.v3+json")
+        .match_header(
+            AUTHORIZATION.as_str(),
+            format!("token {}", GITHUB_ENTERPRISE_OAUTH_TOKEN).as_str(),
+        )
+        .with_status(200)
+}
+
+#[test]
+fn counts_branches_in_github_enterprise_and_github_repositories() {
+    let temp_home_directory = assert_fs::TempDir::new().unwrap();
+    configure_github_enterprise_host(temp_home_directory.path());
+    authenticate_session_with_github(temp_home_directory.path()).unwrap();
+    authenticate_session_with_github_enterprise(temp_home_directory.path()).unwrap();
+    let github_body_string = list_branches_body(&["branch1"]);
+    let _github_mock = require_github_auth_for_mock(
+        mock_github_api_server_for_successful_list_branches("owner", "repo", &github_body_string),
+    )
+    .create();
+    let _github_repository_mock = require_github_auth_for_mock(
+        mock_github_api_server_for_get_repository("owner", "repo", "branch1"),
+    )
+    .create();
+    let _enterprise_mock = require_github_enterprise_auth_for_mock(mockito::mock(
+        Method::Get.as_ref(),
+        format!("{}/repos/team/service/branches", GITHUB_ENTERPRISE_API_PATH).as_str(),
+    ))
+    .match_query(Matcher::UrlEncoded("per_page".into(), "100".into()))
+    .with_body(list_branches_body(&["main", "feature"]))
+    .create();
+    let _enterprise_repository_mock = require_github_enterprise_auth_for_mock(mockito::mock(
+        Method::Get.as_ref(),
+        format!("{}/repos/team/service", GITHUB_ENTERPRISE_API_PATH).as_str(),
+    ))
+    .with_body(serde_json::json!({ "default_branch": "main" }).to_string())
+    .create();
+
+    let assert = branches_command(temp_home_directory.path())
+        .arg("https://github.com/owner/repo")
+        .arg("git@github.corp.example:team/service.git")
+        .assert();
+
+    assert.success().stdout(count_results_with_header(
+        "https://github.com/owner/repo: 1\nhttps://github.corp.example/team/service: 2\n",
+    ));
+    temp_home_directory.close().unwrap();
+}
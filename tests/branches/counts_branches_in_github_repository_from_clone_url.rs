@@ -0,0 +1,37 @@
+use crate::branches::{
+    branches_command, count_results_with_header, list_branches_body,
+    mock_github_api_server_for_get_repository, mock_github_api_server_for_successful_list_branches,
+};
+
+fn counts_branches_in_github_repository_at(url: &str) {
+    let temp_home_directory = assert_fs::TempDir::new().unwrap();
+    let body_string = list_branches_body(&["branch"]);
+    let _mock =
+        mock_github_api_server_for_successful_list_branches("owner", "repo", &body_string).create();
+    let _repository_mock =
+        mock_github_api_server_for_get_repository("owner", "repo", "branch").create();
+
+    let assert = branches_command(temp_home_directory.path())
+        .arg(url)
+        .assert();
+
+    assert.success().stdout(count_results_with_header(
+        "https://github.com/owner/repo: 1\n",
+    ));
+    temp_home_directory.close().unwrap();
+}
+
+#[test]
+fn counts_branches_in_github_repository_from_scp_like_ssh_url() {
+    counts_branches_in_github_repository_at("git@github.com:owner/repo.git");
+}
+
+#[test]
+fn counts_branches_in_github_repository_from_ssh_url() {
+    counts_branches_in_github_repository_at("ssh://git@github.com/owner/repo");
+}
+
+#[test]
+fn counts_branches_in_github_repository_from_url_with_trailing_slash() {
+    counts_branches_in_github_repository_at("https://github.com/owner/repo/");
+}
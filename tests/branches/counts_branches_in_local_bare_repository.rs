@@ -0,0 +1,40 @@
+use crate::branches::{branches_command, count_results_with_header};
+use crate::common::local_repository::LocalRepository;
+
+#[test]
+fn counts_loose_and_packed_branches_in_local_bare_repository() {
+    let temp_home_directory = assert_fs::TempDir::new().unwrap();
+    let temp_repository_directory = assert_fs::TempDir::new().unwrap();
+    let repository = LocalRepository::bare(temp_repository_directory.path(), "main");
+    repository.commit(1, &[], "Author", "2020-01-01T00:00:00Z");
+    repository.branch("main", 1);
+    repository.packed_branches(&[("main", 1), ("archived", 1), ("release", 1)]);
+
+    let assert = branches_command(temp_home_directory.path())
+        .arg(repository.url())
+        .assert();
+
+    assert.success().stdout(count_results_with_header(format!(
+        "{}: 3\n",
+        repository.reported_path()
+    )));
+    temp_repository_directory.close().unwrap();
+    temp_home_directory.close().unwrap();
+}
+
+#[test]
+fn fails_when_local_path_is_not_a_repository() {
+    let temp_home_directory = assert_fs::TempDir::new().unwrap();
+    let temp_directory = assert_fs::TempDir::new().unwrap();
+
+    let assert = branches_command(temp_home_directory.path())
+        .arg(temp_directory.path())
+        .assert();
+
+    assert.failure().stderr(predicates::str::contains(format!(
+        "'{}' is not a git repository",
+        temp_directory.path().display()
+    )));
+    temp_directory.close().unwrap();
+    temp_home_directory.close().unwrap();
+}
@@ -1,19 +1,23 @@
 use crate::branches::{
-    branches_command, count_results_with_header,
-    mock_github_api_server_for_successful_list_branches,
+    branches_command, count_results_with_header, list_branches_body,
+    mock_github_api_server_for_get_repository, mock_github_api_server_for_successful_list_branches,
 };
 
 #[test]
 fn counts_branches_in_multiple_github_repositories() {
     let temp_home_directory = assert_fs::TempDir::new().unwrap();
-    let repo_1_body_string = "[{\"name\": \"branch1\"}]";
+    let repo_1_body_string = list_branches_body(&["branch1"]);
     let _mock_1 =
-        mock_github_api_server_for_successful_list_branches("owner", "repo1", repo_1_body_string)
+        mock_github_api_server_for_successful_list_branches("owner", "repo1", &repo_1_body_string)
             .create();
-    let repo_2_body_string = "[{\"name\": \"branch2\"}]";
+    let repo_2_body_string = list_branches_body(&["branch2"]);
     let _mock_2 =
-        mock_github_api_server_for_successful_list_branches("owner", "repo2", repo_2_body_string)
+        mock_github_api_server_for_successful_list_branches("owner", "repo2", &repo_2_body_string)
             .create();
+    let _repository_mock_1 =
+        mock_github_api_server_for_get_repository("owner", "repo1", "branch1").create();
+    let _repository_mock_2 =
+        mock_github_api_server_for_get_repository("owner", "repo2", "branch2").create();
 
     let assert = branches_command(temp_home_directory.path())
         .arg("https://github.com/owner/repo1")
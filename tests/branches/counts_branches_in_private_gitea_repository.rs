@@ -0,0 +1,53 @@
+use http_types::headers::AUTHORIZATION;
+use http_types::Method;
+use mockito::Matcher;
+
+use crate::branches::{branches_command, count_results_with_header};
+use crate::common::{
+    authenticate_session_with_gitea, configure_gitea_host, GITEA_ACCESS_TOKEN, GITEA_API_PATH,
+    GITEA_HOST,
+};
+
+#[test]
+fn counts_branches_in_private_gitea_repository() {
+    let temp_home_directory = assert_fs::TempDir::new().unwrap();
+    configure_gitea_host(temp_home_directory.path());
+    authenticate_session_with_gitea(temp_home_directory.path()).unwrap();
+    let authorization = format!("token {}", GITEA_ACCESS_TOKEN);
+    let _branches_mock = mockito::mock(
+        Method::Get.as_ref(),
+        format!("{}/repos/owner/repo/branches", GITEA_API_PATH).as_str(),
+    )
+    .match_query(Matcher::AllOf(vec![
+        Matcher::UrlEncoded("page".into(), "1".into()),
+        Matcher::UrlEncoded("limit".into(), "50".into()),
+    ]))
+    .match_header(AUTHORIZATION.as_str(), authorization.as_str())
+    .with_body(
+        serde_json::json!([
+            {"name": "main", "commit": {"id": "main-sha"}, "protected": true},
+            {"name": "feature", "commit": {"id": "feature-sha"}, "protected": false}
+        ])
+        .to_string(),
+    )
+    .with_status(200)
+    .create();
+    let _repository_mock = mockito::mock(
+        Method::Get.as_ref(),
+        format!("{}/repos/owner/repo", GITEA_API_PATH).as_str(),
+    )
+    .match_header(AUTHORIZATION.as_str(), authorization.as_str())
+    .with_body(serde_json::json!({ "default_branch": "main" }).to_string())
+    .with_status(200)
+    .create();
+
+    let assert = branches_command(temp_home_directory.path())
+        .arg(format!("https://{}/owner/repo", GITEA_HOST))
+        .assert();
+
+    assert.success().stdout(count_results_with_header(format!(
+        "https://{}/owner/repo: 2\n",
+        GITEA_HOST
+    )));
+    temp_home_directory.close().unwrap();
+}
@@ -1,17 +1,21 @@
 use crate::branches::{
-    branches_command, count_results_with_header,
-    mock_github_api_server_for_successful_list_branches,
+    branches_command, count_results_with_header, list_branches_body,
+    mock_github_api_server_for_get_repository, mock_github_api_server_for_successful_list_branches,
 };
 use crate::common::{authenticate_session_with_github, require_github_auth_for_mock};
 
 #[test]
 fn counts_branches_in_private_github_repository() {
     let temp_home_directory = assert_fs::TempDir::new().unwrap();
-    let body_string = "[{\"name\": \"branch\"}]";
+    let body_string = list_branches_body(&["branch"]);
     let _mock = require_github_auth_for_mock(mock_github_api_server_for_successful_list_branches(
         "owner",
         "repo",
-        body_string,
+        &body_string,
+    ))
+    .create();
+    let _repository_mock = require_github_auth_for_mock(mock_github_api_server_for_get_repository(
+        "owner", "repo", "branch",
     ))
     .create();
     authenticate_session_with_github(temp_home_directory.path()).unwrap();
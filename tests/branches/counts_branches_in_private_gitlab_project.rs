@@ -0,0 +1,28 @@
+use crate::branches::{
+    branches_command, count_results_with_header, gitlab_list_branches_body,
+    mock_gitlab_api_server_for_get_project, mock_gitlab_api_server_for_successful_list_branches,
+};
+use crate::common::{authenticate_session_with_gitlab, GITLAB_PRIVATE_TOKEN};
+
+#[test]
+fn counts_branches_in_private_gitlab_project() {
+    let temp_home_directory = assert_fs::TempDir::new().unwrap();
+    authenticate_session_with_gitlab(temp_home_directory.path()).unwrap();
+    let body_string = gitlab_list_branches_body(&["main", "feature"]);
+    let _mock =
+        mock_gitlab_api_server_for_successful_list_branches("group%2Fproject", &body_string)
+            .match_header("private-token", GITLAB_PRIVATE_TOKEN)
+            .create();
+    let _project_mock = mock_gitlab_api_server_for_get_project("group%2Fproject", "main")
+        .match_header("private-token", GITLAB_PRIVATE_TOKEN)
+        .create();
+
+    let assert = branches_command(temp_home_directory.path())
+        .arg("https://gitlab.com/group/project")
+        .assert();
+
+    assert.success().stdout(count_results_with_header(
+        "https://gitlab.com/group/project: 2\n",
+    ));
+    temp_home_directory.close().unwrap();
+}
@@ -0,0 +1,72 @@
+use crate::branches::{
+    branches_command, count_results_with_header, list_branches_body,
+    mock_github_api_server_for_get_repository, mock_github_api_server_for_list_owner_repositories,
+    mock_github_api_server_for_successful_list_branches,
+};
+
+#[test]
+fn counts_branches_in_repositories_of_github_organization_and_user() {
+    let temp_home_directory = assert_fs::TempDir::new().unwrap();
+    let _org_mock = mock_github_api_server_for_list_owner_repositories(
+        "orgs/my-org",
+        &[
+            "https://github.com/my-org/repo1",
+            "https://github.com/my-org/repo2",
+        ],
+    )
+    .create();
+    let _user_mock = mock_github_api_server_for_list_owner_repositories(
+        "users/someone",
+        &["https://github.com/someone/repo"],
+    )
+    .create();
+    let _branch_mocks: Vec<mockito::Mock> = [
+        ("my-org", "repo1"),
+        ("my-org", "repo2"),
+        ("someone", "repo"),
+    ]
+    .iter()
+    .flat_map(|(owner, repo)| {
+        vec![
+            mock_github_api_server_for_successful_list_branches(
+                owner,
+                repo,
+                &list_branches_body(&["main"]),
+            )
+            .create(),
+            mock_github_api_server_for_get_repository(owner, repo, "main").create(),
+        ]
+    })
+    .collect();
+
+    let assert = branches_command(temp_home_directory.path())
+        .arg("--org")
+        .arg("my-org")
+        .arg("--user")
+        .arg("someone")
+        .assert();
+
+    assert.success().stdout(count_results_with_header(
+        "https://github.com/my-org/repo1: 1\nhttps://github.com/my-org/repo2: 1\nhttps://github.com/someone/repo: 1\n",
+    ));
+    temp_home_directory.close().unwrap();
+}
+
+#[test]
+fn fails_when_github_organization_does_not_exist() {
+    let temp_home_directory = assert_fs::TempDir::new().unwrap();
+    let _org_mock = mockito::mock("GET", "/orgs/missing/repos")
+        .match_query(mockito::Matcher::Any)
+        .with_status(404)
+        .create();
+
+    let assert = branches_command(temp_home_directory.path())
+        .arg("--org")
+        .arg("missing")
+        .assert();
+
+    assert
+        .failure()
+        .stderr("Error: organization 'missing' not found\n");
+    temp_home_directory.close().unwrap();
+}
@@ -0,0 +1,34 @@
+use crate::branches::{
+    branches_command, count_results_with_header, gitlab_list_branches_body, list_branches_body,
+    mock_github_api_server_for_get_repository, mock_github_api_server_for_successful_list_branches,
+    mock_gitlab_api_server_for_get_project, mock_gitlab_api_server_for_successful_list_branches,
+};
+
+#[test]
+fn counts_branches_in_repositories_on_different_hosts() {
+    let temp_home_directory = assert_fs::TempDir::new().unwrap();
+    let github_body_string = list_branches_body(&["branch1"]);
+    let _github_mock =
+        mock_github_api_server_for_successful_list_branches("owner", "repo", &github_body_string)
+            .create();
+    let _github_repository_mock =
+        mock_github_api_server_for_get_repository("owner", "repo", "branch1").create();
+    let gitlab_body_string = gitlab_list_branches_body(&["branch1", "branch2"]);
+    let _gitlab_mock = mock_gitlab_api_server_for_successful_list_branches(
+        "group%2Fsub%2Fproject",
+        &gitlab_body_string,
+    )
+    .create();
+    let _gitlab_project_mock =
+        mock_gitlab_api_server_for_get_project("group%2Fsub%2Fproject", "branch1").create();
+
+    let assert = branches_command(temp_home_directory.path())
+        .arg("https://github.com/owner/repo")
+        .arg("https://gitlab.com/group/sub/project")
+        .assert();
+
+    assert.success().stdout(count_results_with_header(
+        "https://github.com/owner/repo: 1\nhttps://gitlab.com/group/sub/project: 2\n",
+    ));
+    temp_home_directory.close().unwrap();
+}
@@ -0,0 +1,86 @@
+use crate::branches::{
+    branches_command, count_results_with_header, list_branches_body,
+    mock_github_api_server_for_get_repository, mock_github_api_server_for_successful_list_branches,
+};
+use crate::common::local_repository::LocalRepository;
+
+#[test]
+fn counts_branches_in_repository_of_origin_remote_of_current_directory() {
+    let temp_home_directory = assert_fs::TempDir::new().unwrap();
+    let temp_working_copy_directory = assert_fs::TempDir::new().unwrap();
+    LocalRepository::working_copy(temp_working_copy_directory.path(), "main");
+    let body_string = list_branches_body(&["main", "feature"]);
+    let _mock =
+        mock_github_api_server_for_successful_list_branches("owner", "repo", &body_string).create();
+    let _repository_mock =
+        mock_github_api_server_for_get_repository("owner", "repo", "main").create();
+
+    let assert = branches_command(temp_home_directory.path())
+        .current_dir(temp_working_copy_directory.path())
+        .assert();
+
+    assert.success().stdout(count_results_with_header(
+        "https://github.com/owner/repo: 2\n",
+    ));
+    temp_working_copy_directory.close().unwrap();
+    temp_home_directory.close().unwrap();
+}
+
+#[test]
+fn counts_branches_in_repository_of_chosen_remote_of_repository_directory() {
+    let temp_home_directory = assert_fs::TempDir::new().unwrap();
+    let temp_working_copy_directory = assert_fs::TempDir::new().unwrap();
+    let repository = LocalRepository::working_copy(temp_working_copy_directory.path(), "main");
+    repository.remote("upstream", "git@github.com:upstream-owner/repo.git");
+    let body_string = list_branches_body(&["main"]);
+    let _mock =
+        mock_github_api_server_for_successful_list_branches("upstream-owner", "repo", &body_string)
+            .create();
+    let _repository_mock =
+        mock_github_api_server_for_get_repository("upstream-owner", "repo", "main").create();
+
+    let assert = branches_command(temp_home_directory.path())
+        .arg("--repo-dir")
+        .arg(temp_working_copy_directory.path())
+        .arg("--remote")
+        .arg("upstream")
+        .assert();
+
+    assert.success().stdout(count_results_with_header(
+        "https://github.com/upstream-owner/repo: 1\n",
+    ));
+    temp_working_copy_directory.close().unwrap();
+    temp_home_directory.close().unwrap();
+}
+
+#[test]
+fn counts_branches_in_repository_of_remote_rewritten_by_global_git_config() {
+    let temp_home_directory = assert_fs::TempDir::new().unwrap();
+    let temp_working_copy_directory = assert_fs::TempDir::new().unwrap();
+    let repository = LocalRepository::working_copy(temp_working_copy_directory.path(), "main");
+    repository.remote("upstream", "gh:upstream-owner/repo");
+    std::fs::write(
+        temp_home_directory.path().join(".gitconfig"),
+        "[url \"https://github.com/\"]\n\tinsteadOf = gh:\n",
+    )
+    .unwrap();
+    let body_string = list_branches_body(&["main"]);
+    let _mock =
+        mock_github_api_server_for_successful_list_branches("upstream-owner", "repo", &body_string)
+            .create();
+    let _repository_mock =
+        mock_github_api_server_for_get_repository("upstream-owner", "repo", "main").create();
+
+    let assert = branches_command(temp_home_directory.path())
+        .arg("--repo-dir")
+        .arg(temp_working_copy_directory.path())
+        .arg("--remote")
+        .arg("upstream")
+        .assert();
+
+    assert.success().stdout(count_results_with_header(
+        "https://github.com/upstream-owner/repo: 1\n",
+    ));
+    temp_working_copy_directory.close().unwrap();
+    temp_home_directory.close().unwrap();
+}
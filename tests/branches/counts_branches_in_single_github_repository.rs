@@ -1,14 +1,16 @@
 use crate::branches::{
-    branches_command, count_results_with_header,
-    mock_github_api_server_for_successful_list_branches,
+    branches_command, count_results_with_header, list_branches_body,
+    mock_github_api_server_for_get_repository, mock_github_api_server_for_successful_list_branches,
 };
 
 #[test]
 fn counts_branches_in_single_github_repository() {
     let temp_home_directory = assert_fs::TempDir::new().unwrap();
-    let body_string = "[{\"name\": \"branch\"}]";
+    let body_string = list_branches_body(&["branch"]);
     let _mock =
-        mock_github_api_server_for_successful_list_branches("owner", "repo", body_string).create();
+        mock_github_api_server_for_successful_list_branches("owner", "repo", &body_string).create();
+    let _repository_mock =
+        mock_github_api_server_for_get_repository("owner", "repo", "branch").create();
 
     let assert = branches_command(temp_home_directory.path())
         .arg("https://github.com/owner/repo")
@@ -23,16 +25,18 @@ fn counts_branches_in_single_github_repository() {
 #[test]
 fn counts_branches_in_single_github_repository_without_url_schema() {
     let temp_home_directory = assert_fs::TempDir::new().unwrap();
-    let body_string = "[{\"name\": \"branch\"}]";
+    let body_string = list_branches_body(&["branch"]);
     let _mock =
-        mock_github_api_server_for_successful_list_branches("owner", "repo", body_string).create();
+        mock_github_api_server_for_successful_list_branches("owner", "repo", &body_string).create();
+    let _repository_mock =
+        mock_github_api_server_for_get_repository("owner", "repo", "branch").create();
 
     let assert = branches_command(temp_home_directory.path())
         .arg("github.com/owner/repo")
         .assert();
 
-    assert
-        .success()
-        .stdout(count_results_with_header("github.com/owner/repo: 1\n"));
+    assert.success().stdout(count_results_with_header(
+        "https://github.com/owner/repo: 1\n",
+    ));
     temp_home_directory.close().unwrap();
 }
@@ -0,0 +1,28 @@
+use crate::branches::{
+    branches_command, count_results_with_header, list_branches_body,
+    mock_github_api_server_for_get_repository, mock_github_api_server_for_successful_list_branches,
+};
+
+#[test]
+fn counts_branches_once_for_duplicate_repository_urls() {
+    let temp_home_directory = assert_fs::TempDir::new().unwrap();
+    let body_string = list_branches_body(&["branch"]);
+    let list_branches_mock =
+        mock_github_api_server_for_successful_list_branches("owner", "repo", &body_string)
+            .expect(1)
+            .create();
+    let _repository_mock =
+        mock_github_api_server_for_get_repository("owner", "repo", "branch").create();
+
+    let assert = branches_command(temp_home_directory.path())
+        .arg("github.com/owner/repo")
+        .arg("https://github.com/owner/repo")
+        .arg("https://github.com/OWNER/repo.git")
+        .assert();
+
+    assert.success().stdout(count_results_with_header(
+        "https://github.com/owner/repo: 1\n",
+    ));
+    list_branches_mock.assert();
+    temp_home_directory.close().unwrap();
+}
@@ -0,0 +1,33 @@
+use crate::branches::{
+    branches_command, count_results_with_header, list_branches_body,
+    mock_github_api_server_for_get_repository, mock_github_api_server_for_successful_list_branches,
+};
+
+#[test]
+fn counts_branches_one_repository_at_a_time() {
+    let temp_home_directory = assert_fs::TempDir::new().unwrap();
+    let repo_1_body_string = list_branches_body(&["branch1"]);
+    let _mock_1 =
+        mock_github_api_server_for_successful_list_branches("owner", "repo1", &repo_1_body_string)
+            .create();
+    let repo_2_body_string = list_branches_body(&["branch1", "branch2"]);
+    let _mock_2 =
+        mock_github_api_server_for_successful_list_branches("owner", "repo2", &repo_2_body_string)
+            .create();
+    let _repository_mock_1 =
+        mock_github_api_server_for_get_repository("owner", "repo1", "branch1").create();
+    let _repository_mock_2 =
+        mock_github_api_server_for_get_repository("owner", "repo2", "branch1").create();
+
+    let assert = branches_command(temp_home_directory.path())
+        .arg("--concurrency")
+        .arg("1")
+        .arg("https://github.com/owner/repo1")
+        .arg("https://github.com/owner/repo2")
+        .assert();
+
+    assert.success().stdout(count_results_with_header(
+        "https://github.com/owner/repo1: 1\nhttps://github.com/owner/repo2: 2\n",
+    ));
+    temp_home_directory.close().unwrap();
+}
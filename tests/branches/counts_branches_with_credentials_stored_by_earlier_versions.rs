@@ -0,0 +1,35 @@
+use crate::branches::{
+    branches_command, count_results_with_header, list_branches_body,
+    mock_github_api_server_for_get_repository, mock_github_api_server_for_successful_list_branches,
+};
+use crate::common::{require_github_auth_for_mock, GITHUB_OAUTH_TOKEN};
+
+#[test]
+fn counts_branches_with_credentials_stored_by_earlier_versions() {
+    let temp_home_directory = assert_fs::TempDir::new().unwrap();
+    std::fs::write(
+        temp_home_directory.path().join("credentials.yml"),
+        format!("---\ngithub_token: {}\n", GITHUB_OAUTH_TOKEN),
+    )
+    .unwrap();
+    let body_string = list_branches_body(&["branch"]);
+    let _mock = require_github_auth_for_mock(mock_github_api_server_for_successful_list_branches(
+        "owner",
+        "repo",
+        &body_string,
+    ))
+    .create();
+    let _repository_mock = require_github_auth_for_mock(mock_github_api_server_for_get_repository(
+        "owner", "repo", "branch",
+    ))
+    .create();
+
+    let assert = branches_command(temp_home_directory.path())
+        .arg("https://github.com/owner/repo")
+        .assert();
+
+    assert.success().stdout(count_results_with_header(
+        "https://github.com/owner/repo: 1\n",
+    ));
+    temp_home_directory.close().unwrap();
+}
@@ -0,0 +1,85 @@
+use crate::branches::{
+    branches_command, mock_github_api_server_for_get_commit,
+    mock_github_api_server_for_get_repository, mock_github_api_server_for_successful_list_branches,
+};
+
+fn list_branches_body_with_protection() -> String {
+    serde_json::json!([
+        {"name": "main", "commit": {"sha": "main-sha"}, "protected": true},
+        {"name": "release", "commit": {"sha": "release-sha"}, "protected": true},
+        {"name": "old", "commit": {"sha": "old-sha"}, "protected": false}
+    ])
+    .to_string()
+}
+
+fn mock_old_commits(repo_name: &str) -> Vec<mockito::Mock> {
+    vec!["main-sha", "release-sha", "old-sha"]
+        .into_iter()
+        .map(|sha| {
+            mock_github_api_server_for_get_commit(
+                "owner",
+                repo_name,
+                sha,
+                "Author",
+                "2000-01-01T00:00:00Z",
+            )
+            .create()
+        })
+        .collect()
+}
+
+#[test]
+fn excludes_protected_and_default_branches_from_stale_report() {
+    let temp_home_directory = assert_fs::TempDir::new().unwrap();
+    let _branches_mock = mock_github_api_server_for_successful_list_branches(
+        "owner",
+        "protected-repo",
+        &list_branches_body_with_protection(),
+    )
+    .create();
+    let _repository_mock =
+        mock_github_api_server_for_get_repository("owner", "protected-repo", "main").create();
+    let _commit_mocks = mock_old_commits("protected-repo");
+
+    let assert = branches_command(temp_home_directory.path())
+        .arg("stale")
+        .arg("--older-than")
+        .arg("90d")
+        .arg("https://github.com/owner/protected-repo")
+        .assert();
+
+    assert.success().stdout(
+        "https://github.com/owner/protected-repo: old (last commit on 2000-01-01 by Author)\n",
+    );
+    temp_home_directory.close().unwrap();
+}
+
+#[test]
+fn includes_protected_and_default_branches_in_stale_report_when_requested() {
+    let temp_home_directory = assert_fs::TempDir::new().unwrap();
+    let _branches_mock = mock_github_api_server_for_successful_list_branches(
+        "owner",
+        "included-protected-repo",
+        &list_branches_body_with_protection(),
+    )
+    .create();
+    let _repository_mock =
+        mock_github_api_server_for_get_repository("owner", "included-protected-repo", "main")
+            .create();
+    let _commit_mocks = mock_old_commits("included-protected-repo");
+
+    let assert = branches_command(temp_home_directory.path())
+        .arg("stale")
+        .arg("--older-than")
+        .arg("90d")
+        .arg("--include-protected")
+        .arg("https://github.com/owner/included-protected-repo")
+        .assert();
+
+    assert.success().stdout(
+        "https://github.com/owner/included-protected-repo: main (last commit on 2000-01-01 by Author)\n\
+         https://github.com/owner/included-protected-repo: old (last commit on 2000-01-01 by Author)\n\
+         https://github.com/owner/included-protected-repo: release (last commit on 2000-01-01 by Author)\n",
+    );
+    temp_home_directory.close().unwrap();
+}
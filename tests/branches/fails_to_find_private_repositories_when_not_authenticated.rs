@@ -12,8 +12,9 @@ fn fails_to_find_private_repositories_when_not_authenticated() {
         .arg("https://github.com/owner/repo")
         .assert();
 
-    assert
-        .failure()
-        .stderr("Error: repository 'https://github.com/owner/repo' not found\n");
+    assert.failure().stderr(
+        "Error: https://github.com/owner/repo: \
+         repository 'https://github.com/owner/repo' not found\n",
+    );
     temp_home_directory.close().unwrap();
 }
@@ -0,0 +1,29 @@
+use http_types::headers::ACCEPT;
+use http_types::Method;
+use mockito::Matcher;
+
+use crate::branches::branches_command;
+
+#[test]
+fn fails_when_github_rejects_authentication_token() {
+    let temp_home_directory = assert_fs::TempDir::new().unwrap();
+    let _mock = mockito::mock(
+        Method::Get.as_ref(),
+        "/repos/owner/unauthorized-repo/branches",
+    )
+    .match_query(Matcher::UrlEncoded("per_page".into(), "100".into()))
+    .match_header(ACCEPT.as_str(), "application/vnd.github.v3+json")
+    .with_body("{\"message\": \"Bad credentials\"}")
+    .with_status(401)
+    .create();
+
+    let assert = branches_command(temp_home_directory.path())
+        .arg("https://github.com/owner/unauthorized-repo")
+        .assert();
+
+    assert.failure().stderr(
+        "Error: https://github.com/owner/unauthorized-repo: \
+         GitHub rejected the authentication token: Bad credentials\n",
+    );
+    temp_home_directory.close().unwrap();
+}
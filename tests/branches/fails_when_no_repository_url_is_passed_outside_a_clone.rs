@@ -0,0 +1,20 @@
+use predicates::str::contains;
+
+use crate::branches::branches_command;
+
+#[test]
+fn fails_when_no_repository_url_is_passed_outside_a_clone() {
+    let temp_home_directory = assert_fs::TempDir::new().unwrap();
+    let temp_directory = assert_fs::TempDir::new().unwrap();
+
+    let assert = branches_command(temp_home_directory.path())
+        .current_dir(temp_directory.path())
+        .assert();
+
+    assert
+        .failure()
+        .code(1)
+        .stderr(contains("is not a git repository"));
+    temp_directory.close().unwrap();
+    temp_home_directory.close().unwrap();
+}
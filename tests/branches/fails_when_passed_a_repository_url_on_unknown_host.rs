@@ -1,14 +1,14 @@
 use crate::branches::branches_command;
 
 #[test]
-fn fails_when_passed_a_malformed_repository_url() {
+fn fails_when_passed_a_repository_url_on_unknown_host() {
     let temp_home_directory = assert_fs::TempDir::new().unwrap();
     let assert = branches_command(temp_home_directory.path())
         .arg("https://not-github.com/owner/repo")
         .assert();
 
-    assert
-        .failure()
-        .stderr("Error: failed to parse repository from 'https://not-github.com/owner/repo'\n");
+    assert.failure().stderr(
+        "Error: https://not-github.com/owner/repo: no provider for host 'not-github.com'\n",
+    );
     temp_home_directory.close().unwrap();
 }
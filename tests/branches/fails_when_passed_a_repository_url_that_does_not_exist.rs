@@ -12,8 +12,9 @@ fn fails_when_passed_repository_url_that_does_not_exist() {
         .arg("https://github.com/owner/repo")
         .assert();
 
-    assert
-        .failure()
-        .stderr("Error: repository 'https://github.com/owner/repo' not found\n");
+    assert.failure().stderr(
+        "Error: https://github.com/owner/repo: \
+         repository 'https://github.com/owner/repo' not found\n",
+    );
     temp_home_directory.close().unwrap();
 }
@@ -7,8 +7,9 @@ fn fails_when_passed_repository_url_missing_owner() {
         .arg("https://github.com//repo")
         .assert();
 
-    assert
-        .failure()
-        .stderr("Error: failed to parse repository from 'https://github.com//repo'\n");
+    assert.failure().stderr(
+        "Error: https://github.com//repo: \
+         failed to parse repository from 'https://github.com//repo'\n",
+    );
     temp_home_directory.close().unwrap();
 }
@@ -7,8 +7,9 @@ fn fails_when_passed_repository_url_missing_repo_name() {
         .arg("https://github.com/owner")
         .assert();
 
-    assert
-        .failure()
-        .stderr("Error: failed to parse repository from 'https://github.com/owner'\n");
+    assert.failure().stderr(
+        "Error: https://github.com/owner: \
+         failed to parse repository from 'https://github.com/owner'\n",
+    );
     temp_home_directory.close().unwrap();
 }
@@ -0,0 +1,53 @@
+use crate::branches::branches_command;
+use crate::common::local_repository::LocalRepository;
+
+fn repository_with_merged_and_stale_branches(path: &std::path::Path) -> LocalRepository {
+    let repository = LocalRepository::bare(path, "main");
+    repository.commit(1, &[], "Old Author", "2000-01-01T00:00:00Z");
+    repository.commit(2, &[1], "New Author", "2999-01-01T00:00:00Z");
+    repository.commit(3, &[1], "New Author", "2999-01-02T00:00:00Z");
+    repository.branch("main", 2);
+    repository.branch("done", 1);
+    repository.packed_branches(&[("wip", 3)]);
+    repository
+}
+
+#[test]
+fn lists_merged_branches_in_local_repository() {
+    let temp_home_directory = assert_fs::TempDir::new().unwrap();
+    let temp_repository_directory = assert_fs::TempDir::new().unwrap();
+    let repository = repository_with_merged_and_stale_branches(temp_repository_directory.path());
+
+    let assert = branches_command(temp_home_directory.path())
+        .arg("merged")
+        .arg(repository.url())
+        .assert();
+
+    assert.success().stdout(format!(
+        "{0}: done (merged)\n{0}: wip (unmerged)\n",
+        repository.reported_path()
+    ));
+    temp_repository_directory.close().unwrap();
+    temp_home_directory.close().unwrap();
+}
+
+#[test]
+fn lists_stale_branches_in_local_repository() {
+    let temp_home_directory = assert_fs::TempDir::new().unwrap();
+    let temp_repository_directory = assert_fs::TempDir::new().unwrap();
+    let repository = repository_with_merged_and_stale_branches(temp_repository_directory.path());
+
+    let assert = branches_command(temp_home_directory.path())
+        .arg("stale")
+        .arg("--older-than")
+        .arg("90d")
+        .arg(repository.url())
+        .assert();
+
+    assert.success().stdout(format!(
+        "{}: done (last commit on 2000-01-01 by Old Author)\n",
+        repository.reported_path()
+    ));
+    temp_repository_directory.close().unwrap();
+    temp_home_directory.close().unwrap();
+}
@@ -0,0 +1,58 @@
+use crate::branches::{
+    branches_command, list_branches_body, mock_github_api_server_for_get_commit,
+    mock_github_api_server_for_get_repository, mock_github_api_server_for_successful_list_branches,
+};
+
+#[test]
+fn lists_stale_branches() {
+    let temp_home_directory = assert_fs::TempDir::new().unwrap();
+    let body_string = list_branches_body(&["old", "new"]);
+    let _mock =
+        mock_github_api_server_for_successful_list_branches("owner", "stale-repo", &body_string)
+            .create();
+    let _repository_mock =
+        mock_github_api_server_for_get_repository("owner", "stale-repo", "main").create();
+    let _old_commit_mock = mock_github_api_server_for_get_commit(
+        "owner",
+        "stale-repo",
+        "old-sha",
+        "Old Author",
+        "2000-01-01T00:00:00Z",
+    )
+    .create();
+    let _new_commit_mock = mock_github_api_server_for_get_commit(
+        "owner",
+        "stale-repo",
+        "new-sha",
+        "New Author",
+        "2999-01-01T00:00:00Z",
+    )
+    .create();
+
+    let assert = branches_command(temp_home_directory.path())
+        .arg("stale")
+        .arg("--older-than")
+        .arg("90d")
+        .arg("https://github.com/owner/stale-repo")
+        .assert();
+
+    assert.success().stdout(
+        "https://github.com/owner/stale-repo: old (last commit on 2000-01-01 by Old Author)\n",
+    );
+    temp_home_directory.close().unwrap();
+}
+
+#[test]
+fn fails_to_list_stale_branches_when_passed_an_invalid_age() {
+    let temp_home_directory = assert_fs::TempDir::new().unwrap();
+
+    let assert = branches_command(temp_home_directory.path())
+        .arg("stale")
+        .arg("--older-than")
+        .arg("ninety days")
+        .arg("https://github.com/owner/repo")
+        .assert();
+
+    assert.failure();
+    temp_home_directory.close().unwrap();
+}
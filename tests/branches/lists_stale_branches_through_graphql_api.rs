@@ -0,0 +1,60 @@
+use http_types::Method;
+use mockito::Matcher;
+
+use crate::branches::branches_command;
+
+fn branch_node(name: &str, committed_date: &str, author: &str) -> serde_json::Value {
+    serde_json::json!({
+        "name": name,
+        "branchProtectionRule": null,
+        "target": {
+            "oid": format!("{}-sha", name),
+            "committedDate": committed_date,
+            "author": { "name": author }
+        },
+        "associatedPullRequests": { "nodes": [] }
+    })
+}
+
+#[test]
+fn lists_stale_branches_through_graphql_api() {
+    let temp_home_directory = assert_fs::TempDir::new().unwrap();
+    let body = serde_json::json!({
+        "data": {
+            "repository": {
+                "url": "https://github.com/owner/stale-repo",
+                "defaultBranchRef": { "name": "main" },
+                "refs": {
+                    "pageInfo": { "hasNextPage": false, "endCursor": null },
+                    "nodes": [
+                        branch_node("new", "2999-01-01T00:00:00Z", "New Author"),
+                        branch_node("old", "2000-01-01T00:00:00Z", "Old Author")
+                    ]
+                }
+            }
+        }
+    });
+    let graphql_mock = mockito::mock(Method::Post.as_ref(), "/graphql")
+        .match_body(Matcher::PartialJson(serde_json::json!({
+            "variables": { "owner": "owner", "name": "stale-repo" }
+        })))
+        .with_body(body.to_string())
+        .with_status(200)
+        .expect(1)
+        .create();
+
+    let assert = branches_command(temp_home_directory.path())
+        .arg("stale")
+        .arg("--older-than")
+        .arg("90d")
+        .arg("--api")
+        .arg("graphql")
+        .arg("https://github.com/owner/stale-repo")
+        .assert();
+
+    assert.success().stdout(
+        "https://github.com/owner/stale-repo: old (last commit on 2000-01-01 by Old Author)\n",
+    );
+    graphql_mock.assert();
+    temp_home_directory.close().unwrap();
+}
@@ -3,19 +3,46 @@ use std::path::Path;
 use assert_cmd::Command;
 use http_types::headers::ACCEPT;
 use http_types::Method;
+use mockito::Matcher;
 
 use crate::common::test_command;
 
+mod counts_branches_across_multiple_pages;
+mod counts_branches_in_bitbucket_repository_across_multiple_pages;
+mod counts_branches_in_github_enterprise_and_github_repositories;
+mod counts_branches_in_github_repository_from_clone_url;
+mod counts_branches_in_local_bare_repository;
 mod counts_branches_in_multiple_github_repositories;
+mod counts_branches_in_private_gitea_repository;
 mod counts_branches_in_private_github_repository;
+mod counts_branches_in_private_gitlab_project;
+mod counts_branches_in_repositories_of_github_accounts;
+mod counts_branches_in_repositories_on_different_hosts;
+mod counts_branches_in_repository_detected_from_remote_of_clone;
 mod counts_branches_in_single_github_repository;
+mod counts_branches_once_for_duplicate_repository_urls;
+mod counts_branches_one_repository_at_a_time;
+mod counts_branches_with_credentials_stored_by_earlier_versions;
+mod excludes_protected_and_default_branches_from_reports;
 mod fails_to_find_private_repositories_when_not_authenticated;
 mod fails_when_app_home_environment_variable_is_not_set;
-mod fails_when_passed_a_malformed_repository_url;
+mod fails_when_github_rejects_authentication_token;
+mod fails_when_no_repository_url_is_passed_outside_a_clone;
+mod fails_when_passed_a_repository_url_on_unknown_host;
 mod fails_when_passed_a_repository_url_that_does_not_exist;
 mod fails_when_passed_repository_url_missing_owner;
 mod fails_when_passed_repository_url_missing_repo_name;
-mod returns_empty_results_when_not_passed_any_repository_urls;
+mod lists_merged_and_stale_branches_in_local_repository;
+mod lists_merged_branches;
+mod lists_stale_branches;
+mod lists_stale_branches_through_graphql_api;
+mod prints_branch_counts_as_json;
+mod prints_merged_branches_as_csv;
+mod prints_merged_branches_when_options_precede_report;
+mod reports_failed_repositories_ordered_by_url;
+mod reports_remaining_repositories_when_one_fails;
+mod reuses_cached_branches_when_github_reports_not_modified;
+mod stops_at_first_failing_repository_when_failing_fast;
 
 pub(crate) fn mock_github_api_server_for_successful_list_branches(
     owner: &str,
@@ -26,11 +53,171 @@ pub(crate) fn mock_github_api_server_for_successful_list_branches(
         Method::Get.as_ref(),
         format!("/repos/{}/{}/branches", owner, repo_name).as_str(),
     )
+    .match_query(Matcher::UrlEncoded("per_page".into(), "100".into()))
     .match_header(ACCEPT.as_str(), "application/vnd.github.v3+json")
     .with_body(body)
     .with_status(200)
 }
 
+pub(crate) fn mock_github_api_server_for_get_repository(
+    owner: &str,
+    repo_name: &str,
+    default_branch: &str,
+) -> mockito::Mock {
+    mockito::mock(
+        Method::Get.as_ref(),
+        format!("/repos/{}/{}", owner, repo_name).as_str(),
+    )
+    .match_header(ACCEPT.as_str(), "application/vnd.github.v3+json")
+    .with_body(serde_json::json!({ "default_branch": default_branch }).to_string())
+    .with_status(200)
+}
+
+pub(crate) fn mock_github_api_server_for_compare(
+    owner: &str,
+    repo_name: &str,
+    base: &str,
+    head: &str,
+    ahead_by: u32,
+) -> mockito::Mock {
+    mockito::mock(
+        Method::Get.as_ref(),
+        format!("/repos/{}/{}/compare/{}...{}", owner, repo_name, base, head).as_str(),
+    )
+    .match_header(ACCEPT.as_str(), "application/vnd.github.v3+json")
+    .with_body(serde_json::json!({ "ahead_by": ahead_by, "behind_by": 3 }).to_string())
+    .with_status(200)
+}
+
+pub(crate) fn mock_github_api_server_for_get_commit(
+    owner: &str,
+    repo_name: &str,
+    sha: &str,
+    author: &str,
+    date: &str,
+) -> mockito::Mock {
+    let body = serde_json::json!({
+        "sha": sha,
+        "commit": {
+            "author": {"name": author, "date": date},
+            "committer": {"name": author, "date": date}
+        }
+    });
+    mockito::mock(
+        Method::Get.as_ref(),
+        format!("/repos/{}/{}/commits/{}", owner, repo_name, sha).as_str(),
+    )
+    .match_header(ACCEPT.as_str(), "application/vnd.github.v3+json")
+    .with_body(body.to_string())
+    .with_status(200)
+}
+
+pub(crate) fn mock_github_api_server_for_list_owner_repositories(
+    owner_path: &str,
+    repository_urls: &[&str],
+) -> mockito::Mock {
+    let body = serde_json::json!(repository_urls
+        .iter()
+        .map(|url| serde_json::json!({ "html_url": url }))
+        .collect::<Vec<serde_json::Value>>());
+    mockito::mock(
+        Method::Get.as_ref(),
+        format!("/{}/repos", owner_path).as_str(),
+    )
+    .match_query(Matcher::UrlEncoded("per_page".into(), "100".into()))
+    .match_header(ACCEPT.as_str(), "application/vnd.github.v3+json")
+    .with_body(body.to_string())
+    .with_status(200)
+}
+
+pub(crate) fn mock_gitlab_api_server_for_successful_list_branches(
+    encoded_project_path: &str,
+    body: &str,
+) -> mockito::Mock {
+    mockito::mock(
+        Method::Get.as_ref(),
+        format!("/projects/{}/repository/branches", encoded_project_path).as_str(),
+    )
+    .match_query(Matcher::UrlEncoded("per_page".into(), "100".into()))
+    .with_body(body)
+    .with_status(200)
+}
+
+pub(crate) fn mock_gitlab_api_server_for_get_project(
+    encoded_project_path: &str,
+    default_branch: &str,
+) -> mockito::Mock {
+    mockito::mock(
+        Method::Get.as_ref(),
+        format!("/projects/{}", encoded_project_path).as_str(),
+    )
+    .with_body(serde_json::json!({ "default_branch": default_branch }).to_string())
+    .with_status(200)
+}
+
+pub(crate) fn mock_bitbucket_api_server_for_list_branches_page(
+    workspace: &str,
+    repo_slug: &str,
+    page: Option<&str>,
+    body: &str,
+) -> mockito::Mock {
+    let page_query = match page {
+        Some(page) => Matcher::AllOf(vec![
+            Matcher::UrlEncoded("pagelen".into(), "100".into()),
+            Matcher::UrlEncoded("page".into(), page.into()),
+        ]),
+        None => Matcher::Exact("pagelen=100".into()),
+    };
+    mockito::mock(
+        Method::Get.as_ref(),
+        format!("/repositories/{}/{}/refs/branches", workspace, repo_slug).as_str(),
+    )
+    .match_query(page_query)
+    .with_body(body)
+    .with_status(200)
+}
+
+pub(crate) fn mock_bitbucket_api_server_for_get_repository(
+    workspace: &str,
+    repo_slug: &str,
+    main_branch: &str,
+) -> mockito::Mock {
+    mockito::mock(
+        Method::Get.as_ref(),
+        format!("/repositories/{}/{}", workspace, repo_slug).as_str(),
+    )
+    .with_body(serde_json::json!({ "mainbranch": { "name": main_branch } }).to_string())
+    .with_status(200)
+}
+
+pub(crate) fn bitbucket_list_branches_body(branch_names: &[&str], next: Option<String>) -> String {
+    let values = branch_names
+        .iter()
+        .map(|name| serde_json::json!({"name": name, "target": {"hash": format!("{}-sha", name)}}))
+        .collect::<Vec<serde_json::Value>>();
+    match next {
+        Some(next) => serde_json::json!({ "values": values, "next": next }),
+        None => serde_json::json!({ "values": values }),
+    }
+    .to_string()
+}
+
+pub(crate) fn list_branches_body(branch_names: &[&str]) -> String {
+    serde_json::json!(branch_names
+        .iter()
+        .map(|name| serde_json::json!({"name": name, "commit": {"sha": format!("{}-sha", name)}}))
+        .collect::<Vec<serde_json::Value>>())
+    .to_string()
+}
+
+pub(crate) fn gitlab_list_branches_body(branch_names: &[&str]) -> String {
+    serde_json::json!(branch_names
+        .iter()
+        .map(|name| serde_json::json!({"name": name, "commit": {"id": format!("{}-sha", name)}}))
+        .collect::<Vec<serde_json::Value>>())
+    .to_string()
+}
+
 pub(crate) fn count_results_with_header<S: AsRef<str>>(count_results: S) -> String {
     count_results.as_ref().to_string()
 }
@@ -0,0 +1,27 @@
+use crate::branches::{
+    branches_command, list_branches_body, mock_github_api_server_for_get_repository,
+    mock_github_api_server_for_successful_list_branches,
+};
+
+#[test]
+fn prints_branch_counts_as_json() {
+    let temp_home_directory = assert_fs::TempDir::new().unwrap();
+    let body_string = list_branches_body(&["main", "feature"]);
+    let _branches_mock =
+        mock_github_api_server_for_successful_list_branches("owner", "json-repo", &body_string)
+            .create();
+    let _repository_mock =
+        mock_github_api_server_for_get_repository("owner", "json-repo", "main").create();
+
+    let assert = branches_command(temp_home_directory.path())
+        .arg("--output")
+        .arg("json")
+        .arg("https://github.com/owner/json-repo")
+        .assert();
+
+    assert.success().stdout(
+        "[\n  {\n    \"repository\": \"https://github.com/owner/json-repo\",\n    \
+         \"branch_count\": 2\n  }\n]\n",
+    );
+    temp_home_directory.close().unwrap();
+}
@@ -0,0 +1,33 @@
+use crate::branches::{
+    branches_command, list_branches_body, mock_github_api_server_for_compare,
+    mock_github_api_server_for_get_repository, mock_github_api_server_for_successful_list_branches,
+};
+
+#[test]
+fn prints_merged_branches_as_csv() {
+    let temp_home_directory = assert_fs::TempDir::new().unwrap();
+    let body_string = list_branches_body(&["main", "done", "wip"]);
+    let _branches_mock =
+        mock_github_api_server_for_successful_list_branches("owner", "csv-repo", &body_string)
+            .create();
+    let _repository_mock =
+        mock_github_api_server_for_get_repository("owner", "csv-repo", "main").create();
+    let _done_mock =
+        mock_github_api_server_for_compare("owner", "csv-repo", "main", "done", 0).create();
+    let _wip_mock =
+        mock_github_api_server_for_compare("owner", "csv-repo", "main", "wip", 2).create();
+
+    let assert = branches_command(temp_home_directory.path())
+        .arg("merged")
+        .arg("--output")
+        .arg("csv")
+        .arg("https://github.com/owner/csv-repo")
+        .assert();
+
+    assert.success().stdout(
+        "repository,branch,status\n\
+         https://github.com/owner/csv-repo,done,merged\n\
+         https://github.com/owner/csv-repo,wip,unmerged\n",
+    );
+    temp_home_directory.close().unwrap();
+}
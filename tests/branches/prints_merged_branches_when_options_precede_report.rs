@@ -0,0 +1,35 @@
+use crate::branches::{
+    branches_command, list_branches_body, mock_github_api_server_for_compare,
+    mock_github_api_server_for_get_repository, mock_github_api_server_for_successful_list_branches,
+};
+
+#[test]
+fn prints_merged_branches_when_options_precede_report() {
+    let temp_home_directory = assert_fs::TempDir::new().unwrap();
+    let body_string = list_branches_body(&["main", "done"]);
+    let _branches_mock = mock_github_api_server_for_successful_list_branches(
+        "owner",
+        "preceding-options-repo",
+        &body_string,
+    )
+    .create();
+    let _repository_mock =
+        mock_github_api_server_for_get_repository("owner", "preceding-options-repo", "main")
+            .create();
+    let _done_mock =
+        mock_github_api_server_for_compare("owner", "preceding-options-repo", "main", "done", 0)
+            .create();
+
+    let assert = branches_command(temp_home_directory.path())
+        .arg("--output")
+        .arg("csv")
+        .arg("https://github.com/owner/preceding-options-repo")
+        .arg("merged")
+        .assert();
+
+    assert.success().stdout(
+        "repository,branch,status\n\
+         https://github.com/owner/preceding-options-repo,done,merged\n",
+    );
+    temp_home_directory.close().unwrap();
+}
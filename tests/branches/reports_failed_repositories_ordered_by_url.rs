@@ -0,0 +1,32 @@
+use crate::branches::branches_command;
+use crate::common::mock_github_api_server_for_repository_not_found;
+
+#[test]
+fn reports_failed_repositories_ordered_by_url() {
+    let temp_home_directory = assert_fs::TempDir::new().unwrap();
+    let _first_missing_mock = mock_github_api_server_for_repository_not_found(
+        "owner",
+        "first-missing-repo",
+        "[{\"message\": \"Not Found\"}]",
+    )
+    .create();
+    let _second_missing_mock = mock_github_api_server_for_repository_not_found(
+        "owner",
+        "second-missing-repo",
+        "[{\"message\": \"Not Found\"}]",
+    )
+    .create();
+
+    let assert = branches_command(temp_home_directory.path())
+        .arg("https://github.com/owner/second-missing-repo")
+        .arg("https://github.com/owner/first-missing-repo")
+        .assert();
+
+    assert.code(2).stderr(
+        "Error: https://github.com/owner/first-missing-repo: \
+         repository 'https://github.com/owner/first-missing-repo' not found\n\
+         Error: https://github.com/owner/second-missing-repo: \
+         repository 'https://github.com/owner/second-missing-repo' not found\n",
+    );
+    temp_home_directory.close().unwrap();
+}
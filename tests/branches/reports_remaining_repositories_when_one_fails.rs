@@ -0,0 +1,38 @@
+use crate::branches::{
+    branches_command, count_results_with_header, list_branches_body,
+    mock_github_api_server_for_get_repository, mock_github_api_server_for_successful_list_branches,
+};
+use crate::common::mock_github_api_server_for_repository_not_found;
+
+#[test]
+fn reports_remaining_repositories_when_one_fails() {
+    let temp_home_directory = assert_fs::TempDir::new().unwrap();
+    let body_string = list_branches_body(&["main"]);
+    let _branches_mock =
+        mock_github_api_server_for_successful_list_branches("owner", "found-repo", &body_string)
+            .create();
+    let _repository_mock =
+        mock_github_api_server_for_get_repository("owner", "found-repo", "main").create();
+    let _missing_mock = mock_github_api_server_for_repository_not_found(
+        "owner",
+        "missing-repo",
+        "[{\"message\": \"Not Found\"}]",
+    )
+    .create();
+
+    let assert = branches_command(temp_home_directory.path())
+        .arg("https://github.com/owner/found-repo")
+        .arg("https://github.com/owner/missing-repo")
+        .assert();
+
+    assert
+        .code(2)
+        .stdout(count_results_with_header(
+            "https://github.com/owner/found-repo: 1\n",
+        ))
+        .stderr(
+            "Error: https://github.com/owner/missing-repo: \
+             repository 'https://github.com/owner/missing-repo' not found\n",
+        );
+    temp_home_directory.close().unwrap();
+}
@@ -0,0 +1,40 @@
+use mockito::Matcher;
+
+use crate::branches::{
+    branches_command, count_results_with_header, list_branches_body,
+    mock_github_api_server_for_get_repository, mock_github_api_server_for_successful_list_branches,
+};
+
+#[test]
+fn reuses_cached_branches_when_github_reports_not_modified() {
+    let temp_home_directory = assert_fs::TempDir::new().unwrap();
+    let body_string = list_branches_body(&["main", "feature"]);
+    let first_branches_mock =
+        mock_github_api_server_for_successful_list_branches("owner", "repo", &body_string)
+            .match_header("if-none-match", Matcher::Missing)
+            .with_header("etag", "\"branches-v1\"")
+            .expect(1)
+            .create();
+    let not_modified_branches_mock =
+        mock_github_api_server_for_successful_list_branches("owner", "repo", "")
+            .match_header("if-none-match", "\"branches-v1\"")
+            .with_status(304)
+            .expect(1)
+            .create();
+    let _repository_mock =
+        mock_github_api_server_for_get_repository("owner", "repo", "main").create();
+
+    for _ in 0..2 {
+        branches_command(temp_home_directory.path())
+            .arg("https://github.com/owner/repo")
+            .assert()
+            .success()
+            .stdout(count_results_with_header(
+                "https://github.com/owner/repo: 2\n",
+            ));
+    }
+
+    first_branches_mock.assert();
+    not_modified_branches_mock.assert();
+    temp_home_directory.close().unwrap();
+}
@@ -0,0 +1,37 @@
+use crate::branches::{
+    branches_command, list_branches_body, mock_github_api_server_for_get_repository,
+    mock_github_api_server_for_successful_list_branches,
+};
+use crate::common::mock_github_api_server_for_repository_not_found;
+
+#[test]
+fn stops_at_first_failing_repository_when_failing_fast() {
+    let temp_home_directory = assert_fs::TempDir::new().unwrap();
+    let body_string = list_branches_body(&["main"]);
+    let _branches_mock = mock_github_api_server_for_successful_list_branches(
+        "owner",
+        "fail-fast-found-repo",
+        &body_string,
+    )
+    .create();
+    let _repository_mock =
+        mock_github_api_server_for_get_repository("owner", "fail-fast-found-repo", "main").create();
+    let _missing_mock = mock_github_api_server_for_repository_not_found(
+        "owner",
+        "fail-fast-missing-repo",
+        "[{\"message\": \"Not Found\"}]",
+    )
+    .create();
+
+    let assert = branches_command(temp_home_directory.path())
+        .arg("--fail-fast")
+        .arg("https://github.com/owner/fail-fast-found-repo")
+        .arg("https://github.com/owner/fail-fast-missing-repo")
+        .assert();
+
+    assert
+        .code(1)
+        .stdout("")
+        .stderr("Error: repository 'https://github.com/owner/fail-fast-missing-repo' not found\n");
+    temp_home_directory.close().unwrap();
+}
@@ -0,0 +1,147 @@
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use chrono::DateTime;
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
+
+/// A bare repository written object by object, so tests need neither git nor a network.
+pub(crate) struct LocalRepository {
+    path: PathBuf,
+}
+
+impl LocalRepository {
+    pub(crate) fn bare(path: &Path, default_branch: &str) -> Self {
+        std::fs::create_dir_all(path.join("objects")).unwrap();
+        std::fs::create_dir_all(path.join("refs/heads")).unwrap();
+        std::fs::write(
+            path.join("HEAD"),
+            format!("ref: refs/heads/{}\n", default_branch),
+        )
+        .unwrap();
+        LocalRepository {
+            path: path.to_path_buf(),
+        }
+    }
+
+    /// A clone in `path` with `checked_out` as the current branch and `origin` as its remote.
+    pub(crate) fn working_copy(path: &Path, checked_out: &str) -> Self {
+        let repository = LocalRepository::bare(&path.join(".git"), checked_out);
+        std::fs::write(
+            repository.path.join("config"),
+            "[remote \"origin\"]\n\
+             \turl = https://github.com/owner/repo\n\
+             \tfetch = +refs/heads/*:refs/remotes/origin/*\n",
+        )
+        .unwrap();
+        repository
+    }
+
+    pub(crate) fn url(&self) -> String {
+        format!("file://{}", self.path.display())
+    }
+
+    /// The path results are reported under, whichever way the repository was passed.
+    pub(crate) fn reported_path(&self) -> String {
+        self.path.display().to_string()
+    }
+
+    /// Writes a loose commit object named after `number` and returns that name.
+    pub(crate) fn commit(&self, number: u32, parents: &[u32], author: &str, date: &str) -> String {
+        let sha = sha(number);
+        let timestamp = DateTime::parse_from_rfc3339(date).unwrap().timestamp();
+        let mut content = "tree 4b825dc642cb6eb9a060e54bf8d69288fbee4904\n".to_string();
+        parents
+            .iter()
+            .for_each(|parent| content.push_str(&format!("parent {}\n", self::sha(*parent))));
+        content.push_str(&format!(
+            "author {0} <author@example.com> {1} +0000\n\
+             committer {0} <author@example.com> {1} +0000\n\
+             \n\
+             Commit {2}\n",
+            author, timestamp, number
+        ));
+
+        let mut encoder = ZlibEncoder::new(vec![], Compression::default());
+        write!(encoder, "commit {}\0", content.len()).unwrap();
+        encoder.write_all(content.as_bytes()).unwrap();
+        let object_path = self.path.join("objects").join(&sha[..2]).join(&sha[2..]);
+        std::fs::create_dir_all(object_path.parent().unwrap()).unwrap();
+        std::fs::write(object_path, encoder.finish().unwrap()).unwrap();
+        sha
+    }
+
+    pub(crate) fn branch(&self, name: &str, commit: u32) {
+        std::fs::write(
+            self.path.join("refs/heads").join(name),
+            format!("{}\n", sha(commit)),
+        )
+        .unwrap();
+    }
+
+    pub(crate) fn remote_branch(&self, remote: &str, name: &str, commit: u32) {
+        let path = self.path.join("refs/remotes").join(remote).join(name);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, format!("{}\n", sha(commit))).unwrap();
+    }
+
+    /// Points `refs/remotes/<remote>/HEAD` at the remote's default branch, as `git clone` does.
+    pub(crate) fn remote_head(&self, remote: &str, name: &str) {
+        let path = self.path.join("refs/remotes").join(remote);
+        std::fs::create_dir_all(&path).unwrap();
+        std::fs::write(
+            path.join("HEAD"),
+            format!("ref: refs/remotes/{}/{}\n", remote, name),
+        )
+        .unwrap();
+    }
+
+    pub(crate) fn remote(&self, name: &str, url: &str) {
+        let mut config = std::fs::read_to_string(self.path.join("config")).unwrap_or_default();
+        config.push_str(&format!(
+            "[remote \"{0}\"]\n\turl = {1}\n\tfetch = +refs/heads/*:refs/remotes/{0}/*\n",
+            name, url
+        ));
+        std::fs::write(self.path.join("config"), config).unwrap();
+    }
+
+    /// Sets up `branch` to track the branch of the same name on `remote`.
+    pub(crate) fn track(&self, branch: &str, remote: &str) {
+        let mut config = std::fs::read_to_string(self.path.join("config")).unwrap_or_default();
+        config.push_str(&format!(
+            "[branch \"{0}\"]\n\tremote = {1}\n\tmerge = refs/heads/{0}\n",
+            branch, remote
+        ));
+        std::fs::write(self.path.join("config"), config).unwrap();
+    }
+
+    pub(crate) fn packed_branches(&self, branches: &[(&str, u32)]) {
+        let mut content = "# pack-refs with: peeled fully-peeled sorted \n".to_string();
+        branches.iter().for_each(|(name, commit)| {
+            content.push_str(&format!("{} refs/heads/{}\n", sha(*commit), name));
+        });
+        std::fs::write(self.path.join("packed-refs"), content).unwrap();
+    }
+
+    pub(crate) fn branch_names(&self) -> Vec<String> {
+        let packed_refs =
+            std::fs::read_to_string(self.path.join("packed-refs")).unwrap_or_default();
+        let mut names: Vec<String> = std::fs::read_dir(self.path.join("refs/heads"))
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+            .chain(
+                packed_refs
+                    .lines()
+                    .filter_map(|line| line.split_once(" refs/heads/"))
+                    .map(|(_, name)| name.to_string()),
+            )
+            .collect();
+        names.sort();
+        names.dedup();
+        names
+    }
+}
+
+fn sha(number: u32) -> String {
+    format!("{:040x}", number)
+}
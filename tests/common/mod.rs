@@ -1,20 +1,33 @@
 use std::path::Path;
 
 use assert_cmd::output::OutputResult;
+use http_types::auth::BasicAuth;
 use http_types::headers::{ACCEPT, AUTHORIZATION};
 use http_types::Method;
+use mockito::Matcher;
 
 use crate::authenticate::{
-    authenticate_command, mock_github_api_server_for_successful_authentication_check,
+    authenticate_command, mock_bitbucket_api_server_for_successful_authentication_check,
+    mock_gitea_api_server_for_successful_authentication_check,
+    mock_github_api_server_for_successful_authentication_check,
+    mock_github_enterprise_api_server_for_successful_authentication_check,
+    mock_gitlab_api_server_for_successful_authentication_check,
 };
 
+pub(crate) mod local_repository;
+
 pub(crate) fn test_command(temp_home_directory: &Path) -> assert_cmd::Command {
     let mut cmd = assert_cmd::Command::cargo_bin("tidy-repo")
         .expect("Could not run cargo binary 'tidy-repo'");
-    cmd.env(GITHUB_API_URL, mockito::server_url()).env(
-        APP_HOME_ENVIRONMENT_VARIABLE,
-        temp_home_directory.as_os_str(),
-    );
+    cmd.env(GITHUB_API_URL, mockito::server_url())
+        .env(GITLAB_API_URL, mockito::server_url())
+        .env(BITBUCKET_API_URL, mockito::server_url())
+        .env(
+            APP_HOME_ENVIRONMENT_VARIABLE,
+            temp_home_directory.as_os_str(),
+        )
+        .env("HOME", temp_home_directory.as_os_str())
+        .env_remove("XDG_CONFIG_HOME");
     cmd
 }
 
@@ -41,6 +54,90 @@ pub(crate) fn authenticate_session_with_github_with_token(
         .ok()
 }
 
+pub(crate) fn configure_github_enterprise_host(app_home_directory_path: &Path) {
+    std::fs::write(
+        app_home_directory_path.join("config.yml"),
+        format!(
+            "github_enterprise_hosts:\n  - host: {}\n    api_url: {}{}\n",
+            GITHUB_ENTERPRISE_HOST,
+            mockito::server_url(),
+            GITHUB_ENTERPRISE_API_PATH
+        ),
+    )
+    .unwrap();
+}
+
+pub(crate) fn authenticate_session_with_github_enterprise(
+    app_home_directory_path: &Path,
+) -> OutputResult {
+    let _mock = mock_github_enterprise_api_server_for_successful_authentication_check(
+        GITHUB_ENTERPRISE_OAUTH_TOKEN,
+    )
+    .create();
+    authenticate_command(app_home_directory_path)
+        .arg("github")
+        .arg("--token")
+        .arg(GITHUB_ENTERPRISE_OAUTH_TOKEN)
+        .arg("--host")
+        .arg(GITHUB_ENTERPRISE_HOST)
+        .ok()
+}
+
+pub(crate) fn configure_gitea_host(app_home_directory_path: &Path) {
+    std::fs::write(
+        app_home_directory_path.join("config.yml"),
+        format!(
+            "gitea_hosts:\n  - host: {}\n    api_url: {}{}\n",
+            GITEA_HOST,
+            mockito::server_url(),
+            GITEA_API_PATH
+        ),
+    )
+    .unwrap();
+}
+
+pub(crate) fn authenticate_session_with_gitea(app_home_directory_path: &Path) -> OutputResult {
+    let _mock =
+        mock_gitea_api_server_for_successful_authentication_check(GITEA_ACCESS_TOKEN).create();
+    authenticate_command(app_home_directory_path)
+        .arg("gitea")
+        .arg("--token")
+        .arg(GITEA_ACCESS_TOKEN)
+        .arg("--host")
+        .arg(GITEA_HOST)
+        .ok()
+}
+
+pub(crate) fn authenticate_session_with_gitlab(app_home_directory_path: &Path) -> OutputResult {
+    let _mock =
+        mock_gitlab_api_server_for_successful_authentication_check(GITLAB_PRIVATE_TOKEN).create();
+    authenticate_command(app_home_directory_path)
+        .arg("gitlab")
+        .arg("--token")
+        .arg(GITLAB_PRIVATE_TOKEN)
+        .ok()
+}
+
+pub(crate) fn authenticate_session_with_bitbucket(app_home_directory_path: &Path) -> OutputResult {
+    let _mock = mock_bitbucket_api_server_for_successful_authentication_check().create();
+    authenticate_command(app_home_directory_path)
+        .arg("bitbucket")
+        .arg("--username")
+        .arg(BITBUCKET_USERNAME)
+        .arg("--app-password")
+        .arg(BITBUCKET_APP_PASSWORD)
+        .ok()
+}
+
+pub(crate) fn require_bitbucket_auth_for_mock(mock: mockito::Mock) -> mockito::Mock {
+    mock.match_header(
+        AUTHORIZATION.as_str(),
+        BasicAuth::new(BITBUCKET_USERNAME, BITBUCKET_APP_PASSWORD)
+            .value()
+            .as_str(),
+    )
+}
+
 pub(crate) fn mock_github_api_server_for_repository_not_found(
     owner: &str,
     repo_name: &str,
@@ -50,11 +147,23 @@ pub(crate) fn mock_github_api_server_for_repository_not_found(
         Method::Get.as_ref(),
         format!("/repos/{}/{}/branches", owner, repo_name).as_str(),
     )
+    .match_query(Matcher::UrlEncoded("per_page".into(), "100".into()))
     .match_header(ACCEPT.as_str(), "application/vnd.github.v3+json")
     .with_body(body)
     .with_status(404)
 }
 
 pub const GITHUB_OAUTH_TOKEN: &str = "OAUTH-TOKEN";
+pub const GITHUB_ENTERPRISE_HOST: &str = "github.corp.example";
+pub const GITHUB_ENTERPRISE_API_PATH: &str = "/api/v3";
+pub const GITHUB_ENTERPRISE_OAUTH_TOKEN: &str = "ENTERPRISE-OAUTH-TOKEN";
+pub const GITEA_HOST: &str = "gitea.example.com";
+pub const GITEA_API_PATH: &str = "/api/v1";
+pub const GITEA_ACCESS_TOKEN: &str = "GITEA-ACCESS-TOKEN";
+pub const GITLAB_PRIVATE_TOKEN: &str = "GITLAB-PRIVATE-TOKEN";
+pub const BITBUCKET_USERNAME: &str = "bitbucket-user";
+pub const BITBUCKET_APP_PASSWORD: &str = "BITBUCKET-APP-PASSWORD";
 pub const GITHUB_API_URL: &str = "TIDY_REPO_GITHUB_API_BASE_URL";
+pub const GITLAB_API_URL: &str = "TIDY_REPO_GITLAB_API_BASE_URL";
+pub const BITBUCKET_API_URL: &str = "TIDY_REPO_BITBUCKET_API_BASE_URL";
 pub const APP_HOME_ENVIRONMENT_VARIABLE: &str = "TIDY_REPO_HOME";
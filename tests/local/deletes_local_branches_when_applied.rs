@@ -0,0 +1,31 @@
+use crate::common::local_repository::LocalRepository;
+use crate::local::local_command;
+
+#[test]
+fn deletes_local_branches_whose_upstream_is_gone_or_that_are_merged_when_applied() {
+    let temp_home_directory = assert_fs::TempDir::new().unwrap();
+    let temp_working_copy_directory = assert_fs::TempDir::new().unwrap();
+    let repository = LocalRepository::working_copy(temp_working_copy_directory.path(), "main");
+    repository.commit(1, &[], "Author", "2020-01-01T00:00:00Z");
+    repository.commit(2, &[1], "Author", "2020-01-02T00:00:00Z");
+    repository.commit(3, &[1], "Author", "2020-01-03T00:00:00Z");
+    repository.branch("main", 2);
+    repository.packed_branches(&[("done", 1), ("gone", 3), ("wip", 3)]);
+    repository.remote_branch("origin", "wip", 3);
+    repository.track("gone", "origin");
+    repository.track("wip", "origin");
+
+    let assert = local_command(
+        temp_home_directory.path(),
+        temp_working_copy_directory.path(),
+    )
+    .arg("--apply")
+    .assert();
+
+    assert
+        .success()
+        .stdout("deleted done (merged)\ndeleted gone (upstream gone)\n");
+    assert_eq!(repository.branch_names(), vec!["main", "wip"]);
+    temp_working_copy_directory.close().unwrap();
+    temp_home_directory.close().unwrap();
+}
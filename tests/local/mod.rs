@@ -0,0 +1,14 @@
+use std::path::Path;
+
+use assert_cmd::Command;
+
+use crate::common::test_command;
+
+mod deletes_local_branches_when_applied;
+mod prints_local_cleanup_plan_without_deleting_by_default;
+
+pub(crate) fn local_command(temp_home_directory: &Path, working_copy_directory: &Path) -> Command {
+    let mut cmd = test_command(temp_home_directory);
+    cmd.current_dir(working_copy_directory).arg("local");
+    cmd
+}
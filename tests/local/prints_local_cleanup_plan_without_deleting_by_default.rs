@@ -0,0 +1,38 @@
+use crate::common::local_repository::LocalRepository;
+use crate::local::local_command;
+
+#[test]
+fn prints_local_cleanup_plan_without_deleting_by_default() {
+    let temp_home_directory = assert_fs::TempDir::new().unwrap();
+    let temp_working_copy_directory = assert_fs::TempDir::new().unwrap();
+    let repository = LocalRepository::working_copy(temp_working_copy_directory.path(), "feature");
+    repository.commit(1, &[], "Author", "2020-01-01T00:00:00Z");
+    repository.commit(2, &[1], "Author", "2020-01-02T00:00:00Z");
+    repository.commit(3, &[2], "Author", "2020-01-03T00:00:00Z");
+    repository.branch("main", 2);
+    repository.branch("done", 1);
+    repository.branch("gone", 3);
+    repository.branch("wip", 3);
+    repository.branch("feature", 1);
+    repository.remote_head("origin", "main");
+    repository.remote_branch("origin", "main", 2);
+    repository.remote_branch("origin", "wip", 3);
+    repository.track("gone", "origin");
+    repository.track("wip", "origin");
+
+    let assert = local_command(
+        temp_home_directory.path(),
+        temp_working_copy_directory.path(),
+    )
+    .assert();
+
+    assert
+        .success()
+        .stdout("would delete done (merged)\nwould delete gone (upstream gone)\n");
+    assert_eq!(
+        repository.branch_names(),
+        vec!["done", "feature", "gone", "main", "wip"]
+    );
+    temp_working_copy_directory.close().unwrap();
+    temp_home_directory.close().unwrap();
+}
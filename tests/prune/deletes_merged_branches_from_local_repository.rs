@@ -0,0 +1,29 @@
+use crate::common::local_repository::LocalRepository;
+use crate::prune::prune_command;
+
+#[test]
+fn deletes_merged_loose_and_packed_branches_from_local_repository() {
+    let temp_home_directory = assert_fs::TempDir::new().unwrap();
+    let temp_repository_directory = assert_fs::TempDir::new().unwrap();
+    let repository = LocalRepository::bare(temp_repository_directory.path(), "main");
+    repository.commit(1, &[], "Author", "2020-01-01T00:00:00Z");
+    repository.commit(2, &[1], "Author", "2020-01-02T00:00:00Z");
+    repository.commit(3, &[1], "Author", "2020-01-03T00:00:00Z");
+    repository.branch("main", 2);
+    repository.branch("done", 1);
+    repository.packed_branches(&[("released", 1), ("wip", 3)]);
+
+    let assert = prune_command(temp_home_directory.path())
+        .arg("--merged")
+        .arg("--apply")
+        .arg(repository.url())
+        .assert();
+
+    assert.success().stdout(format!(
+        "deleted {0}: done\ndeleted {0}: released\n",
+        repository.reported_path()
+    ));
+    assert_eq!(repository.branch_names(), vec!["main", "wip"]);
+    temp_repository_directory.close().unwrap();
+    temp_home_directory.close().unwrap();
+}
@@ -0,0 +1,34 @@
+use crate::branches::{
+    list_branches_body, mock_github_api_server_for_compare,
+    mock_github_api_server_for_get_repository, mock_github_api_server_for_successful_list_branches,
+};
+use crate::prune::{mock_github_api_server_for_delete_branch, prune_command};
+
+#[test]
+fn deletes_merged_branches_when_applied() {
+    let temp_home_directory = assert_fs::TempDir::new().unwrap();
+    let body_string = list_branches_body(&["main", "done", "wip"]);
+    let _branches_mock =
+        mock_github_api_server_for_successful_list_branches("owner", "apply-repo", &body_string)
+            .create();
+    let _repository_mock =
+        mock_github_api_server_for_get_repository("owner", "apply-repo", "main").create();
+    let _done_mock =
+        mock_github_api_server_for_compare("owner", "apply-repo", "main", "done", 0).create();
+    let _wip_mock =
+        mock_github_api_server_for_compare("owner", "apply-repo", "main", "wip", 2).create();
+    let delete_mock =
+        mock_github_api_server_for_delete_branch("owner", "apply-repo", "done").create();
+
+    let assert = prune_command(temp_home_directory.path())
+        .arg("--merged")
+        .arg("--apply")
+        .arg("https://github.com/owner/apply-repo")
+        .assert();
+
+    assert
+        .success()
+        .stdout("deleted https://github.com/owner/apply-repo: done\n");
+    delete_mock.assert();
+    temp_home_directory.close().unwrap();
+}
@@ -0,0 +1,46 @@
+use crate::branches::{
+    list_branches_body, mock_github_api_server_for_compare,
+    mock_github_api_server_for_get_repository, mock_github_api_server_for_successful_list_branches,
+};
+use crate::prune::{
+    mock_github_api_server_for_delete_branch, mock_github_api_server_for_rejected_delete_branch,
+    prune_command,
+};
+
+#[test]
+fn deletes_remaining_branches_when_one_cannot_be_deleted() {
+    let temp_home_directory = assert_fs::TempDir::new().unwrap();
+    let body_string = list_branches_body(&["main", "done", "locked"]);
+    let _branches_mock =
+        mock_github_api_server_for_successful_list_branches("owner", "locked-repo", &body_string)
+            .create();
+    let _repository_mock =
+        mock_github_api_server_for_get_repository("owner", "locked-repo", "main").create();
+    let _done_mock =
+        mock_github_api_server_for_compare("owner", "locked-repo", "main", "done", 0).create();
+    let _locked_mock =
+        mock_github_api_server_for_compare("owner", "locked-repo", "main", "locked", 0).create();
+    let delete_mock =
+        mock_github_api_server_for_delete_branch("owner", "locked-repo", "done").create();
+    let rejected_delete_mock =
+        mock_github_api_server_for_rejected_delete_branch("owner", "locked-repo", "locked")
+            .create();
+
+    let assert = prune_command(temp_home_directory.path())
+        .arg("--merged")
+        .arg("--apply")
+        .arg("https://github.com/owner/locked-repo")
+        .assert();
+
+    assert
+        .code(2)
+        .stdout("deleted https://github.com/owner/locked-repo: done\n")
+        .stderr(
+            "Error: https://github.com/owner/locked-repo: locked: \
+             GitHub client error occurred (branch 'locked' in repository \
+             'https://github.com/owner/locked-repo' could not be deleted)\n",
+        );
+    delete_mock.assert();
+    rejected_delete_mock.assert();
+    temp_home_directory.close().unwrap();
+}
@@ -0,0 +1,39 @@
+use std::path::Path;
+
+use assert_cmd::Command;
+use http_types::headers::ACCEPT;
+use http_types::Method;
+
+use crate::common::test_command;
+
+mod deletes_merged_branches_from_local_repository;
+mod deletes_merged_branches_when_applied;
+mod deletes_remaining_branches_when_one_cannot_be_deleted;
+mod prints_plan_without_deleting_by_default;
+
+pub(crate) fn mock_github_api_server_for_delete_branch(
+    owner: &str,
+    repo_name: &str,
+    branch: &str,
+) -> mockito::Mock {
+    mockito::mock(
+        Method::Delete.as_ref(),
+        format!("/repos/{}/{}/git/refs/heads/{}", owner, repo_name, branch).as_str(),
+    )
+    .match_header(ACCEPT.as_str(), "application/vnd.github.v3+json")
+    .with_status(204)
+}
+
+pub(crate) fn mock_github_api_server_for_rejected_delete_branch(
+    owner: &str,
+    repo_name: &str,
+    branch: &str,
+) -> mockito::Mock {
+    mock_github_api_server_for_delete_branch(owner, repo_name, branch).with_status(422)
+}
+
+pub(crate) fn prune_command(temp_home_directory: &Path) -> Command {
+    let mut cmd = test_command(temp_home_directory);
+    cmd.arg("prune");
+    cmd
+}
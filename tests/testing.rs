@@ -2,3 +2,5 @@ mod authenticate;
 mod basic;
 mod branches;
 pub(crate) mod common;
+mod local;
+mod prune;